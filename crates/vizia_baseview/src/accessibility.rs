@@ -0,0 +1,196 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use accesskit::{Action, ActionHandler, ActionRequest, Tree, TreeUpdate};
+use vizia_core::backend::*;
+use vizia_core::prelude::*;
+
+/// Exposes the accessibility tree of a window to the assistive technologies of the platform, such as screen readers.
+///
+/// Adapters are created by the closure passed to
+/// [`Application::accessibility_adapter`](crate::Application::accessibility_adapter) when the window is opened, and
+/// usually wrap the accesskit adapter for the platform. Action requests from assistive technologies are sent back to
+/// the application with the [`ActionRequestSender`] passed to that closure.
+pub trait AccessibilityAdapter {
+    /// Updates the tree exposed to assistive technologies.
+    ///
+    /// The first update contains the complete tree, and later updates only contain the nodes which changed.
+    fn update(&mut self, update: TreeUpdate);
+}
+
+/// Sends action requests from assistive technologies to the application.
+///
+/// The sender can be cloned and used from any thread, and implements [`ActionHandler`] so that it can be passed
+/// directly to accesskit adapters.
+#[derive(Clone)]
+pub struct ActionRequestSender(Sender<ActionRequest>);
+
+impl ActionRequestSender {
+    /// Sends an action request, which is handled by the application at the start of the next frame.
+    pub fn send(&self, request: ActionRequest) {
+        // The application may already have been closed, in which case the request is dropped.
+        let _ = self.0.send(request);
+    }
+}
+
+impl ActionHandler for ActionRequestSender {
+    fn do_action(&mut self, request: ActionRequest) {
+        self.send(request);
+    }
+}
+
+/// The accessibility state of a window, which forwards tree updates to an adapter and routes action requests back to
+/// the views they target.
+pub(crate) struct Accessibility {
+    adapter: Box<dyn AccessibilityAdapter>,
+    action_requests: Receiver<ActionRequest>,
+    initialized: bool,
+}
+
+impl Accessibility {
+    pub fn new(
+        create_adapter: impl FnOnce(ActionRequestSender) -> Box<dyn AccessibilityAdapter>,
+    ) -> Self {
+        let (sender, action_requests) = mpsc::channel();
+
+        Self {
+            adapter: create_adapter(ActionRequestSender(sender)),
+            action_requests,
+            initialized: false,
+        }
+    }
+
+    /// Sends the changes to the accessibility tree since the last call to the adapter.
+    pub fn process_tree_updates(&mut self, cx: &mut BackendContext) {
+        if !self.initialized {
+            self.initialized = true;
+
+            // Update every view so that the first update describes the complete tree.
+            let entities = cx.0.tree.into_iter().collect::<Vec<_>>();
+            for entity in entities {
+                cx.style().needs_access_update(entity);
+            }
+
+            let mut initial_tree = TreeUpdate {
+                nodes: Vec::new(),
+                tree: Some(Tree::new(Entity::root().accesskit_id())),
+                focus: cx.focused().accesskit_id(),
+            };
+
+            cx.process_tree_updates(|tree_updates| {
+                for update in tree_updates.iter_mut().filter_map(Option::take) {
+                    initial_tree.nodes.extend(update.nodes);
+                    initial_tree.focus = update.focus;
+                }
+            });
+
+            self.adapter.update(initial_tree);
+            return;
+        }
+
+        let adapter = &mut self.adapter;
+        cx.process_tree_updates(|tree_updates| {
+            for update in tree_updates.iter_mut().filter_map(Option::take) {
+                adapter.update(update);
+            }
+        });
+    }
+
+    /// Emits the action requests received since the last call to the views they target.
+    pub fn handle_action_requests(&mut self, cx: &mut BackendContext) {
+        for request in self.action_requests.try_iter() {
+            if request.action == Action::ScrollIntoView {
+                continue;
+            }
+
+            let Some(entity) =
+                cx.0.tree.into_iter().find(|entity| entity.accesskit_id() == request.target)
+            else {
+                continue;
+            };
+
+            // Handle focus action from screen reader
+            if request.action == Action::Focus {
+                cx.with_current(entity, |cx| cx.focus());
+            }
+
+            cx.send_event(Event::new(WindowEvent::ActionRequest(request)).direct(entity));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use vizia_core::events::EventManager;
+
+    #[derive(Clone, Default)]
+    struct InMemoryAdapter {
+        updates: Arc<Mutex<Vec<TreeUpdate>>>,
+    }
+
+    impl AccessibilityAdapter for InMemoryAdapter {
+        fn update(&mut self, update: TreeUpdate) {
+            self.updates.lock().unwrap().push(update);
+        }
+    }
+
+    fn accessibility(
+        cx: &mut BackendContext,
+    ) -> (Accessibility, InMemoryAdapter, ActionRequestSender) {
+        let adapter = InMemoryAdapter::default();
+        let mut sender = None;
+        let mut accessibility = Accessibility::new(|actions| {
+            sender = Some(actions);
+            Box::new(adapter.clone())
+        });
+        accessibility.process_tree_updates(cx);
+
+        (accessibility, adapter, sender.unwrap())
+    }
+
+    #[test]
+    fn first_update_contains_the_complete_tree() {
+        let mut cx = BackendContext::new(Context::new());
+        let button = Button::new(cx.context(), |cx| Label::new(cx, "Press")).entity();
+
+        let (mut accessibility, adapter, _) = accessibility(&mut cx);
+
+        {
+            let updates = adapter.updates.lock().unwrap();
+            assert_eq!(updates.len(), 1);
+            assert!(updates[0].tree.is_some());
+            assert!(updates[0].nodes.iter().any(|(node_id, _)| *node_id == button.accesskit_id()));
+        }
+
+        // Later updates only contain the views which changed.
+        cx.style().needs_access_update(button);
+        accessibility.process_tree_updates(&mut cx);
+
+        let updates = adapter.updates.lock().unwrap();
+        assert_eq!(updates.len(), 2);
+        assert!(updates[1].tree.is_none());
+        assert!(updates[1].nodes.iter().any(|(node_id, _)| *node_id == button.accesskit_id()));
+    }
+
+    #[test]
+    fn action_requests_are_routed_to_their_target() {
+        let mut cx = BackendContext::new(Context::new());
+        let button =
+            Button::new(cx.context(), |cx| Label::new(cx, "Press")).navigable(true).entity();
+
+        let (mut accessibility, _, sender) = accessibility(&mut cx);
+
+        sender.send(ActionRequest {
+            action: Action::Focus,
+            target: button.accesskit_id(),
+            data: None,
+        });
+        accessibility.handle_action_requests(&mut cx);
+
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx.context()) {}
+
+        assert_eq!(cx.focused(), button);
+    }
+}
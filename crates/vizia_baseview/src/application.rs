@@ -1,13 +1,16 @@
-use crate::window::create_surface;
-use crate::window::ViziaWindow;
-use baseview::{Window, WindowHandle, WindowScalePolicy};
-use gl_rs as gl;
-use gl_rs::types::GLint;
+use crate::sub_window::{Frame, SubWindow, SubWindowState};
+use crate::window::{create_surface, framebuffer_info, window_open_options, ViziaWindow, Window};
+use baseview::{WindowHandle, WindowScalePolicy};
 use raw_window_handle::HasRawWindowHandle;
-use skia_safe::gpu::gl::FramebufferInfo;
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
 use vizia_core::events::EventManager;
 
+#[cfg(feature = "accesskit")]
+use crate::accessibility::{Accessibility, AccessibilityAdapter, ActionRequestSender};
 use crate::proxy::queue_get;
+#[cfg(feature = "accesskit")]
+use raw_window_handle::RawWindowHandle;
 use vizia_core::backend::*;
 use vizia_core::prelude::*;
 
@@ -36,6 +39,21 @@ where
     window_scale_policy: WindowScalePolicy,
     on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
     ignore_default_theme: bool,
+    params: Option<(ParamBridge, ParamReceiver)>,
+    window_options: WindowOptions,
+}
+
+#[cfg(feature = "accesskit")]
+type CreateAccessibilityAdapter =
+    Box<dyn FnOnce(RawWindowHandle, ActionRequestSender) -> Box<dyn AccessibilityAdapter> + Send>;
+
+/// Options for the main window which are passed on to the application runner.
+#[derive(Default)]
+pub(crate) struct WindowOptions {
+    lock_aspect_ratio: bool,
+    on_resize_request: Option<Box<dyn Fn(WindowSize) -> bool + Send>>,
+    #[cfg(feature = "accesskit")]
+    pub accessibility_adapter: Option<CreateAccessibilityAdapter>,
 }
 
 impl<F> Application<F>
@@ -50,6 +68,8 @@ where
             window_scale_policy: WindowScalePolicy::SystemScaleFactor,
             on_idle: None,
             ignore_default_theme: false,
+            params: None,
+            window_options: WindowOptions::default(),
         }
     }
 
@@ -86,6 +106,102 @@ where
         self
     }
 
+    /// Sets the minimum size which the window can be resized to from within the UI.
+    pub fn min_inner_size(mut self, size: Option<impl Into<WindowSize>>) -> Self {
+        self.window_description.min_inner_size = size.map(|size| size.into());
+
+        self
+    }
+
+    /// Sets the maximum size which the window can be resized to from within the UI.
+    pub fn max_inner_size(mut self, size: Option<impl Into<WindowSize>>) -> Self {
+        self.window_description.max_inner_size = size.map(|size| size.into());
+
+        self
+    }
+
+    /// Sets whether the window can be resized from within the UI, defaults to true.
+    pub fn resizable(mut self, flag: bool) -> Self {
+        self.window_description.resizable = flag;
+
+        self
+    }
+
+    /// Locks the aspect ratio of sizes requested from within the UI to the ratio of the inner size of the window.
+    pub fn lock_aspect_ratio(mut self, flag: bool) -> Self {
+        self.window_options.lock_aspect_ratio = flag;
+
+        self
+    }
+
+    /// Takes a closure which is called when the UI requests a new size for the window, such as when a
+    /// [`ResizeHandle`] is dragged.
+    ///
+    /// The closure receives the new size of the window in logical pixels, including the user scale factor, and
+    /// returns whether the resize was accepted. Plugins should forward the request to the host, as the window is
+    /// only resized when the host allows it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::Application;
+    /// Application::new(|cx|{
+    ///     // Build application here
+    ///     ResizeHandle::new(cx);
+    /// })
+    /// .min_inner_size(Some((400, 300)))
+    /// .on_resize_request(|size| {
+    ///     // Ask the host to resize the editor here
+    ///     true
+    /// })
+    /// .run();
+    /// ```
+    pub fn on_resize_request(
+        mut self,
+        callback: impl Fn(WindowSize) -> bool + Send + 'static,
+    ) -> Self {
+        self.window_options.on_resize_request = Some(Box::new(callback));
+
+        self
+    }
+
+    /// Takes a closure which creates the adapter used to expose the accessibility tree of the application to
+    /// assistive technologies, such as screen readers.
+    ///
+    /// The closure is called when the window is opened with the handle of the window and the sender used to send
+    /// action requests from assistive technologies back to the application.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{AccessibilityAdapter, Application};
+    /// # use accesskit::TreeUpdate;
+    /// struct Adapter;
+    ///
+    /// impl AccessibilityAdapter for Adapter {
+    ///     fn update(&mut self, update: TreeUpdate) {
+    ///         // Forward the update to the accesskit adapter for the platform here
+    ///     }
+    /// }
+    ///
+    /// Application::new(|cx|{
+    ///     // Build application here
+    /// })
+    /// .accessibility_adapter(|window_handle, action_sender| Box::new(Adapter))
+    /// .run();
+    /// ```
+    #[cfg(feature = "accesskit")]
+    pub fn accessibility_adapter(
+        mut self,
+        create_adapter: impl FnOnce(RawWindowHandle, ActionRequestSender) -> Box<dyn AccessibilityAdapter>
+            + Send
+            + 'static,
+    ) -> Self {
+        self.window_options.accessibility_adapter = Some(Box::new(create_adapter));
+
+        self
+    }
+
     /// Open a new window that blocks the current thread until the window is destroyed.
     ///
     /// Do **not** use this in the context of audio plugins, unless it is compiled as a
@@ -99,6 +215,8 @@ where
             self.app,
             self.on_idle,
             self.ignore_default_theme,
+            self.params,
+            self.window_options,
         );
 
         Ok(())
@@ -119,6 +237,8 @@ where
             self.app,
             self.on_idle,
             self.ignore_default_theme,
+            self.params,
+            self.window_options,
         )
     }
 
@@ -145,11 +265,44 @@ where
 
         self
     }
+
+    /// Connects views bound to plugin parameters with the host.
+    ///
+    /// Edit gestures emitted by views such as a [`Knob`] with a `param` are forwarded to the `host`, and parameter
+    /// changes sent by the host through the [`ParamSender`] paired with the `receiver` are emitted to the whole
+    /// tree as [`ParamChanged`] events at the start of every frame.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::Application;
+    /// # struct Host;
+    /// # impl ParamHost for Host {
+    /// #     fn begin_edit(&self, _: ParamId) {}
+    /// #     fn set_normalized(&self, _: ParamId, _: f32) {}
+    /// #     fn end_edit(&self, _: ParamId) {}
+    /// # }
+    /// let (sender, receiver) = param_channel(4);
+    ///
+    /// // Move the sender to the audio thread to send automation to the UI.
+    ///
+    /// Application::new(|cx|{
+    ///     // Build application here
+    /// })
+    /// .param_bridge(Host, receiver)
+    /// .run();
+    /// ```
+    pub fn param_bridge(mut self, host: impl ParamHost, receiver: ParamReceiver) -> Self {
+        self.params = Some((ParamBridge::new(host), receiver));
+
+        self
+    }
 }
 
 pub(crate) struct ApplicationRunner {
     cx: BackendContext,
     event_manager: EventManager,
+    param_receiver: Option<ParamReceiver>,
     pub gr_context: skia_safe::gpu::DirectContext,
     should_redraw: bool,
 
@@ -164,15 +317,26 @@ pub(crate) struct ApplicationRunner {
     /// mouse coordinates to physical window coordinates. For any other use within VIZIA itself this
     /// always needs to be multiplied by `user_scale_factor`.
     window_scale_factor: f64,
-    // /// The scale factor applied on top of the `window_scale` to convert the window's logical size
-    // /// to a physical size. If this is different from `*cx.user_scale_factor` after handling the
-    // /// events then the window will be resized.
-    // current_user_scale_factor: f64,
-    // /// The window's current logical size, before `user_scale_factor` has been applied. Needed to
-    // /// resize the window when changing the scale factor.
-    // current_window_size: WindowSize,
+    /// The scale factor applied on top of the `window_scale` to convert the window's logical size
+    /// to a physical size. If this is different from the `user_scale_factor` of the main window's
+    /// description after handling the events then the window will be resized.
+    current_user_scale_factor: f64,
+    /// The window's current logical size, before `user_scale_factor` has been applied. If this is
+    /// different from the `inner_size` of the main window's description after handling the events
+    /// then the window will be resized.
+    current_window_size: WindowSize,
+    /// The width to height ratio which sizes requested by the UI are constrained to.
+    aspect_ratio: Option<f64>,
+    on_resize_request: Option<Box<dyn Fn(WindowSize) -> bool + Send>>,
+    #[cfg(feature = "accesskit")]
+    pub(crate) accessibility: Option<Accessibility>,
     pub surface: skia_safe::Surface,
     pub dirty_surface: skia_safe::Surface,
+
+    /// The windows opened for `Window` views, keyed by the entity of the view.
+    sub_windows: HashMap<Entity, SubWindowState>,
+    /// Events received by sub windows, which are sent along with the entity of the window.
+    sub_window_events: (Sender<(Entity, baseview::Event)>, Receiver<(Entity, baseview::Event)>),
 }
 
 impl ApplicationRunner {
@@ -183,102 +347,64 @@ impl ApplicationRunner {
         window_scale_factor: f64,
         surface: skia_safe::Surface,
         dirty_surface: skia_safe::Surface,
+        param_receiver: Option<ParamReceiver>,
+        window_options: WindowOptions,
     ) -> Self {
+        let win_desc = &cx.0.windows[&Entity::root()].window_description;
+        let current_window_size = win_desc.inner_size;
+        let current_user_scale_factor = win_desc.user_scale_factor;
+        let aspect_ratio = window_options.lock_aspect_ratio.then(|| {
+            current_window_size.width.max(1) as f64 / current_window_size.height.max(1) as f64
+        });
+
         ApplicationRunner {
             should_redraw: true,
             gr_context,
             event_manager: EventManager::new(),
+            param_receiver,
             use_system_scaling,
             window_scale_factor,
-            //current_user_scale_factor: cx.user_scale_factor(),
-            //current_window_size: *cx.window_size(),
+            current_user_scale_factor,
+            current_window_size,
+            aspect_ratio,
+            on_resize_request: window_options.on_resize_request,
+            #[cfg(feature = "accesskit")]
+            accessibility: None,
             cx,
             surface,
             dirty_surface,
+            sub_windows: HashMap::new(),
+            sub_window_events: mpsc::channel(),
         }
     }
 
     /// Handle all reactivity within a frame. The window instance is used to resize the window when
     /// needed.
-    pub fn on_frame_update(&mut self, window: &mut Window) {
+    pub fn on_frame_update(&mut self, window: &mut baseview::Window) {
         while let Some(event) = queue_get() {
             self.cx.send_event(event);
         }
 
+        while let Ok((window_entity, event)) = self.sub_window_events.1.try_recv() {
+            self.handle_event(window_entity, event, &mut false);
+        }
+
+        #[cfg(feature = "accesskit")]
+        if let Some(accessibility) = &mut self.accessibility {
+            accessibility.handle_action_requests(&mut self.cx);
+        }
+
+        // Parameter changes from the host
+        if let Some(receiver) = &self.param_receiver {
+            receiver.emit_changes(self.cx.context());
+        }
+
         // Events
         while self.event_manager.flush_events(self.cx.context()) {}
 
-        // if *cx.window_size() != self.current_window_size
-        //     || cx.user_scale_factor() != self.current_user_scale_factor
-        // {
-        //     self.current_window_size = *cx.window_size();
-        //     self.current_user_scale_factor = cx.user_scale_factor();
-
-        //     // The user scale factor is not part of the HiDPI scaling, so baseview should treat it
-        //     // as part of our logical size
-        //     window.resize(baseview::Size {
-        //         width: self.current_window_size.width as f64 * self.current_user_scale_factor,
-        //         height: self.current_window_size.height as f64 * self.current_user_scale_factor,
-        //     });
-
-        //     // TODO: These calculations are now repeated in three places, should probably be moved
-        //     //       to a function
-        //     cx.set_scale_factor(self.window_scale_factor * self.current_user_scale_factor);
-        //     let new_physical_width =
-        //         self.current_window_size.width as f32 * cx.style().scale_factor();
-        //     let new_physical_height =
-        //         self.current_window_size.height as f32 * cx.style().scale_factor();
-
-        //     cx.set_window_size(new_physical_width, new_physical_height);
-
-        //     if let Some(surface) = cx.get_surface_mut(Entity::root()) {
-        //         if new_physical_width != 0.0 || new_physical_height != 0.0 {
-        //             let fb_info = {
-        //                 let mut fboid: GLint = 0;
-        //                 unsafe { gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut fboid) };
-
-        //                 FramebufferInfo {
-        //                     fboid: fboid.try_into().unwrap(),
-        //                     format: skia_safe::gpu::gl::Format::RGBA8.into(),
-        //                     ..Default::default()
-        //                 }
-        //             };
-
-        //             let backend_render_target = backend_render_targets::make_gl(
-        //                 (new_physical_width as i32, new_physical_height as i32),
-        //                 None,
-        //                 8,
-        //                 fb_info,
-        //             );
-
-        //             surface.0 = gpu::surfaces::wrap_backend_render_target(
-        //                 &mut self.gr_context,
-        //                 &backend_render_target,
-        //                 SurfaceOrigin::BottomLeft,
-        //                 ColorType::RGBA8888,
-        //                 None,
-        //                 None,
-        //             )
-        //             .expect("Could not create skia surface");
-
-        //             surface.1 = surface
-        //                 .0
-        //                 .new_surface_with_dimensions((
-        //                     new_physical_width.max(1.0) as i32,
-        //                     new_physical_height.max(1.0) as i32,
-        //                 ))
-        //                 .unwrap();
-        //         }
-        //     }
-
-        //     cx.needs_refresh();
-
-        //     // hmmm why are we flushing events again?
-        //     // self.event_manager.flush_events(cx.context());
-        // }
-
         let context = window.gl_context().expect("Window was created without OpenGL support");
         unsafe { context.make_current() };
+        self.update_window_size(window);
         self.cx.process_style_updates();
         unsafe { context.make_not_current() };
 
@@ -286,9 +412,168 @@ impl ApplicationRunner {
 
         self.cx.process_visual_updates();
 
-        if self.cx.0.windows.iter().any(|(_, window_state)| !window_state.redraw_list.is_empty()) {
+        #[cfg(feature = "accesskit")]
+        if let Some(accessibility) = &mut self.accessibility {
+            accessibility.process_tree_updates(&mut self.cx);
+        }
+
+        if self.cx.0.windows.iter().any(|(_, window_state)| {
+            !window_state.redraw_list.is_empty() || !window_state.recomposite_list.is_empty()
+        }) {
             self.should_redraw = true;
         }
+
+        self.sync_sub_windows(window);
+    }
+
+    /// Resizes the main window if its size or user scale factor have been changed from within the UI.
+    fn update_window_size(&mut self, window: &mut baseview::Window) {
+        let Some(state) = self.cx.0.windows.get_mut(&Entity::root()) else {
+            return;
+        };
+
+        let win_desc = &mut state.window_description;
+        let user_scale_factor = win_desc.user_scale_factor;
+        let size = if win_desc.inner_size == self.current_window_size {
+            win_desc.inner_size
+        } else if win_desc.resizable {
+            constrain_size(win_desc.inner_size, win_desc, self.aspect_ratio)
+        } else {
+            self.current_window_size
+        };
+
+        if size == self.current_window_size && user_scale_factor == self.current_user_scale_factor {
+            win_desc.inner_size = size;
+            return;
+        }
+
+        // The user scale factor is not part of the HiDPI scaling, so baseview should treat it as
+        // part of our logical size
+        let host_size = WindowSize::new(
+            (size.width as f64 * user_scale_factor).round() as u32,
+            (size.height as f64 * user_scale_factor).round() as u32,
+        );
+
+        if let Some(on_resize_request) = &self.on_resize_request {
+            if !(on_resize_request)(host_size) {
+                win_desc.inner_size = self.current_window_size;
+                win_desc.user_scale_factor = self.current_user_scale_factor;
+                return;
+            }
+        }
+
+        win_desc.inner_size = size;
+        self.current_window_size = size;
+        self.current_user_scale_factor = user_scale_factor;
+
+        window.resize(baseview::Size::new(host_size.width as f64, host_size.height as f64));
+
+        self.cx.set_scale_factor(self.window_scale_factor * user_scale_factor);
+        let scale_factor = self.cx.scale_factor();
+        self.resize_surfaces((
+            (size.width as f32 * scale_factor).round() as i32,
+            (size.height as f32 * scale_factor).round() as i32,
+        ));
+    }
+
+    /// Recreates the surfaces of the main window with the given physical size, which requires the
+    /// OpenGL context of the window to be current.
+    fn resize_surfaces(&mut self, physical_size: (i32, i32)) {
+        if physical_size.0 <= 0 || physical_size.1 <= 0 {
+            return;
+        }
+
+        self.surface = create_surface(physical_size, framebuffer_info(), &mut self.gr_context);
+        self.dirty_surface = self.surface.new_surface_with_dimensions(physical_size).unwrap();
+
+        self.cx.set_window_size(Entity::root(), physical_size.0 as f32, physical_size.1 as f32);
+        self.cx.needs_refresh(Entity::root());
+    }
+
+    /// Closes the windows of removed `Window` views and opens windows for new ones.
+    fn sync_sub_windows(&mut self, window: &mut baseview::Window) {
+        let window_entities = self
+            .cx
+            .0
+            .windows
+            .iter()
+            .filter(|(entity, state)| **entity != Entity::root() && state.should_close)
+            .map(|(entity, _)| *entity)
+            .collect::<Vec<_>>();
+
+        for window_entity in window_entities {
+            self.cx.0.remove(window_entity);
+        }
+
+        let windows = &self.cx.0.windows;
+        self.sub_windows.retain(|entity, sub_window| {
+            let is_open = windows.contains_key(entity);
+            if !is_open {
+                sub_window.handle.close();
+            }
+            is_open
+        });
+
+        let new_windows = self
+            .cx
+            .0
+            .windows
+            .iter()
+            .filter(|(entity, _)| {
+                **entity != Entity::root() && !self.sub_windows.contains_key(*entity)
+            })
+            .map(|(entity, state)| (*entity, state.clone()))
+            .collect::<Vec<_>>();
+
+        for (window_entity, window_state) in new_windows {
+            // A popup is opened once the window which owns it has been opened.
+            if let Some(owner) = window_state.owner {
+                if owner != Entity::root() && !self.sub_windows.contains_key(&owner) {
+                    continue;
+                }
+            }
+
+            self.open_sub_window(window, window_entity, &window_state);
+        }
+    }
+
+    fn open_sub_window(
+        &mut self,
+        window: &mut baseview::Window,
+        window_entity: Entity,
+        window_state: &WindowState,
+    ) {
+        let win_desc = &window_state.window_description;
+        let scale_factor = self.window_scale_factor;
+        let size = (
+            (win_desc.inner_size.width as f64 * win_desc.user_scale_factor * scale_factor) as i32,
+            (win_desc.inner_size.height as f64 * win_desc.user_scale_factor * scale_factor) as i32,
+        );
+
+        let events = self.sub_window_events.0.clone();
+        let (frame_sender, frames) = mpsc::channel();
+        let open_options =
+            window_open_options(win_desc, WindowScalePolicy::ScaleFactor(scale_factor));
+        let build = move |window: &mut baseview::Window<'_>| {
+            SubWindow::new(window, window_entity, size, events, frames)
+        };
+
+        // Popups are parented to the window which owns them, other windows to the main window.
+        let handle = match window_state.owner.and_then(|owner| self.sub_windows.get(&owner)) {
+            Some(owner) => baseview::Window::open_parented(&owner.handle, open_options, build),
+            None => baseview::Window::open_parented(&*window, open_options, build),
+        };
+
+        self.sub_windows
+            .insert(window_entity, SubWindowState::new(handle, frame_sender, size, scale_factor));
+
+        self.cx.add_main_window(window_entity, win_desc, self.cx.scale_factor());
+        self.cx.mutate_window(window_entity, |cx, win: &mut Window| {
+            if let Some(callback) = &win.on_create {
+                (callback)(&mut EventContext::new_with_current(cx.context(), window_entity));
+            }
+        });
+        self.cx.needs_refresh(window_entity);
     }
 
     pub fn render(&mut self) {
@@ -296,14 +581,38 @@ impl ApplicationRunner {
             self.gr_context.flush_and_submit();
             self.should_redraw = false;
         }
+
+        for (window_entity, sub_window) in self.sub_windows.iter_mut() {
+            if self.cx.draw(*window_entity, &mut sub_window.surface, &mut sub_window.dirty_surface)
+            {
+                if let Some(frame) = Frame::read(&mut sub_window.surface) {
+                    let _ = sub_window.frames.send(frame);
+                }
+            }
+        }
     }
 
-    pub fn handle_event(&mut self, event: baseview::Event, should_quit: &mut bool) {
-        if requests_exit(&event) {
+    pub fn handle_event(
+        &mut self,
+        window_entity: Entity,
+        event: baseview::Event,
+        should_quit: &mut bool,
+    ) {
+        if window_entity != Entity::root() {
+            if let baseview::Event::Window(baseview::WindowEvent::WillClose) = event {
+                self.cx.emit_window_event(window_entity, WindowEvent::WindowClose);
+                return;
+            }
+        } else if requests_exit(&event) {
             self.cx.send_event(Event::new(WindowEvent::WindowClose));
             *should_quit = true;
         }
 
+        let window_scale_factor = self
+            .sub_windows
+            .get(&window_entity)
+            .map_or(self.window_scale_factor, |sub_window| sub_window.scale_factor);
+
         let mut update_modifiers = |modifiers: vizia_input::KeyboardModifiers| {
             self.cx
                 .modifiers()
@@ -330,23 +639,26 @@ impl ApplicationRunner {
                     //       done internally to be able to separate actual HiDPI scaling from
                     //       arbitrary uniform scaling baseview only knows about its own scale
                     //       factor.
-                    let physical_posx = position.x * self.window_scale_factor;
-                    let physical_posy = position.y * self.window_scale_factor;
+                    let physical_posx = position.x * window_scale_factor;
+                    let physical_posy = position.y * window_scale_factor;
                     let cursor_x = (physical_posx) as f32;
                     let cursor_y = (physical_posy) as f32;
-                    self.cx.emit_origin(WindowEvent::MouseMove(cursor_x, cursor_y));
+                    self.cx.emit_window_event(
+                        window_entity,
+                        WindowEvent::MouseMove(cursor_x, cursor_y),
+                    );
                 }
                 baseview::MouseEvent::ButtonPressed { button, modifiers } => {
                     update_modifiers(modifiers);
 
                     let b = translate_mouse_button(button);
-                    self.cx.emit_origin(WindowEvent::MouseDown(b));
+                    self.cx.emit_window_event(window_entity, WindowEvent::MouseDown(b));
                 }
                 baseview::MouseEvent::ButtonReleased { button, modifiers } => {
                     update_modifiers(modifiers);
 
                     let b = translate_mouse_button(button);
-                    self.cx.emit_origin(WindowEvent::MouseUp(b));
+                    self.cx.emit_window_event(window_entity, WindowEvent::MouseUp(b));
                 }
                 baseview::MouseEvent::WheelScrolled { delta, modifiers } => {
                     update_modifiers(modifiers);
@@ -371,15 +683,18 @@ impl ApplicationRunner {
                         ),
                     };
 
-                    self.cx.emit_origin(WindowEvent::MouseScroll(lines_x, lines_y));
+                    self.cx.emit_window_event(
+                        window_entity,
+                        WindowEvent::MouseScroll(lines_x, lines_y),
+                    );
                 }
 
                 baseview::MouseEvent::CursorEntered => {
-                    self.cx.emit_origin(WindowEvent::MouseEnter);
+                    self.cx.emit_window_event(window_entity, WindowEvent::MouseEnter);
                 }
 
                 baseview::MouseEvent::CursorLeft => {
-                    self.cx.emit_origin(WindowEvent::MouseLeave);
+                    self.cx.emit_window_event(window_entity, WindowEvent::MouseLeave);
                 }
 
                 _ => {}
@@ -410,80 +725,68 @@ impl ApplicationRunner {
                     MouseButtonState::Pressed => {
                         if let vizia_input::Key::Character(written) = &event.key {
                             for chr in written.chars() {
-                                self.cx.emit_origin(WindowEvent::CharInput(chr));
+                                self.cx
+                                    .emit_window_event(window_entity, WindowEvent::CharInput(chr));
                             }
                         }
 
-                        self.cx.emit_origin(WindowEvent::KeyDown(event.code, Some(event.key)));
+                        self.cx.emit_window_event(
+                            window_entity,
+                            WindowEvent::KeyDown(event.code, Some(event.key)),
+                        );
                     }
 
                     MouseButtonState::Released => {
-                        self.cx.emit_origin(WindowEvent::KeyUp(event.code, Some(event.key)));
+                        self.cx.emit_window_event(
+                            window_entity,
+                            WindowEvent::KeyUp(event.code, Some(event.key)),
+                        );
                     }
                 }
             }
             baseview::Event::Window(event) => match event {
-                baseview::WindowEvent::Focused => self.cx.needs_refresh(Entity::root()),
-                baseview::WindowEvent::Resized(window_info) => {
-                    let fb_info = {
-                        let mut fboid: GLint = 0;
-                        unsafe { gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut fboid) };
-
-                        FramebufferInfo {
-                            fboid: fboid.try_into().unwrap(),
-                            format: skia_safe::gpu::gl::Format::RGBA8.into(),
-                            ..Default::default()
-                        }
-                    };
+                baseview::WindowEvent::Focused => self.cx.needs_refresh(window_entity),
+                baseview::WindowEvent::Resized(window_info) if window_entity != Entity::root() => {
+                    let physical_size = window_info.physical_size();
+
+                    if let Some(sub_window) = self.sub_windows.get_mut(&window_entity) {
+                        sub_window
+                            .resize((physical_size.width as i32, physical_size.height as i32));
+                        sub_window.scale_factor = window_info.scale();
+                    }
 
-                    self.surface = create_surface(
-                        (
-                            window_info.physical_size().width as i32,
-                            window_info.physical_size().height as i32,
-                        ),
-                        fb_info,
-                        &mut self.gr_context,
+                    self.cx.set_window_size(
+                        window_entity,
+                        physical_size.width as f32,
+                        physical_size.height as f32,
                     );
-
-                    self.dirty_surface = self
-                        .surface
-                        .new_surface_with_dimensions((
-                            window_info.physical_size().width as i32,
-                            window_info.physical_size().height as i32,
-                        ))
-                        .unwrap();
-
-                    // // We keep track of the current size before applying the user scale factor while
-                    // // baseview's logical size includes that factor so we need to compensate for it
-                    // self.current_window_size = *self.cx.window_size();
-                    // self.current_window_size.width = (window_info.logical_size().width
-                    //     / self.cx.user_scale_factor())
-                    // .round() as u32;
-                    // self.current_window_size.height = (window_info.logical_size().height
-                    //     / self.cx.user_scale_factor())
-                    // .round() as u32;
-                    // *self.cx.window_size() = self.current_window_size;
-
+                    self.cx.needs_refresh(window_entity);
+                }
+                baseview::WindowEvent::Resized(window_info) => {
                     // Only use new DPI settings when `WindowScalePolicy::SystemScaleFactor` was
                     // used
                     if self.use_system_scaling {
                         self.window_scale_factor = window_info.scale();
                     }
 
-                    //let user_scale_factor = self.cx.user_scale_factor();
-
-                    //self.cx.set_scale_factor(self.window_scale_factor * user_scale_factor);
-
-                    let physical_size =
-                        (window_info.physical_size().width, window_info.physical_size().height);
+                    self.cx.set_scale_factor(
+                        self.window_scale_factor * self.current_user_scale_factor,
+                    );
 
-                    self.cx.set_window_size(
-                        Entity::root(),
-                        physical_size.0 as f32,
-                        physical_size.1 as f32,
+                    // We keep track of the current size before applying the user scale factor while
+                    // baseview's logical size includes that factor so we need to compensate for it
+                    let logical_size = window_info.logical_size();
+                    self.current_window_size = WindowSize::new(
+                        (logical_size.width / self.current_user_scale_factor).round() as u32,
+                        (logical_size.height / self.current_user_scale_factor).round() as u32,
                     );
 
-                    self.cx.needs_refresh(Entity::root());
+                    if let Some(state) = self.cx.0.windows.get_mut(&Entity::root()) {
+                        state.window_description.inner_size = self.current_window_size;
+                    }
+
+                    let physical_size = window_info.physical_size();
+                    self.resize_surfaces((physical_size.width as i32, physical_size.height as i32));
                 }
                 baseview::WindowEvent::WillClose => {
                     self.cx.send_event(Event::new(WindowEvent::WindowClose));
@@ -501,6 +804,39 @@ impl ApplicationRunner {
     }
 }
 
+impl Drop for ApplicationRunner {
+    fn drop(&mut self) {
+        for sub_window in self.sub_windows.values_mut() {
+            sub_window.handle.close();
+        }
+    }
+}
+
+/// Constrains a size requested for the window to the minimum and maximum sizes of its
+/// description, and to the aspect ratio if there is one.
+fn constrain_size(
+    size: WindowSize,
+    win_desc: &WindowDescription,
+    aspect_ratio: Option<f64>,
+) -> WindowSize {
+    let min = win_desc.min_inner_size.unwrap_or(WindowSize::new(1, 1));
+    let max = win_desc.max_inner_size.unwrap_or(WindowSize::new(u32::MAX, u32::MAX));
+
+    let mut width = size.width.clamp(min.width, max.width.max(min.width)) as f64;
+    let mut height = size.height.clamp(min.height, max.height.max(min.height)) as f64;
+
+    // Shrink the size to fit the aspect ratio.
+    if let Some(aspect_ratio) = aspect_ratio {
+        if width / height > aspect_ratio {
+            width = height * aspect_ratio;
+        } else {
+            height = width / aspect_ratio;
+        }
+    }
+
+    WindowSize::new((width.round() as u32).max(1), (height.round() as u32).max(1))
+}
+
 /// Returns true if the provided event should cause an [`Application`] to
 /// exit.
 pub fn requests_exit(event: &baseview::Event) -> bool {
@@ -1,11 +1,19 @@
 #![allow(clippy::type_complexity)]
+#[cfg(feature = "accesskit")]
+mod accessibility;
 mod application;
 mod parent_window;
 pub(crate) mod proxy;
+mod sub_window;
 mod window;
+mod window_modifiers;
 
 pub use parent_window::ParentWindow;
 
+#[cfg(feature = "accesskit")]
+pub use accessibility::{AccessibilityAdapter, ActionRequestSender};
 pub use application::{Application, ApplicationError};
+pub use window::Window;
+pub use window_modifiers::WindowModifiers;
 
 pub use baseview::{WindowHandle, WindowScalePolicy};
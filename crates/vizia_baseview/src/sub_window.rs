@@ -0,0 +1,158 @@
+use std::sync::mpsc::{Receiver, Sender};
+
+use baseview::{Event, EventStatus, WindowHandle, WindowHandler};
+use skia_safe::{images, Color, Data, ImageInfo, Surface};
+
+use crate::window::{create_gr_context, create_surface, framebuffer_info};
+use vizia_core::prelude::*;
+
+/// The pixels of a sub window, drawn by the application and presented by the window.
+pub(crate) struct Frame {
+    width: i32,
+    height: i32,
+    pixels: Vec<u8>,
+}
+
+impl Frame {
+    /// Reads the pixels of a raster surface into a new frame.
+    pub(crate) fn read(surface: &mut Surface) -> Option<Self> {
+        let (width, height) = (surface.width(), surface.height());
+        let info = ImageInfo::new_n32_premul((width, height), None);
+        let mut pixels = vec![0; info.compute_min_byte_size()];
+
+        if !surface.read_pixels(&info, &mut pixels, info.min_row_bytes(), (0, 0)) {
+            return None;
+        }
+
+        Some(Frame { width, height, pixels })
+    }
+}
+
+/// The state kept by the application for each window opened for a `Window` view.
+///
+/// Sub windows may run on a separate thread, depending on the platform, so the application draws them into raster
+/// surfaces and sends the resulting frames to the window, while the window sends its events back to the
+/// application.
+pub(crate) struct SubWindowState {
+    pub handle: WindowHandle,
+    pub frames: Sender<Frame>,
+    pub surface: Surface,
+    pub dirty_surface: Surface,
+    pub scale_factor: f64,
+}
+
+impl SubWindowState {
+    pub fn new(
+        handle: WindowHandle,
+        frames: Sender<Frame>,
+        size: (i32, i32),
+        scale_factor: f64,
+    ) -> Self {
+        let (surface, dirty_surface) = raster_surfaces(size);
+
+        Self { handle, frames, surface, dirty_surface, scale_factor }
+    }
+
+    /// Recreates the surfaces of the window with the given physical size.
+    pub fn resize(&mut self, size: (i32, i32)) {
+        (self.surface, self.dirty_surface) = raster_surfaces(size);
+    }
+}
+
+fn raster_surfaces(size: (i32, i32)) -> (Surface, Surface) {
+    let size = (size.0.max(1), size.1.max(1));
+    let mut surface =
+        skia_safe::surfaces::raster_n32_premul(size).expect("Could not create skia surface");
+    let dirty_surface = surface.new_surface_with_dimensions(size).unwrap();
+
+    (surface, dirty_surface)
+}
+
+/// Handles a baseview window opened for a `Window` view.
+pub(crate) struct SubWindow {
+    entity: Entity,
+    events: Sender<(Entity, Event)>,
+    frames: Receiver<Frame>,
+    frame: Option<Frame>,
+    gr_context: skia_safe::gpu::DirectContext,
+    surface: Surface,
+}
+
+impl SubWindow {
+    pub fn new(
+        window: &mut baseview::Window,
+        entity: Entity,
+        size: (i32, i32),
+        events: Sender<(Entity, Event)>,
+        frames: Receiver<Frame>,
+    ) -> Self {
+        let context = window.gl_context().expect("Window was created without OpenGL support");
+
+        unsafe { context.make_current() };
+
+        let mut gr_context = create_gr_context(context);
+        let surface = create_surface(size, framebuffer_info(), &mut gr_context);
+
+        unsafe { context.make_not_current() };
+
+        Self { entity, events, frames, frame: None, gr_context, surface }
+    }
+
+    fn present(&mut self) {
+        let Some(frame) = &self.frame else {
+            return;
+        };
+
+        let info = ImageInfo::new_n32_premul((frame.width, frame.height), None);
+        let image =
+            images::raster_from_data(&info, Data::new_copy(&frame.pixels), info.min_row_bytes());
+
+        let canvas = self.surface.canvas();
+        canvas.clear(Color::TRANSPARENT);
+        if let Some(image) = image {
+            canvas.draw_image(image, (0.0, 0.0), None);
+        }
+
+        self.gr_context.flush_and_submit();
+    }
+}
+
+impl WindowHandler for SubWindow {
+    fn on_frame(&mut self, window: &mut baseview::Window) {
+        let Some(frame) = self.frames.try_iter().last() else {
+            return;
+        };
+        self.frame = Some(frame);
+
+        let context = window.gl_context().expect("Window was created without OpenGL support");
+        unsafe { context.make_current() };
+
+        self.present();
+        context.swap_buffers();
+
+        unsafe { context.make_not_current() };
+    }
+
+    fn on_event(&mut self, window: &mut baseview::Window<'_>, event: Event) -> EventStatus {
+        if let Event::Window(baseview::WindowEvent::Resized(window_info)) = &event {
+            let size = (
+                window_info.physical_size().width as i32,
+                window_info.physical_size().height as i32,
+            );
+
+            let context = window.gl_context().expect("Window was created without OpenGL support");
+            unsafe { context.make_current() };
+
+            self.surface = create_surface(size, framebuffer_info(), &mut self.gr_context);
+            self.present();
+            context.swap_buffers();
+
+            unsafe { context.make_not_current() };
+        }
+
+        // The application may already have been closed, in which case the event is dropped.
+        let _ = self.events.send((self.entity, event));
+
+        EventStatus::Ignored
+    }
+}
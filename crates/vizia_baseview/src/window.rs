@@ -1,7 +1,7 @@
-use crate::application::ApplicationRunner;
-use baseview::gl::GlConfig;
+use crate::application::{ApplicationRunner, WindowOptions};
+use baseview::gl::{GlConfig, GlContext};
 use baseview::{
-    Event, EventStatus, Window, WindowHandle, WindowHandler, WindowOpenOptions, WindowScalePolicy,
+    Event, EventStatus, WindowHandle, WindowHandler, WindowOpenOptions, WindowScalePolicy,
 };
 use gl::types::GLint;
 use gl_rs as gl;
@@ -12,6 +12,8 @@ use skia_safe::gpu::{
 };
 use skia_safe::{ColorType, Surface};
 
+#[cfg(feature = "accesskit")]
+use crate::accessibility::Accessibility;
 use crate::proxy::BaseviewProxy;
 use vizia_core::backend::*;
 use vizia_core::prelude::*;
@@ -31,38 +33,15 @@ impl ViziaWindow {
         window: &mut baseview::Window,
         builder: Option<Box<dyn FnOnce(&mut Context) + Send>>,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        params: Option<(ParamBridge, ParamReceiver)>,
+        #[allow(unused_mut)] mut window_options: WindowOptions,
     ) -> ViziaWindow {
         let context = window.gl_context().expect("Window was created without OpenGL support");
 
         unsafe { context.make_current() };
 
-        // Build skia renderer
-        gl::load_with(|s| context.get_proc_address(s));
-        let interface = skia_safe::gpu::gl::Interface::new_load_with(|name| {
-            if name == "eglGetCurrentDisplay" {
-                return std::ptr::null();
-            }
-            context.get_proc_address(name)
-        })
-        .expect("Could not create interface");
-
-        // https://github.com/rust-skia/rust-skia/issues/476
-        let mut context_options = ContextOptions::new();
-        context_options.skip_gl_error_checks = context_options::Enable::Yes;
-
-        let mut gr_context = skia_safe::gpu::direct_contexts::make_gl(interface, &context_options)
-            .expect("Could not create direct context");
-
-        let fb_info = {
-            let mut fboid: GLint = 0;
-            unsafe { gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut fboid) };
-
-            FramebufferInfo {
-                fboid: fboid.try_into().unwrap(),
-                format: skia_safe::gpu::gl::Format::RGBA8.into(),
-                ..Default::default()
-            }
-        };
+        let mut gr_context = create_gr_context(context);
+        let fb_info = framebuffer_info();
 
         let mut surface = create_surface(
             (win_desc.inner_size.width as i32, win_desc.inner_size.height as i32),
@@ -91,7 +70,7 @@ impl ViziaWindow {
         let dpi_factor = window_scale_factor * win_desc.user_scale_factor;
 
         cx.add_main_window(Entity::root(), &win_desc, dpi_factor as f32);
-        cx.add_window(WindowView {});
+        cx.add_window(Window::default());
 
         cx.0.windows.insert(
             Entity::root(),
@@ -99,20 +78,39 @@ impl ViziaWindow {
         );
 
         cx.context().remove_user_themes();
+        let param_receiver = params.map(|(param_bridge, receiver)| {
+            param_bridge.build(cx.context());
+            receiver
+        });
+
         if let Some(builder) = builder {
             (builder)(cx.context());
         }
 
-        let application = ApplicationRunner::new(
+        #[cfg(feature = "accesskit")]
+        let accessibility = window_options.accessibility_adapter.take().map(|create_adapter| {
+            let window_handle = window.raw_window_handle();
+            Accessibility::new(|action_sender| create_adapter(window_handle, action_sender))
+        });
+
+        #[allow(unused_mut)]
+        let mut application = ApplicationRunner::new(
             cx,
             gr_context,
             use_system_scaling,
             window_scale_factor,
             surface,
             dirty_surface,
+            param_receiver,
+            window_options,
         );
         unsafe { context.make_not_current() };
 
+        #[cfg(feature = "accesskit")]
+        {
+            application.accessibility = accessibility;
+        }
+
         ViziaWindow { application, on_idle }
     }
 
@@ -127,25 +125,17 @@ impl ViziaWindow {
         app: F,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
         ignore_default_theme: bool,
+        params: Option<(ParamBridge, ParamReceiver)>,
+        window_options: WindowOptions,
     ) -> WindowHandle
     where
         P: HasRawWindowHandle,
         F: Fn(&mut Context),
         F: 'static + Send,
     {
-        let window_settings = WindowOpenOptions {
-            title: win_desc.title.clone(),
-            size: baseview::Size::new(
-                // We have our own uniform non-DPI scaling factor that gets applied in addition to
-                // the DPI scaling since both can change independently at runtime
-                win_desc.inner_size.width as f64 * win_desc.user_scale_factor,
-                win_desc.inner_size.height as f64 * win_desc.user_scale_factor,
-            ),
-            scale: scale_policy,
-            gl_config: Some(GlConfig { vsync: false, ..GlConfig::default() }),
-        };
+        let window_settings = window_open_options(&win_desc, scale_policy);
 
-        Window::open_parented(
+        baseview::Window::open_parented(
             parent,
             window_settings,
             move |window: &mut baseview::Window<'_>| -> ViziaWindow {
@@ -157,7 +147,16 @@ impl ViziaWindow {
                 let mut cx = BackendContext::new(cx);
 
                 cx.set_event_proxy(Box::new(BaseviewProxy));
-                ViziaWindow::new(cx, win_desc, scale_policy, window, Some(Box::new(app)), on_idle)
+                ViziaWindow::new(
+                    cx,
+                    win_desc,
+                    scale_policy,
+                    window,
+                    Some(Box::new(app)),
+                    on_idle,
+                    params,
+                    window_options,
+                )
             },
         )
     }
@@ -171,21 +170,15 @@ impl ViziaWindow {
         app: F,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
         ignore_default_theme: bool,
+        params: Option<(ParamBridge, ParamReceiver)>,
+        window_options: WindowOptions,
     ) where
         F: Fn(&mut Context),
         F: 'static + Send,
     {
-        let window_settings = WindowOpenOptions {
-            title: win_desc.title.clone(),
-            size: baseview::Size::new(
-                win_desc.inner_size.width as f64 * win_desc.user_scale_factor,
-                win_desc.inner_size.height as f64 * win_desc.user_scale_factor,
-            ),
-            scale: scale_policy,
-            gl_config: Some(GlConfig { vsync: false, ..GlConfig::default() }),
-        };
+        let window_settings = window_open_options(&win_desc, scale_policy);
 
-        Window::open_blocking(
+        baseview::Window::open_blocking(
             window_settings,
             move |window: &mut baseview::Window<'_>| -> ViziaWindow {
                 let mut cx = Context::new();
@@ -196,14 +189,23 @@ impl ViziaWindow {
                 let mut cx = BackendContext::new(cx);
 
                 cx.set_event_proxy(Box::new(BaseviewProxy));
-                ViziaWindow::new(cx, win_desc, scale_policy, window, Some(Box::new(app)), on_idle)
+                ViziaWindow::new(
+                    cx,
+                    win_desc,
+                    scale_policy,
+                    window,
+                    Some(Box::new(app)),
+                    on_idle,
+                    params,
+                    window_options,
+                )
             },
         )
     }
 }
 
 impl WindowHandler for ViziaWindow {
-    fn on_frame(&mut self, window: &mut Window) {
+    fn on_frame(&mut self, window: &mut baseview::Window) {
         self.application.on_frame_update(window);
 
         let context = window.gl_context().expect("Window was created without OpenGL support");
@@ -215,10 +217,10 @@ impl WindowHandler for ViziaWindow {
         unsafe { context.make_not_current() };
     }
 
-    fn on_event(&mut self, _window: &mut Window<'_>, event: Event) -> EventStatus {
+    fn on_event(&mut self, _window: &mut baseview::Window<'_>, event: Event) -> EventStatus {
         let mut should_quit = false;
 
-        self.application.handle_event(event, &mut should_quit);
+        self.application.handle_event(Entity::root(), event, &mut should_quit);
 
         self.application.handle_idle(&self.on_idle);
 
@@ -230,9 +232,164 @@ impl WindowHandler for ViziaWindow {
     }
 }
 
-pub struct WindowView {}
+type WindowCallback = Option<Box<dyn Fn(&mut EventContext)>>;
+
+/// A view which is displayed in its own window.
+///
+/// The main window of the application is created by the backend. Additional windows are opened by the application
+/// runner as child windows of the window which owns them, and are closed when the view is removed from the tree.
+#[derive(Default)]
+pub struct Window {
+    pub(crate) on_close: WindowCallback,
+    pub(crate) on_create: WindowCallback,
+    pub should_close: bool,
+}
+
+impl Window {
+    /// Creates a new window with the given content.
+    pub fn new(cx: &mut Context, content: impl Fn(&mut Context)) -> Handle<Self> {
+        Self::default().build(cx, |cx| {
+            cx.windows.insert(cx.current(), WindowState::default());
+            cx.tree.set_window(cx.current(), true);
+            (content)(cx);
+        })
+    }
+
+    /// Creates a new popup window with the given content, which is owned by the window containing it.
+    ///
+    /// If `is_modal` is true, the owner window is disabled while the popup is open.
+    pub fn popup(cx: &mut Context, is_modal: bool, content: impl Fn(&mut Context)) -> Handle<Self> {
+        Self::default()
+            .build(cx, |cx| {
+                let parent_window = cx.parent_window();
+                if is_modal {
+                    cx.emit_to(parent_window, WindowEvent::SetEnabled(false));
+                }
+
+                cx.windows.insert(
+                    cx.current(),
+                    WindowState {
+                        owner: Some(parent_window),
+                        is_modal: true,
+                        ..Default::default()
+                    },
+                );
+                cx.tree.set_window(cx.current(), true);
+                (content)(cx);
+            })
+            .lock_focus_to_within()
+    }
+}
+
+impl View for Window {
+    fn element(&self) -> Option<&'static str> {
+        Some("window")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::Destroyed => {
+                let parent_window = cx.parent_window().unwrap_or(Entity::root());
+                cx.emit_to(parent_window, WindowEvent::SetEnabled(true));
+            }
+
+            WindowEvent::SetSize(size) => {
+                if let Some(state) = cx.windows.get_mut(&cx.current()) {
+                    state.window_description.inner_size = *size;
+                }
 
-impl View for WindowView {}
+                meta.consume();
+            }
+
+            WindowEvent::SetMinSize(size) => {
+                if let Some(state) = cx.windows.get_mut(&cx.current()) {
+                    state.window_description.min_inner_size = *size;
+                }
+            }
+
+            WindowEvent::SetMaxSize(size) => {
+                if let Some(state) = cx.windows.get_mut(&cx.current()) {
+                    state.window_description.max_inner_size = *size;
+                }
+            }
+
+            WindowEvent::ReloadStyles => {
+                cx.reload_styles().unwrap();
+            }
+
+            WindowEvent::WindowClose => {
+                self.should_close = true;
+
+                cx.close_window();
+
+                if let Some(callback) = &self.on_close {
+                    callback(cx);
+                }
+
+                meta.consume();
+            }
+
+            WindowEvent::FocusNext => {
+                cx.focus_next();
+            }
+
+            WindowEvent::FocusPrev => {
+                cx.focus_prev();
+            }
+
+            _ => {}
+        })
+    }
+}
+
+/// Returns the options used to open a baseview window for the given window description.
+pub(crate) fn window_open_options(
+    win_desc: &WindowDescription,
+    scale_policy: WindowScalePolicy,
+) -> WindowOpenOptions {
+    WindowOpenOptions {
+        title: win_desc.title.clone(),
+        size: baseview::Size::new(
+            // We have our own uniform non-DPI scaling factor that gets applied in addition to
+            // the DPI scaling since both can change independently at runtime
+            win_desc.inner_size.width as f64 * win_desc.user_scale_factor,
+            win_desc.inner_size.height as f64 * win_desc.user_scale_factor,
+        ),
+        scale: scale_policy,
+        gl_config: Some(GlConfig { vsync: false, ..GlConfig::default() }),
+    }
+}
+
+/// Creates a skia context for the given OpenGL context, which must be current.
+pub(crate) fn create_gr_context(context: &GlContext) -> skia_safe::gpu::DirectContext {
+    gl::load_with(|s| context.get_proc_address(s));
+    let interface = skia_safe::gpu::gl::Interface::new_load_with(|name| {
+        if name == "eglGetCurrentDisplay" {
+            return std::ptr::null();
+        }
+        context.get_proc_address(name)
+    })
+    .expect("Could not create interface");
+
+    // https://github.com/rust-skia/rust-skia/issues/476
+    let mut context_options = ContextOptions::new();
+    context_options.skip_gl_error_checks = context_options::Enable::Yes;
+
+    skia_safe::gpu::direct_contexts::make_gl(interface, &context_options)
+        .expect("Could not create direct context")
+}
+
+/// Returns the framebuffer info of the current OpenGL context.
+pub(crate) fn framebuffer_info() -> FramebufferInfo {
+    let mut fboid: GLint = 0;
+    unsafe { gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut fboid) };
+
+    FramebufferInfo {
+        fboid: fboid.try_into().unwrap(),
+        format: skia_safe::gpu::gl::Format::RGBA8.into(),
+        ..Default::default()
+    }
+}
 
 pub fn create_surface(
     size: (i32, i32),
@@ -0,0 +1,63 @@
+use vizia_core::prelude::*;
+
+use crate::window::Window;
+
+/// Modifiers for setting the properties of a window.
+///
+/// Window properties are read when the window is opened, so changes made to them afterwards have no effect.
+pub trait WindowModifiers {
+    /// Sets a callback which is called when the window is closed.
+    fn on_close(self, callback: impl Fn(&mut EventContext) + 'static) -> Self;
+    /// Sets a callback which is called when the window has been opened.
+    fn on_create(self, callback: impl Fn(&mut EventContext) + 'static) -> Self;
+    /// Sets the title of the window to the given value. Accepts a type, or lens to a type, which implements `ToString`.
+    fn title<T: ToString>(self, title: impl Res<T>) -> Self;
+    /// Sets the inner size of the window to the given value. Accepts a value, or lens, which can be converted to a
+    /// [`WindowSize`].
+    fn inner_size<S: Into<WindowSize>>(self, size: impl Res<S>) -> Self;
+    /// Sets the position of the window relative to the window which contains it. Accepts a value, or lens, which can
+    /// be converted to a [`WindowPosition`].
+    ///
+    /// Baseview places windows itself, so the position is only reported to views within the window.
+    fn position<P: Into<WindowPosition>>(self, position: impl Res<P>) -> Self;
+}
+
+impl WindowModifiers for Handle<'_, Window> {
+    fn on_close(self, callback: impl Fn(&mut EventContext) + 'static) -> Self {
+        self.modify(|window| window.on_close = Some(Box::new(callback)))
+    }
+
+    fn on_create(self, callback: impl Fn(&mut EventContext) + 'static) -> Self {
+        self.modify(|window| window.on_create = Some(Box::new(callback)))
+    }
+
+    fn title<T: ToString>(mut self, title: impl Res<T>) -> Self {
+        let entity = self.entity();
+        let title = title.get(&self).to_string();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.title = title;
+        }
+
+        self
+    }
+
+    fn inner_size<S: Into<WindowSize>>(mut self, size: impl Res<S>) -> Self {
+        let entity = self.entity();
+        let size = size.get(&self).into();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.inner_size = size;
+        }
+
+        self
+    }
+
+    fn position<P: Into<WindowPosition>>(mut self, position: impl Res<P>) -> Self {
+        let entity = self.entity();
+        let pos = Some(position.get(&self).into());
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.position = pos;
+        }
+
+        self
+    }
+}
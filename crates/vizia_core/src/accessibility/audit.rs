@@ -0,0 +1,306 @@
+use accesskit::Node;
+use vizia_storage::TreeIterator;
+
+use crate::prelude::*;
+use crate::style::Abilities;
+use crate::systems::get_access_node;
+use crate::tree::is_navigatable;
+
+// Minimum contrast ratio between text and its background, as specified by WCAG 2 level AA.
+const MIN_CONTRAST_RATIO: f32 = 4.5;
+// Minimum contrast ratio for large text, i.e. text which is at least 24px, or 18.66px and bold.
+const MIN_CONTRAST_RATIO_LARGE_TEXT: f32 = 3.0;
+
+/// The kind of problem found by an accessibility audit.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditIssueKind {
+    /// A view which can be focused with the keyboard has no accessible name. A name can be provided with
+    /// the `name` or `labelled_by` modifiers, or by a child label.
+    MissingName,
+    /// A view which can be interacted with has no accessibility role. A role can be provided with the
+    /// `role` modifier.
+    UnknownRole,
+    /// An image has no alternative text. Alternative text can be provided with the `name` modifier, or
+    /// a decorative image can be hidden from assistive technologies with the `hidden` modifier.
+    MissingAltText,
+    /// The contrast ratio between the color of some text and its background is below the minimum.
+    LowContrast {
+        /// The contrast ratio between the font color and the background color.
+        ratio: f32,
+        /// The minimum contrast ratio required for the size of the text.
+        minimum: f32,
+    },
+    /// Keyboard focus is locked within a view which contains nothing to focus, or which isn't displayed.
+    KeyboardTrap,
+}
+
+/// A problem found by an accessibility audit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditIssue {
+    /// The view with the problem.
+    pub entity: Entity,
+    /// The kind of problem.
+    pub kind: AuditIssueKind,
+}
+
+/// The results of an accessibility audit of the view tree, returned by
+/// [`Context::audit_accessibility`](crate::context::Context::audit_accessibility).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditReport {
+    /// The problems found by the audit, in tree order.
+    pub issues: Vec<AuditIssue>,
+}
+
+impl AuditReport {
+    /// Returns true if the audit found no problems.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns an iterator over the problems found with the given view.
+    pub fn issues_for(&self, entity: Entity) -> impl Iterator<Item = &AuditIssueKind> {
+        self.issues.iter().filter(move |issue| issue.entity == entity).map(|issue| &issue.kind)
+    }
+}
+
+/// Audits the view tree and the accessibility nodes built from it.
+pub(crate) fn audit(cx: &mut Context) -> AuditReport {
+    let mut report = AuditReport::default();
+
+    let entities = TreeIterator::full(&cx.tree).collect::<Vec<_>>();
+    for entity in entities {
+        if entity == Entity::root() || !cx.views.contains_key(&entity) || !is_displayed(cx, entity)
+        {
+            continue;
+        }
+
+        let mut issue = |kind| report.issues.push(AuditIssue { entity, kind });
+
+        if is_focus_trap(cx, entity) {
+            issue(AuditIssueKind::KeyboardTrap);
+        }
+
+        let Some(node) = build_node(cx, entity) else {
+            continue;
+        };
+
+        // Views hidden from assistive technologies only need to meet the visual checks.
+        if !is_hidden(cx, entity) {
+            let abilities = cx.style.abilities.get(entity).copied().unwrap_or_default();
+            let navigable = abilities.contains(Abilities::NAVIGABLE);
+            let interactive = navigable
+                || abilities.contains(Abilities::CHECKABLE)
+                || node.default_action_verb().is_some();
+
+            if interactive && node.role() == Role::Unknown {
+                issue(AuditIssueKind::UnknownRole);
+            }
+
+            if node.role() == Role::Image {
+                if !has_name(&node) {
+                    issue(AuditIssueKind::MissingAltText);
+                }
+            } else if navigable && !has_name(&node) && !has_text_content(cx, entity) {
+                issue(AuditIssueKind::MissingName);
+            }
+        }
+
+        if let Some((ratio, minimum)) = text_contrast(cx, entity) {
+            if ratio < minimum {
+                issue(AuditIssueKind::LowContrast { ratio, minimum });
+            }
+        }
+    }
+
+    report
+}
+
+// Builds the accessibility node of a view as it would be sent to the platform.
+fn build_node(cx: &mut Context, entity: Entity) -> Option<Node> {
+    let mut access_context = AccessContext {
+        current: entity,
+        tree: &cx.tree,
+        cache: &cx.cache,
+        style: &cx.style,
+        text_context: &mut cx.text_context,
+    };
+
+    get_access_node(&mut access_context, &mut cx.views, entity)
+        .map(|node| node.node_builder.build())
+}
+
+fn has_name(node: &Node) -> bool {
+    node.name().is_some_and(|name| !name.trim().is_empty()) || !node.labelled_by().is_empty()
+}
+
+// Returns true if the view, or one of its descendants, contains text which can name the view.
+fn has_text_content(cx: &Context, entity: Entity) -> bool {
+    entity.branch_iter(&cx.tree).any(|descendant| {
+        cx.style.text.get(descendant).is_some_and(|text| !text.trim().is_empty())
+            || (cx.style.role.get(descendant) == Some(&Role::Label)
+                && cx.style.name.get(descendant).is_some_and(|name| !name.trim().is_empty()))
+    })
+}
+
+fn is_displayed(cx: &Context, entity: Entity) -> bool {
+    entity.parent_iter(&cx.tree).all(|ancestor| {
+        cx.style.display.get(ancestor).copied().unwrap_or_default() != Display::None
+    })
+}
+
+fn is_hidden(cx: &Context, entity: Entity) -> bool {
+    entity.parent_iter(&cx.tree).any(|ancestor| cx.style.hidden.get(ancestor) == Some(&true))
+}
+
+// A view which locks focus within its subtree traps keyboard users if there is nothing within it to focus.
+fn is_focus_trap(cx: &Context, entity: Entity) -> bool {
+    if cx.tree.is_window(entity) || cx.tree.lock_focus_within(entity) != entity {
+        return false;
+    }
+
+    !entity.branch_iter(&cx.tree).any(|node| is_navigatable(&cx.tree, &cx.style, node, entity))
+}
+
+// Returns the contrast ratio of the text of a view and the minimum ratio required for its size.
+fn text_contrast(cx: &Context, entity: Entity) -> Option<(f32, f32)> {
+    if cx.style.text.get(entity).map_or(true, |text| text.trim().is_empty()) {
+        return None;
+    }
+
+    let background = background_color(cx, entity);
+    let font_color = cx.style.font_color.get(entity).copied().unwrap_or(Color::black());
+    let foreground = blend(font_color, background);
+
+    let font_size = cx.style.font_size.get(entity).map_or(16.0, |font_size| font_size.0);
+    let bold = cx.style.font_weight.get(entity).is_some_and(|font_weight| font_weight.0 >= 700);
+    let minimum = if font_size >= 24.0 || (bold && font_size >= 18.66) {
+        MIN_CONTRAST_RATIO_LARGE_TEXT
+    } else {
+        MIN_CONTRAST_RATIO
+    };
+
+    Some((contrast_ratio(foreground, background), minimum))
+}
+
+// Resolves the opaque color behind the text of a view by blending the background colors of the view and its
+// ancestors, on top of a white window if none of them are opaque.
+fn background_color(cx: &Context, entity: Entity) -> [f32; 3] {
+    let mut layers = Vec::new();
+    for ancestor in entity.parent_iter(&cx.tree) {
+        if let Some(color) = cx.style.background_color.get(ancestor) {
+            layers.push(*color);
+            if color.a() == 255 {
+                break;
+            }
+        }
+    }
+
+    layers.into_iter().rev().fold([1.0, 1.0, 1.0], |background, color| blend(color, background))
+}
+
+// Blends a color over an opaque background, returning the resulting color with components in the range 0 to 1.
+fn blend(color: Color, background: [f32; 3]) -> [f32; 3] {
+    let alpha = color.a() as f32 / 255.0;
+    let rgb = [color.r(), color.g(), color.b()];
+    std::array::from_fn(|i| rgb[i] as f32 / 255.0 * alpha + background[i] * (1.0 - alpha))
+}
+
+fn relative_luminance(rgb: [f32; 3]) -> f32 {
+    let [r, g, b] =
+        rgb.map(|c| if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) });
+
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+fn contrast_ratio(a: [f32; 3], b: [f32; 3]) -> f32 {
+    let a = relative_luminance(a);
+    let b = relative_luminance(b);
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contrast_ratio_of_black_and_white() {
+        assert!((contrast_ratio([0.0; 3], [1.0; 3]) - 21.0).abs() < 1e-3);
+        assert!((contrast_ratio([0.5; 3], [0.5; 3]) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn reports_missing_name_and_role() {
+        let mut cx = Context::new();
+        let icon_button = Button::new(&mut cx, |cx| Element::new(cx)).entity();
+        let named_button = Button::new(&mut cx, |cx| Label::new(cx, "Save")).entity();
+        let unknown = Element::new(&mut cx).navigable(true).name("Custom").entity();
+
+        let report = cx.audit_accessibility();
+
+        assert_eq!(
+            report.issues_for(icon_button).collect::<Vec<_>>(),
+            [&AuditIssueKind::MissingName]
+        );
+        assert_eq!(report.issues_for(named_button).count(), 0);
+        assert_eq!(report.issues_for(unknown).collect::<Vec<_>>(), [&AuditIssueKind::UnknownRole]);
+    }
+
+    #[test]
+    fn reports_missing_alt_text() {
+        let mut cx = Context::new();
+        let image = Image::new(&mut cx, "image.png").entity();
+        let described = Image::new(&mut cx, "image.png").name("A description").entity();
+        let decorative = Image::new(&mut cx, "image.png").hidden(true).entity();
+
+        let report = cx.audit_accessibility();
+
+        assert_eq!(report.issues_for(image).collect::<Vec<_>>(), [&AuditIssueKind::MissingAltText]);
+        assert_eq!(report.issues_for(described).count(), 0);
+        assert_eq!(report.issues_for(decorative).count(), 0);
+    }
+
+    #[test]
+    fn reports_low_contrast() {
+        let mut cx = Context::new();
+        let mut faint = Entity::null();
+        let mut large = Entity::null();
+        let mut readable = Entity::null();
+        VStack::new(&mut cx, |cx| {
+            faint = Label::new(cx, "Faint").color(Color::rgb(170, 170, 170)).entity();
+            large =
+                Label::new(cx, "Large").color(Color::rgb(140, 140, 140)).font_size(24.0).entity();
+            readable = Label::new(cx, "Readable").color(Color::rgb(30, 30, 30)).entity();
+        })
+        .background_color(Color::white());
+
+        let report = cx.audit_accessibility();
+
+        assert!(matches!(
+            report.issues_for(faint).collect::<Vec<_>>()[..],
+            [AuditIssueKind::LowContrast { minimum, .. }] if *minimum == MIN_CONTRAST_RATIO
+        ));
+        assert_eq!(report.issues_for(large).count(), 0);
+        assert_eq!(report.issues_for(readable).count(), 0);
+    }
+
+    #[test]
+    fn reports_keyboard_trap() {
+        let mut cx = Context::new();
+        let trap = VStack::new(&mut cx, |cx| {
+            Label::new(cx, "Nothing to focus");
+        })
+        .lock_focus_to_within()
+        .entity();
+
+        let dialog = VStack::new(&mut cx, |cx| {
+            Button::new(cx, |cx| Label::new(cx, "Close"));
+        })
+        .lock_focus_to_within()
+        .entity();
+
+        let report = cx.audit_accessibility();
+
+        assert_eq!(report.issues_for(trap).collect::<Vec<_>>(), [&AuditIssueKind::KeyboardTrap]);
+        assert_eq!(report.issues_for(dialog).count(), 0);
+    }
+}
@@ -1,3 +1,5 @@
+pub(crate) mod audit;
+
 use crate::entity::Entity;
 use accesskit::NodeId;
 use vizia_id::GenerationalId;
@@ -1,6 +1,6 @@
 use crate::prelude::*;
 
-use vizia_style::{BorderWidth, Property};
+use vizia_style::{BorderWidth, EasingFunction, Property};
 
 pub struct AnimationBuilder<'a> {
     pub(crate) keyframes: Vec<KeyframeBuilder<'a>>,
@@ -31,13 +31,24 @@ impl<'a> AnimationBuilder<'a> {
 
 pub struct KeyframeBuilder<'a> {
     pub(crate) time: f32,
+    pub(crate) timing_function: Option<EasingFunction>,
     pub(crate) properties: Vec<Property<'a>>,
 }
 
 // TODO: Make a macro for these
 impl<'a> KeyframeBuilder<'a> {
     pub(crate) fn new(time: f32) -> Self {
-        Self { time, properties: Vec::new() }
+        Self { time, timing_function: None, properties: Vec::new() }
+    }
+
+    /// Sets the easing function used between this keyframe and the next. Defaults to linear.
+    ///
+    /// A spring easing function, e.g. `EasingFunction::Spring(170.0, 26.0, 1.0)`, is scaled so that
+    /// the spring comes to rest at the next keyframe.
+    pub fn timing_function(mut self, easing: EasingFunction) -> Self {
+        self.timing_function = Some(easing);
+
+        self
     }
 
     // DISPLAY
@@ -1,5 +1,6 @@
 use crate::animation::Interpolator;
 use hashbrown::HashSet;
+use vizia_style::{AnimationDirection, AnimationFillMode};
 
 use crate::prelude::*;
 
@@ -13,6 +14,33 @@ pub(crate) struct Keyframe<T: Interpolator> {
     pub timing_function: TimingFunction,
 }
 
+/// The kinds of event emitted by a playing animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum AnimationEventKind {
+    Start,
+    Iteration,
+    End,
+}
+
+/// An action which changes the playback of an active animation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PlaybackAction {
+    /// Applies the playback options of the animated entity when the animation is played.
+    Configure {
+        iteration_count: f32,
+        direction: AnimationDirection,
+        fill_mode: AnimationFillMode,
+        paused: bool,
+    },
+    Pause,
+    Resume,
+    /// Reverses the playback rate.
+    Reverse,
+    /// Moves the animation to the given time, excluding the delay.
+    Seek(Duration),
+    SetPlaybackRate(f32),
+}
+
 /// Represents an animation of a property with type `T`.
 #[derive(Clone, Debug)]
 pub(crate) struct AnimationState<T: Interpolator> {
@@ -37,6 +65,26 @@ pub(crate) struct AnimationState<T: Interpolator> {
 
     pub active: bool,
 
+    /// Whether the animation has passed its delay and started playing.
+    pub started: bool,
+
+    /// The number of times the animation repeats, which may be infinite.
+    pub iteration_count: f32,
+    /// The current iteration, used to emit iteration events.
+    pub iteration: u32,
+    /// The direction of each iteration.
+    pub direction: AnimationDirection,
+    /// Whether values are applied during the delay and after the animation ends.
+    pub fill_mode: AnimationFillMode,
+    /// The playback rate, where negative values play the animation backwards.
+    pub rate: f32,
+    /// The elapsed time in seconds, scaled by the playback rate, at `start_time`.
+    pub offset: f32,
+    /// Whether the animation is paused at `offset`.
+    pub paused: bool,
+    /// Whether the output must be recomputed, e.g. after seeking a paused or finished animation.
+    pub needs_update: bool,
+
     /// For transitions. The starting rule for this transition.
     pub from_rule: usize,
     /// For tansitions. The ending rule for this transition.
@@ -63,6 +111,15 @@ where
             t: 0.0,
             dt: 0.0,
             active: false,
+            started: false,
+            iteration_count: 1.0,
+            iteration: 0,
+            direction: AnimationDirection::Normal,
+            fill_mode: AnimationFillMode::None,
+            rate: 1.0,
+            offset: 0.0,
+            paused: false,
+            needs_update: false,
             entities: HashSet::new(),
             from_rule: usize::MAX,
             to_rule: usize::MAX,
@@ -94,6 +151,11 @@ where
     pub(crate) fn play(&mut self, entity: Entity) {
         self.active = true;
         self.t = 0.0;
+        self.started = false;
+        self.iteration = 0;
+        self.rate = 1.0;
+        self.offset = 0.0;
+        self.paused = false;
         self.start_time = Instant::now();
         self.entities.insert(entity);
     }
@@ -101,6 +163,136 @@ where
     pub(crate) fn is_transition(&self) -> bool {
         !(self.from_rule == usize::MAX && self.to_rule == usize::MAX)
     }
+
+    /// Returns the elapsed time in seconds at the given time, including the delay.
+    fn elapsed(&self, time: Instant) -> f32 {
+        if self.paused {
+            self.offset
+        } else {
+            self.offset + time.saturating_duration_since(self.start_time).as_secs_f32() * self.rate
+        }
+    }
+
+    /// Returns the number of iterations played at the given time, excluding the delay.
+    pub(crate) fn active_time(&self, time: Instant) -> f32 {
+        self.elapsed(time) / self.duration.as_secs_f32().max(f32::EPSILON) - self.dt
+    }
+
+    /// Returns true if the animation has played to its end, or back to its start when playing backwards.
+    pub(crate) fn is_finished(&self, active_time: f32) -> bool {
+        if self.rate < 0.0 {
+            active_time <= 0.0
+        } else {
+            active_time >= self.iteration_count
+        }
+    }
+
+    /// Returns the current iteration and the progress through it, between 0.0 and 1.0.
+    pub(crate) fn iteration_progress(&self, active_time: f32) -> (u32, f32) {
+        let active_time = active_time.clamp(0.0, self.iteration_count);
+
+        // The end of the animation belongs to the last iteration rather than the start of the next.
+        let iteration = if active_time >= self.iteration_count {
+            (self.iteration_count.ceil() - 1.0).max(0.0)
+        } else {
+            active_time.floor()
+        };
+
+        (iteration as u32, (active_time - iteration).clamp(0.0, 1.0))
+    }
+
+    /// Returns the progress through the keyframes for an iteration, taking the direction into account.
+    pub(crate) fn directed_progress(&self, iteration: u32, progress: f32) -> f32 {
+        let reversed = match self.direction {
+            AnimationDirection::Normal => false,
+            AnimationDirection::Reverse => true,
+            AnimationDirection::Alternate => iteration % 2 == 1,
+            AnimationDirection::AlternateReverse => iteration % 2 == 0,
+        };
+
+        if reversed {
+            1.0 - progress
+        } else {
+            progress
+        }
+    }
+
+    /// Applies a playback action at the given time.
+    pub(crate) fn apply(&mut self, action: PlaybackAction, time: Instant) {
+        match action {
+            PlaybackAction::Configure { iteration_count, direction, fill_mode, paused } => {
+                self.iteration_count = iteration_count.max(0.0);
+                self.direction = direction;
+                self.fill_mode = fill_mode;
+                self.persistent =
+                    matches!(fill_mode, AnimationFillMode::Forwards | AnimationFillMode::Both);
+                if paused {
+                    self.apply(PlaybackAction::Pause, time);
+                }
+            }
+
+            PlaybackAction::Pause => {
+                if !self.paused {
+                    self.offset = self.elapsed(time);
+                    self.paused = true;
+                }
+            }
+
+            PlaybackAction::Resume => {
+                if self.paused {
+                    self.start_time = time;
+                    self.paused = false;
+                }
+            }
+
+            PlaybackAction::Reverse => {
+                self.rebase(time);
+                self.rate = -self.rate;
+            }
+
+            PlaybackAction::Seek(seek_time) => {
+                self.offset = seek_time.as_secs_f32() + self.dt * self.duration.as_secs_f32();
+                self.start_time = time;
+            }
+
+            PlaybackAction::SetPlaybackRate(rate) => {
+                self.rebase(time);
+                self.rate = rate;
+            }
+        }
+
+        self.needs_update = true;
+    }
+
+    // Moves the start time to the given time without changing the elapsed time.
+    fn rebase(&mut self, time: Instant) {
+        self.offset = self.elapsed(time);
+        self.start_time = time;
+    }
+}
+
+impl<T> AnimationState<T>
+where
+    T: Interpolator + Clone,
+{
+    /// Returns the interpolated value of the keyframes at the given progress.
+    pub(crate) fn sample(&self, progress: f32) -> T {
+        if self.keyframes.len() == 1 {
+            return self.keyframes[0].value.clone();
+        }
+
+        let mut i = 0;
+        while i < self.keyframes.len() - 1 && self.keyframes[i + 1].time < progress {
+            i += 1;
+        }
+        let start = &self.keyframes[i];
+        let end = &self.keyframes[i + 1];
+
+        let normalised_elapsed_time = (progress - start.time) / (end.time - start.time);
+
+        let timing_t = start.timing_function.value(normalised_elapsed_time);
+        T::interpolate(&start.value, &end.value, timing_t)
+    }
 }
 
 impl<Prop> Default for AnimationState<Prop>
@@ -119,6 +311,15 @@ where
             t: 0.0,
             dt: 0.0,
             active: false,
+            started: false,
+            iteration_count: 1.0,
+            iteration: 0,
+            direction: AnimationDirection::Normal,
+            fill_mode: AnimationFillMode::None,
+            rate: 1.0,
+            offset: 0.0,
+            paused: false,
+            needs_update: false,
             entities: HashSet::new(),
             from_rule: usize::MAX,
             to_rule: usize::MAX,
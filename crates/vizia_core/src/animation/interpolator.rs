@@ -1,8 +1,8 @@
 use morphorm::Units;
 use vizia_style::{
-    Angle, BackgroundSize, ClipPath, Color, ColorStop, Display, Filter, FontSize, Gradient, Length,
-    LengthOrPercentage, LengthPercentageOrAuto, LengthValue, LineDirection, LinearGradient,
-    Opacity, PercentageOrNumber, Rect, Scale, Shadow, Transform, Translate, RGBA,
+    Angle, BackgroundSize, ClipPath, Color, ColorStop, Display, DropShadow, Filter, FontSize,
+    Gradient, Length, LengthOrPercentage, LengthPercentageOrAuto, LengthValue, LineDirection,
+    LinearGradient, Opacity, PercentageOrNumber, Rect, Scale, Shadow, Transform, Translate, RGBA,
 };
 
 use skia_safe::Matrix;
@@ -93,10 +93,77 @@ impl Interpolator for Filter {
             (Filter::Blur(start), Filter::Blur(end)) => {
                 Filter::Blur(Length::interpolate(start, end, t))
             }
+
+            (Filter::Brightness(start), Filter::Brightness(end)) => {
+                Filter::Brightness(f32::interpolate(start, end, t))
+            }
+
+            (Filter::Contrast(start), Filter::Contrast(end)) => {
+                Filter::Contrast(f32::interpolate(start, end, t))
+            }
+
+            (Filter::Grayscale(start), Filter::Grayscale(end)) => {
+                Filter::Grayscale(f32::interpolate(start, end, t))
+            }
+
+            (Filter::HueRotate(start), Filter::HueRotate(end)) => {
+                Filter::HueRotate(Angle::interpolate(start, end, t))
+            }
+
+            (Filter::Invert(start), Filter::Invert(end)) => {
+                Filter::Invert(f32::interpolate(start, end, t))
+            }
+
+            (Filter::Saturate(start), Filter::Saturate(end)) => {
+                Filter::Saturate(f32::interpolate(start, end, t))
+            }
+
+            (Filter::Sepia(start), Filter::Sepia(end)) => {
+                Filter::Sepia(f32::interpolate(start, end, t))
+            }
+
+            (Filter::DropShadow(start), Filter::DropShadow(end)) => {
+                Filter::DropShadow(DropShadow {
+                    x_offset: Length::interpolate(&start.x_offset, &end.x_offset, t),
+                    y_offset: Length::interpolate(&start.y_offset, &end.y_offset, t),
+                    blur_radius: Option::interpolate(&start.blur_radius, &end.blur_radius, t),
+                    color: Option::interpolate(&start.color, &end.color, t),
+                })
+            }
+
+            _ => {
+                if t < 0.5 {
+                    start.clone()
+                } else {
+                    end.clone()
+                }
+            }
         }
     }
 }
 
+// A filter list is interpolated function by function when both lists use the same functions, with the
+// shorter list padded by identity functions. Otherwise the lists cannot be interpolated and change halfway.
+impl Interpolator for Vec<Filter> {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        let len = start.len().max(end.len());
+
+        let compatible = start.iter().zip(end.iter()).all(|(start, end)| start.is_same_kind(end));
+
+        if !compatible {
+            return if t < 0.5 { start.clone() } else { end.clone() };
+        }
+
+        (0..len)
+            .map(|index| {
+                let s = start.get(index).cloned().unwrap_or_else(|| end[index].identity());
+                let e = end.get(index).cloned().unwrap_or_else(|| s.identity());
+                Filter::interpolate(&s, &e, t)
+            })
+            .collect()
+    }
+}
+
 impl Interpolator for LengthValue {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         match (end, start) {
@@ -215,16 +282,24 @@ impl Interpolator for Matrix {
     }
 }
 
-impl<T: Interpolator> Interpolator for Vec<T> {
-    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
-        start
-            .iter()
-            .zip(end.iter())
-            .map(|(start, end)| T::interpolate(start, end, t))
-            .collect::<Vec<T>>()
-    }
+macro_rules! impl_vec_interpolator {
+    ($($t:ty),+) => {
+        $(
+            impl Interpolator for Vec<$t> {
+                fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+                    start
+                        .iter()
+                        .zip(end.iter())
+                        .map(|(start, end)| <$t>::interpolate(start, end, t))
+                        .collect::<Vec<$t>>()
+                }
+            }
+        )+
+    };
 }
 
+impl_vec_interpolator!(Transform, ImageOrGradient, BackgroundSize, Shadow);
+
 impl Interpolator for ImageOrGradient {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         match (start, end) {
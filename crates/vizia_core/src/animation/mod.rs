@@ -2,7 +2,7 @@ mod animation_id;
 pub use animation_id::{AnimId, Animation};
 
 mod animation_state;
-pub(crate) use animation_state::{AnimationState, Keyframe};
+pub(crate) use animation_state::{AnimationEventKind, AnimationState, Keyframe, PlaybackAction};
 
 mod interpolator;
 pub(crate) use interpolator::Interpolator;
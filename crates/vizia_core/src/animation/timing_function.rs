@@ -1,9 +1,17 @@
-#[derive(Debug, Clone, Copy)]
-pub(crate) struct TimingFunction {
-    x1: f32,
-    x2: f32,
-    y1: f32,
-    y2: f32,
+use vizia_style::{EasingFunction, StepPosition};
+
+/// The distance from the target, as a fraction of the total distance, below which a spring is considered settled.
+const SPRING_REST_DISTANCE: f32 = 0.001;
+/// The velocity, in fractions of the total distance per second, below which a spring is considered settled.
+const SPRING_REST_VELOCITY: f32 = 0.01;
+/// Upper bound on the duration of a spring animation in seconds.
+const SPRING_MAX_DURATION: f32 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TimingFunction {
+    CubicBezier { x1: f32, y1: f32, x2: f32, y2: f32 },
+    Steps { steps: u32, position: StepPosition },
+    Spring(Spring),
 }
 
 impl Default for TimingFunction {
@@ -30,18 +38,87 @@ impl TimingFunction {
     }
 }
 
+impl From<EasingFunction> for TimingFunction {
+    fn from(easing: EasingFunction) -> Self {
+        match easing {
+            EasingFunction::Linear => TimingFunction::linear(),
+            EasingFunction::Ease => TimingFunction::ease(),
+            EasingFunction::EaseIn => TimingFunction::ease_in(),
+            EasingFunction::EaseOut => TimingFunction::ease_out(),
+            EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
+            EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
+            EasingFunction::Steps(steps, position) => TimingFunction::steps(steps, position),
+            EasingFunction::Spring(stiffness, damping, mass) => {
+                TimingFunction::spring(stiffness, damping, mass)
+            }
+        }
+    }
+}
+
 impl TimingFunction {
     pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
-        Self { x1, y1, x2, y2 }
+        Self::CubicBezier { x1, y1, x2, y2 }
+    }
+
+    pub fn steps(steps: u32, position: StepPosition) -> Self {
+        // Without a jump at either end, a single step would have no jumps at all.
+        let min_steps = if position == StepPosition::JumpNone { 2 } else { 1 };
+        Self::Steps { steps: steps.max(min_steps), position }
+    }
+
+    pub fn spring(stiffness: f32, damping: f32, mass: f32) -> Self {
+        Self::Spring(Spring::new(stiffness, damping, mass, 0.0))
+    }
+
+    /// Returns the spring parameters if this is a spring timing function.
+    pub fn as_spring(&self) -> Option<&Spring> {
+        match self {
+            Self::Spring(spring) => Some(spring),
+            _ => None,
+        }
     }
 
     pub fn value(&self, x: f32) -> f32 {
-        // Linear
-        if self.x1 == self.y1 && self.x2 == self.y2 {
-            return x;
+        match *self {
+            Self::CubicBezier { x1, y1, x2, y2 } => {
+                // Linear
+                if x1 == y1 && x2 == y2 {
+                    return x;
+                }
+
+                Self::calc_bezier(Self::find_t_for_x(x, x1, x2), y1, y2)
+            }
+
+            Self::Steps { steps, position } => Self::calc_steps(x, steps, position),
+
+            Self::Spring(spring) => spring.value(x),
+        }
+    }
+
+    // See https://www.w3.org/TR/css-easing-1/#step-easing-algo
+    fn calc_steps(x: f32, steps: u32, position: StepPosition) -> f32 {
+        let steps = steps as f32;
+        let mut current_step = (x * steps).floor();
+
+        if matches!(position, StepPosition::Start | StepPosition::JumpBoth) {
+            current_step += 1.0;
         }
 
-        Self::calc_bezier(self.find_t_for_x(x), self.y1, self.y2)
+        let jumps = match position {
+            StepPosition::JumpNone => steps - 1.0,
+            StepPosition::JumpBoth => steps + 1.0,
+            _ => steps,
+        };
+
+        if x >= 0.0 && current_step < 0.0 {
+            current_step = 0.0;
+        }
+
+        if x <= 1.0 && current_step > jumps {
+            current_step = jumps;
+        }
+
+        current_step / jumps
     }
 
     fn calc_bezier(t: f32, a1: f32, a2: f32) -> f32 {
@@ -60,16 +137,16 @@ impl TimingFunction {
         3.0 * a(a1, a2) * t * t + 2.0 * b(a1, a2) * t + c(a1)
     }
 
-    fn find_t_for_x(&self, x: f32) -> f32 {
+    fn find_t_for_x(x: f32, x1: f32, x2: f32) -> f32 {
         let mut guess = x;
         let mut error = f32::MAX;
         for _ in 0..8 {
-            let pos = Self::calc_bezier(guess, self.x1, self.x2);
+            let pos = Self::calc_bezier(guess, x1, x2);
             error = pos - x;
             if error.abs() <= 0.0000001 {
                 return guess;
             }
-            let slope = Self::calc_bezier_slope(guess, self.x1, self.x2);
+            let slope = Self::calc_bezier_slope(guess, x1, x2);
             guess -= error / slope;
         }
         if error.abs() <= 0.0000001 {
@@ -80,9 +157,127 @@ impl TimingFunction {
     }
 }
 
+/// A damped harmonic oscillator which moves from 0.0 to 1.0.
+///
+/// Unlike the other timing functions, the duration of a spring is determined by its physical
+/// parameters, see [`Spring::duration`]. The normalized time `x` passed to [`Spring::value`] is
+/// mapped onto this duration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Spring {
+    pub stiffness: f32,
+    pub damping: f32,
+    pub mass: f32,
+    /// The initial velocity in fractions of the total distance per second.
+    pub velocity: f32,
+    /// The time in seconds for the spring to come to rest.
+    duration: f32,
+}
+
+impl Spring {
+    pub fn new(stiffness: f32, damping: f32, mass: f32, velocity: f32) -> Self {
+        let mut spring = Self {
+            stiffness: stiffness.max(f32::EPSILON),
+            damping: damping.max(0.0),
+            mass: mass.max(f32::EPSILON),
+            velocity,
+            duration: SPRING_MAX_DURATION,
+        };
+
+        spring.duration = spring.settle_time();
+
+        spring
+    }
+
+    /// Returns a copy of the spring with a new initial velocity.
+    pub fn with_velocity(&self, velocity: f32) -> Self {
+        Self::new(self.stiffness, self.damping, self.mass, velocity)
+    }
+
+    /// The time in seconds for the spring to come to rest.
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// Returns the position of the spring at normalized time `x`.
+    pub fn value(&self, x: f32) -> f32 {
+        if x >= 1.0 {
+            return 1.0;
+        }
+
+        1.0 - self.displacement(x.max(0.0) * self.duration)
+    }
+
+    /// Returns the velocity of the spring, in fractions of the total distance per second, at normalized time `x`.
+    pub fn velocity_at(&self, x: f32) -> f32 {
+        if x >= 1.0 {
+            return 0.0;
+        }
+
+        const H: f32 = 0.0001;
+        let t = x.max(0.0) * self.duration;
+        (self.displacement(t) - self.displacement(t + H)) / H
+    }
+
+    // The remaining (signed) distance to the target, as a fraction of the total distance, at time `t` in seconds.
+    fn displacement(&self, t: f32) -> f32 {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping / (2.0 * (self.stiffness * self.mass).sqrt());
+        let v0 = -self.velocity;
+
+        if zeta < 1.0 {
+            // Under-damped
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let b = (zeta * omega + v0) / omega_d;
+            (-zeta * omega * t).exp() * ((omega_d * t).cos() + b * (omega_d * t).sin())
+        } else if zeta == 1.0 {
+            // Critically damped
+            let b = v0 + omega;
+            (1.0 + b * t) * (-omega * t).exp()
+        } else {
+            // Over-damped
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+            let c2 = (v0 - r1) / (r2 - r1);
+            let c1 = 1.0 - c2;
+            c1 * (r1 * t).exp() + c2 * (r2 * t).exp()
+        }
+    }
+
+    fn settle_time(&self) -> f32 {
+        const STEP: f32 = 1.0 / 120.0;
+
+        // The oscillation decays, so once the spring has stayed at rest for a full period it stays at rest. Very short
+        // periods are only sampled a few times per oscillation, so the spring must stay at rest for at least 0.1s.
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping / (2.0 * (self.stiffness * self.mass).sqrt());
+        let period = if zeta < 1.0 {
+            std::f32::consts::TAU / (omega * (1.0 - zeta * zeta).sqrt())
+        } else {
+            std::f32::consts::TAU / omega
+        };
+
+        let mut settled_at = 0.0;
+        let mut t = 0.0;
+        let mut previous = self.displacement(0.0);
+        while t < SPRING_MAX_DURATION && t - settled_at <= period.max(0.1) {
+            t += STEP;
+            let current = self.displacement(t);
+            let velocity = (current - previous).abs() / STEP;
+            if current.abs() > SPRING_REST_DISTANCE || velocity > SPRING_REST_VELOCITY {
+                settled_at = t;
+            }
+            previous = current;
+        }
+
+        (settled_at + STEP).min(SPRING_MAX_DURATION)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::TimingFunction;
+    use super::{TimingFunction, SPRING_MAX_DURATION, SPRING_REST_DISTANCE};
+    use vizia_style::StepPosition;
 
     #[test]
     fn linear() {
@@ -95,4 +290,77 @@ mod tests {
         let timing_func = TimingFunction::ease();
         assert_eq!(timing_func.value(0.25), 0.4085106);
     }
+
+    #[test]
+    fn steps_end() {
+        let timing_func = TimingFunction::steps(4, StepPosition::End);
+        assert_eq!(timing_func.value(0.0), 0.0);
+        assert_eq!(timing_func.value(0.3), 0.25);
+        assert_eq!(timing_func.value(0.99), 0.75);
+        assert_eq!(timing_func.value(1.0), 1.0);
+    }
+
+    #[test]
+    fn steps_start() {
+        let timing_func = TimingFunction::steps(4, StepPosition::Start);
+        assert_eq!(timing_func.value(0.0), 0.25);
+        assert_eq!(timing_func.value(0.3), 0.5);
+        assert_eq!(timing_func.value(1.0), 1.0);
+    }
+
+    #[test]
+    fn steps_jump_none() {
+        let timing_func = TimingFunction::steps(5, StepPosition::JumpNone);
+        assert_eq!(timing_func.value(0.0), 0.0);
+        assert_eq!(timing_func.value(0.5), 0.5);
+        assert_eq!(timing_func.value(1.0), 1.0);
+    }
+
+    #[test]
+    fn steps_jump_none_single_step() {
+        let timing_func = TimingFunction::steps(1, StepPosition::JumpNone);
+        assert_eq!(timing_func, TimingFunction::steps(2, StepPosition::JumpNone));
+        assert_eq!(timing_func.value(0.25), 0.0);
+        assert_eq!(timing_func.value(0.75), 1.0);
+    }
+
+    #[test]
+    fn spring_settles() {
+        let timing_func = TimingFunction::spring(170.0, 26.0, 1.0);
+        let spring = timing_func.as_spring().unwrap();
+        assert!(spring.duration() > 0.0 && spring.duration() < 10.0);
+        assert_eq!(timing_func.value(0.0), 0.0);
+        assert_eq!(timing_func.value(1.0), 1.0);
+        assert!((timing_func.value(0.99) - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn spring_overshoots_when_underdamped() {
+        let timing_func = TimingFunction::spring(300.0, 5.0, 1.0);
+        let max = (0..100).map(|i| timing_func.value(i as f32 / 100.0)).fold(0.0, f32::max);
+        assert!(max > 1.0);
+    }
+
+    #[test]
+    fn spring_initial_velocity() {
+        let spring =
+            TimingFunction::spring(170.0, 26.0, 1.0).as_spring().unwrap().with_velocity(5.0);
+        assert!((spring.velocity_at(0.0) - 5.0).abs() < 0.1);
+        assert!(spring.value(0.01) > 0.0);
+    }
+
+    #[test]
+    fn spring_stays_at_rest_after_duration() {
+        for (stiffness, damping, mass) in
+            [(170.0, 26.0, 1.0), (300.0, 5.0, 1.0), (100.0, 40.0, 2.0)]
+        {
+            let timing_func = TimingFunction::spring(stiffness, damping, mass);
+            let spring = timing_func.as_spring().unwrap();
+            let steps = ((SPRING_MAX_DURATION - spring.duration()) * 120.0) as usize;
+            for step in 0..steps {
+                let t = spring.duration() + step as f32 / 120.0;
+                assert!(spring.displacement(t).abs() <= SPRING_REST_DISTANCE);
+            }
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use std::{ptr, rc::Rc, sync::Arc};
 
-use chrono::{NaiveDate, NaiveTime};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 
 use crate::prelude::*;
 
@@ -64,13 +64,16 @@ impl_data_simple!(Translate);
 impl_data_simple!(Display);
 impl_data_simple!(Visibility);
 impl_data_simple!(NaiveDate);
+impl_data_simple!(NaiveDateTime);
 impl_data_simple!(NaiveTime);
+impl_data_simple!(Weekday);
 impl_data_simple!(Angle);
 impl_data_simple!(String);
 impl_data_simple!(Entity);
 impl_data_simple!(Localized);
 impl_data_simple!(Length);
 impl_data_simple!(KeyChord);
+impl_data_simple!(KeySequence);
 impl_data_simple!(FamilyOwned);
 impl_data_simple!(FontWeight);
 impl_data_simple!(TextAlign);
@@ -337,3 +340,9 @@ impl Data for Color {
         *self == *other
     }
 }
+
+impl Data for Gradient {
+    fn same(&self, other: &Self) -> bool {
+        *self == *other
+    }
+}
@@ -133,6 +133,10 @@ impl_res_simple!(ButtonVariant);
 impl_res_simple!(AvatarVariant);
 impl_res_clone!(FamilyOwned);
 impl_res_simple!(TextDecorationLine);
+impl_res_simple!(chrono::NaiveDate);
+impl_res_simple!(chrono::NaiveDateTime);
+impl_res_simple!(chrono::NaiveTime);
+impl_res_simple!(chrono::Weekday);
 
 impl<'i> ResGet<FontFamily<'i>> for FontFamily<'i> {
     fn get_ref<'a>(&'a self, _: &'a impl DataContext) -> Option<LensValue<'a, Self>> {
@@ -1,4 +1,4 @@
-use accesskit::{NodeBuilder, NodeId, Rect, TextDirection, TextSelection};
+use accesskit::{NodeBuilder, NodeId, Rect, TextDirection, TextSelection, Toggled};
 
 use crate::{cache::CachedData, prelude::*, text::TextContext};
 
@@ -51,6 +51,36 @@ impl AccessNode {
         self.node_builder.set_role(role);
     }
 
+    /// Sets the name of the node.
+    pub fn set_name(&mut self, name: impl Into<Box<str>>) {
+        self.node_builder.set_name(name);
+    }
+
+    /// Adds an action which can be performed on the node by an assistive technology.
+    pub fn add_action(&mut self, action: Action) {
+        self.node_builder.add_action(action);
+    }
+
+    /// Sets the toggled state of a node, such as a checkbox or switch.
+    pub fn set_toggled(&mut self, toggled: Toggled) {
+        self.node_builder.set_toggled(toggled);
+    }
+
+    /// Sets whether the node is selected, such as the selected tab of a tab view.
+    pub fn set_selected(&mut self, selected: bool) {
+        self.node_builder.set_selected(selected);
+    }
+
+    /// Sets whether the content controlled by the node, such as a popup or submenu, is expanded.
+    pub fn set_expanded(&mut self, expanded: bool) {
+        self.node_builder.set_expanded(expanded);
+    }
+
+    /// Sets the level of the node within a hierarchy, such as an item of a tree view, starting from 1.
+    pub fn set_hierarchical_level(&mut self, level: usize) {
+        self.node_builder.set_hierarchical_level(level);
+    }
+
     /// Sets the direction of any text within the node.
     pub fn set_text_direction(&mut self, text_direction: TextDirection) {
         self.node_builder.set_text_direction(text_direction);
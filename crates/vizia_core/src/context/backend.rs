@@ -204,7 +204,7 @@ impl BackendContext {
     /// Calls the accessibility system and updates the accesskit node tree.
     pub fn process_tree_updates(
         &mut self,
-        process: impl Fn(&mut Vec<Option<accesskit::TreeUpdate>>),
+        mut process: impl FnMut(&mut Vec<Option<accesskit::TreeUpdate>>),
     ) {
         accessibility_system(&mut self.0);
 
@@ -227,7 +227,12 @@ impl BackendContext {
 
     // Returns true if animations are playing
     pub fn process_animations(&mut self) -> bool {
-        animation_system(&mut self.0)
+        let animating = animation_system(&mut self.0);
+
+        // Remove any views which have finished their exit animation.
+        exit_system(&mut self.0);
+
+        animating
     }
 
     /// Massages the style system until everything is coherent
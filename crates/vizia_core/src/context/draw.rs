@@ -19,6 +19,7 @@ use crate::events::ViewHandler;
 use crate::model::ModelDataStore;
 use crate::prelude::*;
 use crate::resource::{ImageOrSvg, ResourceManager};
+use crate::systems::RenderLayer;
 use crate::text::TextContext;
 use vizia_input::MouseState;
 
@@ -65,6 +66,7 @@ pub struct DrawContext<'a> {
     pub(crate) text_context: &'a mut TextContext,
     pub(crate) modifiers: &'a Modifiers,
     pub(crate) mouse: &'a MouseState<Entity>,
+    pub(crate) render_layers: &'a mut HashMap<Entity, RenderLayer>,
 }
 
 macro_rules! get_units_property {
@@ -448,6 +450,11 @@ impl<'a> DrawContext<'a> {
         self.style.shadow.get(self.current)
     }
 
+    /// Returns a reference to the filters of the current view.
+    pub fn filter(&self) -> Option<&Vec<Filter>> {
+        self.style.filter.get(self.current)
+    }
+
     pub fn backdrop_filter(&self) -> Option<&Filter> {
         self.style.backdrop_filter.get(self.current)
     }
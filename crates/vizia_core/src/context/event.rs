@@ -8,7 +8,7 @@ use hashbrown::{HashMap, HashSet};
 use vizia_storage::{LayoutTreeIterator, TreeIterator};
 use vizia_window::WindowPosition;
 
-use crate::animation::{AnimId, Interpolator};
+use crate::animation::{AnimId, Interpolator, PlaybackAction};
 use crate::cache::CachedData;
 use crate::events::{TimedEvent, TimedEventHandle, TimerState, ViewHandler};
 use crate::model::ModelDataStore;
@@ -71,6 +71,8 @@ pub struct EventContext<'a> {
     pub(crate) views: &'a mut HashMap<Entity, Box<dyn ViewHandler>>,
     pub(crate) listeners:
         &'a mut HashMap<Entity, Box<dyn Fn(&mut dyn ViewHandler, &mut EventContext, &mut Event)>>,
+    pub(crate) capture_listeners:
+        &'a mut HashMap<Entity, Vec<Box<dyn Fn(&mut EventContext, &mut Event)>>>,
     pub(crate) resource_manager: &'a mut ResourceManager,
     pub(crate) text_context: &'a mut TextContext,
     pub(crate) modifiers: &'a Modifiers,
@@ -123,6 +125,7 @@ impl<'a> EventContext<'a> {
             data: &mut cx.data,
             views: &mut cx.views,
             listeners: &mut cx.listeners,
+            capture_listeners: &mut cx.capture_listeners,
             resource_manager: &mut cx.resource_manager,
             text_context: &mut cx.text_context,
             modifiers: &cx.modifiers,
@@ -156,6 +159,7 @@ impl<'a> EventContext<'a> {
             data: &mut cx.data,
             views: &mut cx.views,
             listeners: &mut cx.listeners,
+            capture_listeners: &mut cx.capture_listeners,
             resource_manager: &mut cx.resource_manager,
             text_context: &mut cx.text_context,
             modifiers: &cx.modifiers,
@@ -406,6 +410,50 @@ impl<'a> EventContext<'a> {
         false
     }
 
+    /// Pauses an animation playing on the current view.
+    pub fn pause_animation(&mut self, anim_id: impl AnimId) {
+        self.control_animation(anim_id, PlaybackAction::Pause);
+    }
+
+    /// Resumes a paused animation on the current view.
+    pub fn resume_animation(&mut self, anim_id: impl AnimId) {
+        self.control_animation(anim_id, PlaybackAction::Resume);
+    }
+
+    /// Reverses the playback direction of an animation playing on the current view.
+    ///
+    /// A reversed animation ends when it reaches its start.
+    pub fn reverse_animation(&mut self, anim_id: impl AnimId) {
+        self.control_animation(anim_id, PlaybackAction::Reverse);
+    }
+
+    /// Moves an animation playing on the current view to the given time, excluding any delay.
+    ///
+    /// For an animation with multiple iterations, a time greater than the duration seeks into a later iteration.
+    pub fn seek_animation(&mut self, anim_id: impl AnimId, time: Duration) {
+        self.control_animation(anim_id, PlaybackAction::Seek(time));
+    }
+
+    /// Sets the playback rate of an animation playing on the current view.
+    ///
+    /// A rate of 2.0 plays the animation at twice the speed, while a negative rate plays it backwards.
+    pub fn set_animation_playback_rate(&mut self, anim_id: impl AnimId, rate: f32) {
+        self.control_animation(anim_id, PlaybackAction::SetPlaybackRate(rate));
+    }
+
+    /// Returns the progress, between 0.0 and 1.0, through the current iteration of an animation playing
+    /// on the current view, or `None` if the animation is not playing.
+    pub fn animation_progress(&self, anim_id: impl AnimId) -> Option<f32> {
+        let animation_id = anim_id.get(self)?;
+        self.style.animation_progress(self.current, animation_id)
+    }
+
+    fn control_animation(&mut self, anim_id: impl AnimId, action: PlaybackAction) {
+        if let Some(animation_id) = anim_id.get(self) {
+            self.style.enqueue_playback_action(self.current, animation_id, action);
+        }
+    }
+
     /// Add a listener to an entity.
     ///
     /// A listener can be used to handle events which would not normally propagate to the entity.
@@ -721,6 +769,19 @@ impl<'a> EventContext<'a> {
         }
     }
 
+    /// Marks the current view as needing to be composited again without redrawing its contents.
+    pub fn needs_recomposite(&mut self) {
+        let parent_window = self.tree.get_parent_window(self.current).unwrap_or(Entity::root());
+        if let Some(window_state) = self.windows.get_mut(&parent_window) {
+            window_state.recomposite_list.insert(self.current);
+        }
+    }
+
+    /// Marks the current view as needing its accessibility node to be updated.
+    pub fn needs_access_update(&mut self) {
+        self.style.needs_access_update(self.current);
+    }
+
     /// Marks the current view as needing a layout computation.
     pub fn needs_relayout(&mut self) {
         self.style.needs_relayout();
@@ -893,6 +954,7 @@ impl<'a> EventContext<'a> {
         }
 
         self.needs_restyle();
+        self.needs_access_update();
     }
 
     /// Sets the valid state of the current view.
@@ -1055,6 +1117,12 @@ impl<'a> EventContext<'a> {
 
     // FILTER
 
+    /// Sets the filters of the current view.
+    pub fn set_filter(&mut self, filter: impl Into<Vec<Filter>>) {
+        self.style.filter.insert(self.current, filter.into());
+        self.needs_recomposite();
+    }
+
     /// Sets the backdrop filter of the current view.
     pub fn set_backdrop_filter(&mut self, filter: Filter) {
         self.style.backdrop_filter.insert(self.current, filter);
@@ -45,6 +45,7 @@ use crate::{cache::CachedData, resource::ImageOrSvg};
 use crate::model::ModelDataStore;
 use crate::prelude::*;
 use crate::resource::ResourceManager;
+use crate::systems::{compute_matched_rules, ExitingEntity, RenderLayer, EXIT_GRACE_PERIOD};
 use crate::text::TextContext;
 use vizia_input::MouseState;
 use vizia_storage::{ChildIterator, LayoutTreeIterator};
@@ -73,6 +74,7 @@ pub struct WindowState {
     pub needs_relayout: bool,
     pub needs_redraw: bool,
     pub redraw_list: HashSet<Entity>,
+    pub recomposite_list: HashSet<Entity>,
     pub dirty_rect: Option<BoundingBox>,
     pub owner: Option<Entity>,
     pub is_modal: bool,
@@ -98,6 +100,7 @@ pub struct Context {
     pub(crate) listeners:
         HashMap<Entity, Box<dyn Fn(&mut dyn ViewHandler, &mut EventContext, &mut Event)>>,
     pub(crate) global_listeners: Vec<Box<dyn Fn(&mut EventContext, &mut Event)>>,
+    pub(crate) capture_listeners: HashMap<Entity, Vec<Box<dyn Fn(&mut EventContext, &mut Event)>>>,
     pub(crate) style: Style,
     pub(crate) cache: CachedData,
     pub windows: HashMap<Entity, WindowState>,
@@ -130,6 +133,12 @@ pub struct Context {
     pub window_has_focus: bool,
 
     pub(crate) drop_data: Option<DropData>,
+
+    /// Entities which have been removed but remain in the tree until their exit animation finishes.
+    pub(crate) exiting: Vec<ExitingEntity>,
+
+    /// Cached render layers of views which set the `will-change` property.
+    pub(crate) render_layers: HashMap<Entity, RenderLayer>,
 }
 
 impl Default for Context {
@@ -163,6 +172,7 @@ impl Context {
             tree_updates: Vec::new(),
             listeners: HashMap::default(),
             global_listeners: Vec::new(),
+            capture_listeners: HashMap::default(),
             mouse: MouseState::default(),
             modifiers: Modifiers::empty(),
             captured: Entity::null(),
@@ -214,6 +224,9 @@ impl Context {
             window_has_focus: true,
 
             drop_data: None,
+
+            exiting: Vec::new(),
+            render_layers: HashMap::new(),
         };
 
         result.tree.set_window(Entity::root(), true);
@@ -273,6 +286,19 @@ impl Context {
         }
     }
 
+    /// Mark the entity as needing to be composited again without redrawing its contents.
+    ///
+    /// This is used when a property which only affects how a view is composited, such as its transform
+    /// or opacity, changes. A view rendered into a render layer can then reuse its cached contents.
+    pub fn needs_recomposite(&mut self, entity: Entity) {
+        if self.entity_manager.is_alive(entity) {
+            let parent_window = self.tree.get_parent_window(entity).unwrap_or(Entity::root());
+            if let Some(window_state) = self.windows.get_mut(&parent_window) {
+                window_state.recomposite_list.insert(entity);
+            }
+        }
+    }
+
     /// Mark the application as needing to recompute view styles
     pub fn needs_restyle(&mut self, entity: Entity) {
         self.style.restyle.insert(entity).unwrap();
@@ -293,6 +319,12 @@ impl Context {
         self.style.needs_relayout();
     }
 
+    /// Audits the view tree for common accessibility problems, such as focusable views without a name,
+    /// images without alternative text, text with insufficient contrast, and keyboard traps.
+    pub fn audit_accessibility(&mut self) -> AuditReport {
+        crate::accessibility::audit::audit(self)
+    }
+
     pub(crate) fn set_system_flags(&mut self, entity: Entity, system_flags: SystemFlags) {
         if system_flags.contains(SystemFlags::RESTYLE) {
             self.needs_restyle(entity);
@@ -302,6 +334,10 @@ impl Context {
             self.needs_redraw(entity);
         }
 
+        if system_flags.contains(SystemFlags::RECOMPOSITE) {
+            self.needs_recomposite(entity);
+        }
+
         if system_flags.contains(SystemFlags::REFLOW) {
             self.style.needs_text_update(entity);
         }
@@ -387,7 +423,21 @@ impl Context {
     }
 
     /// Removes the provided entity from the application.
+    ///
+    /// If the entity declares an exit animation, either with the `exit_animation` modifier or with a
+    /// style rule using the `:exiting` pseudo-class, then the entity remains in the tree, but cannot
+    /// be interacted with, until the animation has finished. Removing an entity which is already
+    /// exiting removes it immediately.
     pub fn remove(&mut self, entity: Entity) {
+        // The entity may have already been removed along with an ancestor.
+        if !self.entity_manager.is_alive(entity) {
+            return;
+        }
+
+        if self.start_exit(entity) {
+            return;
+        }
+
         let delete_list = entity.branch_iter(&self.tree).collect::<Vec<_>>();
 
         if !delete_list.is_empty() {
@@ -417,8 +467,11 @@ impl Context {
                 image.observers.remove(entity);
             }
 
+            // A view rebuilt with the same id while this one was exiting keeps the id.
             if let Some(identifier) = self.style.ids.get(*entity) {
-                self.entity_identifiers.remove(identifier);
+                if self.entity_identifiers.get(identifier) == Some(entity) {
+                    self.entity_identifiers.remove(identifier);
+                }
             }
 
             if let Some(index) = self.focus_stack.iter().position(|r| r == entity) {
@@ -437,6 +490,9 @@ impl Context {
                 self.captured = Entity::null();
             }
 
+            // Cancel the exit of the entity, which may be removed along with an ancestor while exiting.
+            self.exiting.retain(|exiting| exiting.entity != *entity);
+
             // Remove any map lenses associated with the entity.
 
             MAP_MANAGER.with_borrow_mut(|manager| {
@@ -484,6 +540,15 @@ impl Context {
             }
 
             self.windows.get_mut(&window_entity).unwrap().redraw_list.remove(entity);
+            self.windows.get_mut(&window_entity).unwrap().recomposite_list.remove(entity);
+
+            // The contents of any render layer containing the entity are now stale.
+            for ancestor in entity.parent_iter(&self.tree) {
+                if let Some(render_layer) = self.render_layers.get_mut(&ancestor) {
+                    render_layer.invalidate();
+                }
+            }
+            self.render_layers.remove(entity);
 
             if self.windows.contains_key(entity) {
                 self.windows.remove(entity);
@@ -494,12 +559,94 @@ impl Context {
             self.style.remove(*entity);
             self.data.remove(entity);
             self.views.remove(entity);
+            self.capture_listeners.remove(entity);
             self.text_context.text_bounds.remove(*entity);
             self.text_context.text_paragraphs.remove(*entity);
             self.entity_manager.destroy(*entity);
         }
     }
 
+    /// Returns true if the entity has been removed and is playing its exit animation.
+    pub fn is_exiting(&self, entity: Entity) -> bool {
+        self.exiting.iter().any(|exiting| exiting.entity == entity)
+    }
+
+    /// Begins the exit phase of an entity being removed.
+    ///
+    /// Returns false if the entity has no exit animation and should be removed immediately.
+    fn start_exit(&mut self, entity: Entity) -> bool {
+        if !self.entity_manager.is_alive(entity) || self.is_exiting(entity) {
+            return false;
+        }
+
+        let exit_animation = self.style.exit_animation.get(entity).copied();
+
+        if exit_animation.is_none() && !self.matches_exit_rule(entity) {
+            return false;
+        }
+
+        if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(entity) {
+            pseudo_classes.set(PseudoClassFlags::EXITING, true);
+        }
+
+        if let Some((animation, duration)) = exit_animation {
+            self.style.enqueue_animation(entity, animation, duration, Duration::ZERO);
+        }
+
+        // An exiting view is no longer interactive.
+        if self.focused == entity || self.focused.is_descendant_of(&self.tree, entity) {
+            let new_focus = self.tree.get_parent(entity).unwrap_or(Entity::root());
+            self.with_current(new_focus, |cx| cx.focus());
+        }
+
+        if self.captured == entity || self.captured.is_descendant_of(&self.tree, entity) {
+            self.captured = Entity::null();
+        }
+
+        self.needs_restyle(entity);
+        self.style.needs_access_update(entity);
+
+        // An exit animation which is paused or repeats forever doesn't keep the entity in the tree past the longest
+        // exit animation or transition.
+        let duration = exit_animation
+            .map(|(_, duration)| duration)
+            .into_iter()
+            .chain(self.style.exit_transitions.values().copied())
+            .max()
+            .unwrap_or_default();
+
+        self.exiting.push(ExitingEntity {
+            entity,
+            animation: exit_animation.map(|(animation, _)| animation),
+            deadline: Instant::now() + duration + EXIT_GRACE_PERIOD,
+        });
+
+        true
+    }
+
+    /// Returns true if any style rule containing the `:exiting` pseudo-class would match the entity.
+    fn matches_exit_rule(&mut self, entity: Entity) -> bool {
+        if self.style.exit_rules.is_empty() {
+            return false;
+        }
+
+        let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(entity) else {
+            return false;
+        };
+
+        let was_exiting = pseudo_classes.contains(PseudoClassFlags::EXITING);
+        pseudo_classes.set(PseudoClassFlags::EXITING, true);
+
+        let mut matched_rules = Vec::new();
+        compute_matched_rules(self, entity, &mut matched_rules);
+
+        if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(entity) {
+            pseudo_classes.set(PseudoClassFlags::EXITING, was_exiting);
+        }
+
+        matched_rules.iter().any(|(rule, _)| self.style.exit_rules.contains(rule))
+    }
+
     /// Add a listener to an entity.
     ///
     /// A listener can be used to handle events which would not normally propagate to the entity.
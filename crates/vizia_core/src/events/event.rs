@@ -6,18 +6,30 @@ use web_time::Instant;
 /// Determines how an event propagates through the tree.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Propagation {
-    // /// Events propagate down the tree to the target entity, e.g. from grand-parent to parent to child (target)
-    // Down,
+    /// Events propagate down the tree to the target entity, e.g. from grand-parent to parent to child (target).
+    Down,
     /// Events propagate up the tree from the target entity from ancestor to ancestor, e.g. from child (target) to parent to grand-parent etc.
     Up,
-    // /// Events propagate down the tree to the target entity and then back up to the root
-    // DownUp,
+    /// Events propagate down the tree to the target entity and then back up to the root.
+    DownUp,
     /// Events propagate starting at the target entity and visiting every entity that is a descendent of the target.
     Subtree,
     /// Events propagate directly to the target entity and to no others.
     Direct,
 }
 
+/// The phase of propagation an [`Event`] is in when it is visiting an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventPhase {
+    /// The event is travelling down the tree from the root towards the target, visiting capture listeners
+    /// and, for [`Propagation::Down`] and [`Propagation::DownUp`] events, the ancestors of the target.
+    Capture,
+    /// The event is visiting the target entity, or an entity in the subtree of the target.
+    Target,
+    /// The event is travelling up the tree from the target towards the root.
+    Bubble,
+}
+
 /// A wrapper around a message, providing metadata on how the event travels through the view tree.
 pub struct Event {
     /// The meta data of the event
@@ -167,6 +179,8 @@ pub struct EventMeta {
     pub target: Entity,
     /// How the event propagates through the tree.
     pub propagation: Propagation,
+    /// The phase of propagation the event is currently in.
+    pub phase: EventPhase,
     /// Determines whether the event should continue to be propagated.
     pub(crate) consumed: bool,
 }
@@ -184,6 +198,7 @@ impl Default for EventMeta {
             origin: Entity::null(),
             target: Entity::root(),
             propagation: Propagation::Up,
+            phase: EventPhase::Target,
             consumed: false,
         }
     }
@@ -23,6 +23,8 @@ const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
 pub struct EventManager {
     // Queue of events to be processed.
     event_queue: Vec<Event>,
+    // Path from the target of an event to the root, reused between events.
+    path: Vec<Entity>,
 }
 
 impl Default for EventManager {
@@ -33,7 +35,7 @@ impl Default for EventManager {
 
 impl EventManager {
     pub fn new() -> Self {
-        EventManager { event_queue: Vec::with_capacity(10) }
+        EventManager { event_queue: Vec::with_capacity(10), path: Vec::new() }
     }
 
     /// Flush the event queue, dispatching events to their targets.
@@ -102,8 +104,40 @@ impl EventManager {
 
             // Copy the target to prevent multiple mutable borrows error.
             let target = event.meta.target;
+            let propagation = event.meta.propagation;
+
+            // Propagate down from root to target, visiting capture listeners of the target and its ancestors,
+            // and the ancestors themselves for events which propagate down the tree. Events which only propagate
+            // up skip this phase when there are no capture listeners.
+            if matches!(propagation, Propagation::Down | Propagation::DownUp)
+                || (propagation == Propagation::Up && !cx.capture_listeners.is_empty())
+            {
+                event.meta.phase = EventPhase::Capture;
+
+                self.path.clear();
+                self.path.extend(target.parent_iter(cx.tree));
+                for &entity in self.path.iter().rev() {
+                    visit_capture_listeners(cx, entity, event);
+
+                    // Skip to the next event if the current event was consumed.
+                    if event.meta.consumed {
+                        continue 'events;
+                    }
+
+                    if entity != target && propagation != Propagation::Up {
+                        // Send event to the ancestor of the target.
+                        visit_entity(cx, entity, event);
+
+                        // Skip to the next event if the current event was consumed.
+                        if event.meta.consumed {
+                            continue 'events;
+                        }
+                    }
+                }
+            }
 
             // Send event to target.
+            event.meta.phase = EventPhase::Target;
             visit_entity(cx, target, event);
 
             // Skip to next event if the current event was consumed.
@@ -112,7 +146,9 @@ impl EventManager {
             }
 
             // Propagate up from target to root (not including the target).
-            if event.meta.propagation == Propagation::Up {
+            if matches!(propagation, Propagation::Up | Propagation::DownUp) {
+                event.meta.phase = EventPhase::Bubble;
+
                 // Create a parent iterator and skip the first element which is the target.
                 let iter = target.parent_iter(cx.tree).skip(1);
 
@@ -128,7 +164,7 @@ impl EventManager {
             }
 
             // Propagate the event down the subtree from the target (not including the target).
-            if event.meta.propagation == Propagation::Subtree {
+            if propagation == Propagation::Subtree {
                 // Create a branch (subtree) iterator and skip the first element which is the target.
                 let iter = target.branch_iter(cx.tree).skip(1);
 
@@ -151,6 +187,32 @@ impl EventManager {
     }
 }
 
+fn visit_capture_listeners(cx: &mut EventContext, entity: Entity, event: &mut Event) {
+    if cx.capture_listeners.is_empty() {
+        return;
+    }
+
+    // Take the listeners out of the map so they can be called with the context.
+    let listeners = match cx.capture_listeners.get_mut(&entity) {
+        Some(listeners) if !listeners.is_empty() => std::mem::take(listeners),
+        _ => return,
+    };
+
+    cx.current = entity;
+    for listener in listeners.iter() {
+        (listener)(cx, event);
+
+        if event.meta.consumed {
+            break;
+        }
+    }
+
+    // Keep any capture listeners which were added to the entity while handling the event.
+    let entry = cx.capture_listeners.entry(entity).or_default();
+    let added = std::mem::replace(entry, listeners);
+    entry.extend(added);
+}
+
 fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
     // Send event to models attached to the entity
     if let Some(ids) = cx
@@ -475,6 +537,17 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                 }
             }
 
+            #[cfg(debug_assertions)]
+            if *code == Code::KeyA
+                && cx.modifiers == Modifiers::CTRL | Modifiers::SHIFT | Modifiers::ALT
+            {
+                let report = cx.audit_accessibility();
+                debug!("Accessibility audit found {} issues", report.issues.len());
+                for issue in report.issues.iter() {
+                    debug!("{}: {:?}", issue.entity, issue.kind);
+                }
+            }
+
             #[cfg(debug_assertions)]
             if *code == Code::KeyT
                 && cx.modifiers == Modifiers::CTRL | Modifiers::SHIFT | Modifiers::ALT
@@ -655,3 +728,99 @@ fn emit_direct_or_up<M: Any + Send>(
     mutate_direct_or_up(&mut event.meta, direct, up, root);
     cx.emit_custom(event);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type Log = Rc<RefCell<Vec<(Entity, EventPhase)>>>;
+
+    struct TestEvent;
+
+    struct Recorder {
+        log: Log,
+    }
+
+    impl Recorder {
+        fn new<'a>(
+            cx: &'a mut Context,
+            log: &Log,
+            content: impl FnOnce(&mut Context),
+        ) -> Handle<'a, Self> {
+            Self { log: log.clone() }.build(cx, content)
+        }
+    }
+
+    impl View for Recorder {
+        fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+            event.map(|_: &TestEvent, meta| self.log.borrow_mut().push((cx.current(), meta.phase)));
+        }
+    }
+
+    fn send(cx: &mut Context, target: Entity, propagation: Propagation) {
+        cx.emit_custom(Event::new(TestEvent).target(target).propagate(propagation));
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    #[test]
+    fn down_up_visits_ancestors_in_both_phases() {
+        let mut cx = Context::new();
+        let log = Log::default();
+        let mut child = Entity::null();
+        let parent = Recorder::new(&mut cx, &log, |cx| {
+            child = Recorder::new(cx, &log, |_| {}).entity();
+        })
+        .entity();
+
+        send(&mut cx, child, Propagation::DownUp);
+        assert_eq!(
+            *log.borrow(),
+            [
+                (parent, EventPhase::Capture),
+                (child, EventPhase::Target),
+                (parent, EventPhase::Bubble)
+            ]
+        );
+
+        log.borrow_mut().clear();
+        send(&mut cx, child, Propagation::Down);
+        assert_eq!(*log.borrow(), [(parent, EventPhase::Capture), (child, EventPhase::Target)]);
+
+        // Without capture listeners, events which propagate up go straight to the target.
+        log.borrow_mut().clear();
+        send(&mut cx, child, Propagation::Up);
+        assert_eq!(*log.borrow(), [(child, EventPhase::Target), (parent, EventPhase::Bubble)]);
+    }
+
+    #[test]
+    fn capture_listener_can_consume_event() {
+        let mut cx = Context::new();
+        let log = Log::default();
+        let mut child = Entity::null();
+        let captured = Rc::new(RefCell::new(0));
+        let counter = captured.clone();
+        Recorder::new(&mut cx, &log, |cx| {
+            child = Recorder::new(cx, &log, |_| {}).entity();
+        })
+        .on_capture(move |_, event| {
+            event.map(|_: &TestEvent, meta| {
+                *counter.borrow_mut() += 1;
+                meta.consume();
+            })
+        });
+
+        send(&mut cx, child, Propagation::Up);
+        assert_eq!(*captured.borrow(), 1);
+        assert!(log.borrow().is_empty());
+        // Ancestors without capture listeners are not added to the listener map.
+        assert_eq!(cx.capture_listeners.len(), 1);
+
+        // Events sent directly to a view do not have a capture phase.
+        send(&mut cx, child, Propagation::Direct);
+        assert_eq!(*captured.borrow(), 1);
+        assert_eq!(*log.borrow(), [(child, EventPhase::Target)]);
+    }
+}
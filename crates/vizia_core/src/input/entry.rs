@@ -4,6 +4,8 @@ use crate::context::EventContext;
 ///
 /// It consists of an action which is usually just an enum variant
 /// and a callback function that gets called if the action got triggered.
+/// An entry can be limited to a scope, in which case it's only triggered while
+/// the view with the id of the scope, or one of its descendants, has focus.
 #[derive(Copy, Clone)]
 pub struct KeymapEntry<T>
 where
@@ -11,6 +13,7 @@ where
 {
     action: T,
     on_action: fn(&mut EventContext),
+    scope: Option<&'static str>,
 }
 
 impl<T> KeymapEntry<T>
@@ -32,7 +35,30 @@ where
     /// KeymapEntry::new(Action::One, |_| debug!("Action One"));
     /// ```
     pub fn new(action: T, on_action: fn(&mut EventContext)) -> Self {
-        Self { action, on_action }
+        Self { action, on_action, scope: None }
+    }
+
+    /// Limits the keymap entry to the scope of the view with the given id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Copy, Clone, PartialEq)]
+    /// # enum Action {
+    /// #     Rename,
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// KeymapEntry::new(Action::Rename, |_| debug!("Rename")).with_scope("file-list");
+    ///
+    /// VStack::new(cx, |_| {}).id("file-list");
+    /// ```
+    pub fn with_scope(mut self, scope: &'static str) -> Self {
+        self.scope = Some(scope);
+        self
     }
 
     /// Returns the action of the keymap entry.
@@ -44,6 +70,11 @@ where
     pub fn on_action(&self) -> &fn(&mut EventContext) {
         &self.on_action
     }
+
+    /// Returns the id of the view to which the keymap entry is limited, if any.
+    pub fn scope(&self) -> Option<&'static str> {
+        self.scope
+    }
 }
 
 impl<T> PartialEq for KeymapEntry<T>
@@ -1,5 +1,8 @@
 use crate::prelude::*;
 use indexmap::IndexMap;
+use std::fmt::{Display, Write};
+use std::marker::PhantomData;
+use std::time::Duration;
 
 /// A keymap that associates key chords with actions.
 ///
@@ -42,12 +45,194 @@ use indexmap::IndexMap;
 ///     (KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::KeyC), KeymapEntry::new(Action::Three, |_| debug!("Action Three"))),
 /// ]);
 /// ```
-#[derive(Default)]
+///
+/// Actions can also be bound to a sequence of key chords which are pressed one after another, and key chords can be
+/// parsed from strings. While a sequence is only partially pressed the pressed key chords are stored in the
+/// [`pending`](Keymap::pending) lens, which can be used to show the pending sequence to the user. If no further key
+/// chord is pressed within the [`timeout`](Keymap::with_timeout) of the keymap, the actions bound to the pending
+/// sequence itself, if any, are triggered.
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(PartialEq, Copy, Clone)]
+/// # enum Action {
+/// #     Save,
+/// #     SaveAll,
+/// # }
+/// #
+/// let keymap = Keymap::from(vec![
+///     ("CmdOrCtrl+S".parse::<KeySequence>().unwrap(), KeymapEntry::new(Action::Save, |_| debug!("Save"))),
+///     ("Ctrl+K Ctrl+S".parse::<KeySequence>().unwrap(), KeymapEntry::new(Action::SaveAll, |_| debug!("Save All"))),
+/// ]);
+/// ```
+///
+/// The bindings the keymap is created with are its defaults. The bindings customized by the user can be saved with
+/// [`to_config`](Keymap::to_config), loaded again with [`load_config`](Keymap::load_config), and reset with
+/// [`KeymapEvent::ResetAll`]. The [`bindings`](Keymap::bindings) lens lists the bindings of every action, and can be
+/// edited by the user with a [`KeybindingEditor`].
+#[derive(Lens)]
 pub struct Keymap<T>
 where
     T: 'static + Clone + PartialEq + Send + Sync,
 {
-    entries: IndexMap<KeyChord, Vec<KeymapEntry<T>>>,
+    #[lens(ignore)]
+    entries: IndexMap<KeySequence, Vec<KeymapEntry<T>>>,
+    #[lens(ignore)]
+    defaults: Vec<(KeySequence, KeymapEntry<T>)>,
+    /// The key sequences bound to every action of the keymap.
+    pub bindings: Vec<KeyBinding<T>>,
+    /// The key chords of a partially pressed key sequence.
+    pub pending: KeySequence,
+    #[lens(ignore)]
+    timeout: Duration,
+    #[lens(ignore)]
+    timer: Option<Timer>,
+    #[lens(ignore)]
+    on_change: Option<fn(&mut EventContext, &Keymap<T>)>,
+}
+
+impl<T> Default for Keymap<T>
+where
+    T: 'static + Clone + PartialEq + Send + Sync,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The key sequences bound to an action of a [`Keymap`].
+#[derive(Debug, Clone, PartialEq, Data)]
+pub struct KeyBinding<T> {
+    /// The action of the binding.
+    pub action: T,
+    /// The id of the view to which the action is limited, if any.
+    pub scope: Option<&'static str>,
+    /// The key sequences which trigger the action.
+    pub sequences: Vec<KeySequence>,
+    /// The key sequences which trigger the action by default.
+    pub defaults: Vec<KeySequence>,
+}
+
+impl<T> KeyBinding<T> {
+    /// Returns whether the action is bound to its default key sequences.
+    pub fn is_default(&self) -> bool {
+        self.sequences.len() == self.defaults.len()
+            && self.sequences.iter().all(|sequence| self.defaults.contains(sequence))
+    }
+
+    // Returns whether the actions of both bindings can be triggered while the same view has focus.
+    fn overlaps(&self, other: &Self) -> bool {
+        self.scope.is_none() || other.scope.is_none() || self.scope == other.scope
+    }
+}
+
+/// Two actions of a [`Keymap`] which are bound to conflicting key sequences in overlapping scopes.
+///
+/// The key sequences either are the same, in which case both actions are triggered, or the first key sequence is the
+/// start of the second, in which case the first action is only triggered after the timeout of the keymap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeymapConflict<T> {
+    /// The first action.
+    pub action: T,
+    /// The key sequence of the first action.
+    pub sequence: KeySequence,
+    /// The second action.
+    pub other_action: T,
+    /// The key sequence of the second action, which is equal to or starts with the key sequence of the first.
+    pub other_sequence: KeySequence,
+}
+
+/// Returns the conflicts between the given bindings.
+pub(crate) fn binding_conflicts<T: Clone>(bindings: &[KeyBinding<T>]) -> Vec<KeymapConflict<T>> {
+    let mut conflicts = Vec::new();
+    for (index, first) in bindings.iter().enumerate() {
+        for second in bindings[index + 1..].iter().filter(|second| first.overlaps(second)) {
+            for sequence in first.sequences.iter() {
+                for other_sequence in second.sequences.iter() {
+                    let (first, sequence, second, other_sequence) =
+                        if other_sequence.starts_with(sequence.chords()) {
+                            (first, sequence, second, other_sequence)
+                        } else if sequence.starts_with(other_sequence.chords()) {
+                            (second, other_sequence, first, sequence)
+                        } else {
+                            continue;
+                        };
+
+                    conflicts.push(KeymapConflict {
+                        action: first.action.clone(),
+                        sequence: sequence.clone(),
+                        other_action: second.action.clone(),
+                        other_sequence: other_sequence.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    conflicts
+}
+
+/// The kind of error which occurred while loading the config of a [`Keymap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeymapConfigErrorKind {
+    /// The line doesn't separate the action from the key sequence with `=`.
+    MissingSeparator,
+    /// The keymap has no action with the given name.
+    UnknownAction(String),
+    /// The key sequence can't be parsed.
+    InvalidKeySequence(ParseKeyChordError),
+}
+
+/// An error returned when the config of a [`Keymap`] can't be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapConfigError {
+    /// The line of the config at which the error occurred, starting at 1.
+    pub line: usize,
+    /// The kind of error.
+    pub kind: KeymapConfigErrorKind,
+}
+
+impl std::fmt::Display for KeymapConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            KeymapConfigErrorKind::MissingSeparator => {
+                write!(f, "Missing '=' after the action at line {}", self.line)
+            }
+            KeymapConfigErrorKind::UnknownAction(action) => {
+                write!(f, "Unknown action '{}' at line {}", action, self.line)
+            }
+            KeymapConfigErrorKind::InvalidKeySequence(error) => {
+                write!(f, "{} at line {}", error, self.line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for KeymapConfigError {}
+
+// Returns whether the entry isn't limited to a scope, or is limited to one of the given scopes.
+fn is_active<T>(entry: &KeymapEntry<T>, scopes: &[&str]) -> bool
+where
+    T: 'static + Clone + PartialEq + Send + Sync,
+{
+    entry.scope().map_or(true, |scope| scopes.contains(&scope))
+}
+
+// The pending sequence of the keymap with the same type of actions timed out.
+struct KeymapTimeout<T>(PhantomData<T>);
+
+/// The result of looking up a sequence of key chords in a [`Keymap`].
+enum Lookup<T>
+where
+    T: 'static + Clone + PartialEq + Send + Sync,
+{
+    /// The sequence triggers the given entries and isn't the start of a longer sequence.
+    Exact(Vec<KeymapEntry<T>>),
+    /// The sequence is the start of a longer sequence, and may trigger entries itself.
+    Prefix,
+    /// The sequence isn't bound to any entries.
+    None,
 }
 
 impl<T> Keymap<T>
@@ -71,20 +256,211 @@ where
     /// let keymap = Keymap::<Action>::new();
     /// ```
     pub fn new() -> Self {
-        Self { entries: IndexMap::new() }
+        Self {
+            entries: IndexMap::new(),
+            defaults: Vec::new(),
+            bindings: Vec::new(),
+            pending: KeySequence::default(),
+            timeout: Duration::from_millis(1500),
+            timer: None,
+            on_change: None,
+        }
+    }
+
+    /// Sets the time to wait for the next key chord of a partially pressed key sequence. Defaults to 1.5 seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use std::time::Duration;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// # }
+    /// #
+    /// let keymap = Keymap::<Action>::new().with_timeout(Duration::from_secs(1));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the callback which is called when the bindings of the keymap are changed at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// # }
+    /// #
+    /// # impl std::fmt::Display for Action {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    /// #         write!(f, "{:?}", self)
+    /// #     }
+    /// # }
+    /// #
+    /// let keymap = Keymap::<Action>::new().on_change(|_, keymap| {
+    ///     let _ = std::fs::write("keybindings.txt", keymap.to_config());
+    /// });
+    /// ```
+    pub fn on_change(mut self, callback: fn(&mut EventContext, &Keymap<T>)) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    /// Returns the conflicting key sequences of the keymap.
+    ///
+    /// Key sequences conflict when they're bound to different actions whose scopes overlap, i.e. when either action
+    /// isn't limited to a scope or both are limited to the same scope, and either sequence starts with the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     Save,
+    /// #     Search,
+    /// # }
+    /// #
+    /// let keymap = Keymap::from(vec![
+    ///     (KeyChord::new(Modifiers::CTRL, Code::KeyS), KeymapEntry::new(Action::Save, |_| {})),
+    ///     (KeyChord::new(Modifiers::CTRL, Code::KeyS), KeymapEntry::new(Action::Search, |_| {})),
+    /// ]);
+    ///
+    /// for conflict in keymap.conflicts() {
+    ///     debug!("{:?} and {:?} are both bound to {}", conflict.action, conflict.other_action, conflict.sequence);
+    /// }
+    /// ```
+    pub fn conflicts(&self) -> Vec<KeymapConflict<T>> {
+        binding_conflicts(&self.bindings)
+    }
+
+    /// Returns the bindings which differ from the defaults as a config, which can be loaded with
+    /// [`load_config`](Keymap::load_config).
+    ///
+    /// Every line of the config binds an action, named by its `Display` implementation, to a key sequence, such as
+    /// `Save All = Ctrl+K Ctrl+S`. An action which is bound to several key sequences has a line for each of them, and
+    /// an action which isn't bound to any key sequence has a line without a key sequence.
+    pub fn to_config(&self) -> String
+    where
+        T: Display,
+    {
+        let mut config = String::new();
+        for binding in self.bindings.iter().filter(|binding| !binding.is_default()) {
+            if binding.sequences.is_empty() {
+                let _ = writeln!(config, "{} =", binding.action);
+            }
+
+            for sequence in binding.sequences.iter() {
+                let _ = writeln!(config, "{} = {}", binding.action, sequence);
+            }
+        }
+
+        config
+    }
+
+    /// Loads the bindings of a config created with [`to_config`](Keymap::to_config).
+    ///
+    /// The key sequences of every action in the config replace the key sequences the action is bound to, while the
+    /// other actions keep their key sequences. Empty lines and lines starting with `#` are ignored. If any line of the
+    /// config is invalid the keymap is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     Save,
+    /// # }
+    /// #
+    /// # impl std::fmt::Display for Action {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    /// #         write!(f, "{:?}", self)
+    /// #     }
+    /// # }
+    /// #
+    /// let mut keymap = Keymap::from(vec![(
+    ///     KeyChord::new(Modifiers::CTRL, Code::KeyS),
+    ///     KeymapEntry::new(Action::Save, |_| debug!("Save")),
+    /// )]);
+    ///
+    /// keymap.load_config("# My keybindings\nSave = Ctrl+Shift+S").unwrap();
+    /// ```
+    pub fn load_config(&mut self, config: &str) -> Result<(), KeymapConfigError>
+    where
+        T: Display,
+    {
+        let mut changes: Vec<(T, Vec<KeySequence>)> = Vec::new();
+        for (index, line) in config.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |kind| KeymapConfigError { line: index + 1, kind };
+            let (name, sequence) = line
+                .split_once('=')
+                .ok_or_else(|| error(KeymapConfigErrorKind::MissingSeparator))?;
+            let name = name.trim();
+            let action = self
+                .bindings
+                .iter()
+                .find(|binding| binding.action.to_string() == name)
+                .map(|binding| binding.action.clone())
+                .ok_or_else(|| error(KeymapConfigErrorKind::UnknownAction(name.to_string())))?;
+
+            let change = match changes.iter().position(|(other, _)| *other == action) {
+                Some(change) => change,
+                None => {
+                    changes.push((action, Vec::new()));
+                    changes.len() - 1
+                }
+            };
+
+            let sequence = sequence.trim();
+            if !sequence.is_empty() {
+                let sequence = sequence
+                    .parse()
+                    .map_err(|err| error(KeymapConfigErrorKind::InvalidKeySequence(err)))?;
+                if !changes[change].1.contains(&sequence) {
+                    changes[change].1.push(sequence);
+                }
+            }
+        }
+
+        for (action, sequences) in changes {
+            self.set_bindings(&action, &sequences);
+        }
+
+        self.sync_bindings();
+        Ok(())
     }
 
     /// Inserts an entry into the keymap.
     ///
     /// This method is for internal use only.
     /// To insert an entry into the keymap at runtime use the [`KeymapEvent::InsertAction`] event.
-    fn insert(&mut self, chord: KeyChord, keymap_entry: KeymapEntry<T>) {
-        if let Some(actions) = self.entries.get_mut(&chord) {
+    fn insert(&mut self, sequence: KeySequence, keymap_entry: KeymapEntry<T>) {
+        if sequence.is_empty() {
+            return;
+        }
+
+        if let Some(actions) = self.entries.get_mut(&sequence) {
             if !actions.contains(&keymap_entry) {
                 actions.push(keymap_entry);
             }
         } else {
-            self.entries.insert(chord, vec![keymap_entry]);
+            self.entries.insert(sequence, vec![keymap_entry]);
         }
     }
 
@@ -92,11 +468,11 @@ where
     ///
     /// This method is for internal use only.
     /// To remove an entry of the keymap at runtime use the [`KeymapEvent::RemoveAction`] event.
-    fn remove(&mut self, chord: &KeyChord, action: &T) {
-        if let Some(actions) = self.entries.get_mut(chord) {
+    fn remove(&mut self, sequence: &KeySequence, action: &T) {
+        if let Some(actions) = self.entries.get_mut(sequence) {
             if let Some(index) = actions.iter().position(|x| x == action) {
                 if actions.len() == 1 {
-                    self.entries.swap_remove(chord);
+                    self.entries.swap_remove(sequence);
                 } else {
                     actions.swap_remove(index);
                 }
@@ -104,6 +480,162 @@ where
         }
     }
 
+    /// Replaces the key sequences bound to an action.
+    fn set_bindings(&mut self, action: &T, sequences: &[KeySequence]) {
+        let Some(entry) = self
+            .entries
+            .values()
+            .flatten()
+            .chain(self.defaults.iter().map(|(_, entry)| entry))
+            .find(|entry| *entry == action)
+            .cloned()
+        else {
+            return;
+        };
+
+        for entries in self.entries.values_mut() {
+            entries.retain(|entry| entry != action);
+        }
+        self.entries.retain(|_, entries| !entries.is_empty());
+
+        for sequence in sequences {
+            self.insert(sequence.clone(), entry.clone());
+        }
+    }
+
+    /// Updates the bindings of every action after the entries of the keymap changed.
+    fn sync_bindings(&mut self) {
+        let mut bindings: Vec<KeyBinding<T>> = Vec::new();
+        let defaults = self.defaults.iter().map(|(sequence, entry)| (sequence, entry, true));
+        let entries = self.entries.iter().flat_map(|(sequence, entries)| {
+            entries.iter().map(move |entry| (sequence, entry, false))
+        });
+
+        for (sequence, entry, is_default) in defaults.chain(entries) {
+            let index = match bindings.iter().position(|binding| *entry == binding.action) {
+                Some(index) => index,
+                None => {
+                    bindings.push(KeyBinding {
+                        action: entry.action().clone(),
+                        scope: entry.scope(),
+                        sequences: Vec::new(),
+                        defaults: Vec::new(),
+                    });
+                    bindings.len() - 1
+                }
+            };
+
+            if is_default {
+                bindings[index].defaults.push(sequence.clone());
+            } else {
+                bindings[index].sequences.push(sequence.clone());
+            }
+        }
+
+        self.bindings = bindings;
+    }
+
+    /// Returns the scopes of the keymap which contain the focused view.
+    fn active_scopes(&self, cx: &EventContext) -> Vec<&'static str> {
+        let focused = cx.focused();
+        self.bindings
+            .iter()
+            .filter_map(|binding| binding.scope)
+            .filter(|scope| {
+                cx.resolve_entity_identifier(scope).is_some_and(|entity| {
+                    focused == entity || focused.is_descendant_of(cx.tree, entity)
+                })
+            })
+            .collect()
+    }
+
+    /// Looks up a sequence of key chords, where a prefix of a longer sequence takes precedence over an exact match.
+    ///
+    /// Entries which are limited to a scope are ignored unless the scope is one of the given active scopes.
+    fn lookup(&self, chords: &[KeyChord], scopes: &[&str]) -> Lookup<T> {
+        if self.entries.iter().any(|(sequence, entries)| {
+            sequence.chords().len() > chords.len()
+                && sequence.starts_with(chords)
+                && entries.iter().any(|entry| is_active(entry, scopes))
+        }) {
+            return Lookup::Prefix;
+        }
+
+        let entries = self.active_entries(chords, scopes);
+        if entries.is_empty() {
+            Lookup::None
+        } else {
+            Lookup::Exact(entries)
+        }
+    }
+
+    /// Returns the entries which are triggered by exactly the given sequence of key chords.
+    fn entries_of(&self, chords: &[KeyChord]) -> &[KeymapEntry<T>] {
+        self.entries
+            .iter()
+            .find(|(sequence, _)| sequence.chords() == chords)
+            .map(|(_, entries)| entries.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns the entries of the given sequence of key chords which are active in the given scopes.
+    fn active_entries(&self, chords: &[KeyChord], scopes: &[&str]) -> Vec<KeymapEntry<T>> {
+        self.entries_of(chords).iter().filter(|entry| is_active(entry, scopes)).cloned().collect()
+    }
+
+    /// Triggers the active entries of the pending sequence, if any, and clears it.
+    fn flush(&mut self, cx: &mut EventContext) {
+        if let Some(timer) = self.timer {
+            cx.stop_timer(timer);
+        }
+
+        let pending = std::mem::take(&mut self.pending);
+        let scopes = self.active_scopes(cx);
+        for entry in self.active_entries(pending.chords(), &scopes) {
+            (entry.on_action())(cx)
+        }
+    }
+
+    /// Handles a pressed key chord, returning false if it neither continues nor starts a key sequence.
+    fn press(&mut self, cx: &mut EventContext, chord: KeyChord) -> bool {
+        let mut chords = self.pending.chords().to_vec();
+        chords.push(chord);
+
+        let scopes = self.active_scopes(cx);
+        match self.lookup(&chords, &scopes) {
+            Lookup::Prefix => {
+                self.pending = KeySequence::new(chords);
+
+                let timer = *self.timer.get_or_insert_with(|| {
+                    cx.add_timer(self.timeout, None, |cx, action| {
+                        if let TimerAction::Tick(_) = action {
+                            cx.emit(KeymapTimeout::<T>(PhantomData));
+                        }
+                    })
+                });
+
+                // Restart the timeout of the sequence.
+                cx.stop_timer(timer);
+                cx.start_timer(timer);
+                true
+            }
+
+            Lookup::Exact(entries) => {
+                self.pending = KeySequence::default();
+                if let Some(timer) = self.timer {
+                    cx.stop_timer(timer);
+                }
+
+                for entry in entries {
+                    (entry.on_action())(cx)
+                }
+                true
+            }
+
+            Lookup::None => false,
+        }
+    }
+
     /// Returns an iterator over every pressed keymap entry.
     ///
     /// # Examples
@@ -127,16 +659,12 @@ where
         cx: &Context,
         code: Code,
     ) -> impl Iterator<Item = &KeymapEntry<T>> {
-        if let Some(actions) = self.entries.get(&KeyChord::new(cx.modifiers, code)) {
-            actions.iter()
-        } else {
-            [].iter()
-        }
+        self.entries_of(&[KeyChord::new(cx.modifiers, code)]).iter()
     }
 
-    /// Exports all keymap entries and their associated key chords.
+    /// Exports all keymap entries and their associated key sequences.
     ///
-    /// This is useful if you want to have a settings window and need to access every key chord
+    /// This is useful if you want to have a settings window and need to access every key sequence
     /// and keymap entry of a keymap.
     ///
     /// # Examples
@@ -153,15 +681,15 @@ where
     /// #
     /// let actions_chords = keymap.export();
     ///
-    /// for (chord, entry) in actions_chords {
-    ///     debug!("The key chord {:?} triggers the action {:?}!", chord, entry.action());
+    /// for (sequence, entry) in actions_chords {
+    ///     debug!("The key sequence {} triggers the action {:?}!", sequence, entry.action());
     /// }
     /// ```
-    pub fn export(&self) -> Vec<(&KeyChord, &KeymapEntry<T>)> {
+    pub fn export(&self) -> Vec<(&KeySequence, &KeymapEntry<T>)> {
         let mut vec = Vec::new();
-        for (chord, entries) in self.entries.iter() {
+        for (sequence, entries) in self.entries.iter() {
             for entry in entries {
-                vec.push((chord, entry));
+                vec.push((sequence, entry));
             }
         }
         vec
@@ -173,16 +701,67 @@ where
     T: 'static + Clone + PartialEq + Send + Sync,
 {
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.map(|keymap_event, _| match keymap_event {
-            KeymapEvent::InsertAction(chord, entry) => self.insert(*chord, entry.clone()),
-            KeymapEvent::RemoveAction(chord, action) => self.remove(chord, action),
+        event.map(|keymap_event, _| {
+            match keymap_event {
+                KeymapEvent::InsertAction(chord, entry) => {
+                    self.insert((*chord).into(), entry.clone())
+                }
+                KeymapEvent::RemoveAction(chord, action) => self.remove(&(*chord).into(), action),
+                KeymapEvent::InsertSequence(sequence, entry) => {
+                    self.insert(sequence.clone(), entry.clone())
+                }
+                KeymapEvent::RemoveSequence(sequence, action) => self.remove(sequence, action),
+                KeymapEvent::SetBindings(action, sequences) => self.set_bindings(action, sequences),
+                KeymapEvent::ResetAction(action) => {
+                    let defaults = self
+                        .defaults
+                        .iter()
+                        .filter(|(_, entry)| entry == action)
+                        .map(|(sequence, _)| sequence.clone())
+                        .collect::<Vec<_>>();
+                    self.set_bindings(action, &defaults);
+                }
+                KeymapEvent::ResetAll => {
+                    self.entries.clear();
+                    for (sequence, entry) in self.defaults.clone() {
+                        self.insert(sequence, entry);
+                    }
+                }
+            }
+
+            self.sync_bindings();
+            if let Some(callback) = self.on_change {
+                (callback)(cx, self);
+            }
         });
+
+        event.map(|_: &KeymapTimeout<T>, meta| {
+            self.flush(cx);
+            meta.consume();
+        });
+
         event.map(|window_event, _| match window_event {
             WindowEvent::KeyDown(code, _) => {
-                if let Some(entries) = self.entries.get(&KeyChord::new(*cx.modifiers, *code)) {
-                    for entry in entries {
-                        (entry.on_action())(cx)
-                    }
+                // Pressing a modifier key doesn't continue or break a key sequence.
+                if matches!(
+                    code,
+                    Code::ShiftLeft
+                        | Code::ShiftRight
+                        | Code::ControlLeft
+                        | Code::ControlRight
+                        | Code::AltLeft
+                        | Code::AltRight
+                        | Code::MetaLeft
+                        | Code::MetaRight
+                ) {
+                    return;
+                }
+
+                let chord = KeyChord::new(*cx.modifiers, *code);
+                if !self.press(cx, chord) && !self.pending.is_empty() {
+                    // The key chord breaks the pending sequence, so it's handled on its own.
+                    self.flush(cx);
+                    self.press(cx, chord);
                 }
             }
             _ => {}
@@ -195,10 +774,26 @@ where
     T: 'static + Clone + PartialEq + Send + Sync,
 {
     fn from(vec: Vec<(KeyChord, KeymapEntry<T>)>) -> Self {
+        Self::from(
+            vec.into_iter()
+                .map(|(chord, entry)| (KeySequence::from(chord), entry))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<T> From<Vec<(KeySequence, KeymapEntry<T>)>> for Keymap<T>
+where
+    T: 'static + Clone + PartialEq + Send + Sync,
+{
+    fn from(vec: Vec<(KeySequence, KeymapEntry<T>)>) -> Self {
         let mut keymap = Self::new();
-        for (chord, entry) in vec {
-            keymap.insert(chord, entry);
+        for (sequence, entry) in vec {
+            keymap.insert(sequence, entry);
         }
+        keymap.defaults =
+            keymap.export().into_iter().map(|(s, e)| (s.clone(), e.clone())).collect();
+        keymap.sync_bindings();
         keymap
     }
 }
@@ -248,4 +843,187 @@ where
     /// ));
     /// ```
     RemoveAction(KeyChord, T),
+    /// Inserts an entry which is triggered by a sequence of key chords into the [`Keymap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// cx.emit(KeymapEvent::InsertSequence(
+    ///     "Ctrl+K Ctrl+O".parse().unwrap(),
+    ///     KeymapEntry::new(Action::One, |_| debug!("Action One")),
+    /// ));
+    /// ```
+    InsertSequence(KeySequence, KeymapEntry<T>),
+    /// Removes an entry which is triggered by a sequence of key chords from the [`Keymap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// cx.emit(KeymapEvent::RemoveSequence("Ctrl+K Ctrl+O".parse().unwrap(), Action::One));
+    /// ```
+    RemoveSequence(KeySequence, T),
+    /// Replaces the key sequences bound to an action of the [`Keymap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// cx.emit(KeymapEvent::SetBindings(Action::One, vec!["Ctrl+1".parse().unwrap()]));
+    /// ```
+    SetBindings(T, Vec<KeySequence>),
+    /// Binds an action of the [`Keymap`] to its default key sequences again.
+    ResetAction(T),
+    /// Replaces the bindings of the [`Keymap`] with the bindings it was created with.
+    ResetAll,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keymap() -> Keymap<&'static str> {
+        Keymap::from(vec![
+            (sequence("Ctrl+K"), KeymapEntry::new("Kill Line", |_| {})),
+            (sequence("Ctrl+K Ctrl+S"), KeymapEntry::new("Save All", |_| {})),
+            (sequence("Ctrl+S"), KeymapEntry::new("Save", |_| {})),
+            (sequence("Ctrl+S"), KeymapEntry::new("Save", |_| {})),
+            (sequence("F2"), KeymapEntry::new("Rename", |_| {}).with_scope("files")),
+            (sequence("F2"), KeymapEntry::new("Edit Cell", |_| {}).with_scope("table")),
+        ])
+    }
+
+    fn sequence(sequence: &str) -> KeySequence {
+        sequence.parse().unwrap()
+    }
+
+    fn chords(sequence: &str) -> Vec<KeyChord> {
+        self::sequence(sequence).0
+    }
+
+    fn actions(lookup: Lookup<&'static str>) -> Vec<&'static str> {
+        match lookup {
+            Lookup::Exact(entries) => entries.iter().map(|entry| *entry.action()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        let keymap = keymap();
+
+        assert!(matches!(keymap.lookup(&chords("Ctrl+K"), &[]), Lookup::Prefix));
+        assert_eq!(actions(keymap.lookup(&chords("Ctrl+S"), &[])), ["Save"]);
+        assert_eq!(actions(keymap.lookup(&chords("Ctrl+K Ctrl+S"), &[])), ["Save All"]);
+        assert!(matches!(keymap.lookup(&chords("Ctrl+K S"), &[]), Lookup::None));
+        assert!(matches!(keymap.lookup(&chords("Ctrl+S Ctrl+K"), &[]), Lookup::None));
+
+        assert_eq!(keymap.active_entries(&chords("Ctrl+K"), &[])[0].action(), &"Kill Line");
+        assert!(keymap.entries_of(&chords("Ctrl+K S")).is_empty());
+    }
+
+    #[test]
+    fn scopes() {
+        let keymap = keymap();
+
+        assert!(matches!(keymap.lookup(&chords("F2"), &[]), Lookup::None));
+        assert_eq!(actions(keymap.lookup(&chords("F2"), &["files"])), ["Rename"]);
+        assert_eq!(actions(keymap.lookup(&chords("F2"), &["table", "other"])), ["Edit Cell"]);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut keymap = keymap();
+
+        keymap.remove(&sequence("Ctrl+K Ctrl+S"), &"Save All");
+        assert_eq!(actions(keymap.lookup(&chords("Ctrl+K"), &[])), ["Kill Line"]);
+
+        keymap.insert(KeySequence::default(), KeymapEntry::new("Nothing", |_| {}));
+        assert_eq!(keymap.export().len(), 4);
+    }
+
+    #[test]
+    fn conflicts() {
+        let mut keymap = keymap();
+        assert_eq!(
+            keymap.conflicts(),
+            [KeymapConflict {
+                action: "Kill Line",
+                sequence: sequence("Ctrl+K"),
+                other_action: "Save All",
+                other_sequence: sequence("Ctrl+K Ctrl+S"),
+            }]
+        );
+
+        keymap.set_bindings(&"Kill Line", &[sequence("Ctrl+Shift+K")]);
+        keymap.set_bindings(&"Rename", &[sequence("Ctrl+S")]);
+        keymap.set_bindings(&"Edit Cell", &[sequence("Ctrl+S")]);
+        keymap.sync_bindings();
+
+        // Only the scoped actions conflict with the unscoped one, not with each other.
+        let conflicts = keymap.conflicts();
+        assert_eq!(conflicts.len(), 2);
+        assert!(conflicts.iter().all(|conflict| conflict.action == "Save"));
+    }
+
+    #[test]
+    fn config() {
+        let mut keymap = keymap();
+        assert_eq!(keymap.to_config(), "");
+
+        keymap.set_bindings(&"Save", &[sequence("Ctrl+S"), sequence("F12")]);
+        keymap.set_bindings(&"Kill Line", &[]);
+        keymap.sync_bindings();
+        let config = keymap.to_config();
+        assert_eq!(config, "Kill Line =\nSave = Ctrl+S\nSave = F12\n");
+
+        let mut other = self::keymap();
+        other.load_config(&format!("# Keybindings\n\n{}", config)).unwrap();
+        assert_eq!(other.bindings, keymap.bindings);
+        assert!(matches!(other.lookup(&chords("Ctrl+K"), &[]), Lookup::Prefix));
+        assert_eq!(actions(other.lookup(&chords("F12"), &[])), ["Save"]);
+
+        assert_eq!(
+            other.load_config("Save = Ctrl+S\nQuit = Ctrl+Q"),
+            Err(KeymapConfigError {
+                line: 2,
+                kind: KeymapConfigErrorKind::UnknownAction(String::from("Quit"))
+            })
+        );
+        assert_eq!(
+            other.load_config("Save Ctrl+S").unwrap_err().kind,
+            KeymapConfigErrorKind::MissingSeparator
+        );
+        assert!(other.load_config("Save = Ctrl+Nope").is_err());
+        assert_eq!(other.bindings, keymap.bindings);
+
+        // Whitespace is allowed around the `+` of a key chord.
+        other.load_config("Save = Ctrl + Shift + S").unwrap();
+        assert_eq!(actions(other.lookup(&chords("Ctrl+Shift+S"), &[])), ["Save"]);
+    }
 }
@@ -22,6 +22,7 @@ pub mod layout;
 pub mod localization;
 pub mod model;
 pub mod modifiers;
+pub mod param;
 pub mod resource;
 pub mod style;
 pub(crate) mod systems;
@@ -62,6 +63,7 @@ pub mod prelude {
 
     pub use crate::model::Model;
 
+    pub use super::accessibility::audit::{AuditIssue, AuditIssueKind, AuditReport};
     pub use super::animation::{Animation, AnimationBuilder, KeyframeBuilder};
     pub use super::context::{
         AccessContext, AccessNode, Context, ContextProxy, DataContext, DrawContext, EmitContext,
@@ -69,25 +71,35 @@ pub mod prelude {
     };
     pub use super::entity::Entity;
     pub use super::environment::{AppTheme, Environment, EnvironmentEvent, ThemeMode};
-    pub use super::events::{Event, Propagation, Timer, TimerAction};
+    pub use super::events::{Event, EventPhase, Propagation, Timer, TimerAction};
     pub use super::include_style;
-    pub use super::input::{Keymap, KeymapEntry, KeymapEvent};
+    pub use super::input::{
+        KeyBinding, Keymap, KeymapConfigError, KeymapConfigErrorKind, KeymapConflict, KeymapEntry,
+        KeymapEvent,
+    };
     pub use super::layout::{BoundingBox, GeoChanged};
     pub use super::localization::{Localized, ToStringLocalized};
     pub use super::modifiers::{
         AbilityModifiers, AccessibilityModifiers, ActionModifiers, LayoutModifiers,
         LinearGradientBuilder, ShadowBuilder, StyleModifiers, TextModifiers,
     };
+    pub use super::param::{
+        param_channel, ParamBridge, ParamChanged, ParamEvent, ParamHost, ParamId, ParamReceiver,
+        ParamSender,
+    };
     pub use super::resource::{ImageId, ImageRetentionPolicy};
     pub use super::util::{IntoCssStr, CSS};
     pub use super::view::{Handle, View};
     pub use super::views::*;
     pub use super::window::{DropData, WindowEvent};
-    pub use accesskit::{Action, DefaultActionVerb, Live, Role};
+    pub use accesskit::{Action, DefaultActionVerb, Live, Role, Toggled};
     pub use skia_safe::Canvas;
     pub use vizia_derive::{Data, Lens};
     pub use vizia_id::GenerationalId;
-    pub use vizia_input::{Code, Key, KeyChord, Modifiers, MouseButton, MouseButtonState};
+    pub use vizia_input::{
+        Code, Key, KeyChord, KeySequence, Modifiers, MouseButton, MouseButtonState,
+        ParseKeyChordError,
+    };
     pub use vizia_storage::{Tree, TreeExt};
     pub use vizia_window::{WindowButtons, WindowPosition, WindowSize};
 
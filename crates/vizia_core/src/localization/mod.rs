@@ -307,6 +307,34 @@ where
     }
 }
 
+// The likely regions of languages which are commonly used without a region, and whose regional conventions, such as
+// the first day of the week or the use of a 12-hour clock, differ from the defaults used by the built-in views.
+const LIKELY_REGIONS: [(&str, &str); 12] = [
+    ("ar", "EG"),
+    ("bn", "BD"),
+    ("en", "US"),
+    ("fa", "IR"),
+    ("he", "IL"),
+    ("hi", "IN"),
+    ("id", "ID"),
+    ("ja", "JP"),
+    ("ko", "KR"),
+    ("pt", "BR"),
+    ("th", "TH"),
+    ("ur", "PK"),
+];
+
+/// Returns the region of a locale, or the likely region of its language if the locale doesn't specify one.
+pub(crate) fn locale_region(locale: &LanguageIdentifier) -> Option<&str> {
+    match &locale.region {
+        Some(region) => Some(region.as_str()),
+        None => LIKELY_REGIONS
+            .iter()
+            .find(|(language, _)| *language == locale.language.as_str())
+            .map(|(_, region)| *region),
+    }
+}
+
 impl<T: ToString> ToStringLocalized for T {
     fn to_string_local(&self, _cx: &impl DataContext) -> String {
         self.to_string()
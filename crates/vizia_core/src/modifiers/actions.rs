@@ -163,6 +163,14 @@ impl Model for ActionsModel {
                 }
             }
 
+            WindowEvent::ActionRequest(request) if request.action == Action::Default => {
+                if !cx.is_disabled() && cx.current == meta.target {
+                    if let Some(action) = &self.on_press {
+                        (action)(cx);
+                    }
+                }
+            }
+
             WindowEvent::PressDown { mouse } => {
                 let over = if *mouse { cx.hovered() } else { cx.focused() };
                 if cx.current() != over && !over.is_descendant_of(cx.tree, cx.current()) {
@@ -474,6 +482,29 @@ pub trait ActionModifiers<V> {
     fn on_drop<F>(self, action: F) -> Self
     where
         F: 'static + Fn(&mut EventContext, DropData) + Send + Sync;
+
+    /// Adds a capture listener which receives events targeting the view or any of its descendants
+    /// before the target itself does. Consuming the event in the listener prevents it from
+    /// reaching the target.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// VStack::new(cx, |cx| {
+    ///     Button::new(cx, |cx| Label::new(cx, "Blocked"));
+    /// })
+    /// .on_capture(|_, event| {
+    ///     event.map(|window_event, meta| {
+    ///         if let WindowEvent::Press { .. } = window_event {
+    ///             meta.consume();
+    ///         }
+    ///     });
+    /// });
+    /// ```
+    fn on_capture<F>(self, listener: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &mut Event);
 }
 
 // If the entity doesn't have an `ActionsModel` then add one to the entity
@@ -563,6 +594,12 @@ impl<'a, V: View> ActionModifiers<V> for Handle<'a, V> {
 
         build_action_model(self.cx, self.entity);
 
+        // Let assistive technologies press the view.
+        if self.cx.style.default_action_verb.get(self.entity).is_none() {
+            self.cx.style.default_action_verb.insert(self.entity, DefaultActionVerb::Click);
+            self.cx.style.needs_access_update(self.entity);
+        }
+
         self.cx.emit_custom(
             Event::new(ActionsEvent::OnPress(Box::new(action)))
                 .target(self.entity)
@@ -785,4 +822,13 @@ impl<'a, V: View> ActionModifiers<V> for Handle<'a, V> {
 
         self
     }
+
+    fn on_capture<F>(self, listener: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &mut Event),
+    {
+        self.cx.capture_listeners.entry(self.entity).or_default().push(Box::new(listener));
+
+        self
+    }
 }
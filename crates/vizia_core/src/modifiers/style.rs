@@ -1,6 +1,7 @@
 use vizia_style::{ColorStop, CornerRadius, Rect};
 
 use super::internal;
+use crate::animation::{AnimId, PlaybackAction};
 use crate::prelude::*;
 
 /// Modifiers for changing the style properties of a view.
@@ -104,6 +105,7 @@ pub trait StyleModifiers: internal::Modifiable {
                     pseudo_classes.set(PseudoClassFlags::CHECKED, val);
                 }
                 cx.needs_restyle(entity);
+                cx.style.needs_access_update(entity);
             });
         });
 
@@ -168,14 +170,22 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
-    modifier!(
-        /// Sets the view to be disabled.
-        ///
-        /// This property is inherited by the descendants of the view.
-        disabled,
-        bool,
-        SystemFlags::RESTYLE
-    );
+    /// Sets the view to be disabled.
+    ///
+    /// This property is inherited by the descendants of the view.
+    fn disabled<U: Into<bool>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        value.set_or_bind(self.context(), current, move |cx, v| {
+            cx.style.disabled.insert(entity, v.get(cx).into());
+            cx.style.needs_access_update(entity);
+
+            cx.style.system_flags |= SystemFlags::RESTYLE;
+            cx.set_system_flags(entity, SystemFlags::RESTYLE);
+        });
+
+        self
+    }
 
     modifier!(
         /// Sets whether the view should be positioned and rendered.
@@ -201,6 +211,24 @@ pub trait StyleModifiers: internal::Modifiable {
         /// Exects a value between 0.0 (transparent) and 1.0 (opaque).
         opacity,
         Opacity,
+        SystemFlags::RECOMPOSITE
+    );
+
+    modifier!(
+        /// Sets the properties of the view which are expected to change.
+        ///
+        /// Any value other than `auto` renders the view and its descendants into a cached render layer,
+        /// which is reused until the contents of the subtree change. Changes to the transform or
+        /// opacity of the view then only composite the cached layer without redrawing its contents.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// Element::new(cx).will_change("transform, opacity");
+        /// ```
+        will_change,
+        WillChange,
         SystemFlags::REDRAW
     );
 
@@ -270,6 +298,29 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
+    /// Sets the filters applied to the view and its children, such as blur, grayscale, or drop-shadow.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).filter(vec![Filter::Grayscale(1.0), Filter::Blur(Length::px(2.0))]);
+    /// ```
+    fn filter<U: Into<Vec<Filter>>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            value.set_or_bind(cx, entity, move |cx, v| {
+                let value = v.get(cx).into();
+                cx.style.filter.insert(cx.current, value);
+
+                cx.needs_recomposite(entity);
+            });
+        });
+
+        self
+    }
+
     /// Sets the backdrop filter for the view.
     fn backdrop_filter<U: Into<Filter>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
@@ -602,7 +653,7 @@ pub trait StyleModifiers: internal::Modifiable {
             value.set_or_bind(cx, entity, move |cx, v| {
                 let value = v.get(cx).into();
                 cx.style.transform.insert(cx.current, value);
-                cx.needs_redraw(entity);
+                cx.needs_recomposite(entity);
             });
         });
 
@@ -619,7 +670,7 @@ pub trait StyleModifiers: internal::Modifiable {
                 let x = value.x.to_length_or_percentage();
                 let y = value.y.to_length_or_percentage();
                 cx.style.transform_origin.insert(cx.current, Translate { x, y });
-                cx.needs_redraw(entity);
+                cx.needs_recomposite(entity);
             });
         });
 
@@ -633,7 +684,7 @@ pub trait StyleModifiers: internal::Modifiable {
         /// Translation applies to the rendered view and does not affect layout.
         translate,
         Translate,
-        SystemFlags::REDRAW
+        SystemFlags::RECOMPOSITE
     );
 
     // Rotate
@@ -643,7 +694,7 @@ pub trait StyleModifiers: internal::Modifiable {
         /// Rotation applies to the rendered view and does not affect layout.
         rotate,
         Angle,
-        SystemFlags::REDRAW
+        SystemFlags::RECOMPOSITE
     );
 
     // Scale
@@ -653,8 +704,73 @@ pub trait StyleModifiers: internal::Modifiable {
         /// Scale applies to the rendered view and does not affect layout.
         scale,
         Scale,
-        SystemFlags::REDRAW
+        SystemFlags::RECOMPOSITE
+    );
+
+    /// Sets an animation to play when the view is removed.
+    ///
+    /// The view remains in the tree, but cannot be interacted with, until the animation has finished.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let fade_out = cx.add_animation(
+    ///     AnimationBuilder::new()
+    ///         .keyframe(0.0, |key| key.opacity(1.0))
+    ///         .keyframe(1.0, |key| key.opacity(0.0)),
+    /// );
+    ///
+    /// Element::new(cx).exit_animation(fade_out, Duration::from_millis(200));
+    /// ```
+    fn exit_animation(mut self, animation: impl AnimId, duration: Duration) -> Self {
+        let entity = self.entity();
+        let cx = self.context();
+        if let Some(animation) = animation.get(&EventContext::new_with_current(cx, entity)) {
+            cx.style.exit_animation.insert(entity, (animation, duration));
+        }
+
+        self
+    }
+
+    // Animation playback
+    modifier!(
+        /// Sets the number of times animations played on the view repeat.
+        animation_iteration_count,
+        AnimationIterationCount,
+        SystemFlags::empty()
+    );
+
+    modifier!(
+        /// Sets whether animations played on the view play forwards, backwards, or alternate.
+        animation_direction,
+        AnimationDirection,
+        SystemFlags::empty()
+    );
+
+    modifier!(
+        /// Sets whether animations played on the view apply their values before starting and after finishing.
+        animation_fill_mode,
+        AnimationFillMode,
+        SystemFlags::empty()
     );
+
+    /// Sets whether animations played on the view are running or paused.
+    fn animation_play_state<U: Into<AnimationPlayState>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        value.set_or_bind(self.context(), current, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.animation_play_state.insert(entity, value);
+
+            let action = match value {
+                AnimationPlayState::Running => PlaybackAction::Resume,
+                AnimationPlayState::Paused => PlaybackAction::Pause,
+            };
+            cx.style.control_animation(entity, None, action, Instant::now());
+        });
+
+        self
+    }
 }
 
 impl<'a, V: View> StyleModifiers for Handle<'a, V> {}
@@ -0,0 +1,349 @@
+//! Parameters for communicating edits between the views of an audio plugin and its host.
+//!
+//! Views such as [`Knob`](crate::views::Knob), [`Slider`](crate::views::Slider), [`XYPad`](crate::views::XYPad)
+//! and [`Switch`](crate::views::Switch) can be bound to a parameter with their `param` modifier. When the user edits
+//! the view it emits a gesture made up of a [`ParamEvent::BeginEdit`], any number of [`ParamEvent::SetNormalized`],
+//! and a [`ParamEvent::EndEdit`]. A [`ParamBridge`] model forwards these gestures to a [`ParamHost`], which is
+//! implemented by the plugin to notify the host.
+//!
+//! Changes to parameters made by the host, for example by automation, are sent from the audio thread with a
+//! [`ParamSender`] and received on the UI thread with a [`ParamReceiver`], which emits a [`ParamChanged`] event to
+//! the whole tree so that models can update their bound values.
+//!
+//! # Example
+//! ```no_run
+//! # use vizia_core::prelude::*;
+//! # let cx = &mut Context::default();
+//! const GAIN: ParamId = ParamId(0);
+//!
+//! #[derive(Lens)]
+//! struct PluginData {
+//!     gain: f32,
+//! }
+//!
+//! impl Model for PluginData {
+//!     fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+//!         event.map(|param_event, _| match param_event {
+//!             ParamEvent::SetNormalized(GAIN, normalized) => self.gain = *normalized,
+//!             _ => {}
+//!         });
+//!
+//!         event.map(|changed: &ParamChanged, _| {
+//!             if changed.param == GAIN {
+//!                 self.gain = changed.normalized;
+//!             }
+//!         });
+//!     }
+//! }
+//!
+//! PluginData { gain: 0.5 }.build(cx);
+//!
+//! Knob::new(cx, 0.5, PluginData::gain, false).param(GAIN);
+//! ```
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+
+use crate::prelude::*;
+
+/// Identifies a parameter of an audio plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParamId(pub u32);
+
+/// Events emitted by views bound to a parameter when the user edits the parameter.
+///
+/// The events are emitted up the tree from the view, so they can be handled by any model above the view as well
+/// as by a [`ParamBridge`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParamEvent {
+    /// The user has started to edit the parameter, for example by pressing the mouse on a knob.
+    BeginEdit(ParamId),
+    /// The user has set the normalized value of the parameter, in the range 0 to 1.
+    SetNormalized(ParamId, f32),
+    /// The user has finished editing the parameter.
+    EndEdit(ParamId),
+}
+
+/// An event sent to every model and view in the tree when the host changes the value of a parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParamChanged {
+    /// The parameter which changed.
+    pub param: ParamId,
+    /// The new normalized value of the parameter, in the range 0 to 1.
+    pub normalized: f32,
+}
+
+/// Receives the edit gestures made by the user, usually to notify the plugin host.
+pub trait ParamHost: Send + 'static {
+    /// Called when the user starts editing the parameter.
+    fn begin_edit(&self, param: ParamId);
+    /// Called when the user sets the normalized value of the parameter.
+    fn set_normalized(&self, param: ParamId, normalized: f32);
+    /// Called when the user finishes editing the parameter.
+    fn end_edit(&self, param: ParamId);
+}
+
+/// A model which forwards the [`ParamEvent`]s emitted by views below it to a [`ParamHost`].
+pub struct ParamBridge {
+    host: Box<dyn ParamHost>,
+}
+
+impl ParamBridge {
+    /// Creates a new parameter bridge which forwards edit gestures to the given host.
+    pub fn new(host: impl ParamHost) -> Self {
+        Self { host: Box::new(host) }
+    }
+}
+
+impl Model for ParamBridge {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|param_event, _| match param_event {
+            ParamEvent::BeginEdit(param) => self.host.begin_edit(*param),
+            ParamEvent::SetNormalized(param, normalized) => {
+                self.host.set_normalized(*param, *normalized)
+            }
+            ParamEvent::EndEdit(param) => self.host.end_edit(*param),
+        });
+    }
+}
+
+// The latest value of each parameter, with a flag for each parameter which has changed since the last poll.
+struct ParamSlots {
+    values: Box<[AtomicU32]>,
+    changed: Box<[AtomicBool]>,
+    pending: AtomicBool,
+}
+
+/// Creates a lock-free channel for sending changes to the values of parameters `0..num_params` from the host.
+///
+/// Changes are coalesced so that only the latest value of a parameter is received, which means the sender never
+/// blocks or allocates and can be used from the audio thread.
+pub fn param_channel(num_params: usize) -> (ParamSender, ParamReceiver) {
+    let slots = Arc::new(ParamSlots {
+        values: (0..num_params).map(|_| AtomicU32::new(0)).collect(),
+        changed: (0..num_params).map(|_| AtomicBool::new(false)).collect(),
+        pending: AtomicBool::new(false),
+    });
+
+    (ParamSender { slots: slots.clone() }, ParamReceiver { slots })
+}
+
+/// The sending half of a [`param_channel`], which can be used from any thread.
+#[derive(Clone)]
+pub struct ParamSender {
+    slots: Arc<ParamSlots>,
+}
+
+impl ParamSender {
+    /// Sets the normalized value of a parameter. Does nothing if the parameter is outside the channel.
+    pub fn set_normalized(&self, param: ParamId, normalized: f32) {
+        let index = param.0 as usize;
+        if let Some(value) = self.slots.values.get(index) {
+            value.store(normalized.to_bits(), Ordering::Relaxed);
+            self.slots.changed[index].store(true, Ordering::Release);
+            self.slots.pending.store(true, Ordering::Release);
+        }
+    }
+}
+
+/// The receiving half of a [`param_channel`], which is polled on the UI thread.
+pub struct ParamReceiver {
+    slots: Arc<ParamSlots>,
+}
+
+impl ParamReceiver {
+    /// Calls `f` with the latest value of each parameter which has changed since the last poll.
+    pub fn poll(&self, mut f: impl FnMut(ParamId, f32)) {
+        if !self.slots.pending.swap(false, Ordering::Acquire) {
+            return;
+        }
+
+        for (index, changed) in self.slots.changed.iter().enumerate() {
+            if changed.swap(false, Ordering::Acquire) {
+                let normalized = f32::from_bits(self.slots.values[index].load(Ordering::Relaxed));
+                f(ParamId(index as u32), normalized);
+            }
+        }
+    }
+
+    /// Sends a [`ParamChanged`] event to the whole tree for each parameter which has changed since the last poll.
+    pub fn emit_changes(&self, cx: &mut Context) {
+        self.poll(|param, normalized| {
+            cx.emit_custom(
+                Event::new(ParamChanged { param, normalized })
+                    .target(Entity::root())
+                    .origin(Entity::root())
+                    .propagate(Propagation::Subtree),
+            );
+        });
+    }
+}
+
+// Emits a complete edit gesture for a parameter which the user changed in a single step, such as with a key press.
+pub(crate) fn emit_param_gesture(cx: &mut EventContext, param: Option<ParamId>, normalized: f32) {
+    if let Some(param) = param {
+        cx.emit(ParamEvent::BeginEdit(param));
+        cx.emit(ParamEvent::SetNormalized(param, normalized));
+        cx.emit(ParamEvent::EndEdit(param));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accessibility::IntoNode;
+    use crate::events::EventManager;
+    use std::sync::Mutex;
+
+    const PARAM: ParamId = ParamId(1);
+
+    #[derive(Clone, Default)]
+    struct MockHost {
+        gestures: Arc<Mutex<Vec<ParamEvent>>>,
+    }
+
+    impl MockHost {
+        fn take(&self) -> Vec<ParamEvent> {
+            std::mem::take(&mut *self.gestures.lock().unwrap())
+        }
+    }
+
+    impl ParamHost for MockHost {
+        fn begin_edit(&self, param: ParamId) {
+            self.gestures.lock().unwrap().push(ParamEvent::BeginEdit(param));
+        }
+
+        fn set_normalized(&self, param: ParamId, normalized: f32) {
+            self.gestures.lock().unwrap().push(ParamEvent::SetNormalized(param, normalized));
+        }
+
+        fn end_edit(&self, param: ParamId) {
+            self.gestures.lock().unwrap().push(ParamEvent::EndEdit(param));
+        }
+    }
+
+    #[derive(Lens)]
+    struct TestData {
+        value: f32,
+        flag: bool,
+    }
+
+    impl Model for TestData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|changed: &ParamChanged, _| {
+                if changed.param == PARAM {
+                    self.value = changed.normalized;
+                }
+            });
+        }
+    }
+
+    fn context(host: &MockHost) -> Context {
+        let mut cx = Context::new();
+        ParamBridge::new(host.clone()).build(&mut cx);
+        TestData { value: 0.5, flag: false }.build(&mut cx);
+        cx
+    }
+
+    fn send(cx: &mut Context, target: Entity, window_event: WindowEvent) {
+        cx.emit_custom(Event::new(window_event).target(target).origin(target));
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    #[test]
+    fn knob_drag_is_a_single_gesture() {
+        let host = MockHost::default();
+        let mut cx = context(&host);
+        let knob = Knob::new(&mut cx, 0.5, TestData::value, false).param(PARAM).entity();
+
+        send(&mut cx, knob, WindowEvent::MouseDown(MouseButton::Left));
+        send(&mut cx, knob, WindowEvent::MouseMove(0.0, -10.0));
+        send(&mut cx, knob, WindowEvent::MouseMove(0.0, -20.0));
+        send(&mut cx, knob, WindowEvent::MouseUp(MouseButton::Left));
+
+        let gestures = host.take();
+        assert_eq!(gestures.first(), Some(&ParamEvent::BeginEdit(PARAM)));
+        assert_eq!(gestures.last(), Some(&ParamEvent::EndEdit(PARAM)));
+        assert_eq!(gestures.len(), 4);
+        assert!(gestures[1..3]
+            .iter()
+            .all(|event| matches!(event, ParamEvent::SetNormalized(PARAM, _))));
+    }
+
+    #[test]
+    fn key_press_emits_complete_gesture() {
+        let host = MockHost::default();
+        let mut cx = context(&host);
+        let knob = Knob::new(&mut cx, 0.5, TestData::value, false).param(PARAM).entity();
+
+        send(&mut cx, knob, WindowEvent::KeyDown(Code::ArrowUp, None));
+
+        assert!(matches!(
+            host.take()[..],
+            [
+                ParamEvent::BeginEdit(PARAM),
+                ParamEvent::SetNormalized(PARAM, normalized),
+                ParamEvent::EndEdit(PARAM)
+            ] if (normalized - 0.6).abs() < 1e-6
+        ));
+    }
+
+    #[test]
+    fn switch_toggle_sets_opposite_value() {
+        let host = MockHost::default();
+        let mut cx = context(&host);
+        let switch = Switch::new(&mut cx, TestData::flag).param(PARAM).entity();
+
+        send(
+            &mut cx,
+            switch,
+            WindowEvent::ActionRequest(accesskit::ActionRequest {
+                action: Action::Default,
+                target: switch.accesskit_id(),
+                data: None,
+            }),
+        );
+
+        assert_eq!(
+            host.take(),
+            [
+                ParamEvent::BeginEdit(PARAM),
+                ParamEvent::SetNormalized(PARAM, 1.0),
+                ParamEvent::EndEdit(PARAM)
+            ]
+        );
+    }
+
+    #[test]
+    fn host_changes_are_coalesced_and_reach_models() {
+        let host = MockHost::default();
+        let mut cx = context(&host);
+        let (sender, receiver) = param_channel(2);
+
+        std::thread::spawn(move || {
+            sender.set_normalized(PARAM, 0.25);
+            sender.set_normalized(PARAM, 0.75);
+            sender.set_normalized(ParamId(5), 1.0);
+        })
+        .join()
+        .unwrap();
+
+        let mut received = Vec::new();
+        receiver.poll(|param, normalized| received.push((param, normalized)));
+        assert_eq!(received, [(PARAM, 0.75)]);
+
+        received.clear();
+        receiver.poll(|param, normalized| received.push((param, normalized)));
+        assert!(received.is_empty());
+
+        let (sender, receiver) = param_channel(2);
+        sender.set_normalized(PARAM, 0.9);
+        receiver.emit_changes(&mut cx);
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(&mut cx) {}
+
+        assert_eq!(TestData::value.get(&cx), 0.9);
+        // Changes from the host are not edits by the user, so they are not sent back to the host.
+        assert!(host.take().is_empty());
+    }
+}
@@ -1,6 +1,10 @@
-use crate::animation::{AnimationState, Interpolator};
+use crate::animation::{
+    AnimationEventKind, AnimationState, Interpolator, PlaybackAction, TimingFunction,
+};
 use crate::prelude::*;
+use vizia_id::GenerationalId;
 use vizia_storage::{SparseSet, SparseSetGeneric, SparseSetIndex};
+use vizia_style::AnimationFillMode;
 
 const INDEX_MASK: u32 = u32::MAX / 4;
 const INLINE_MASK: u32 = 1 << 31;
@@ -161,6 +165,8 @@ where
             if active_anim_index < self.active_animations.len() {
                 let anim_state = &mut self.active_animations[active_anim_index];
                 anim_state.t = 1.0;
+                anim_state.entities.remove(&entity);
+                self.inline_data.sparse[entity_index].anim_index = u32::MAX;
 
                 self.remove_innactive_animations();
             }
@@ -383,42 +389,82 @@ where
         }
     }
 
-    pub fn tick(&mut self, time: Instant) -> Vec<Entity> {
+    /// Updates the output of any active animations and returns the entities linked to them.
+    ///
+    /// Start, iteration, and end events for keyframe animations are pushed to `events`. Transitions do not emit events.
+    pub fn tick(
+        &mut self,
+        time: Instant,
+        events: &mut Vec<(Entity, Animation, AnimationEventKind)>,
+    ) -> Vec<Entity> {
         self.remove_innactive_animations();
 
         if self.has_animations() {
             for state in self.active_animations.iter_mut() {
-                // If the animation is already finished then skip
-                if state.t == 1.0 {
+                // If the animation is already finished or paused then skip
+                if (state.t == 1.0 || state.paused) && !state.needs_update {
                     continue;
                 }
 
+                state.needs_update = false;
+
                 if state.keyframes.len() == 1 {
                     state.output = Some(state.keyframes[0].value.clone());
                     continue;
                 }
 
-                let elapsed_time = time.duration_since(state.start_time);
-                let mut normalised_time =
-                    (elapsed_time.as_secs_f32() / state.duration.as_secs_f32()) - state.dt;
+                let active_time = state.active_time(time);
+                let finished = state.is_finished(active_time);
+                let (iteration, progress) = state.iteration_progress(active_time);
 
-                normalised_time = normalised_time.clamp(0.0, 1.0);
+                let emit_events = !state.is_transition() && !state.id.is_null();
+                let mut emit = |kind: AnimationEventKind| {
+                    if emit_events {
+                        events
+                            .extend(state.entities.iter().map(|entity| (*entity, state.id, kind)));
+                    }
+                };
 
-                let mut i = 0;
-                while i < state.keyframes.len() - 1 && state.keyframes[i + 1].time < normalised_time
-                {
-                    i += 1;
+                if !state.started && active_time >= 0.0 {
+                    state.started = true;
+                    emit(AnimationEventKind::Start);
+                } else if state.started && !finished && iteration != state.iteration {
+                    emit(AnimationEventKind::Iteration);
                 }
-                let start = &state.keyframes[i];
-                let end = &state.keyframes[i + 1];
 
-                let normalised_elapsed_time =
-                    (normalised_time - start.time) / (end.time - start.time);
+                state.iteration = iteration;
+
+                let was_finished = state.t == 1.0;
+
+                state.t = if finished {
+                    1.0
+                } else {
+                    (active_time / state.iteration_count).clamp(0.0, 1.0 - f32::EPSILON)
+                };
+
+                if finished && !was_finished {
+                    emit(AnimationEventKind::End);
+                }
 
-                state.t = normalised_time;
+                let fills_backwards = state.is_transition()
+                    || matches!(
+                        state.fill_mode,
+                        AnimationFillMode::Backwards | AnimationFillMode::Both
+                    );
 
-                let timing_t = start.timing_function.value(normalised_elapsed_time);
-                state.output = Some(T::interpolate(&start.value, &end.value, timing_t));
+                state.output = if active_time < 0.0 && !fills_backwards {
+                    // During the delay the entity keeps its inline or shared value.
+                    state.entities.iter().next().and_then(|entity| {
+                        let data_index = self.inline_data.sparse.get(entity.index())?.data_index;
+                        if data_index.is_inline() {
+                            self.inline_data.dense.get(data_index.index()).map(|e| e.value.clone())
+                        } else {
+                            self.shared_data.dense.get(data_index.index()).map(|e| e.value.clone())
+                        }
+                    })
+                } else {
+                    Some(state.sample(state.directed_progress(iteration, progress)))
+                };
             }
 
             self.active_animations
@@ -430,31 +476,117 @@ where
         }
     }
 
-    /// Returns true if the given entity is linked to an active animation
-    // pub fn is_animating(&self, entity: Entity) -> bool {
-    //     let entity_index = entity.index();
-    //     if entity_index < self.inline_data.sparse.len() {
-    //         let anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
-    //         if anim_index < self.active_animations.len() {
-    //             return true;
-    //         }
-    //     }
+    /// Applies a playback action to the active animation of an entity.
+    ///
+    /// If `animation` is `None` then the action applies to any active animation which is not a transition.
+    pub(crate) fn control_animation(
+        &mut self,
+        entity: Entity,
+        animation: Option<Animation>,
+        action: PlaybackAction,
+        time: Instant,
+    ) {
+        let entity_index = entity.index();
+        if entity_index < self.inline_data.sparse.len() {
+            let anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
+            if let Some(state) = self.active_animations.get_mut(anim_index) {
+                let matches = match animation {
+                    Some(animation) => state.id == animation,
+                    None => !state.is_transition(),
+                };
+
+                if matches {
+                    state.apply(action, time);
+                }
+            }
+        }
+    }
 
-    //     false
-    // }
+    /// Returns the progress through the current iteration of the given animation on an entity.
+    pub(crate) fn animation_progress(
+        &self,
+        entity: Entity,
+        animation: Animation,
+        time: Instant,
+    ) -> Option<f32> {
+        let state = self.get_active_animation(entity)?;
+        if state.id != animation {
+            return None;
+        }
+
+        let (_, progress) = state.iteration_progress(state.active_time(time));
+        Some(progress)
+    }
+
+    /// Restarts a spring transition from the current value while preserving its velocity.
+    ///
+    /// The `distance` is the distance to the new target as a fraction of the distance covered by the
+    /// current transition, and is used to rescale the normalized velocity of the spring. Returns false,
+    /// leaving the state untouched, if the transition does not use a spring.
+    fn retarget_spring(state: &mut AnimationState<T>, current_value: T, distance: f32) -> bool {
+        let Some(spring) = state
+            .keyframes
+            .first()
+            .and_then(|keyframe| keyframe.timing_function.as_spring().copied())
+        else {
+            return false;
+        };
+
+        // Avoid very large velocities when retargeting close to the current value.
+        let distance = if distance.abs() < 0.01 { 0.01f32.copysign(distance) } else { distance };
+        let spring = spring.with_velocity(spring.velocity_at(state.t) / distance);
+
+        let first = state.keyframes.first_mut().unwrap();
+        first.value = current_value;
+        first.timing_function = TimingFunction::Spring(spring);
+
+        state.duration = Duration::from_secs_f32(spring.duration());
+        state.t = 0.0;
+        state.dt = 0.0;
+        state.start_time = Instant::now();
+
+        true
+    }
+
+    /// Returns true if the given entity is linked to an active animation which has not yet finished.
+    pub fn is_animating(&self, entity: Entity) -> bool {
+        let entity_index = entity.index();
+        if entity_index < self.inline_data.sparse.len() {
+            let anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
+            if anim_index < self.active_animations.len() {
+                return self.active_animations[anim_index].t < 1.0;
+            }
+        }
+
+        false
+    }
+
+    /// Returns the id of the animation playing on an entity if it has not yet finished.
+    pub(crate) fn playing_animation(&self, entity: Entity) -> Option<Animation> {
+        let entity_index = entity.index();
+        if entity_index < self.inline_data.sparse.len() {
+            let anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
+            if anim_index < self.active_animations.len()
+                && self.active_animations[anim_index].t < 1.0
+            {
+                return Some(self.active_animations[anim_index].id);
+            }
+        }
+
+        None
+    }
 
     pub fn remove_innactive_animations(&mut self) {
         // Create a list of finished animations
-        let inactive: Vec<AnimationState<T>> = self
-            .active_animations
-            .iter()
-            .filter(|e| e.t == 1.0 && !e.persistent)
-            .cloned()
-            .collect();
+        let is_active =
+            |e: &AnimationState<T>| (e.t < 1.0 || e.persistent) && !e.entities.is_empty();
+
+        let inactive: Vec<AnimationState<T>> =
+            self.active_animations.iter().filter(|e| !is_active(e)).cloned().collect();
 
         // Remove inactive animation states from active animations list
-        // Retains persistent animations
-        self.active_animations.retain(|e| e.t < 1.0 || e.persistent);
+        // Retains persistent animations which are still linked to an entity
+        self.active_animations.retain(is_active);
 
         for state in inactive.into_iter() {
             for entity in state.entities.iter() {
@@ -471,7 +603,7 @@ where
 
     pub fn has_animations(&self) -> bool {
         for state in self.active_animations.iter() {
-            if state.t < 1.0 {
+            if (state.t < 1.0 && !state.paused) || state.needs_update {
                 return true;
             }
         }
@@ -609,6 +741,10 @@ where
                     if current_anim_state.is_transition() {
                         // Skip if the transition hasn't changed
                         if current_anim_state.to_rule != rule_data_index {
+                            let progress = current_anim_state.keyframes[0]
+                                .timing_function
+                                .value(current_anim_state.t);
+
                             if rule_data_index == current_anim_state.from_rule {
                                 // Transitioning back to previous rule
                                 current_anim_state.from_rule = current_anim_state.to_rule;
@@ -625,17 +761,27 @@ where
 
                                 current_anim_state.dt = current_anim_state.t - 1.0;
                                 current_anim_state.start_time = Instant::now();
+
+                                Self::retarget_spring(current_anim_state, current_value, -progress);
                             } else {
                                 // Transitioning to new rule
                                 current_anim_state.to_rule = rule_data_index;
                                 current_anim_state.keyframes.first_mut().unwrap().value =
-                                    current_value;
+                                    current_value.clone();
                                 current_anim_state.keyframes.last_mut().unwrap().value =
                                     self.shared_data.dense[current_anim_state.to_rule]
                                         .value
                                         .clone();
-                                current_anim_state.t = 0.0;
-                                current_anim_state.start_time = Instant::now();
+
+                                // Assumes the new target lies in the same direction as the previous one.
+                                if !Self::retarget_spring(
+                                    current_anim_state,
+                                    current_value,
+                                    1.0 - progress,
+                                ) {
+                                    current_anim_state.t = 0.0;
+                                    current_anim_state.start_time = Instant::now();
+                                }
                             }
                         }
                     }
@@ -795,4 +941,108 @@ mod tests {
         animatable_storage.insert(Entity::root(), 5.0);
         //assert_eq!(animatable_storage.entity_indices.first().unwrap().data_index, DataIndex::inline(0));
     }
+
+    /// Creates a storage with a linear animation from 0.0 to 1.0 over one second playing on the root entity.
+    fn play_linear_animation() -> (AnimatableSet<f32>, Animation, Instant) {
+        use crate::animation::Keyframe;
+
+        let mut animatable_storage = AnimatableSet::<f32>::default();
+        let animation = Animation::root();
+        let timing_function = TimingFunction::linear();
+        let anim_state = AnimationState::new(animation)
+            .with_keyframe(Keyframe { time: 0.0, value: 0.0, timing_function })
+            .with_keyframe(Keyframe { time: 1.0, value: 1.0, timing_function });
+        animatable_storage.insert_animation(animation, anim_state);
+
+        let start_time = Instant::now();
+        animatable_storage.play_animation(
+            Entity::root(),
+            animation,
+            start_time,
+            Duration::from_secs(1),
+            Duration::ZERO,
+        );
+
+        (animatable_storage, animation, start_time)
+    }
+
+    fn assert_output(animatable_storage: &AnimatableSet<f32>, expected: f32) {
+        let output = *animatable_storage.get(Entity::root()).unwrap();
+        assert!((output - expected).abs() < 0.01, "expected {expected}, got {output}");
+    }
+
+    /// Test that playing an animation emits start and end events.
+    #[test]
+    fn animation_events() {
+        let (mut animatable_storage, animation, start_time) = play_linear_animation();
+
+        let mut events = Vec::new();
+        animatable_storage.tick(start_time, &mut events);
+        assert_eq!(events, vec![(Entity::root(), animation, AnimationEventKind::Start)]);
+
+        events.clear();
+        animatable_storage.tick(start_time + Duration::from_secs(2), &mut events);
+        assert_eq!(events, vec![(Entity::root(), animation, AnimationEventKind::End)]);
+    }
+
+    /// Test that the iteration count and direction determine the output of an animation.
+    #[test]
+    fn animation_iterations() {
+        let (mut animatable_storage, animation, start_time) = play_linear_animation();
+        animatable_storage.control_animation(
+            Entity::root(),
+            Some(animation),
+            PlaybackAction::Configure {
+                iteration_count: 2.0,
+                direction: AnimationDirection::Alternate,
+                fill_mode: AnimationFillMode::Forwards,
+                paused: false,
+            },
+            start_time,
+        );
+
+        let mut events = Vec::new();
+        animatable_storage.tick(start_time + Duration::from_millis(250), &mut events);
+        assert_output(&animatable_storage, 0.25);
+
+        animatable_storage.tick(start_time + Duration::from_millis(1250), &mut events);
+        assert_output(&animatable_storage, 0.75);
+        assert!(events.contains(&(Entity::root(), animation, AnimationEventKind::Iteration)));
+
+        // The final value is kept after the animation ends
+        animatable_storage.tick(start_time + Duration::from_secs(3), &mut events);
+        animatable_storage.tick(start_time + Duration::from_secs(4), &mut events);
+        assert_output(&animatable_storage, 0.0);
+        assert!(!animatable_storage.is_animating(Entity::root()));
+    }
+
+    /// Test that a paused animation holds its value and can be moved by seeking.
+    #[test]
+    fn animation_pause_and_seek() {
+        let (mut animatable_storage, animation, start_time) = play_linear_animation();
+        animatable_storage.control_animation(
+            Entity::root(),
+            Some(animation),
+            PlaybackAction::Pause,
+            start_time,
+        );
+
+        let mut events = Vec::new();
+        animatable_storage.tick(start_time + Duration::from_secs(10), &mut events);
+        assert_output(&animatable_storage, 0.0);
+        assert!(!animatable_storage.has_animations());
+
+        animatable_storage.control_animation(
+            Entity::root(),
+            Some(animation),
+            PlaybackAction::Seek(Duration::from_millis(500)),
+            start_time,
+        );
+        animatable_storage.tick(start_time + Duration::from_secs(20), &mut events);
+        assert_output(&animatable_storage, 0.5);
+        assert_eq!(
+            animatable_storage.animation_progress(Entity::root(), animation, Instant::now()),
+            Some(0.5)
+        );
+    }
 }
@@ -69,18 +69,19 @@ use std::ops::{Deref, DerefMut, Range};
 use crate::prelude::*;
 
 pub use vizia_style::{
-    Angle, BackgroundImage, BackgroundSize, BorderStyleKeyword, ClipPath, Color, CornerShape,
-    CssRule, CursorIcon, Display, Filter, FontFamily, FontSize, FontSlant, FontVariation,
+    Angle, AnimationDirection, AnimationFillMode, AnimationIterationCount, AnimationPlayState,
+    BackgroundImage, BackgroundSize, BorderStyleKeyword, ClipPath, Color, CornerShape, CssRule,
+    CursorIcon, Display, EasingFunction, Filter, FontFamily, FontSize, FontSlant, FontVariation,
     FontWeight, FontWeightKeyword, FontWidth, GenericFontFamily, Gradient, HorizontalPosition,
     HorizontalPositionKeyword, Length, LengthOrPercentage, LengthValue, LineClamp, LineDirection,
-    LinearGradient, Matrix, Opacity, Overflow, PointerEvents, Position, Scale, Shadow, TextAlign,
-    TextDecorationLine, TextDecorationStyle, TextOverflow, Transform, Transition, Translate,
-    VerticalPosition, VerticalPositionKeyword, Visibility, RGBA,
+    LinearGradient, Matrix, Opacity, Overflow, PointerEvents, Position, Scale, Shadow,
+    StepPosition, TextAlign, TextDecorationLine, TextDecorationStyle, TextOverflow, Transform,
+    Transition, Translate, VerticalPosition, VerticalPositionKeyword, Visibility, WillChange, RGBA,
 };
 
 use vizia_style::{
-    BlendMode, EasingFunction, KeyframeSelector, ParserOptions, Property, SelectorList, Selectors,
-    StyleSheet,
+    selectors::parser::Component, BlendMode, KeyframeSelector, ParserOptions, Property,
+    PseudoClass, SelectorList, Selectors, StyleSheet,
 };
 
 mod rule;
@@ -92,7 +93,7 @@ pub(crate) use pseudoclass::*;
 mod transform;
 pub(crate) use transform::*;
 
-use crate::animation::{AnimationState, Interpolator, Keyframe, TimingFunction};
+use crate::animation::{AnimationState, Interpolator, Keyframe, PlaybackAction, TimingFunction};
 use crate::storage::animatable_set::AnimatableSet;
 use crate::storage::style_set::StyleSet;
 use bitflags::bitflags;
@@ -129,6 +130,8 @@ bitflags! {
         const RESTYLE = 1 << 1;
         const REFLOW = 1 << 2;
         const REDRAW = 1 << 3;
+        /// Composite flag. Set when a view needs to be composited again but its contents have not changed.
+        const RECOMPOSITE = 1 << 4;
     }
 }
 
@@ -197,9 +200,17 @@ pub struct Style {
     pub(crate) animations: HashMap<String, Animation>,
     // List of animations to be started on the next frame
     pub(crate) pending_animations: Vec<(Entity, Animation, Duration, Duration)>,
+    // List of playback actions to be applied after pending animations have started
+    pub(crate) pending_playback_actions: Vec<(Entity, Animation, PlaybackAction)>,
+    // Animations played when an entity is removed, with the duration of the animation
+    pub(crate) exit_animation: SparseSet<(Animation, Duration)>,
 
     // List of rules
     pub(crate) rules: IndexMap<Rule, SelectorList<Selectors>>,
+    // List of rules with an `:exiting` pseudo-class on the subject of a selector
+    pub(crate) exit_rules: HashSet<Rule>,
+    // Transitions of rules with an `:exiting` pseudo-class, with the duration of the transition including its delay
+    pub(crate) exit_transitions: HashMap<Animation, Duration>,
 
     pub(crate) default_font: Vec<FamilyOwned>,
 
@@ -237,10 +248,14 @@ pub struct Style {
     pub(crate) overflowy: StyleSet<Overflow>,
 
     // Filters
+    pub(crate) filter: AnimatableSet<Vec<Filter>>,
     pub(crate) backdrop_filter: AnimatableSet<Filter>,
 
     pub(crate) blend_mode: StyleSet<BlendMode>,
 
+    // Render Layer
+    pub(crate) will_change: StyleSet<WillChange>,
+
     // Transform
     pub(crate) transform: AnimatableSet<Vec<Transform>>,
     pub(crate) transform_origin: AnimatableSet<Translate>,
@@ -314,6 +329,12 @@ pub struct Style {
 
     pub(crate) pointer_events: StyleSet<PointerEvents>,
 
+    // Animation playback
+    pub(crate) animation_iteration_count: StyleSet<AnimationIterationCount>,
+    pub(crate) animation_direction: StyleSet<AnimationDirection>,
+    pub(crate) animation_fill_mode: StyleSet<AnimationFillMode>,
+    pub(crate) animation_play_state: StyleSet<AnimationPlayState>,
+
     // LAYOUT
 
     // Display
@@ -391,6 +412,8 @@ impl Style {
     pub(crate) fn remove_rules(&mut self) {
         self.rule_manager.reset();
         self.rules.clear();
+        self.exit_rules.clear();
+        self.exit_transitions.clear();
     }
 
     pub(crate) fn get_animation(&self, name: &str) -> Option<&Animation> {
@@ -401,15 +424,17 @@ impl Style {
         &mut self,
         animation_id: Animation,
         time: f32,
+        timing_function: TimingFunction,
         properties: &[Property],
     ) {
         fn insert_keyframe<T: 'static + Interpolator + Debug + Clone + PartialEq + Default>(
             storage: &mut AnimatableSet<T>,
             animation_id: Animation,
             time: f32,
+            timing_function: TimingFunction,
             value: T,
         ) {
-            let keyframe = Keyframe { time, value, timing_function: TimingFunction::linear() };
+            let keyframe = Keyframe { time, value, timing_function };
 
             if let Some(anim_state) = storage.get_animation_mut(animation_id) {
                 anim_state.keyframes.push(keyframe)
@@ -423,39 +448,63 @@ impl Style {
             match property {
                 // DISPLAY
                 Property::Display(value) => {
-                    insert_keyframe(&mut self.display, animation_id, time, *value);
+                    insert_keyframe(&mut self.display, animation_id, time, timing_function, *value);
                 }
 
                 Property::Opacity(value) => {
-                    insert_keyframe(&mut self.opacity, animation_id, time, *value);
+                    insert_keyframe(&mut self.opacity, animation_id, time, timing_function, *value);
                 }
 
                 Property::ClipPath(value) => {
-                    insert_keyframe(&mut self.clip_path, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.clip_path,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 // TRANSFORM
                 Property::Transform(value) => {
-                    insert_keyframe(&mut self.transform, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.transform,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 Property::TransformOrigin(transform_origin) => {
                     let x = transform_origin.x.to_length_or_percentage();
                     let y = transform_origin.y.to_length_or_percentage();
                     let value = Translate { x, y };
-                    insert_keyframe(&mut self.transform_origin, animation_id, time, value);
+                    insert_keyframe(
+                        &mut self.transform_origin,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value,
+                    );
                 }
 
                 Property::Translate(value) => {
-                    insert_keyframe(&mut self.translate, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.translate,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 Property::Rotate(value) => {
-                    insert_keyframe(&mut self.rotate, animation_id, time, *value);
+                    insert_keyframe(&mut self.rotate, animation_id, time, timing_function, *value);
                 }
 
                 Property::Scale(value) => {
-                    insert_keyframe(&mut self.scale, animation_id, time, *value);
+                    insert_keyframe(&mut self.scale, animation_id, time, timing_function, *value);
                 }
 
                 // BORDER
@@ -464,12 +513,19 @@ impl Style {
                         &mut self.border_width,
                         animation_id,
                         time,
+                        timing_function,
                         value.left.0.clone(),
                     );
                 }
 
                 Property::BorderColor(value) => {
-                    insert_keyframe(&mut self.border_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.border_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::CornerTopLeftRadius(value) => {
@@ -477,6 +533,7 @@ impl Style {
                         &mut self.corner_top_left_radius,
                         animation_id,
                         time,
+                        timing_function,
                         value.clone(),
                     );
                 }
@@ -486,6 +543,7 @@ impl Style {
                         &mut self.corner_top_right_radius,
                         animation_id,
                         time,
+                        timing_function,
                         value.clone(),
                     );
                 }
@@ -495,6 +553,7 @@ impl Style {
                         &mut self.corner_bottom_left_radius,
                         animation_id,
                         time,
+                        timing_function,
                         value.clone(),
                     );
                 }
@@ -504,6 +563,7 @@ impl Style {
                         &mut self.corner_bottom_right_radius,
                         animation_id,
                         time,
+                        timing_function,
                         value.clone(),
                     );
                 }
@@ -514,21 +574,40 @@ impl Style {
                         &mut self.outline_width,
                         animation_id,
                         time,
+                        timing_function,
                         value.left.0.clone(),
                     );
                 }
 
                 Property::OutlineColor(value) => {
-                    insert_keyframe(&mut self.outline_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.outline_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::OutlineOffset(value) => {
-                    insert_keyframe(&mut self.outline_offset, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.outline_offset,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 // BACKGROUND
                 Property::BackgroundColor(value) => {
-                    insert_keyframe(&mut self.background_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.background_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::BackgroundImage(images) => {
@@ -544,142 +623,297 @@ impl Style {
                             }
                         })
                         .collect::<Vec<_>>();
-                    insert_keyframe(&mut self.background_image, animation_id, time, images);
+                    insert_keyframe(
+                        &mut self.background_image,
+                        animation_id,
+                        time,
+                        timing_function,
+                        images,
+                    );
                 }
 
                 Property::BackgroundSize(value) => {
-                    insert_keyframe(&mut self.background_size, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.background_size,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 // BOX SHADOW
                 Property::Shadow(value) => {
-                    insert_keyframe(&mut self.shadow, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.shadow,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
+                }
+
+                // FILTER
+                Property::Filter(value) => {
+                    insert_keyframe(
+                        &mut self.filter,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 // TEXT
                 Property::FontColor(value) => {
-                    insert_keyframe(&mut self.font_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.font_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::FontSize(value) => {
-                    insert_keyframe(&mut self.font_size, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.font_size,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::CaretColor(value) => {
-                    insert_keyframe(&mut self.caret_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.caret_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::SelectionColor(value) => {
-                    insert_keyframe(&mut self.selection_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.selection_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 // SPACE
                 Property::Left(value) => {
-                    insert_keyframe(&mut self.left, animation_id, time, *value);
+                    insert_keyframe(&mut self.left, animation_id, time, timing_function, *value);
                 }
 
                 Property::Right(value) => {
-                    insert_keyframe(&mut self.right, animation_id, time, *value);
+                    insert_keyframe(&mut self.right, animation_id, time, timing_function, *value);
                 }
 
                 Property::Top(value) => {
-                    insert_keyframe(&mut self.top, animation_id, time, *value);
+                    insert_keyframe(&mut self.top, animation_id, time, timing_function, *value);
                 }
 
                 Property::Bottom(value) => {
-                    insert_keyframe(&mut self.bottom, animation_id, time, *value);
+                    insert_keyframe(&mut self.bottom, animation_id, time, timing_function, *value);
                 }
 
                 // CHILD SPACE
                 Property::ChildLeft(value) => {
-                    insert_keyframe(&mut self.child_left, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.child_left,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::ChildRight(value) => {
-                    insert_keyframe(&mut self.child_right, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.child_right,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::ChildTop(value) => {
-                    insert_keyframe(&mut self.child_top, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.child_top,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::ChildBottom(value) => {
-                    insert_keyframe(&mut self.child_bottom, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.child_bottom,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::ColBetween(value) => {
-                    insert_keyframe(&mut self.col_between, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.col_between,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::RowBetween(value) => {
-                    insert_keyframe(&mut self.row_between, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.row_between,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 // SIZE
                 Property::Width(value) => {
-                    insert_keyframe(&mut self.width, animation_id, time, *value);
+                    insert_keyframe(&mut self.width, animation_id, time, timing_function, *value);
                 }
 
                 Property::Height(value) => {
-                    insert_keyframe(&mut self.height, animation_id, time, *value);
+                    insert_keyframe(&mut self.height, animation_id, time, timing_function, *value);
                 }
 
                 // SIZE CONSTRAINTS
                 Property::MinWidth(value) => {
-                    insert_keyframe(&mut self.min_width, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_width,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MaxWidth(value) => {
-                    insert_keyframe(&mut self.max_width, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_width,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MinHeight(value) => {
-                    insert_keyframe(&mut self.min_height, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_height,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MaxHeight(value) => {
-                    insert_keyframe(&mut self.max_height, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_height,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 // SPACE CONSTRAINTS
                 Property::MinLeft(value) => {
-                    insert_keyframe(&mut self.min_left, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_left,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MaxLeft(value) => {
-                    insert_keyframe(&mut self.max_left, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_left,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MinRight(value) => {
-                    insert_keyframe(&mut self.min_right, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_right,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MaxRight(value) => {
-                    insert_keyframe(&mut self.max_right, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_right,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MinTop(value) => {
-                    insert_keyframe(&mut self.min_top, animation_id, time, *value);
+                    insert_keyframe(&mut self.min_top, animation_id, time, timing_function, *value);
                 }
 
                 Property::MaxTop(value) => {
-                    insert_keyframe(&mut self.max_top, animation_id, time, *value);
+                    insert_keyframe(&mut self.max_top, animation_id, time, timing_function, *value);
                 }
 
                 Property::MinBottom(value) => {
-                    insert_keyframe(&mut self.min_bottom, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_bottom,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MaxBottom(value) => {
-                    insert_keyframe(&mut self.max_bottom, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_bottom,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::UnderlineColor(value) => {
-                    insert_keyframe(&mut self.underline_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.underline_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::Fill(value) => {
-                    insert_keyframe(&mut self.fill, animation_id, time, *value);
+                    insert_keyframe(&mut self.fill, animation_id, time, timing_function, *value);
                 }
 
                 _ => {}
@@ -690,7 +924,9 @@ impl Style {
     pub(crate) fn add_animation(&mut self, animation: AnimationBuilder) -> Animation {
         let animation_id = self.animation_manager.create();
         for keyframe in animation.keyframes.iter() {
-            self.add_keyframe(animation_id, keyframe.time, &keyframe.properties);
+            let timing_function =
+                keyframe.timing_function.map(Into::into).unwrap_or_else(TimingFunction::linear);
+            self.add_keyframe(animation_id, keyframe.time, timing_function, &keyframe.properties);
         }
 
         animation_id
@@ -714,6 +950,21 @@ impl Style {
         for (entity, animation, duration, delay) in pending_animations {
             self.play_animation(entity, animation, start_time + delay, duration, delay)
         }
+
+        let pending_actions = self.pending_playback_actions.drain(..).collect::<Vec<_>>();
+
+        for (entity, animation, action) in pending_actions {
+            self.control_animation(entity, Some(animation), action, start_time);
+        }
+    }
+
+    pub(crate) fn enqueue_playback_action(
+        &mut self,
+        entity: Entity,
+        animation: Animation,
+        action: PlaybackAction,
+    ) {
+        self.pending_playback_actions.push((entity, animation, action));
     }
 
     pub(crate) fn play_animation(
@@ -753,6 +1004,7 @@ impl Style {
         self.background_size.play_animation(entity, animation, start_time, duration, delay);
 
         self.shadow.play_animation(entity, animation, start_time, duration, delay);
+        self.filter.play_animation(entity, animation, start_time, duration, delay);
 
         self.font_color.play_animation(entity, animation, start_time, duration, delay);
         self.font_size.play_animation(entity, animation, start_time, duration, delay);
@@ -791,6 +1043,158 @@ impl Style {
         self.underline_color.play_animation(entity, animation, start_time, duration, delay);
 
         self.fill.play_animation(entity, animation, start_time, duration, delay);
+
+        // Apply the playback options of the entity
+        let action = PlaybackAction::Configure {
+            iteration_count: self
+                .animation_iteration_count
+                .get(entity)
+                .copied()
+                .unwrap_or_default()
+                .count(),
+            direction: self.animation_direction.get(entity).copied().unwrap_or_default(),
+            fill_mode: self.animation_fill_mode.get(entity).copied().unwrap_or_default(),
+            paused: self.animation_play_state.get(entity).copied().unwrap_or_default()
+                == AnimationPlayState::Paused,
+        };
+
+        self.control_animation(entity, Some(animation), action, Instant::now());
+    }
+
+    /// Applies a playback action to the active animations of an entity.
+    ///
+    /// If `animation` is `None` then the action applies to any active keyframe animation of the entity.
+    pub(crate) fn control_animation(
+        &mut self,
+        entity: Entity,
+        animation: Option<Animation>,
+        action: PlaybackAction,
+        time: Instant,
+    ) {
+        self.display.control_animation(entity, animation, action, time);
+        self.opacity.control_animation(entity, animation, action, time);
+        self.clip_path.control_animation(entity, animation, action, time);
+
+        self.transform.control_animation(entity, animation, action, time);
+        self.transform_origin.control_animation(entity, animation, action, time);
+        self.translate.control_animation(entity, animation, action, time);
+        self.rotate.control_animation(entity, animation, action, time);
+        self.scale.control_animation(entity, animation, action, time);
+
+        self.border_width.control_animation(entity, animation, action, time);
+        self.border_color.control_animation(entity, animation, action, time);
+
+        self.corner_top_left_radius.control_animation(entity, animation, action, time);
+        self.corner_top_right_radius.control_animation(entity, animation, action, time);
+        self.corner_bottom_left_radius.control_animation(entity, animation, action, time);
+        self.corner_bottom_right_radius.control_animation(entity, animation, action, time);
+
+        self.outline_width.control_animation(entity, animation, action, time);
+        self.outline_color.control_animation(entity, animation, action, time);
+        self.outline_offset.control_animation(entity, animation, action, time);
+
+        self.background_color.control_animation(entity, animation, action, time);
+        self.background_image.control_animation(entity, animation, action, time);
+        self.background_size.control_animation(entity, animation, action, time);
+
+        self.shadow.control_animation(entity, animation, action, time);
+        self.filter.control_animation(entity, animation, action, time);
+
+        self.font_color.control_animation(entity, animation, action, time);
+        self.font_size.control_animation(entity, animation, action, time);
+        self.caret_color.control_animation(entity, animation, action, time);
+        self.selection_color.control_animation(entity, animation, action, time);
+
+        self.left.control_animation(entity, animation, action, time);
+        self.right.control_animation(entity, animation, action, time);
+        self.top.control_animation(entity, animation, action, time);
+        self.bottom.control_animation(entity, animation, action, time);
+
+        self.child_left.control_animation(entity, animation, action, time);
+        self.child_right.control_animation(entity, animation, action, time);
+        self.child_top.control_animation(entity, animation, action, time);
+        self.child_bottom.control_animation(entity, animation, action, time);
+        self.col_between.control_animation(entity, animation, action, time);
+        self.row_between.control_animation(entity, animation, action, time);
+
+        self.width.control_animation(entity, animation, action, time);
+        self.height.control_animation(entity, animation, action, time);
+
+        self.min_width.control_animation(entity, animation, action, time);
+        self.max_width.control_animation(entity, animation, action, time);
+        self.min_height.control_animation(entity, animation, action, time);
+        self.max_height.control_animation(entity, animation, action, time);
+
+        self.min_left.control_animation(entity, animation, action, time);
+        self.max_left.control_animation(entity, animation, action, time);
+        self.min_right.control_animation(entity, animation, action, time);
+        self.max_right.control_animation(entity, animation, action, time);
+        self.min_top.control_animation(entity, animation, action, time);
+        self.max_top.control_animation(entity, animation, action, time);
+        self.min_bottom.control_animation(entity, animation, action, time);
+        self.max_bottom.control_animation(entity, animation, action, time);
+
+        self.underline_color.control_animation(entity, animation, action, time);
+
+        self.fill.control_animation(entity, animation, action, time);
+    }
+
+    /// Returns the progress through the current iteration of an animation playing on an entity.
+    pub(crate) fn animation_progress(&self, entity: Entity, animation: Animation) -> Option<f32> {
+        let time = Instant::now();
+        self.display
+            .animation_progress(entity, animation, time)
+            .or_else(|| self.opacity.animation_progress(entity, animation, time))
+            .or_else(|| self.clip_path.animation_progress(entity, animation, time))
+            .or_else(|| self.transform.animation_progress(entity, animation, time))
+            .or_else(|| self.transform_origin.animation_progress(entity, animation, time))
+            .or_else(|| self.translate.animation_progress(entity, animation, time))
+            .or_else(|| self.rotate.animation_progress(entity, animation, time))
+            .or_else(|| self.scale.animation_progress(entity, animation, time))
+            .or_else(|| self.border_width.animation_progress(entity, animation, time))
+            .or_else(|| self.border_color.animation_progress(entity, animation, time))
+            .or_else(|| self.corner_top_left_radius.animation_progress(entity, animation, time))
+            .or_else(|| self.corner_top_right_radius.animation_progress(entity, animation, time))
+            .or_else(|| self.corner_bottom_left_radius.animation_progress(entity, animation, time))
+            .or_else(|| self.corner_bottom_right_radius.animation_progress(entity, animation, time))
+            .or_else(|| self.outline_width.animation_progress(entity, animation, time))
+            .or_else(|| self.outline_color.animation_progress(entity, animation, time))
+            .or_else(|| self.outline_offset.animation_progress(entity, animation, time))
+            .or_else(|| self.background_color.animation_progress(entity, animation, time))
+            .or_else(|| self.background_image.animation_progress(entity, animation, time))
+            .or_else(|| self.background_size.animation_progress(entity, animation, time))
+            .or_else(|| self.shadow.animation_progress(entity, animation, time))
+            .or_else(|| self.filter.animation_progress(entity, animation, time))
+            .or_else(|| self.font_color.animation_progress(entity, animation, time))
+            .or_else(|| self.font_size.animation_progress(entity, animation, time))
+            .or_else(|| self.caret_color.animation_progress(entity, animation, time))
+            .or_else(|| self.selection_color.animation_progress(entity, animation, time))
+            .or_else(|| self.left.animation_progress(entity, animation, time))
+            .or_else(|| self.right.animation_progress(entity, animation, time))
+            .or_else(|| self.top.animation_progress(entity, animation, time))
+            .or_else(|| self.bottom.animation_progress(entity, animation, time))
+            .or_else(|| self.child_left.animation_progress(entity, animation, time))
+            .or_else(|| self.child_right.animation_progress(entity, animation, time))
+            .or_else(|| self.child_top.animation_progress(entity, animation, time))
+            .or_else(|| self.child_bottom.animation_progress(entity, animation, time))
+            .or_else(|| self.col_between.animation_progress(entity, animation, time))
+            .or_else(|| self.row_between.animation_progress(entity, animation, time))
+            .or_else(|| self.width.animation_progress(entity, animation, time))
+            .or_else(|| self.height.animation_progress(entity, animation, time))
+            .or_else(|| self.min_width.animation_progress(entity, animation, time))
+            .or_else(|| self.max_width.animation_progress(entity, animation, time))
+            .or_else(|| self.min_height.animation_progress(entity, animation, time))
+            .or_else(|| self.max_height.animation_progress(entity, animation, time))
+            .or_else(|| self.min_left.animation_progress(entity, animation, time))
+            .or_else(|| self.max_left.animation_progress(entity, animation, time))
+            .or_else(|| self.min_right.animation_progress(entity, animation, time))
+            .or_else(|| self.max_right.animation_progress(entity, animation, time))
+            .or_else(|| self.min_top.animation_progress(entity, animation, time))
+            .or_else(|| self.max_top.animation_progress(entity, animation, time))
+            .or_else(|| self.min_bottom.animation_progress(entity, animation, time))
+            .or_else(|| self.max_bottom.animation_progress(entity, animation, time))
+            .or_else(|| self.underline_color.animation_progress(entity, animation, time))
+            .or_else(|| self.fill.animation_progress(entity, animation, time))
     }
 
     pub(crate) fn is_animating(&self, entity: Entity, animation: Animation) -> bool {
@@ -815,6 +1219,7 @@ impl Style {
             | self.background_image.has_active_animation(entity, animation)
             | self.background_size.has_active_animation(entity, animation)
             | self.shadow.has_active_animation(entity, animation)
+            | self.filter.has_active_animation(entity, animation)
             | self.font_color.has_active_animation(entity, animation)
             | self.font_size.has_active_animation(entity, animation)
             | self.caret_color.has_active_animation(entity, animation)
@@ -847,6 +1252,129 @@ impl Style {
             | self.fill.has_active_animation(entity, animation)
     }
 
+    /// Returns true if the entity is linked to an animation or transition which has not yet finished.
+    pub(crate) fn has_active_animations(&self, entity: Entity) -> bool {
+        self.display.is_animating(entity)
+            | self.opacity.is_animating(entity)
+            | self.clip_path.is_animating(entity)
+            | self.transform.is_animating(entity)
+            | self.transform_origin.is_animating(entity)
+            | self.translate.is_animating(entity)
+            | self.rotate.is_animating(entity)
+            | self.scale.is_animating(entity)
+            | self.border_width.is_animating(entity)
+            | self.border_color.is_animating(entity)
+            | self.corner_top_left_radius.is_animating(entity)
+            | self.corner_top_right_radius.is_animating(entity)
+            | self.corner_bottom_left_radius.is_animating(entity)
+            | self.corner_bottom_right_radius.is_animating(entity)
+            | self.outline_width.is_animating(entity)
+            | self.outline_color.is_animating(entity)
+            | self.outline_offset.is_animating(entity)
+            | self.background_color.is_animating(entity)
+            | self.background_image.is_animating(entity)
+            | self.background_size.is_animating(entity)
+            | self.shadow.is_animating(entity)
+            | self.filter.is_animating(entity)
+            | self.font_color.is_animating(entity)
+            | self.font_size.is_animating(entity)
+            | self.caret_color.is_animating(entity)
+            | self.selection_color.is_animating(entity)
+            | self.left.is_animating(entity)
+            | self.right.is_animating(entity)
+            | self.top.is_animating(entity)
+            | self.bottom.is_animating(entity)
+            | self.child_left.is_animating(entity)
+            | self.child_right.is_animating(entity)
+            | self.child_top.is_animating(entity)
+            | self.child_bottom.is_animating(entity)
+            | self.col_between.is_animating(entity)
+            | self.row_between.is_animating(entity)
+            | self.width.is_animating(entity)
+            | self.height.is_animating(entity)
+            | self.min_width.is_animating(entity)
+            | self.max_width.is_animating(entity)
+            | self.min_height.is_animating(entity)
+            | self.max_height.is_animating(entity)
+            | self.min_left.is_animating(entity)
+            | self.max_left.is_animating(entity)
+            | self.min_right.is_animating(entity)
+            | self.max_right.is_animating(entity)
+            | self.min_top.is_animating(entity)
+            | self.max_top.is_animating(entity)
+            | self.min_bottom.is_animating(entity)
+            | self.max_bottom.is_animating(entity)
+            | self.underline_color.is_animating(entity)
+            | self.fill.is_animating(entity)
+    }
+
+    /// Returns true if the entity is playing its exit animation or a transition of an `:exiting` rule which has
+    /// not yet finished.
+    pub(crate) fn has_exit_animations(
+        &self,
+        entity: Entity,
+        exit_animation: Option<Animation>,
+    ) -> bool {
+        let is_exit = |animation: Option<Animation>| {
+            animation.is_some_and(|animation| {
+                Some(animation) == exit_animation || self.exit_transitions.contains_key(&animation)
+            })
+        };
+
+        is_exit(self.display.playing_animation(entity))
+            | is_exit(self.opacity.playing_animation(entity))
+            | is_exit(self.clip_path.playing_animation(entity))
+            | is_exit(self.transform.playing_animation(entity))
+            | is_exit(self.transform_origin.playing_animation(entity))
+            | is_exit(self.translate.playing_animation(entity))
+            | is_exit(self.rotate.playing_animation(entity))
+            | is_exit(self.scale.playing_animation(entity))
+            | is_exit(self.border_width.playing_animation(entity))
+            | is_exit(self.border_color.playing_animation(entity))
+            | is_exit(self.corner_top_left_radius.playing_animation(entity))
+            | is_exit(self.corner_top_right_radius.playing_animation(entity))
+            | is_exit(self.corner_bottom_left_radius.playing_animation(entity))
+            | is_exit(self.corner_bottom_right_radius.playing_animation(entity))
+            | is_exit(self.outline_width.playing_animation(entity))
+            | is_exit(self.outline_color.playing_animation(entity))
+            | is_exit(self.outline_offset.playing_animation(entity))
+            | is_exit(self.background_color.playing_animation(entity))
+            | is_exit(self.background_image.playing_animation(entity))
+            | is_exit(self.background_size.playing_animation(entity))
+            | is_exit(self.shadow.playing_animation(entity))
+            | is_exit(self.filter.playing_animation(entity))
+            | is_exit(self.font_color.playing_animation(entity))
+            | is_exit(self.font_size.playing_animation(entity))
+            | is_exit(self.caret_color.playing_animation(entity))
+            | is_exit(self.selection_color.playing_animation(entity))
+            | is_exit(self.left.playing_animation(entity))
+            | is_exit(self.right.playing_animation(entity))
+            | is_exit(self.top.playing_animation(entity))
+            | is_exit(self.bottom.playing_animation(entity))
+            | is_exit(self.child_left.playing_animation(entity))
+            | is_exit(self.child_right.playing_animation(entity))
+            | is_exit(self.child_top.playing_animation(entity))
+            | is_exit(self.child_bottom.playing_animation(entity))
+            | is_exit(self.col_between.playing_animation(entity))
+            | is_exit(self.row_between.playing_animation(entity))
+            | is_exit(self.width.playing_animation(entity))
+            | is_exit(self.height.playing_animation(entity))
+            | is_exit(self.min_width.playing_animation(entity))
+            | is_exit(self.max_width.playing_animation(entity))
+            | is_exit(self.min_height.playing_animation(entity))
+            | is_exit(self.max_height.playing_animation(entity))
+            | is_exit(self.min_left.playing_animation(entity))
+            | is_exit(self.max_left.playing_animation(entity))
+            | is_exit(self.min_right.playing_animation(entity))
+            | is_exit(self.max_right.playing_animation(entity))
+            | is_exit(self.min_top.playing_animation(entity))
+            | is_exit(self.max_top.playing_animation(entity))
+            | is_exit(self.min_bottom.playing_animation(entity))
+            | is_exit(self.max_bottom.playing_animation(entity))
+            | is_exit(self.underline_color.playing_animation(entity))
+            | is_exit(self.fill.playing_animation(entity))
+    }
+
     pub(crate) fn parse_theme(&mut self, stylesheet: &str) {
         if let Ok(stylesheet) = StyleSheet::parse("test.css", stylesheet, ParserOptions::default())
         {
@@ -859,14 +1387,32 @@ impl Style {
 
                         let selectors = style_rule.selectors;
 
+                        let is_exit_rule = selectors.0.iter().any(|selector| {
+                            selector.iter().any(|component| {
+                                matches!(
+                                    component,
+                                    Component::NonTSPseudoClass(PseudoClass::Exiting)
+                                )
+                            })
+                        });
+
+                        if is_exit_rule {
+                            self.exit_rules.insert(rule_id);
+                        }
+
                         self.rules.insert(rule_id, selectors);
 
+                        let mut transitions = Vec::new();
+                        let mut timing_functions = Vec::new();
+
                         for property in style_rule.declarations.declarations {
                             match property {
-                                Property::Transition(transitions) => {
-                                    for transition in transitions.iter() {
-                                        self.insert_transition(rule_id, transition);
-                                    }
+                                Property::Transition(rule_transitions) => {
+                                    transitions.extend(rule_transitions);
+                                }
+
+                                Property::TransitionTimingFunction(easing_functions) => {
+                                    timing_functions = easing_functions;
                                 }
 
                                 _ => {
@@ -874,6 +1420,25 @@ impl Style {
                                 }
                             }
                         }
+
+                        // Timing functions are applied to the transitions in order, repeating if there are fewer
+                        // timing functions than transitions.
+                        if !timing_functions.is_empty() {
+                            for (index, transition) in transitions.iter_mut().enumerate() {
+                                transition.timing_function =
+                                    Some(timing_functions[index % timing_functions.len()]);
+                            }
+                        }
+
+                        for transition in transitions.iter() {
+                            let animation = self.insert_transition(rule_id, transition);
+
+                            if is_exit_rule {
+                                let duration = Self::transition_duration(transition)
+                                    + transition.delay.unwrap_or_default();
+                                self.exit_transitions.insert(animation, duration);
+                            }
+                        }
                     }
 
                     CssRule::Keyframes(keyframes_rule) => {
@@ -882,6 +1447,20 @@ impl Style {
                         let animation_id = self.animation_manager.create();
 
                         for keyframes in keyframes_rule.keyframes {
+                            // The timing function of a keyframe applies from that keyframe to the next.
+                            let timing_function = keyframes
+                                .declarations
+                                .declarations
+                                .iter()
+                                .rev()
+                                .find_map(|property| match property {
+                                    Property::AnimationTimingFunction(easing_function) => {
+                                        Some(TimingFunction::from(*easing_function))
+                                    }
+                                    _ => None,
+                                })
+                                .unwrap_or_else(TimingFunction::linear);
+
                             for selector in keyframes.selectors.iter() {
                                 let time = match selector {
                                     KeyframeSelector::From => 0.0,
@@ -894,6 +1473,7 @@ impl Style {
                                 self.add_keyframe(
                                     animation_id,
                                     time,
+                                    timing_function,
                                     &keyframes.declarations.declarations,
                                 );
                             }
@@ -908,7 +1488,7 @@ impl Style {
         }
     }
 
-    fn insert_transition(&mut self, rule_id: Rule, transition: &Transition) {
+    fn insert_transition(&mut self, rule_id: Rule, transition: &Transition) -> Animation {
         let animation = self.animation_manager.create();
         match transition.property.as_ref() {
             "display" => {
@@ -1049,6 +1629,11 @@ impl Style {
                 self.shadow.insert_transition(rule_id, animation);
             }
 
+            "filter" => {
+                self.filter.insert_animation(animation, self.add_transition(transition));
+                self.filter.insert_transition(rule_id, animation);
+            }
+
             "color" => {
                 self.font_color.insert_animation(animation, self.add_transition(transition));
                 self.font_color.insert_transition(rule_id, animation);
@@ -1201,6 +1786,8 @@ impl Style {
 
             _ => {}
         }
+
+        animation
     }
 
     fn insert_property(&mut self, rule_id: Rule, property: Property) {
@@ -1226,6 +1813,10 @@ impl Style {
             }
 
             // Filters
+            Property::Filter(filter) => {
+                self.filter.insert_rule(rule_id, filter);
+            }
+
             Property::BackdropFilter(filter) => {
                 self.backdrop_filter.insert_rule(rule_id, filter);
             }
@@ -1235,6 +1826,11 @@ impl Style {
                 self.blend_mode.insert_rule(rule_id, blend_mode);
             }
 
+            // Will Change
+            Property::WillChange(will_change) => {
+                self.will_change.insert_rule(rule_id, will_change);
+            }
+
             // Layout Type
             Property::LayoutType(layout_type) => {
                 self.layout_type.insert_rule(rule_id, layout_type);
@@ -1634,6 +2230,23 @@ impl Style {
                 self.pointer_events.insert_rule(rule_id, pointer_events);
             }
 
+            // Animation playback
+            Property::AnimationIterationCount(iteration_count) => {
+                self.animation_iteration_count.insert_rule(rule_id, iteration_count);
+            }
+
+            Property::AnimationDirection(direction) => {
+                self.animation_direction.insert_rule(rule_id, direction);
+            }
+
+            Property::AnimationFillMode(fill_mode) => {
+                self.animation_fill_mode.insert_rule(rule_id, fill_mode);
+            }
+
+            Property::AnimationPlayState(play_state) => {
+                self.animation_play_state.insert_rule(rule_id, play_state);
+            }
+
             // Unparsed. TODO: Log the error.
             Property::Unparsed(unparsed) => {
                 warn!("Unparsed: {}", unparsed.name);
@@ -1660,24 +2273,27 @@ impl Style {
     }
 
     // Helper function for generating AnimationState from a transition definition.
+    // Returns the duration of a transition, excluding its delay.
+    fn transition_duration(transition: &Transition) -> Duration {
+        let timing_function: TimingFunction =
+            transition.timing_function.map(Into::into).unwrap_or_default();
+
+        // The duration of a spring is determined by its physical parameters.
+        timing_function
+            .as_spring()
+            .map(|spring| Duration::from_secs_f32(spring.duration()))
+            .unwrap_or(transition.duration)
+    }
+
     fn add_transition<T: Default + Interpolator>(
         &self,
         transition: &Transition,
     ) -> AnimationState<T> {
-        let timing_function = transition
-            .timing_function
-            .map(|easing| match easing {
-                EasingFunction::Linear => TimingFunction::linear(),
-                EasingFunction::Ease => TimingFunction::ease(),
-                EasingFunction::EaseIn => TimingFunction::ease_in(),
-                EasingFunction::EaseOut => TimingFunction::ease_out(),
-                EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
-                EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
-            })
-            .unwrap_or_default();
+        let timing_function: TimingFunction =
+            transition.timing_function.map(Into::into).unwrap_or_default();
 
         AnimationState::new(Animation::null())
-            .with_duration(transition.duration)
+            .with_duration(Self::transition_duration(transition))
             .with_delay(transition.delay.unwrap_or_default())
             .with_keyframe(Keyframe { time: 0.0, value: Default::default(), timing_function })
             .with_keyframe(Keyframe { time: 1.0, value: Default::default(), timing_function })
@@ -1710,6 +2326,12 @@ impl Style {
         self.text_value.remove(entity);
         self.numeric_value.remove(entity);
 
+        self.exit_animation.remove(entity);
+        // Cancel any animations which haven't started playing, such as the exit animation of a view
+        // removed along with an ancestor before the animation was played.
+        self.pending_animations.retain(|(e, ..)| *e != entity);
+        self.pending_playback_actions.retain(|(e, ..)| *e != entity);
+
         // Display
         self.display.remove(entity);
         // Visibility
@@ -1724,12 +2346,16 @@ impl Style {
         self.overflowx.remove(entity);
         self.overflowy.remove(entity);
 
-        // Backdrop Filter
+        // Filters
+        self.filter.remove(entity);
         self.backdrop_filter.remove(entity);
 
         // Blend Mode
         self.blend_mode.remove(entity);
 
+        // Will Change
+        self.will_change.remove(entity);
+
         // Transform
         self.transform.remove(entity);
         self.transform_origin.remove(entity);
@@ -1795,6 +2421,11 @@ impl Style {
 
         self.pointer_events.remove(entity);
 
+        self.animation_iteration_count.remove(entity);
+        self.animation_direction.remove(entity);
+        self.animation_fill_mode.remove(entity);
+        self.animation_play_state.remove(entity);
+
         // Layout Type
         self.layout_type.remove(entity);
 
@@ -1882,12 +2513,16 @@ impl Style {
         // Clipping
         self.clip_path.clear_rules();
 
-        // Backdrop Filer
+        // Filters
+        self.filter.clear_rules();
         self.backdrop_filter.clear_rules();
 
         // Blend Mode
         self.blend_mode.clear_rules();
 
+        // Will Change
+        self.will_change.clear_rules();
+
         // Transform
         self.transform.clear_rules();
         self.transform_origin.clear_rules();
@@ -1989,8 +2624,43 @@ impl Style {
 
         self.pointer_events.clear_rules();
 
+        self.animation_iteration_count.clear_rules();
+        self.animation_direction.clear_rules();
+        self.animation_fill_mode.clear_rules();
+        self.animation_play_state.clear_rules();
+
         self.name.clear_rules();
 
         self.fill.clear_rules();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyframe_timing_functions() {
+        let mut style = Style::default();
+        style.parse_theme(
+            "@keyframes fade {
+                from { opacity: 0; animation-timing-function: steps(2, jump-none); }
+                50% { opacity: 0.5; animation-timing-function: ease-in; }
+                to { opacity: 1; }
+            }",
+        );
+
+        let animation = style.animations.get("fade").copied().unwrap();
+        let keyframes = &style.opacity.get_animation_mut(animation).unwrap().keyframes;
+        let timing_functions =
+            keyframes.iter().map(|keyframe| keyframe.timing_function).collect::<Vec<_>>();
+        assert_eq!(
+            timing_functions,
+            vec![
+                TimingFunction::steps(2, StepPosition::JumpNone),
+                TimingFunction::ease_in(),
+                TimingFunction::linear(),
+            ]
+        );
+    }
+}
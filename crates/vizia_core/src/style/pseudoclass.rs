@@ -29,6 +29,7 @@ bitflags! {
         const OPTIONAL = 1 << 18;
         const USER_VALID = 1 << 19;
         const USER_INVALID = 1 << 20;
+        const EXITING = 1 << 21;
     }
 }
 
@@ -62,6 +63,9 @@ impl std::fmt::Display for PseudoClassFlags {
         if self.contains(PseudoClassFlags::FOCUS_VISIBLE) {
             write!(f, ":focus-visible")?;
         }
+        if self.contains(PseudoClassFlags::EXITING) {
+            write!(f, ":exiting")?;
+        }
 
         Ok(())
     }
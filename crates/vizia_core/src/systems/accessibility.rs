@@ -65,6 +65,33 @@ pub(crate) fn accessibility_system(cx: &mut Context) {
     }
 }
 
+// The accessibility state which the checked state of a view maps to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CheckedState {
+    Toggled,
+    Selected,
+    None,
+}
+
+pub(crate) fn checked_state(role: Role) -> CheckedState {
+    match role {
+        Role::Tab
+        | Role::ListBoxOption
+        | Role::ListItem
+        | Role::MenuListOption
+        | Role::TreeItem
+        | Role::Row
+        | Role::Cell
+        | Role::GridCell => CheckedState::Selected,
+
+        // Views with these roles use the checked state to show that their popup is open, which is
+        // exposed as the expanded state by the view instead.
+        Role::MenuItem | Role::ComboBox | Role::Menu => CheckedState::None,
+
+        _ => CheckedState::Toggled,
+    }
+}
+
 pub(crate) fn get_access_node(
     cx: &mut AccessContext,
     views: &mut HashMap<Entity, Box<dyn ViewHandler>>,
@@ -152,10 +179,18 @@ pub(crate) fn get_access_node(
             .get(entity)
             .map(|pseudoclass| pseudoclass.contains(PseudoClassFlags::CHECKED))
         {
-            if checked {
-                node_builder.set_toggled(Toggled::True);
-            } else {
-                node_builder.set_toggled(Toggled::False);
+            match checked_state(node_builder.role()) {
+                CheckedState::Toggled => {
+                    if checked {
+                        node_builder.set_toggled(Toggled::True);
+                    } else {
+                        node_builder.set_toggled(Toggled::False);
+                    }
+                }
+
+                CheckedState::Selected => node_builder.set_selected(checked),
+
+                CheckedState::None => {}
             }
         }
     }
@@ -185,3 +220,327 @@ pub(crate) fn get_access_node(
 
     Some(node)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+    use accesskit::{ActionRequest, Node};
+
+    #[derive(Lens)]
+    struct TestData {
+        flag: bool,
+        value: f32,
+        rating: u32,
+        list: Vec<String>,
+        selected: usize,
+    }
+
+    enum TestEvent {
+        SetValue(f32),
+    }
+
+    impl Model for TestData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|test_event, _| match test_event {
+                TestEvent::SetValue(value) => self.value = *value,
+            });
+        }
+    }
+
+    fn context() -> Context {
+        let mut cx = Context::new();
+        TestData {
+            flag: true,
+            value: 0.5,
+            rating: 3,
+            list: vec![String::from("One"), String::from("Two")],
+            selected: 1,
+        }
+        .build(&mut cx);
+        cx
+    }
+
+    // Updates the accessibility nodes of every view and returns the nodes of the produced tree updates.
+    fn access_nodes(cx: &mut Context) -> HashMap<NodeId, Node> {
+        for entity in cx.tree.into_iter() {
+            cx.style.needs_access_update(entity);
+        }
+
+        accessibility_system(cx);
+
+        cx.tree_updates.drain(..).flatten().flat_map(|update| update.nodes).collect()
+    }
+
+    fn access_node(cx: &mut Context, entity: Entity) -> Node {
+        access_nodes(cx).remove(&entity.accesskit_id()).expect("missing accessibility node")
+    }
+
+    fn nodes_with_role(cx: &mut Context, role: Role) -> Vec<Node> {
+        let mut nodes = access_nodes(cx).into_iter().collect::<Vec<_>>();
+        nodes.sort_by_key(|(node_id, _)| node_id.0);
+        nodes.into_iter().map(|(_, node)| node).filter(|node| node.role() == role).collect()
+    }
+
+    fn request_action(cx: &mut Context, entity: Entity, action: Action) {
+        cx.emit_custom(
+            Event::new(WindowEvent::ActionRequest(ActionRequest {
+                action,
+                target: entity.accesskit_id(),
+                data: None,
+            }))
+            .direct(entity),
+        );
+
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    #[test]
+    fn checkbox_is_toggled() {
+        let mut cx = context();
+        let checkbox = Checkbox::new(&mut cx, TestData::flag).entity();
+        let unchecked = Checkbox::new(&mut cx, TestData::flag.map(|flag| !flag)).entity();
+
+        let node = access_node(&mut cx, checkbox);
+        assert_eq!(node.role(), Role::CheckBox);
+        assert_eq!(node.toggled(), Some(Toggled::True));
+        assert_eq!(node.default_action_verb(), Some(DefaultActionVerb::Click));
+        assert!(node.supports_action(Action::Focus));
+
+        assert_eq!(access_node(&mut cx, unchecked).toggled(), Some(Toggled::False));
+    }
+
+    #[test]
+    fn intermediate_checkbox_is_mixed() {
+        let mut cx = context();
+        let checkbox =
+            Checkbox::intermediate(&mut cx, TestData::flag.map(|flag| !flag), TestData::flag)
+                .entity();
+
+        let node = access_node(&mut cx, checkbox);
+        assert_eq!(node.role(), Role::CheckBox);
+        assert_eq!(node.toggled(), Some(Toggled::Mixed));
+    }
+
+    #[test]
+    fn switch_and_radio_button_are_toggled() {
+        let mut cx = context();
+        let switch = Switch::new(&mut cx, TestData::flag).entity();
+        let radio = RadioButton::new(&mut cx, TestData::flag.map(|flag| !flag)).entity();
+
+        let node = access_node(&mut cx, switch);
+        assert_eq!(node.role(), Role::Switch);
+        assert_eq!(node.toggled(), Some(Toggled::True));
+
+        let node = access_node(&mut cx, radio);
+        assert_eq!(node.role(), Role::RadioButton);
+        assert_eq!(node.toggled(), Some(Toggled::False));
+    }
+
+    #[test]
+    fn disabled_state() {
+        let mut cx = context();
+        let button = Button::new(&mut cx, |cx| Label::new(cx, "Press")).disabled(true).entity();
+
+        assert!(access_node(&mut cx, button).is_disabled());
+    }
+
+    #[test]
+    fn knob_exposes_range_and_step() {
+        let mut cx = context();
+        let knob = Knob::new(&mut cx, 0.0, TestData::value, false).entity();
+
+        let node = access_node(&mut cx, knob);
+        assert_eq!(node.role(), Role::Slider);
+        assert_eq!(node.numeric_value(), Some(0.5));
+        assert_eq!(node.min_numeric_value(), Some(0.0));
+        assert_eq!(node.max_numeric_value(), Some(1.0));
+        assert!(node.numeric_value_step().is_some());
+        assert!(node.supports_action(Action::Increment));
+        assert!(node.supports_action(Action::Decrement));
+    }
+
+    #[test]
+    fn knob_increment_action() {
+        let mut cx = context();
+        let knob = Knob::new(&mut cx, 0.0, TestData::value, false)
+            .on_changing(|cx, value| cx.emit(TestEvent::SetValue(value)))
+            .entity();
+
+        request_action(&mut cx, knob, Action::Increment);
+
+        let value = access_node(&mut cx, knob).numeric_value().unwrap();
+        assert!((value - 0.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn spinbox_exposes_actions() {
+        let mut cx = context();
+        let spinbox = Spinbox::new(&mut cx, TestData::value)
+            .on_increment(|_| {})
+            .on_decrement(|_| {})
+            .entity();
+
+        let node = access_node(&mut cx, spinbox);
+        assert_eq!(node.role(), Role::SpinButton);
+        assert_eq!(node.value(), Some("0.5"));
+        assert!(node.supports_action(Action::Increment));
+        assert!(node.supports_action(Action::Decrement));
+    }
+
+    #[test]
+    fn rating_exposes_range() {
+        let mut cx = context();
+        let rating = Rating::new(&mut cx, 5, TestData::rating).entity();
+
+        let node = access_node(&mut cx, rating);
+        assert_eq!(node.role(), Role::RadioGroup);
+        assert_eq!(node.numeric_value(), Some(3.0));
+        assert_eq!(node.min_numeric_value(), Some(0.0));
+        assert_eq!(node.max_numeric_value(), Some(5.0));
+        assert_eq!(node.numeric_value_step(), Some(1.0));
+        assert!(node.supports_action(Action::Increment));
+    }
+
+    #[test]
+    fn dropdown_expands() {
+        let mut cx = context();
+        let dropdown = Dropdown::new(
+            &mut cx,
+            |cx| {
+                Label::new(cx, "Trigger");
+            },
+            |cx| {
+                Label::new(cx, "Content");
+            },
+        )
+        .entity();
+
+        let node = access_node(&mut cx, dropdown);
+        assert_eq!(node.is_expanded(), Some(false));
+        assert!(node.supports_action(Action::Expand));
+        assert!(!node.supports_action(Action::Collapse));
+
+        request_action(&mut cx, dropdown, Action::Expand);
+
+        let node = access_node(&mut cx, dropdown);
+        assert_eq!(node.is_expanded(), Some(true));
+        assert!(node.supports_action(Action::Collapse));
+    }
+
+    #[test]
+    fn combobox_expands() {
+        let mut cx = context();
+        let combobox = ComboBox::new(&mut cx, TestData::list, TestData::selected).entity();
+
+        let node = access_node(&mut cx, combobox);
+        assert_eq!(node.role(), Role::ComboBox);
+        assert_eq!(node.value(), Some("Two"));
+        assert_eq!(node.is_expanded(), Some(false));
+
+        request_action(&mut cx, combobox, Action::Expand);
+
+        assert_eq!(access_node(&mut cx, combobox).is_expanded(), Some(true));
+
+        let options = nodes_with_role(&mut cx, Role::ListBoxOption);
+        assert_eq!(options.len(), 2);
+        assert_eq!(options[0].is_selected(), Some(false));
+        assert_eq!(options[1].is_selected(), Some(true));
+        assert_eq!(options[1].toggled(), None);
+    }
+
+    #[test]
+    fn list_items_are_selected() {
+        let mut cx = context();
+        let list = List::new(&mut cx, TestData::list, |cx, _, item| {
+            Label::new(cx, item);
+        })
+        .selected(TestData::selected.map(|selected| vec![*selected]))
+        .entity();
+
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(&mut cx) {}
+
+        assert_eq!(access_node(&mut cx, list).role(), Role::List);
+
+        let items = nodes_with_role(&mut cx, Role::ListItem);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].is_selected(), Some(false));
+        assert_eq!(items[1].is_selected(), Some(true));
+        assert_eq!(items[1].toggled(), None);
+    }
+
+    #[test]
+    fn list_without_selection_keeps_item_roles() {
+        let mut cx = context();
+        List::new(&mut cx, TestData::list, |cx, index, item| {
+            Label::new(cx, item).role(Role::ListBoxOption).checked(index == 0);
+        });
+
+        let options = nodes_with_role(&mut cx, Role::ListBoxOption);
+        assert_eq!(options.len(), 2);
+        assert_eq!(options[0].is_selected(), Some(true));
+        assert_eq!(options[1].is_selected(), Some(false));
+        assert!(nodes_with_role(&mut cx, Role::ListItem).is_empty());
+    }
+
+    #[test]
+    fn tabview_selects_tab() {
+        let mut cx = context();
+        TabView::new(&mut cx, TestData::list, |_, item| {
+            TabPair::new(
+                move |cx| {
+                    Label::new(cx, item);
+                },
+                |cx| {
+                    Element::new(cx);
+                },
+            )
+        })
+        .with_selected(1usize);
+
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(&mut cx) {}
+
+        assert_eq!(nodes_with_role(&mut cx, Role::TabList).len(), 1);
+        assert_eq!(nodes_with_role(&mut cx, Role::TabPanel).len(), 1);
+
+        let tabs = nodes_with_role(&mut cx, Role::Tab);
+        assert_eq!(tabs.len(), 2);
+        assert_eq!(tabs[0].is_selected(), Some(false));
+        assert_eq!(tabs[1].is_selected(), Some(true));
+        assert_eq!(tabs[1].default_action_verb(), Some(DefaultActionVerb::Click));
+    }
+
+    #[test]
+    fn submenu_expands() {
+        let mut cx = context();
+        let menubar = MenuBar::new(&mut cx, |cx| {
+            Submenu::new(
+                cx,
+                |cx| Label::new(cx, "File"),
+                |cx| {
+                    MenuButton::new(cx, |_| {}, |cx| Label::new(cx, "Open"));
+                },
+            );
+        })
+        .entity();
+        let submenu = menubar.first_child(&cx.tree).unwrap();
+
+        assert_eq!(access_node(&mut cx, menubar).role(), Role::MenuBar);
+
+        let node = access_node(&mut cx, submenu);
+        assert_eq!(node.role(), Role::MenuItem);
+        assert_eq!(node.is_expanded(), Some(false));
+        assert_eq!(node.toggled(), None);
+
+        request_action(&mut cx, submenu, Action::Expand);
+
+        assert_eq!(access_node(&mut cx, submenu).is_expanded(), Some(true));
+
+        let items = nodes_with_role(&mut cx, Role::MenuItem);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[1].default_action_verb(), Some(DefaultActionVerb::Click));
+    }
+}
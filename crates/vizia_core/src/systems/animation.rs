@@ -1,3 +1,4 @@
+use crate::animation::AnimationEventKind;
 use crate::prelude::*;
 
 pub(crate) fn animation_system(cx: &mut Context) -> bool {
@@ -7,80 +8,87 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
 
     let time = Instant::now();
 
+    let mut events = Vec::new();
+
     let mut redraw_entities = Vec::new();
+    let mut recomposite_entities = Vec::new();
     let mut reflow_entities = Vec::new();
     let mut relayout_entities = Vec::new();
 
-    // Properties which affect rendering
+    // Properties which affect compositing
     // Opacity
-    redraw_entities.extend(cx.style.opacity.tick(time));
+    recomposite_entities.extend(cx.style.opacity.tick(time, &mut events));
+    // Transform
+    recomposite_entities.extend(cx.style.transform.tick(time, &mut events));
+    recomposite_entities.extend(cx.style.transform_origin.tick(time, &mut events));
+    recomposite_entities.extend(cx.style.translate.tick(time, &mut events));
+    recomposite_entities.extend(cx.style.rotate.tick(time, &mut events));
+    recomposite_entities.extend(cx.style.scale.tick(time, &mut events));
+    // Filter
+    recomposite_entities.extend(cx.style.filter.tick(time, &mut events));
+
+    // Properties which affect rendering
     // Corner Colour
-    redraw_entities.extend(cx.style.border_color.tick(time));
+    redraw_entities.extend(cx.style.border_color.tick(time, &mut events));
     // Corner Radius
-    redraw_entities.extend(cx.style.corner_top_left_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_top_right_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_bottom_left_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_bottom_right_radius.tick(time));
+    redraw_entities.extend(cx.style.corner_top_left_radius.tick(time, &mut events));
+    redraw_entities.extend(cx.style.corner_top_right_radius.tick(time, &mut events));
+    redraw_entities.extend(cx.style.corner_bottom_left_radius.tick(time, &mut events));
+    redraw_entities.extend(cx.style.corner_bottom_right_radius.tick(time, &mut events));
     // Background
-    redraw_entities.extend(cx.style.background_color.tick(time));
-    redraw_entities.extend(cx.style.background_image.tick(time));
-    redraw_entities.extend(cx.style.background_size.tick(time));
+    redraw_entities.extend(cx.style.background_color.tick(time, &mut events));
+    redraw_entities.extend(cx.style.background_image.tick(time, &mut events));
+    redraw_entities.extend(cx.style.background_size.tick(time, &mut events));
     // Box Shadow
-    redraw_entities.extend(cx.style.shadow.tick(time));
-    // Transform
-    redraw_entities.extend(cx.style.transform.tick(time));
-    redraw_entities.extend(cx.style.transform_origin.tick(time));
-    redraw_entities.extend(cx.style.translate.tick(time));
-    redraw_entities.extend(cx.style.rotate.tick(time));
-    redraw_entities.extend(cx.style.scale.tick(time));
+    redraw_entities.extend(cx.style.shadow.tick(time, &mut events));
     // Outline
-    redraw_entities.extend(cx.style.outline_color.tick(time));
-    redraw_entities.extend(cx.style.outline_offset.tick(time));
-    redraw_entities.extend(cx.style.outline_width.tick(time));
+    redraw_entities.extend(cx.style.outline_color.tick(time, &mut events));
+    redraw_entities.extend(cx.style.outline_offset.tick(time, &mut events));
+    redraw_entities.extend(cx.style.outline_width.tick(time, &mut events));
     // Clip Path
-    redraw_entities.extend(cx.style.clip_path.tick(time));
+    redraw_entities.extend(cx.style.clip_path.tick(time, &mut events));
 
-    redraw_entities.extend(cx.style.fill.tick(time));
+    redraw_entities.extend(cx.style.fill.tick(time, &mut events));
 
     // Font Color
-    reflow_entities.extend(cx.style.font_color.tick(time));
+    reflow_entities.extend(cx.style.font_color.tick(time, &mut events));
     // Font Size
-    reflow_entities.extend(cx.style.font_size.tick(time));
+    reflow_entities.extend(cx.style.font_size.tick(time, &mut events));
 
     // Properties which affect layout
-    relayout_entities.extend(cx.style.display.tick(time));
+    relayout_entities.extend(cx.style.display.tick(time, &mut events));
     // Border Width
-    relayout_entities.extend(cx.style.border_width.tick(time));
+    relayout_entities.extend(cx.style.border_width.tick(time, &mut events));
     // Space
-    relayout_entities.extend(cx.style.left.tick(time));
-    relayout_entities.extend(cx.style.right.tick(time));
-    relayout_entities.extend(cx.style.top.tick(time));
-    relayout_entities.extend(cx.style.bottom.tick(time));
+    relayout_entities.extend(cx.style.left.tick(time, &mut events));
+    relayout_entities.extend(cx.style.right.tick(time, &mut events));
+    relayout_entities.extend(cx.style.top.tick(time, &mut events));
+    relayout_entities.extend(cx.style.bottom.tick(time, &mut events));
     // Size
-    relayout_entities.extend(cx.style.width.tick(time));
-    relayout_entities.extend(cx.style.height.tick(time));
+    relayout_entities.extend(cx.style.width.tick(time, &mut events));
+    relayout_entities.extend(cx.style.height.tick(time, &mut events));
     // Min/Max Size
-    relayout_entities.extend(cx.style.max_width.tick(time));
-    relayout_entities.extend(cx.style.max_height.tick(time));
-    relayout_entities.extend(cx.style.min_width.tick(time));
-    relayout_entities.extend(cx.style.min_height.tick(time));
+    relayout_entities.extend(cx.style.max_width.tick(time, &mut events));
+    relayout_entities.extend(cx.style.max_height.tick(time, &mut events));
+    relayout_entities.extend(cx.style.min_width.tick(time, &mut events));
+    relayout_entities.extend(cx.style.min_height.tick(time, &mut events));
     // Min/Max Space
-    relayout_entities.extend(cx.style.min_left.tick(time));
-    relayout_entities.extend(cx.style.max_left.tick(time));
-    relayout_entities.extend(cx.style.min_right.tick(time));
-    relayout_entities.extend(cx.style.max_right.tick(time));
-    relayout_entities.extend(cx.style.min_top.tick(time));
-    relayout_entities.extend(cx.style.max_top.tick(time));
-    relayout_entities.extend(cx.style.min_bottom.tick(time));
-    relayout_entities.extend(cx.style.max_bottom.tick(time));
+    relayout_entities.extend(cx.style.min_left.tick(time, &mut events));
+    relayout_entities.extend(cx.style.max_left.tick(time, &mut events));
+    relayout_entities.extend(cx.style.min_right.tick(time, &mut events));
+    relayout_entities.extend(cx.style.max_right.tick(time, &mut events));
+    relayout_entities.extend(cx.style.min_top.tick(time, &mut events));
+    relayout_entities.extend(cx.style.max_top.tick(time, &mut events));
+    relayout_entities.extend(cx.style.min_bottom.tick(time, &mut events));
+    relayout_entities.extend(cx.style.max_bottom.tick(time, &mut events));
     // Row/Col Between
-    relayout_entities.extend(cx.style.row_between.tick(time));
-    relayout_entities.extend(cx.style.col_between.tick(time));
+    relayout_entities.extend(cx.style.row_between.tick(time, &mut events));
+    relayout_entities.extend(cx.style.col_between.tick(time, &mut events));
     // Child Space
-    relayout_entities.extend(cx.style.child_left.tick(time));
-    relayout_entities.extend(cx.style.child_right.tick(time));
-    relayout_entities.extend(cx.style.child_top.tick(time));
-    relayout_entities.extend(cx.style.child_bottom.tick(time));
+    relayout_entities.extend(cx.style.child_left.tick(time, &mut events));
+    relayout_entities.extend(cx.style.child_right.tick(time, &mut events));
+    relayout_entities.extend(cx.style.child_top.tick(time, &mut events));
+    relayout_entities.extend(cx.style.child_bottom.tick(time, &mut events));
 
     if !relayout_entities.is_empty() {
         cx.style.system_flags.set(SystemFlags::RELAYOUT, true);
@@ -90,9 +98,36 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
         cx.needs_redraw(*entity);
     }
 
+    for entity in recomposite_entities.iter() {
+        cx.needs_recomposite(*entity);
+    }
+
     for entity in reflow_entities.iter() {
         cx.style.text_construction.insert(*entity).unwrap();
     }
 
-    !redraw_entities.is_empty() | !relayout_entities.is_empty() | !reflow_entities.is_empty()
+    // An animation of several properties reports the same event for each property.
+    let mut emitted = Vec::with_capacity(events.len());
+    for event in events {
+        if !emitted.contains(&event) {
+            emitted.push(event);
+        }
+    }
+
+    for (entity, animation, kind) in emitted {
+        let event = match kind {
+            AnimationEventKind::Start => WindowEvent::AnimationStart(animation),
+            AnimationEventKind::Iteration => WindowEvent::AnimationIteration(animation),
+            AnimationEventKind::End => WindowEvent::AnimationEnd(animation),
+        };
+
+        cx.emit_custom(
+            Event::new(event).target(entity).origin(entity).propagate(Propagation::Direct),
+        );
+    }
+
+    !redraw_entities.is_empty()
+        | !recomposite_entities.is_empty()
+        | !relayout_entities.is_empty()
+        | !reflow_entities.is_empty()
 }
@@ -1,13 +1,45 @@
 use crate::{animation::Interpolator, cache::CachedData, prelude::*};
+use hashbrown::HashSet;
 use morphorm::Node;
 use skia_safe::{
-    canvas::SaveLayerRec, ClipOp, ImageFilter, Matrix, Paint, Rect, SamplingOptions, Surface,
+    canvas::SaveLayerRec, color_filters, image_filters, ClipOp, FilterMode, IRect, Image,
+    ImageFilter, ImageInfo, Matrix, MipmapMode, Paint, Rect, SamplingOptions, Surface,
 };
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 use vizia_storage::{DrawChildIterator, LayoutTreeIterator};
 use vizia_style::BlendMode;
 
+/// The maximum width or height, in physical pixels, of a render layer.
+///
+/// Views with larger draw bounds are drawn directly to the window.
+const MAX_RENDER_LAYER_SIZE: i32 = 8192;
+
+/// The cached contents of a view which sets the `will-change` property.
+///
+/// The view and its descendants are rendered into an offscreen image in the local coordinate space of
+/// the view, so changes to the transform or opacity of the view only require the image to be composited
+/// again. The layer is rendered again when the contents of the subtree change.
+pub(crate) struct RenderLayer {
+    image: Image,
+    // The bounds of the image in the local coordinate space of the view.
+    rect: IRect,
+    // The inherited visibility the layer was rendered with.
+    visible: bool,
+    dirty: bool,
+}
+
+impl RenderLayer {
+    /// Marks the contents of the layer as stale so that it is rendered again before it is next composited.
+    pub(crate) fn invalidate(&mut self) {
+        self.dirty = true;
+    }
+
+    fn is_valid(&self, rect: IRect, visible: bool) -> bool {
+        !self.dirty && self.rect == rect && self.visible == visible
+    }
+}
+
 pub(crate) fn transform_system(cx: &mut Context) {
     let iter = LayoutTreeIterator::full(&cx.tree);
 
@@ -157,12 +189,15 @@ pub(crate) fn draw_system(
 
     let mut dirty_rect = std::mem::take(&mut window.dirty_rect);
     let redraw_list = std::mem::take(&mut window.redraw_list);
+    let recomposite_list = std::mem::take(&mut window.recomposite_list);
 
-    if redraw_list.is_empty() {
+    if redraw_list.is_empty() && recomposite_list.is_empty() {
         return false;
     }
 
-    for &entity in &redraw_list {
+    invalidate_render_layers(cx, &redraw_list, &recomposite_list);
+
+    for &entity in redraw_list.union(&recomposite_list) {
         // Skip binding views
         if cx.tree.is_ignored(entity) {
             continue;
@@ -229,9 +264,11 @@ pub(crate) fn draw_system(
                 text_context: &mut cx.text_context,
                 modifiers: &cx.modifiers,
                 mouse: &cx.mouse,
+                render_layers: &mut cx.render_layers,
             },
             &dirty_rect,
             canvas,
+            &Matrix::new_identity(),
             zentity.index,
             &mut queue,
             zentity.visible,
@@ -256,10 +293,41 @@ pub(crate) fn draw_system(
     true
 }
 
+// Drops the render layers of views which no longer set `will-change` and invalidates the render layers
+// containing views which need to be redrawn. A view which only needs to be composited again can reuse its
+// own layer but invalidates those of its ancestors.
+fn invalidate_render_layers(
+    cx: &mut Context,
+    redraw_list: &HashSet<Entity>,
+    recomposite_list: &HashSet<Entity>,
+) {
+    let style = &cx.style;
+    cx.render_layers.retain(|entity, _| {
+        style.will_change.get(*entity).is_some_and(|will_change| will_change.creates_layer())
+    });
+
+    for &entity in redraw_list {
+        for ancestor in entity.parent_iter(&cx.tree) {
+            if let Some(render_layer) = cx.render_layers.get_mut(&ancestor) {
+                render_layer.invalidate();
+            }
+        }
+    }
+
+    for &entity in recomposite_list {
+        for ancestor in entity.parent_iter(&cx.tree).skip(1) {
+            if let Some(render_layer) = cx.render_layers.get_mut(&ancestor) {
+                render_layer.invalidate();
+            }
+        }
+    }
+}
+
 fn draw_entity(
     cx: &mut DrawContext,
     dirty_rect: &Option<BoundingBox>,
     canvas: &Canvas,
+    base: &Matrix,
     current_z: i32,
     queue: &mut BinaryHeap<ZEntity>,
     visible: bool,
@@ -278,38 +346,65 @@ fn draw_entity(
         return;
     }
 
-    let backdrop_filter = cx.backdrop_filter();
+    let filter = cx.filter().and_then(|filters| image_filter(filters, cx.scale_factor(), None));
+    let backdrop_filter = cx.backdrop_filter().and_then(|backdrop_filter| {
+        let rect: Rect = cx.bounds().into();
+        let crop = ImageFilter::crop(rect, None, None);
+        image_filter(std::slice::from_ref(backdrop_filter), cx.scale_factor(), crop)
+    });
     let blend_mode = cx.style.blend_mode.get(current).copied().unwrap_or_default();
 
     canvas.save();
-    let layer_count =
-        if cx.opacity() != 1.0 || backdrop_filter.is_some() || blend_mode != BlendMode::Normal {
-            let mut paint = Paint::default();
-            paint.set_alpha_f(cx.opacity());
-            paint.set_blend_mode(blend_mode.into());
-
-            let rect: Rect = cx.bounds().into();
-            let mut filter = ImageFilter::crop(rect, None, None).unwrap();
-
-            let slr = if let Some(backdrop_filter) = backdrop_filter {
-                match backdrop_filter {
-                    Filter::Blur(radius) => {
-                        let sigma = radius.to_px().unwrap() * cx.scale_factor() / 2.0;
-                        filter = filter.blur(None, (sigma, sigma), None).unwrap();
-                        SaveLayerRec::default().paint(&paint).backdrop(&filter)
-                    }
-                }
-            } else {
-                SaveLayerRec::default().paint(&paint)
-            };
+    let layer_count = if cx.opacity() != 1.0
+        || filter.is_some()
+        || backdrop_filter.is_some()
+        || blend_mode != BlendMode::Normal
+    {
+        let mut paint = Paint::default();
+        paint.set_alpha_f(cx.opacity());
+        paint.set_blend_mode(blend_mode.into());
+        paint.set_image_filter(filter);
 
-            Some(canvas.save_layer(&slr))
+        let slr = if let Some(backdrop_filter) = &backdrop_filter {
+            SaveLayerRec::default().paint(&paint).backdrop(backdrop_filter)
         } else {
-            None
+            SaveLayerRec::default().paint(&paint)
         };
 
+        Some(canvas.save_layer(&slr))
+    } else {
+        None
+    };
+
+    let creates_layer =
+        cx.style.will_change.get(current).is_some_and(|will_change| will_change.creates_layer());
+
+    if !creates_layer || !draw_render_layer(cx, canvas, base, current_z, visible) {
+        draw_contents(cx, dirty_rect, canvas, base, current_z, queue, visible);
+    }
+
+    if let Some(count) = layer_count {
+        canvas.restore_to_count(count);
+    }
+    canvas.restore();
+    cx.current = current;
+}
+
+// Draws the current view and its descendants.
+fn draw_contents(
+    cx: &mut DrawContext,
+    dirty_rect: &Option<BoundingBox>,
+    canvas: &Canvas,
+    base: &Matrix,
+    current_z: i32,
+    queue: &mut BinaryHeap<ZEntity>,
+    visible: bool,
+) {
+    let current = cx.current;
+
     if let Some(transform) = cx.cache.transform.get(current) {
-        canvas.set_matrix(&(transform.into()));
+        let matrix = *base * *transform;
+        canvas.set_matrix(&(&matrix).into());
     }
 
     if let Some(clip_path) = cx.clip_path() {
@@ -322,16 +417,15 @@ fn draw_entity(
         (_, Some(Visibility::Visible)) => true,
     };
 
-    // Draw the view
-    if is_visible {
-        if let Some(dirty_rect) = dirty_rect {
-            let bounds = draw_bounds(cx.style, cx.cache, cx.tree, current);
-            if bounds.intersects(dirty_rect) {
-                if let Some(view) = cx.views.remove(&current) {
-                    view.draw(cx, canvas);
-                    cx.views.insert(current, view);
-                }
-            }
+    // Draw the view. Views within a render layer are always drawn, as there is no dirty rect.
+    if is_visible
+        && dirty_rect.as_ref().map_or(true, |dirty_rect| {
+            draw_bounds(cx.style, cx.cache, cx.tree, current).intersects(dirty_rect)
+        })
+    {
+        if let Some(view) = cx.views.remove(&current) {
+            view.draw(cx, canvas);
+            cx.views.insert(current, view);
         }
     }
 
@@ -341,16 +435,100 @@ fn draw_entity(
     for child in child_iter {
         cx.current = child;
         // TODO: Skip views with zero-sized bounding boxes here? Or let user decide if they want to skip?
-        draw_entity(cx, dirty_rect, canvas, current_z, queue, is_visible);
+        draw_entity(cx, dirty_rect, canvas, base, current_z, queue, is_visible);
     }
 
-    if let Some(count) = layer_count {
-        canvas.restore_to_count(count);
-    }
-    canvas.restore();
     cx.current = current;
 }
 
+// Composites the render layer of the current view, rendering its contents again if the layer is stale.
+// Returns false if the view cannot be rendered into a layer and should be drawn directly.
+fn draw_render_layer(
+    cx: &mut DrawContext,
+    canvas: &Canvas,
+    base: &Matrix,
+    current_z: i32,
+    visible: bool,
+) -> bool {
+    let current = cx.current;
+
+    let transform = cx.cache.transform.get(current).copied().unwrap_or_default();
+    let Some(inverse) = transform.invert() else {
+        return false;
+    };
+
+    let bounds = layer_bounds(cx.style, cx.cache, cx.tree, current);
+    let rect = inverse.map_rect(Rect::from(bounds)).0.round_out();
+
+    if rect.is_empty()
+        || rect.width() > MAX_RENDER_LAYER_SIZE
+        || rect.height() > MAX_RENDER_LAYER_SIZE
+    {
+        cx.render_layers.remove(&current);
+        return false;
+    }
+
+    if !cx.render_layers.get(&current).is_some_and(|layer| layer.is_valid(rect, visible)) {
+        let info = ImageInfo::new_n32_premul((rect.width(), rect.height()), None);
+        let Some(mut surface) = canvas.new_surface(&info, None) else {
+            return false;
+        };
+
+        let layer_canvas = surface.canvas();
+        layer_canvas.clear(Color::transparent());
+
+        // Maps the absolute coordinates of the subtree into the local coordinates of the layer.
+        let layer_base = Matrix::translate((-rect.left as f32, -rect.top as f32)) * inverse;
+
+        // The whole subtree is drawn, including any parts clipped by ancestors of the view, so that the layer
+        // stays valid when only the transform of the view changes.
+        let dirty_rect = None;
+
+        // Views within the layer with a higher z-index are drawn on top of the layer contents
+        // rather than on top of the rest of the window.
+        let mut queue = BinaryHeap::new();
+
+        layer_canvas.save();
+        draw_contents(cx, &dirty_rect, layer_canvas, &layer_base, current_z, &mut queue, visible);
+        layer_canvas.restore();
+
+        while let Some(zentity) = queue.pop() {
+            cx.current = zentity.entity;
+            layer_canvas.save();
+            draw_entity(
+                cx,
+                &dirty_rect,
+                layer_canvas,
+                &layer_base,
+                zentity.index,
+                &mut queue,
+                zentity.visible,
+            );
+            layer_canvas.restore();
+        }
+
+        cx.current = current;
+
+        cx.render_layers.insert(
+            current,
+            RenderLayer { image: surface.image_snapshot(), rect, visible, dirty: false },
+        );
+    }
+
+    if let Some(render_layer) = cx.render_layers.get(&current) {
+        let matrix = *base * transform;
+        canvas.set_matrix(&(&matrix).into());
+        canvas.draw_image_with_sampling_options(
+            &render_layer.image,
+            (render_layer.rect.left as f32, render_layer.rect.top as f32),
+            SamplingOptions::new(FilterMode::Linear, MipmapMode::None),
+            None,
+        );
+    }
+
+    true
+}
+
 // Must be called after transform and clipping systems to be valid.
 pub(crate) fn draw_bounds(
     style: &Style,
@@ -358,6 +536,69 @@ pub(crate) fn draw_bounds(
     tree: &Tree<Entity>,
     entity: Entity,
 ) -> BoundingBox {
+    let dirty_bounds = unclipped_draw_bounds(style, cache, tree, entity);
+
+    let z_index = style.z_index.get(entity).copied().unwrap_or_default();
+
+    let parent = tree
+        .get_layout_parent(entity)
+        .unwrap_or(tree.get_parent_window(entity).unwrap_or(Entity::root()));
+    if let Some(clip_bounds) = cache.clip_path.get(parent) {
+        if z_index != 0 {
+            dirty_bounds
+        } else {
+            dirty_bounds.intersection(clip_bounds)
+        }
+    } else {
+        dirty_bounds
+    }
+}
+
+// The draw bounds of an entity before clipping by its parent.
+fn unclipped_draw_bounds(
+    style: &Style,
+    cache: &CachedData,
+    tree: &Tree<Entity>,
+    entity: Entity,
+) -> BoundingBox {
+    let mut dirty_bounds = visual_overflow_bounds(style, cache, entity);
+
+    if style.overflowx.get(entity).copied().unwrap_or_default() == Overflow::Visible
+        || style.overflowy.get(entity).copied().unwrap_or_default() == Overflow::Visible
+    {
+        let child_iter = DrawChildIterator::new(tree, entity);
+        for child in child_iter {
+            dirty_bounds = dirty_bounds.union(&draw_bounds(style, cache, tree, child));
+        }
+    }
+
+    filter_bounds(style, entity, dirty_bounds)
+}
+
+// The bounds of the render layer of an entity. Unlike the draw bounds, the bounds of descendants are not
+// clipped by the ancestors of the entity.
+fn layer_bounds(
+    style: &Style,
+    cache: &CachedData,
+    tree: &Tree<Entity>,
+    entity: Entity,
+) -> BoundingBox {
+    let mut bounds = visual_overflow_bounds(style, cache, entity);
+
+    if style.overflowx.get(entity).copied().unwrap_or_default() == Overflow::Visible
+        || style.overflowy.get(entity).copied().unwrap_or_default() == Overflow::Visible
+    {
+        let child_iter = DrawChildIterator::new(tree, entity);
+        for child in child_iter {
+            bounds = bounds.union(&layer_bounds(style, cache, tree, child));
+        }
+    }
+
+    filter_bounds(style, entity, bounds)
+}
+
+// The transformed bounds of an entity including its shadows and outline.
+fn visual_overflow_bounds(style: &Style, cache: &CachedData, entity: Entity) -> BoundingBox {
     let mut layout_bounds = cache.bounds.get(entity).copied().unwrap();
 
     if let Some(shadows) = style.shadow.get(entity) {
@@ -371,16 +612,17 @@ pub(crate) fn draw_bounds(
 
             let scale_factor = style.scale_factor();
 
+            // The blur is drawn with a sigma of half the blur radius, and extends three sigmas past the shadow.
             if let Some(blur_radius) =
                 shadow.blur_radius.as_ref().map(|br| br.clone().to_px().unwrap() * scale_factor)
             {
-                shadow_bounds = shadow_bounds.expand(blur_radius);
+                shadow_bounds = shadow_bounds.expand(1.5 * blur_radius);
             }
 
             if let Some(spread_radius) =
                 shadow.spread_radius.as_ref().map(|sr| sr.clone().to_px().unwrap() * scale_factor)
             {
-                shadow_bounds = shadow_bounds.expand(spread_radius * style.scale_factor());
+                shadow_bounds = shadow_bounds.expand(spread_radius);
             }
 
             layout_bounds = layout_bounds.union(&shadow_bounds);
@@ -403,35 +645,17 @@ pub(crate) fn draw_bounds(
 
     let matrix = cache.transform.get(entity).copied().unwrap_or_default();
 
-    let rect: Rect = layout_bounds.into();
-    let tr = matrix.map_rect(rect).0;
-
-    let mut dirty_bounds: BoundingBox = tr.into();
-
-    //
-    if style.overflowx.get(entity).copied().unwrap_or_default() == Overflow::Visible
-        || style.overflowy.get(entity).copied().unwrap_or_default() == Overflow::Visible
-    {
-        let child_iter = DrawChildIterator::new(tree, entity);
-        for child in child_iter {
-            dirty_bounds = dirty_bounds.union(&draw_bounds(style, cache, tree, child));
-        }
-    }
-
-    let z_index = style.z_index.get(entity).copied().unwrap_or_default();
+    matrix.map_rect(Rect::from(layout_bounds)).0.into()
+}
 
-    let parent = tree
-        .get_layout_parent(entity)
-        .unwrap_or(tree.get_parent_window(entity).unwrap_or(Entity::root()));
-    if let Some(clip_bounds) = cache.clip_path.get(parent) {
-        if z_index != 0 {
-            dirty_bounds
-        } else {
-            dirty_bounds.intersection(clip_bounds)
-        }
-    } else {
-        dirty_bounds
-    }
+// Expands the bounds of an entity by any filters, such as blur and drop-shadow, which draw outside of the
+// bounds of the view and its children.
+fn filter_bounds(style: &Style, entity: Entity, bounds: BoundingBox) -> BoundingBox {
+    style
+        .filter
+        .get(entity)
+        .and_then(|filters| image_filter(filters, style.scale_factor(), None))
+        .map_or(bounds, |filter| filter.compute_fast_bounds(Rect::from(bounds)).into())
 }
 
 struct ZEntity {
@@ -457,3 +681,275 @@ impl PartialEq for ZEntity {
 }
 
 impl Eq for ZEntity {}
+
+/// Converts a list of filter functions into a chain of skia image filters, applied in order after `input`.
+pub(crate) fn image_filter(
+    filters: &[Filter],
+    scale_factor: f32,
+    input: Option<ImageFilter>,
+) -> Option<ImageFilter> {
+    let mut result = input;
+
+    for filter in filters {
+        let input = result.clone();
+
+        let image_filter = match filter {
+            Filter::Blur(radius) => {
+                let sigma = radius.to_px().unwrap_or_default() * scale_factor / 2.0;
+                image_filters::blur((sigma, sigma), None, input, None)
+            }
+
+            Filter::DropShadow(shadow) => {
+                let x = shadow.x_offset.to_px().unwrap_or_default() * scale_factor;
+                let y = shadow.y_offset.to_px().unwrap_or_default() * scale_factor;
+                let sigma = shadow.blur_radius.as_ref().and_then(Length::to_px).unwrap_or_default()
+                    * scale_factor
+                    / 2.0;
+                let color: skia_safe::Color = shadow.color.unwrap_or_default().into();
+                image_filters::drop_shadow((x, y), (sigma, sigma), color, None, input, None)
+            }
+
+            _ => color_matrix(filter).and_then(|matrix| {
+                image_filters::color_filter(
+                    color_filters::matrix_row_major(&matrix, None),
+                    input,
+                    None,
+                )
+            }),
+        };
+
+        if image_filter.is_some() {
+            result = image_filter;
+        }
+    }
+
+    result
+}
+
+// Returns the row-major 4x5 color matrix of a color filter function.
+// See https://drafts.fxtf.org/filter-effects/#ShorthandEquivalents
+fn color_matrix(filter: &Filter) -> Option<[f32; 20]> {
+    let matrix = match filter {
+        Filter::Brightness(amount) => {
+            let b = *amount;
+            rgb_matrix([[b, 0.0, 0.0], [0.0, b, 0.0], [0.0, 0.0, b]], 0.0)
+        }
+
+        Filter::Contrast(amount) => {
+            let c = *amount;
+            rgb_matrix([[c, 0.0, 0.0], [0.0, c, 0.0], [0.0, 0.0, c]], 0.5 - 0.5 * c)
+        }
+
+        Filter::Grayscale(amount) => {
+            let a = 1.0 - amount.clamp(0.0, 1.0);
+            rgb_matrix(
+                [
+                    [0.2126 + 0.7874 * a, 0.7152 - 0.7152 * a, 0.0722 - 0.0722 * a],
+                    [0.2126 - 0.2126 * a, 0.7152 + 0.2848 * a, 0.0722 - 0.0722 * a],
+                    [0.2126 - 0.2126 * a, 0.7152 - 0.7152 * a, 0.0722 + 0.9278 * a],
+                ],
+                0.0,
+            )
+        }
+
+        Filter::Sepia(amount) => {
+            let a = 1.0 - amount.clamp(0.0, 1.0);
+            rgb_matrix(
+                [
+                    [0.393 + 0.607 * a, 0.769 - 0.769 * a, 0.189 - 0.189 * a],
+                    [0.349 - 0.349 * a, 0.686 + 0.314 * a, 0.168 - 0.168 * a],
+                    [0.272 - 0.272 * a, 0.534 - 0.534 * a, 0.131 + 0.869 * a],
+                ],
+                0.0,
+            )
+        }
+
+        Filter::Saturate(amount) => {
+            let s = *amount;
+            rgb_matrix(
+                [
+                    [0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s],
+                    [0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s],
+                    [0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s],
+                ],
+                0.0,
+            )
+        }
+
+        Filter::HueRotate(angle) => {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            rgb_matrix(
+                [
+                    [
+                        0.213 + cos * 0.787 - sin * 0.213,
+                        0.715 - cos * 0.715 - sin * 0.715,
+                        0.072 - cos * 0.072 + sin * 0.928,
+                    ],
+                    [
+                        0.213 - cos * 0.213 + sin * 0.143,
+                        0.715 + cos * 0.285 + sin * 0.140,
+                        0.072 - cos * 0.072 - sin * 0.283,
+                    ],
+                    [
+                        0.213 - cos * 0.213 - sin * 0.787,
+                        0.715 - cos * 0.715 + sin * 0.715,
+                        0.072 + cos * 0.928 + sin * 0.072,
+                    ],
+                ],
+                0.0,
+            )
+        }
+
+        Filter::Invert(amount) => {
+            let a = amount.clamp(0.0, 1.0);
+            let c = 1.0 - 2.0 * a;
+            rgb_matrix([[c, 0.0, 0.0], [0.0, c, 0.0], [0.0, 0.0, c]], a)
+        }
+
+        Filter::Blur(_) | Filter::DropShadow(_) => return None,
+    };
+
+    Some(matrix)
+}
+
+// Expands a 3x3 matrix of the color channels and an offset added to each channel into a 4x5 color
+// matrix which leaves the alpha channel unchanged.
+fn rgb_matrix(rgb: [[f32; 3]; 3], offset: f32) -> [f32; 20] {
+    let mut matrix = [0.0; 20];
+    for (row, channels) in rgb.iter().enumerate() {
+        matrix[row * 5..row * 5 + 3].copy_from_slice(channels);
+        matrix[row * 5 + 4] = offset;
+    }
+    matrix[18] = 1.0;
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{color_matrix, invalidate_render_layers, layer_bounds, rgb_matrix, RenderLayer};
+    use crate::prelude::*;
+    use hashbrown::HashSet;
+    use skia_safe::{surfaces, IRect};
+    use vizia_style::{Angle, Filter};
+
+    fn assert_matrix_eq(a: [f32; 20], b: [f32; 20]) {
+        for (a, b) in a.iter().zip(b.iter()) {
+            assert!((a - b).abs() < 0.0001, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn identity_filters_have_identity_matrix() {
+        let filters = [
+            Filter::Brightness(0.3),
+            Filter::Contrast(2.0),
+            Filter::Grayscale(1.0),
+            Filter::HueRotate(Angle::Deg(90.0)),
+            Filter::Invert(1.0),
+            Filter::Saturate(0.0),
+            Filter::Sepia(0.5),
+        ];
+
+        for filter in filters.iter() {
+            assert_matrix_eq(
+                color_matrix(&filter.identity()).unwrap(),
+                rgb_matrix([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]], 0.0),
+            );
+        }
+    }
+
+    #[test]
+    fn invert_matrix() {
+        let matrix = color_matrix(&Filter::Invert(1.0)).unwrap();
+        // A white pixel becomes black.
+        let red = matrix[0] + matrix[1] + matrix[2] + matrix[4];
+        assert!(red.abs() < 0.0001);
+    }
+
+    #[test]
+    fn blur_has_no_color_matrix() {
+        assert!(color_matrix(&Filter::default()).is_none());
+    }
+
+    // A render layer with up to date contents.
+    fn render_layer() -> RenderLayer {
+        let image = surfaces::raster_n32_premul((1, 1)).unwrap().image_snapshot();
+        RenderLayer { image, rect: IRect::new(0, 0, 1, 1), visible: true, dirty: false }
+    }
+
+    // Builds a stack which sets `will-change` and has a render layer, containing an element.
+    fn context() -> (Context, Entity, Entity) {
+        let mut cx = Context::default();
+        let mut element = Entity::null();
+        let stack = VStack::new(&mut cx, |cx| {
+            element = Element::new(cx).entity();
+        })
+        .will_change("transform")
+        .entity();
+        cx.render_layers.insert(stack, render_layer());
+
+        (cx, stack, element)
+    }
+
+    fn set(entities: &[Entity]) -> HashSet<Entity> {
+        entities.iter().copied().collect()
+    }
+
+    #[test]
+    fn redrawing_child_invalidates_layer() {
+        let (mut cx, stack, element) = context();
+
+        invalidate_render_layers(&mut cx, &set(&[element]), &set(&[]));
+        assert!(cx.render_layers[&stack].dirty);
+    }
+
+    #[test]
+    fn recompositing_child_invalidates_layer() {
+        let (mut cx, stack, element) = context();
+
+        // Changing the transform of the view itself only composites its layer again.
+        invalidate_render_layers(&mut cx, &set(&[]), &set(&[stack]));
+        assert!(!cx.render_layers[&stack].dirty);
+
+        invalidate_render_layers(&mut cx, &set(&[]), &set(&[element]));
+        assert!(cx.render_layers[&stack].dirty);
+    }
+
+    #[test]
+    fn will_change_keeps_layer() {
+        let (mut cx, stack, _) = context();
+
+        invalidate_render_layers(&mut cx, &set(&[stack]), &set(&[]));
+        assert!(cx.render_layers.contains_key(&stack));
+
+        cx.style.will_change.insert(stack, WillChange::Auto);
+        invalidate_render_layers(&mut cx, &set(&[stack]), &set(&[]));
+        assert!(!cx.render_layers.contains_key(&stack));
+    }
+
+    #[test]
+    fn removing_view_frees_layer() {
+        let (mut cx, stack, element) = context();
+
+        cx.remove(element);
+        assert!(cx.render_layers[&stack].dirty);
+
+        cx.remove(stack);
+        assert!(cx.render_layers.is_empty());
+    }
+
+    #[test]
+    fn layer_bounds_are_not_clipped_by_ancestors() {
+        let (mut cx, stack, element) = context();
+        cx.style.dpi_factor = 1.0;
+        cx.style.outline_width.insert(stack, LengthOrPercentage::px(10.0));
+        cx.cache.set_bounds(stack, BoundingBox { x: 0.0, y: 0.0, w: 100.0, h: 100.0 });
+        cx.cache.set_bounds(element, BoundingBox { x: 50.0, y: 50.0, w: 100.0, h: 100.0 });
+
+        // An ancestor of the stack clips it to its top left corner.
+        cx.cache.clip_path.insert(stack, BoundingBox { x: 0.0, y: 0.0, w: 50.0, h: 50.0 });
+
+        let bounds = layer_bounds(&cx.style, &cx.cache, &cx.tree, stack);
+        assert_eq!(bounds, BoundingBox { x: -10.0, y: -10.0, w: 160.0, h: 160.0 });
+    }
+}
@@ -0,0 +1,279 @@
+use crate::prelude::*;
+
+/// Extra time given to exit animations to finish before an exiting entity is removed regardless.
+pub(crate) const EXIT_GRACE_PERIOD: Duration = Duration::from_millis(100);
+
+/// An entity which has been removed and is playing its exit animation.
+pub(crate) struct ExitingEntity {
+    pub entity: Entity,
+    /// The animation set with the `exit_animation` modifier, if any.
+    pub animation: Option<Animation>,
+    /// The time after which the entity is removed even if its exit animations have not finished.
+    pub deadline: Instant,
+}
+
+/// Removes any exiting entities which have finished playing their exit animation.
+///
+/// Only the exit animation of an entity and the transitions of `:exiting` rules delay its removal, so other
+/// animations which repeat forever or are paused don't keep the entity in the tree.
+pub(crate) fn exit_system(cx: &mut Context) {
+    if cx.exiting.is_empty() {
+        return;
+    }
+
+    let now = Instant::now();
+    let finished = cx
+        .exiting
+        .iter()
+        .filter(|exiting| {
+            now >= exiting.deadline
+                || !cx.style.has_exit_animations(exiting.entity, exiting.animation)
+        })
+        .map(|exiting| exiting.entity)
+        .collect::<Vec<_>>();
+
+    for entity in finished {
+        // Skip entities which were removed along with an exiting ancestor.
+        if !cx.is_exiting(entity) {
+            continue;
+        }
+
+        let parent = cx.tree.get_layout_parent(entity).unwrap_or(Entity::root());
+        cx.with_current(parent, |cx| cx.remove(entity));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::PlaybackAction;
+    use crate::systems::{animation_system, hover_system, style_system};
+    use crate::tree::is_navigatable;
+
+    const EXIT_DURATION: Duration = Duration::from_millis(100);
+
+    // Builds a stack containing an element which fades out when removed.
+    fn context() -> (Context, Entity, Entity) {
+        let mut cx = Context::default();
+        let fade_out = cx.add_animation(
+            AnimationBuilder::new()
+                .keyframe(0.0, |key| key.opacity(1.0))
+                .keyframe(1.0, |key| key.opacity(0.0)),
+        );
+
+        let stack = VStack::new(&mut cx, |cx| {
+            Element::new(cx).id("element").exit_animation(fade_out, EXIT_DURATION);
+        })
+        .entity();
+        let element = stack.first_child(&cx.tree).unwrap();
+
+        (cx, stack, element)
+    }
+
+    fn is_alive(cx: &Context, entity: Entity) -> bool {
+        cx.entity_manager.is_alive(entity)
+    }
+
+    fn has_exiting_class(cx: &Context, entity: Entity) -> bool {
+        cx.style
+            .pseudo_classes
+            .get(entity)
+            .is_some_and(|pseudo_classes| pseudo_classes.contains(PseudoClassFlags::EXITING))
+    }
+
+    fn process_animations(cx: &mut Context) {
+        animation_system(cx);
+        exit_system(cx);
+    }
+
+    #[test]
+    fn exiting_entity_stays_until_animation_finishes() {
+        let (mut cx, stack, element) = context();
+
+        cx.remove(element);
+        assert!(cx.is_exiting(element));
+        assert!(has_exiting_class(&cx, element));
+        assert_eq!(cx.tree.get_parent(element), Some(stack));
+
+        process_animations(&mut cx);
+        assert!(cx.style.has_active_animations(element));
+        assert!(is_alive(&cx, element));
+
+        std::thread::sleep(EXIT_DURATION * 2);
+        process_animations(&mut cx);
+        assert!(!cx.style.has_active_animations(element));
+        assert!(!is_alive(&cx, element));
+        assert!(!cx.is_exiting(element));
+        assert_eq!(cx.tree.get_child(stack, 0), None);
+    }
+
+    #[test]
+    fn entity_without_exit_animation_is_removed_immediately() {
+        let mut cx = Context::default();
+        let element = Element::new(&mut cx).entity();
+
+        cx.remove(element);
+        assert!(!cx.is_exiting(element));
+        assert!(!is_alive(&cx, element));
+    }
+
+    #[test]
+    fn exit_rule_matches_exiting() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(".fade:exiting { opacity: 0; }").unwrap();
+        let element = Element::new(&mut cx).class("fade").entity();
+        let other = Element::new(&mut cx).class("other").entity();
+
+        cx.remove(element);
+        cx.remove(other);
+        assert!(cx.is_exiting(element));
+        assert!(has_exiting_class(&cx, element));
+        assert!(!is_alive(&cx, other));
+
+        // Without a transition on the exiting state, the entity is removed on the next update.
+        process_animations(&mut cx);
+        assert!(!is_alive(&cx, element));
+    }
+
+    #[test]
+    fn exit_rule_transition_delays_removal() {
+        let mut cx = Context::default();
+        cx.add_stylesheet(
+            ".fade { opacity: 1; } .fade:exiting { opacity: 0; transition: opacity 100ms; }",
+        )
+        .unwrap();
+        let element = Element::new(&mut cx).class("fade").entity();
+        style_system(&mut cx);
+
+        cx.remove(element);
+        style_system(&mut cx);
+        process_animations(&mut cx);
+        assert!(is_alive(&cx, element));
+
+        std::thread::sleep(EXIT_DURATION * 2);
+        process_animations(&mut cx);
+        assert!(!is_alive(&cx, element));
+    }
+
+    #[test]
+    fn repeating_animation_does_not_delay_exit() {
+        let (mut cx, _, element) = context();
+        let pulse = cx.add_animation(
+            AnimationBuilder::new()
+                .keyframe(0.0, |key| key.background_color(Color::red()))
+                .keyframe(1.0, |key| key.background_color(Color::blue())),
+        );
+        let repeat_forever = PlaybackAction::Configure {
+            iteration_count: f32::INFINITY,
+            direction: AnimationDirection::default(),
+            fill_mode: AnimationFillMode::default(),
+            paused: false,
+        };
+        cx.style.enqueue_animation(element, pulse, EXIT_DURATION, Duration::ZERO);
+        cx.style.enqueue_playback_action(element, pulse, repeat_forever);
+
+        cx.remove(element);
+        process_animations(&mut cx);
+        assert!(is_alive(&cx, element));
+
+        // The exit animation has finished while the pulse keeps repeating.
+        std::thread::sleep(EXIT_DURATION * 2);
+        animation_system(&mut cx);
+        assert!(cx.style.has_active_animations(element));
+
+        exit_system(&mut cx);
+        assert!(!is_alive(&cx, element));
+        assert!(!cx.is_exiting(element));
+    }
+
+    #[test]
+    fn paused_exit_animation_times_out() {
+        let (mut cx, _, element) = context();
+        cx.style.animation_play_state.insert(element, AnimationPlayState::Paused);
+
+        cx.remove(element);
+        process_animations(&mut cx);
+        assert!(is_alive(&cx, element));
+
+        // The exit animation never finishes, so the entity is removed once it has had time to play.
+        std::thread::sleep(EXIT_DURATION + EXIT_GRACE_PERIOD);
+        process_animations(&mut cx);
+        assert!(!is_alive(&cx, element));
+        assert!(!cx.is_exiting(element));
+    }
+
+    #[test]
+    fn exiting_entity_ignores_input() {
+        let (mut cx, stack, element) = context();
+        let bounds = BoundingBox { x: 0.0, y: 0.0, w: 100.0, h: 100.0 };
+        for entity in [Entity::root(), stack, element] {
+            cx.cache.set_bounds(entity, bounds);
+        }
+        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(Entity::root()) {
+            pseudo_classes.set(PseudoClassFlags::OVER, true);
+        }
+        if let Some(abilities) = cx.style.abilities.get_mut(element) {
+            abilities.set(Abilities::FOCUSABLE | Abilities::NAVIGABLE, true);
+        }
+        cx.mouse.cursor_x = 50.0;
+        cx.mouse.cursor_y = 50.0;
+
+        hover_system(&mut cx, Entity::root());
+        assert_eq!(cx.hovered, element);
+        cx.with_current(element, |cx| cx.focus());
+        assert_eq!(cx.focused, element);
+        assert!(is_navigatable(&cx.tree, &cx.style, element, Entity::root()));
+
+        cx.remove(element);
+        assert!(is_alive(&cx, element));
+        assert_ne!(cx.focused, element);
+        assert!(!is_navigatable(&cx.tree, &cx.style, element, Entity::root()));
+
+        hover_system(&mut cx, Entity::root());
+        assert_eq!(cx.hovered, stack);
+    }
+
+    #[test]
+    fn removing_parent_cancels_exit() {
+        let (mut cx, stack, element) = context();
+
+        cx.remove(element);
+        assert!(cx.is_exiting(element));
+
+        // The parent has no exit animation, so it is removed along with the exiting element.
+        cx.remove(stack);
+        assert!(!is_alive(&cx, stack));
+        assert!(!is_alive(&cx, element));
+        assert!(!cx.is_exiting(element));
+        assert!(cx.style.pending_animations.is_empty());
+
+        // A view built with the recycled entity doesn't play the canceled exit animation.
+        let new_element = Element::new(&mut cx).entity();
+        process_animations(&mut cx);
+        assert!(!cx.style.has_active_animations(new_element));
+        assert!(is_alive(&cx, new_element));
+    }
+
+    #[test]
+    fn rebuilding_mid_exit() {
+        let (mut cx, stack, element) = context();
+
+        cx.remove(element);
+        let rebuilt = cx.with_current(stack, |cx| Element::new(cx).id("element").entity());
+
+        // The exiting view stays next to the rebuilt view, which takes its id.
+        assert_eq!(cx.tree.get_child(stack, 0), Some(element));
+        assert_eq!(cx.tree.get_child(stack, 1), Some(rebuilt));
+        assert_eq!(cx.resolve_entity_identifier("element"), Some(rebuilt));
+
+        // Removing an exiting view again removes it immediately, leaving the rebuilt view.
+        cx.remove(element);
+        assert!(!is_alive(&cx, element));
+        assert!(!cx.is_exiting(element));
+        assert_eq!(cx.resolve_entity_identifier("element"), Some(rebuilt));
+
+        process_animations(&mut cx);
+        assert!(is_alive(&cx, rebuilt));
+        assert!(!has_exiting_class(&cx, rebuilt));
+    }
+}
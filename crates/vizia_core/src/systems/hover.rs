@@ -106,6 +106,16 @@ fn hover_entity(
         return;
     }
 
+    // Skip if exiting (will skip any descendants)
+    if cx
+        .style
+        .pseudo_classes
+        .get(cx.current)
+        .is_some_and(|pseudo_classes| pseudo_classes.contains(PseudoClassFlags::EXITING))
+    {
+        return;
+    }
+
     // Skip if not displayed.
     // TODO: Should this skip descendants? Probably not...?
     if cx.style.display.get(cx.current).copied().unwrap_or_default() == Display::None
@@ -1,4 +1,4 @@
-use crate::{events::ViewHandler, prelude::*};
+use crate::{animation::PlaybackAction, events::ViewHandler, prelude::*};
 use hashbrown::HashMap;
 use vizia_storage::{LayoutParentIterator, TreeBreadthIterator};
 use vizia_style::{
@@ -220,6 +220,7 @@ impl<'s, 't, 'v> Element for Node<'s, 't, 'v> {
                 PseudoClass::UserInvalid => {
                     psudeo_class_flag.contains(PseudoClassFlags::USER_INVALID)
                 }
+                PseudoClass::Exiting => psudeo_class_flag.contains(PseudoClassFlags::EXITING),
                 PseudoClass::Lang(_) => todo!(),
                 PseudoClass::Dir(_) => todo!(),
                 PseudoClass::Custom(name) => {
@@ -237,8 +238,12 @@ impl<'s, 't, 'v> Element for Node<'s, 't, 'v> {
 pub(crate) fn inline_inheritance_system(cx: &mut Context, redraw_entities: &mut Vec<Entity>) {
     for entity in cx.tree.into_iter() {
         if let Some(parent) = cx.tree.get_layout_parent(entity) {
-            if cx.style.disabled.inherit_inline(entity, parent)
-                | cx.style.caret_color.inherit_inline(entity, parent)
+            if cx.style.disabled.inherit_inline(entity, parent) {
+                cx.style.needs_access_update(entity);
+                redraw_entities.push(entity);
+            }
+
+            if cx.style.caret_color.inherit_inline(entity, parent)
                 | cx.style.selection_color.inherit_inline(entity, parent)
             {
                 redraw_entities.push(entity);
@@ -322,6 +327,10 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.filter.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.backdrop_filter.link(entity, matched_rules) {
         should_redraw = true;
     }
@@ -330,6 +339,10 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.will_change.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     // Opacity
     if style.opacity.link(entity, matched_rules) {
         should_redraw = true;
@@ -659,6 +672,18 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    style.animation_iteration_count.link(entity, matched_rules);
+    style.animation_direction.link(entity, matched_rules);
+    style.animation_fill_mode.link(entity, matched_rules);
+
+    if style.animation_play_state.link(entity, matched_rules) {
+        let action = match style.animation_play_state.get(entity).copied().unwrap_or_default() {
+            AnimationPlayState::Running => PlaybackAction::Resume,
+            AnimationPlayState::Paused => PlaybackAction::Pause,
+        };
+        style.control_animation(entity, None, action, Instant::now());
+    }
+
     // Transform
     if style.transform.link(entity, matched_rules) {
         should_redraw = true;
@@ -1,6 +1,6 @@
 use crate::entity::Entity;
 use crate::prelude::Style;
-use crate::style::{Abilities, Display};
+use crate::style::{Abilities, Display, PseudoClassFlags};
 use vizia_id::GenerationalId;
 use vizia_storage::{
     DoubleEndedTreeTour, FocusTreeIterator, TourDirection, Tree, TreeExt, TreeTour,
@@ -28,6 +28,16 @@ pub(crate) fn is_navigatable(
         return false;
     }
 
+    // Skip widgets which are being removed
+    if node.parent_iter(tree).any(|ancestor| {
+        style
+            .pseudo_classes
+            .get(ancestor)
+            .is_some_and(|pseudo_classes| pseudo_classes.contains(PseudoClassFlags::EXITING))
+    }) {
+        return false;
+    }
+
     // Skip nodes outside of the subtree
     if !node.is_descendant_of(tree, lock_focus_to) {
         return false;
@@ -102,6 +102,9 @@ impl<'a, V> Handle<'a, V> {
             .and_then(|view_handler| view_handler.downcast_mut::<V>())
         {
             (f)(view);
+
+            // The modified data may be exposed by the accessibility node of the view.
+            self.cx.style.needs_access_update(self.entity);
         }
 
         self
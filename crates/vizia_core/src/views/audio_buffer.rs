@@ -0,0 +1,153 @@
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::prelude::*;
+
+// How often audio views poll their buffer for new samples.
+const POLL_INTERVAL: Duration = Duration::from_millis(16);
+
+struct RingBuffer {
+    samples: Box<[AtomicU32]>,
+    // The total number of samples written by the producer.
+    head: AtomicUsize,
+    // The total number of samples read by the consumer.
+    tail: AtomicUsize,
+}
+
+/// Creates a lock-free single-producer single-consumer ring buffer for sending audio samples to the
+/// [`Meter`], [`Waveform`] and [`Spectrum`] views.
+///
+/// The [`AudioProducer`] never blocks or allocates so it can be used from the audio thread. When the buffer is full
+/// the newest samples are dropped until the view catches up.
+///
+/// # Example
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// let (producer, consumer) = audio_ring_buffer(4096);
+///
+/// // Move the producer to the audio thread and push samples with `producer.push_slice(&samples)`.
+///
+/// Meter::new(cx, consumer);
+/// ```
+pub fn audio_ring_buffer(capacity: usize) -> (AudioProducer, AudioConsumer) {
+    let buffer = Arc::new(RingBuffer {
+        samples: (0..capacity.max(1)).map(|_| AtomicU32::new(0)).collect(),
+        head: AtomicUsize::new(0),
+        tail: AtomicUsize::new(0),
+    });
+
+    (AudioProducer { buffer: buffer.clone() }, AudioConsumer { buffer })
+}
+
+/// The writing half of an [`audio_ring_buffer`].
+pub struct AudioProducer {
+    buffer: Arc<RingBuffer>,
+}
+
+impl AudioProducer {
+    /// Pushes samples into the buffer, returning the number of samples which fit.
+    pub fn push_slice(&mut self, samples: &[f32]) -> usize {
+        let capacity = self.buffer.samples.len();
+        let head = self.buffer.head.load(Ordering::Relaxed);
+        let tail = self.buffer.tail.load(Ordering::Acquire);
+        let count = samples.len().min(capacity - (head - tail));
+
+        for (offset, sample) in samples[..count].iter().enumerate() {
+            self.buffer.samples[(head + offset) % capacity]
+                .store(sample.to_bits(), Ordering::Relaxed);
+        }
+
+        self.buffer.head.store(head + count, Ordering::Release);
+
+        count
+    }
+}
+
+/// The reading half of an [`audio_ring_buffer`], owned by an audio view.
+pub struct AudioConsumer {
+    buffer: Arc<RingBuffer>,
+}
+
+impl AudioConsumer {
+    /// Calls `f` with each sample in the buffer, oldest first, returning the number of samples read.
+    pub fn drain(&mut self, mut f: impl FnMut(f32)) -> usize {
+        let capacity = self.buffer.samples.len();
+        let tail = self.buffer.tail.load(Ordering::Relaxed);
+        let head = self.buffer.head.load(Ordering::Acquire);
+
+        for index in tail..head {
+            f(f32::from_bits(self.buffer.samples[index % capacity].load(Ordering::Relaxed)));
+        }
+
+        self.buffer.tail.store(head, Ordering::Release);
+
+        head - tail
+    }
+}
+
+// Sent to an audio view by its timer to poll the buffer for new samples.
+pub(crate) struct PollAudio;
+
+// Starts a timer which periodically sends `PollAudio` to the view, so that the view can redraw when new samples
+// arrive without rebinding any models.
+pub(crate) fn poll_audio<V: View>(handle: Handle<'_, V>) -> Handle<'_, V> {
+    let timer = handle.cx.add_timer(POLL_INTERVAL, None, |cx, action| {
+        if matches!(action, TimerAction::Tick(_)) {
+            cx.emit(PollAudio);
+        }
+    });
+
+    let entity = handle.entity();
+    handle.cx.with_current(entity, |cx| cx.start_timer(timer));
+
+    handle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(consumer: &mut AudioConsumer) -> Vec<f32> {
+        let mut samples = Vec::new();
+        consumer.drain(|sample| samples.push(sample));
+        samples
+    }
+
+    #[test]
+    fn samples_are_received_in_order_across_wrap() {
+        let (mut producer, mut consumer) = audio_ring_buffer(4);
+
+        assert_eq!(producer.push_slice(&[1.0, 2.0, 3.0]), 3);
+        assert_eq!(drain(&mut consumer), [1.0, 2.0, 3.0]);
+
+        assert_eq!(producer.push_slice(&[4.0, 5.0, 6.0]), 3);
+        assert_eq!(drain(&mut consumer), [4.0, 5.0, 6.0]);
+        assert!(drain(&mut consumer).is_empty());
+    }
+
+    #[test]
+    fn full_buffer_drops_newest_samples() {
+        let (mut producer, mut consumer) = audio_ring_buffer(3);
+
+        assert_eq!(producer.push_slice(&[1.0, 2.0, 3.0, 4.0]), 3);
+        assert_eq!(producer.push_slice(&[5.0]), 0);
+        assert_eq!(drain(&mut consumer), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn producer_on_another_thread() {
+        let (mut producer, mut consumer) = audio_ring_buffer(1024);
+
+        std::thread::spawn(move || {
+            let samples = (0..512).map(|i| i as f32).collect::<Vec<_>>();
+            producer.push_slice(&samples);
+        })
+        .join()
+        .unwrap();
+
+        let samples = drain(&mut consumer);
+        assert_eq!(samples.len(), 512);
+        assert!(samples.iter().enumerate().all(|(i, sample)| *sample == i as f32));
+    }
+}
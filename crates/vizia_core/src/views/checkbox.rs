@@ -111,6 +111,8 @@ use crate::prelude::*;
 /// ```
 pub struct Checkbox {
     on_toggle: Option<Box<dyn Fn(&mut EventContext)>>,
+    // Whether the checkbox is in the intermediate (mixed) state.
+    intermediate: bool,
 }
 
 impl Checkbox {
@@ -135,7 +137,7 @@ impl Checkbox {
     /// Checkbox::new(cx, AppData::value);
     /// ```
     pub fn new(cx: &mut Context, checked: impl Lens<Target = bool>) -> Handle<Self> {
-        Self { on_toggle: None }
+        Self { on_toggle: None, intermediate: false }
             .build(cx, |cx| {
                 Binding::new(cx, checked, |cx, checked| {
                     if checked.get(cx) {
@@ -184,7 +186,7 @@ impl Checkbox {
     where
         T: AsRef<[u8]> + 'static,
     {
-        Self { on_toggle: None }
+        Self { on_toggle: None, intermediate: false }
             .build(cx, |cx| {
                 Binding::new(cx, checked, move |cx, checked| {
                     let icon_default = icon_default.clone();
@@ -209,13 +211,15 @@ impl Checkbox {
         checked: impl Lens<Target = bool>,
         intermediate: impl Lens<Target = bool>,
     ) -> Handle<Self> {
-        Self { on_toggle: None }
+        Self { on_toggle: None, intermediate: false }
             .build(cx, |_| {})
             .bind(checked, move |handle, c| {
                 handle.bind(intermediate, move |handle, i| {
+                    let is_intermediate = !c.get(&handle) && i.get(&handle);
+                    let handle = handle.modify(|checkbox| checkbox.intermediate = is_intermediate);
                     if c.get(&handle) {
                         handle.text(ICON_CHECK).toggle_class("intermediate", false);
-                    } else if i.get(&handle) {
+                    } else if is_intermediate {
                         handle.text("-").toggle_class("intermediate", true);
                     } else {
                         handle.text("").toggle_class("intermediate", false);
@@ -223,6 +227,8 @@ impl Checkbox {
                 });
             })
             .checked(checked)
+            .role(Role::CheckBox)
+            .default_action_verb(DefaultActionVerb::Click)
             .navigable(true)
     }
 }
@@ -265,6 +271,12 @@ impl View for Checkbox {
         Some("checkbox")
     }
 
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        if self.intermediate {
+            node.set_toggled(Toggled::Mixed);
+        }
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|window_event, meta| match window_event {
             WindowEvent::PressDown { mouse: _ } => {
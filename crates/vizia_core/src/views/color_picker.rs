@@ -0,0 +1,799 @@
+use crate::prelude::*;
+
+/// The color space of the channels shown in the text fields of a [`ColorPicker`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Data)]
+pub enum ColorPickerMode {
+    /// Red, green and blue channels, from 0 to 255.
+    #[default]
+    Rgb,
+    /// Hue in degrees, with saturation and lightness as percentages.
+    Hsl,
+    /// Perceptual lightness as a percentage, chroma and hue in degrees.
+    Oklch,
+}
+
+impl_res_simple!(ColorPickerMode);
+
+impl ColorPickerMode {
+    fn name(&self) -> &'static str {
+        match self {
+            ColorPickerMode::Rgb => "RGB",
+            ColorPickerMode::Hsl => "HSL",
+            ColorPickerMode::Oklch => "OKLCH",
+        }
+    }
+
+    // Returns the channels of the mode, followed by the alpha channel.
+    fn channels(&self) -> [Channel; 4] {
+        let [first, second, third] = match self {
+            ColorPickerMode::Rgb => [
+                Channel { name: "R", max: 255.0, precision: 0 },
+                Channel { name: "G", max: 255.0, precision: 0 },
+                Channel { name: "B", max: 255.0, precision: 0 },
+            ],
+            ColorPickerMode::Hsl => [
+                Channel { name: "H", max: 360.0, precision: 0 },
+                Channel { name: "S", max: 100.0, precision: 0 },
+                Channel { name: "L", max: 100.0, precision: 0 },
+            ],
+            ColorPickerMode::Oklch => [
+                Channel { name: "L", max: 100.0, precision: 1 },
+                Channel { name: "C", max: 0.4, precision: 3 },
+                Channel { name: "H", max: 360.0, precision: 0 },
+            ],
+        };
+
+        [first, second, third, Channel { name: "A", max: 100.0, precision: 0 }]
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Channel {
+    name: &'static str,
+    max: f32,
+    precision: usize,
+}
+
+impl Channel {
+    // Parses the text of a channel field, which may end with a percent or degree sign.
+    fn parse(&self, text: &str) -> Option<f32> {
+        let value = text.trim().trim_end_matches(['%', '°']).trim_end().parse::<f32>().ok()?;
+        (0.0..=self.max).contains(&value).then_some(value)
+    }
+}
+
+/// Events which can be sent to a [`ColorPicker`].
+pub enum ColorPickerEvent {
+    /// Sets the color shown by the picker, without calling the `on_change` callback.
+    SetColor(Color),
+    /// Sets the color space of the channels shown in the text fields.
+    SetMode(ColorPickerMode),
+    /// Sets the colors of the swatch palette.
+    SetPalette(Vec<Color>),
+}
+
+pub(crate) enum ColorPickerControlEvent {
+    SetSaturationValue(f32, f32),
+    SetHue(f32),
+    SetAlpha(f32),
+    SetChannel(usize, f32),
+    Pick(Color),
+    SetOklchEnabled(bool),
+}
+
+/// A view for choosing a color, with an area for the saturation and value of the color, sliders for its hue and
+/// alpha, text fields for its channels and hex code, and a palette of swatches.
+///
+/// The picker shows the color of the bound lens, and calls the [`on_change`](Handle::on_change) callback when a
+/// color is picked, which should be used to update the bound data. The picker can be shown inline, or within a
+/// [`Popup`].
+///
+/// # Example
+/// ```rust
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     color: Color,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { color: Color::red() }.build(cx);
+/// #
+/// ColorPicker::new(cx, AppData::color)
+///     .mode(ColorPickerMode::Hsl)
+///     .on_change(|cx, color| debug!("Picked {:?}", color));
+/// ```
+#[derive(Lens)]
+pub struct ColorPicker {
+    // The hue in degrees, with the saturation and value of the color from 0 to 1. These are kept separately from
+    // the color so that the hue and saturation aren't lost when picking a gray or black color.
+    hue: f32,
+    saturation: f32,
+    value: f32,
+    alpha: f32,
+    mode: ColorPickerMode,
+    oklch: bool,
+    palette: Vec<Color>,
+    #[lens(ignore)]
+    on_change: Option<Box<dyn Fn(&mut EventContext, Color)>>,
+}
+
+impl ColorPicker {
+    /// Creates a new [`ColorPicker`] showing the color of a lens.
+    pub fn new<L: Lens<Target = Color>>(cx: &mut Context, lens: L) -> Handle<Self> {
+        let mut picker = Self {
+            hue: 0.0,
+            saturation: 0.0,
+            value: 0.0,
+            alpha: 1.0,
+            mode: ColorPickerMode::default(),
+            oklch: false,
+            palette: default_palette(),
+            on_change: None,
+        };
+        picker.set_color(lens.get(cx));
+
+        let mut handle = picker.build(cx, |cx| {
+            XYPad::new(cx, ColorPicker::root.map(|picker| (picker.saturation, picker.value)))
+                .class("color-picker-area")
+                .width(Stretch(1.0))
+                .height(Pixels(150.0))
+                .background_color(ColorPicker::hue.map(|hue| hsv_color(*hue, 1.0, 1.0, 1.0)))
+                .on_change(|cx, saturation, value| {
+                    cx.emit(ColorPickerControlEvent::SetSaturationValue(saturation, value))
+                });
+
+            HStack::new(cx, |cx| {
+                VStack::new(cx, |cx| {
+                    Slider::new(cx, ColorPicker::hue)
+                        .range(0.0..360.0)
+                        .step(1.0)
+                        .class("color-picker-hue")
+                        .on_changing(|cx, hue| cx.emit(ColorPickerControlEvent::SetHue(hue)));
+
+                    Slider::new(cx, ColorPicker::alpha)
+                        .class("color-picker-alpha")
+                        .background_image(
+                            ColorPicker::root.map(|picker| alpha_gradient(picker.color())),
+                        )
+                        .on_changing(|cx, alpha| cx.emit(ColorPickerControlEvent::SetAlpha(alpha)));
+                })
+                .class("color-picker-sliders");
+
+                Element::new(cx)
+                    .class("color-picker-preview")
+                    .background_color(ColorPicker::root.map(|picker| picker.color()));
+            })
+            .class("color-picker-row");
+
+            HStack::new(cx, |cx| {
+                Binding::new(cx, ColorPicker::oklch, |cx, oklch| {
+                    let modes: &[ColorPickerMode] = if oklch.get(cx) {
+                        &[ColorPickerMode::Rgb, ColorPickerMode::Hsl, ColorPickerMode::Oklch]
+                    } else {
+                        &[ColorPickerMode::Rgb, ColorPickerMode::Hsl]
+                    };
+
+                    for mode in modes.iter().copied() {
+                        Button::new(cx, |cx| Label::new(cx, mode.name()))
+                            .checked(ColorPicker::mode.map(move |selected| *selected == mode))
+                            .on_press(move |cx| cx.emit(ColorPickerEvent::SetMode(mode)));
+                    }
+                });
+            })
+            .class("color-picker-modes");
+
+            HStack::new(cx, |cx| {
+                Binding::new(cx, ColorPicker::mode, |cx, mode| {
+                    let mode = mode.get(cx);
+                    for (index, channel) in mode.channels().into_iter().enumerate() {
+                        Self::build_channel_field(cx, index, channel);
+                    }
+                });
+            })
+            .class("color-picker-channels");
+
+            VStack::new(cx, |cx| {
+                Textbox::new(cx, ColorPicker::root.map(|picker| format_hex(picker.color())))
+                    .validate(|text: &String| parse_hex(text).is_some())
+                    .on_submit(|cx, text, _| {
+                        if let Some(color) = parse_hex(&text) {
+                            cx.emit(ColorPickerControlEvent::Pick(color));
+                        }
+                    });
+                Label::new(cx, "Hex");
+            })
+            .class("color-picker-field")
+            .class("color-picker-hex");
+
+            HStack::new(cx, |cx| {
+                Binding::new(cx, ColorPicker::palette, |cx, palette| {
+                    for color in palette.get(cx) {
+                        Element::new(cx)
+                            .class("color-picker-swatch")
+                            .background_color(color)
+                            .checked(ColorPicker::root.map(move |picker| picker.color() == color))
+                            .navigable(true)
+                            .role(Role::Button)
+                            .on_press(move |cx| cx.emit(ColorPickerControlEvent::Pick(color)));
+                    }
+                });
+            })
+            .class("color-picker-palette");
+        });
+
+        let entity = handle.entity();
+        lens.set_or_bind(handle.context(), entity, |cx, color| {
+            let color = color.get(cx);
+            cx.emit(ColorPickerEvent::SetColor(color));
+        });
+
+        handle
+    }
+
+    fn build_channel_field(cx: &mut Context, index: usize, channel: Channel) {
+        VStack::new(cx, |cx| {
+            Textbox::new(cx, ColorPicker::root.map(move |picker| picker.channel_text(index)))
+                .validate(move |text: &String| channel.parse(text).is_some())
+                .on_submit(move |cx, text, _| {
+                    if let Some(value) = channel.parse(&text) {
+                        cx.emit(ColorPickerControlEvent::SetChannel(index, value));
+                    }
+                });
+            Label::new(cx, channel.name);
+        })
+        .class("color-picker-field");
+    }
+
+    fn color(&self) -> Color {
+        hsv_color(self.hue, self.saturation, self.value, self.alpha)
+    }
+
+    // Sets the color, keeping the hue of gray colors and the saturation of black colors.
+    fn set_color(&mut self, color: Color) {
+        let rgb = [color.r(), color.g(), color.b()].map(|channel| channel as f32 / 255.0);
+        self.set_rgb(rgb);
+        self.alpha = color.a() as f32 / 255.0;
+    }
+
+    fn set_rgb(&mut self, rgb: [f32; 3]) {
+        let [hue, saturation, value] = rgb_to_hsv(rgb);
+        if value > 0.0 {
+            if saturation > 0.0 {
+                self.hue = hue;
+            }
+            self.saturation = saturation;
+        }
+        self.value = value;
+    }
+
+    fn channel_value(&self, index: usize) -> f32 {
+        if index == 3 {
+            return self.alpha * 100.0;
+        }
+
+        let rgb = hsv_to_rgb([self.hue, self.saturation, self.value]);
+        match self.mode {
+            ColorPickerMode::Rgb => rgb[index] * 255.0,
+            ColorPickerMode::Hsl => {
+                let [hue, saturation, lightness] =
+                    hsv_to_hsl([self.hue, self.saturation, self.value]);
+                [hue, saturation * 100.0, lightness * 100.0][index]
+            }
+            ColorPickerMode::Oklch => {
+                let [lightness, chroma, hue] = rgb_to_oklch(rgb);
+                [lightness * 100.0, chroma, hue][index]
+            }
+        }
+    }
+
+    fn channel_text(&self, index: usize) -> String {
+        let channel = self.mode.channels()[index];
+        // Round the value to avoid showing negative zero.
+        let scale = 10f32.powi(channel.precision as i32);
+        let value = (self.channel_value(index) * scale).round() / scale + 0.0;
+        format!("{:.*}", channel.precision, value)
+    }
+
+    fn set_channel(&mut self, index: usize, value: f32) {
+        if index == 3 {
+            self.alpha = value / 100.0;
+            return;
+        }
+
+        match self.mode {
+            ColorPickerMode::Rgb => {
+                let mut rgb = hsv_to_rgb([self.hue, self.saturation, self.value]);
+                rgb[index] = value / 255.0;
+                self.set_rgb(rgb);
+            }
+
+            ColorPickerMode::Hsl => {
+                let mut hsl = hsv_to_hsl([self.hue, self.saturation, self.value]);
+                hsl[index] = if index == 0 { value % 360.0 } else { value / 100.0 };
+                [self.hue, self.saturation, self.value] = hsl_to_hsv(hsl);
+            }
+
+            ColorPickerMode::Oklch => {
+                let mut lch = rgb_to_oklch(hsv_to_rgb([self.hue, self.saturation, self.value]));
+                lch[index] = if index == 0 { value / 100.0 } else { value };
+                self.set_rgb(oklch_to_rgb(lch));
+            }
+        }
+    }
+
+    fn notify_change(&self, cx: &mut EventContext) {
+        if let Some(callback) = &self.on_change {
+            (callback)(cx, self.color());
+        }
+    }
+}
+
+impl View for ColorPicker {
+    fn element(&self) -> Option<&'static str> {
+        Some("colorpicker")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|picker_event, meta| {
+            match picker_event {
+                ColorPickerEvent::SetColor(color) => {
+                    if *color != self.color() {
+                        self.set_color(*color);
+                    }
+                }
+
+                ColorPickerEvent::SetMode(mode) => self.mode = *mode,
+
+                ColorPickerEvent::SetPalette(palette) => self.palette.clone_from(palette),
+            }
+            meta.consume();
+        });
+
+        event.map(|control_event, meta| {
+            match control_event {
+                ColorPickerControlEvent::SetSaturationValue(saturation, value) => {
+                    self.saturation = *saturation;
+                    self.value = *value;
+                }
+
+                ColorPickerControlEvent::SetHue(hue) => self.hue = *hue % 360.0,
+
+                ColorPickerControlEvent::SetAlpha(alpha) => self.alpha = *alpha,
+
+                ColorPickerControlEvent::SetChannel(index, value) => {
+                    self.set_channel(*index, *value)
+                }
+
+                ColorPickerControlEvent::Pick(color) => self.set_color(*color),
+
+                ColorPickerControlEvent::SetOklchEnabled(enabled) => {
+                    self.oklch = *enabled;
+                    meta.consume();
+                    return;
+                }
+            }
+
+            self.notify_change(cx);
+            meta.consume();
+        });
+    }
+}
+
+impl Handle<'_, ColorPicker> {
+    /// Sets the callback which is called with the picked color when the user changes the color.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Color),
+    {
+        self.modify(|picker: &mut ColorPicker| picker.on_change = Some(Box::new(callback)))
+    }
+
+    /// Sets the color space of the channels shown in the text fields. Defaults to [`ColorPickerMode::Rgb`].
+    pub fn mode(mut self, mode: impl Res<ColorPickerMode>) -> Self {
+        let entity = self.entity();
+        mode.set_or_bind(self.context(), entity, |cx, mode| {
+            let mode = mode.get(cx);
+            cx.emit(ColorPickerEvent::SetMode(mode));
+        });
+
+        self
+    }
+
+    /// Sets whether the user can choose to show the channels of the color in the OKLCH color space.
+    pub fn oklch(mut self, enabled: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        enabled.set_or_bind(self.context(), entity, |cx, enabled| {
+            let enabled = enabled.get(cx);
+            cx.emit(ColorPickerControlEvent::SetOklchEnabled(enabled));
+        });
+
+        self
+    }
+
+    /// Sets the colors of the swatch palette, which can be pressed to pick the color.
+    pub fn palette(mut self, palette: impl Res<Vec<Color>>) -> Self {
+        let entity = self.entity();
+        palette.set_or_bind(self.context(), entity, |cx, palette| {
+            let palette = palette.get(cx);
+            cx.emit(ColorPickerEvent::SetPalette(palette));
+        });
+
+        self
+    }
+}
+
+fn default_palette() -> Vec<Color> {
+    vec![
+        Color::rgb(0, 0, 0),
+        Color::rgb(128, 128, 128),
+        Color::rgb(255, 255, 255),
+        Color::rgb(244, 67, 54),
+        Color::rgb(255, 152, 0),
+        Color::rgb(255, 235, 59),
+        Color::rgb(76, 175, 80),
+        Color::rgb(0, 150, 136),
+        Color::rgb(33, 150, 243),
+        Color::rgb(103, 58, 183),
+        Color::rgb(233, 30, 99),
+    ]
+}
+
+fn hsv_color(hue: f32, saturation: f32, value: f32, alpha: f32) -> Color {
+    let [r, g, b] = hsv_to_rgb([hue, saturation, value]).map(to_byte);
+    Color::rgba(r, g, b, to_byte(alpha))
+}
+
+fn to_byte(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+// Returns a gradient from transparent to the opaque color, for the track of the alpha slider.
+fn alpha_gradient(color: Color) -> Gradient {
+    let opaque = Color::rgb(color.r(), color.g(), color.b());
+    let transparent = Color::rgba(color.r(), color.g(), color.b(), 0);
+    Gradient::from(
+        format!("linear-gradient(to right, {}, {})", format_hex(transparent), format_hex(opaque))
+            .as_str(),
+    )
+}
+
+/// Formats a color as a hex code, which includes the alpha channel if the color isn't opaque.
+fn format_hex(color: Color) -> String {
+    let hex = format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b());
+    if color.a() == 255 {
+        hex
+    } else {
+        format!("{}{:02x}", hex, color.a())
+    }
+}
+
+// Parses a hex code with 3, 4, 6 or 8 digits, with or without a leading `#`.
+fn parse_hex(text: &str) -> Option<Color> {
+    let text = text.trim();
+    let digits = text.strip_prefix('#').unwrap_or(text);
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |index: usize, len: usize| {
+        let value = u8::from_str_radix(&digits[index * len..(index + 1) * len], 16).ok()?;
+        Some(if len == 1 { value * 17 } else { value })
+    };
+
+    let len = match digits.len() {
+        3 | 4 => 1,
+        6 | 8 => 2,
+        _ => return None,
+    };
+    let alpha = if digits.len() == 4 * len { channel(3, len)? } else { 255 };
+
+    Some(Color::rgba(channel(0, len)?, channel(1, len)?, channel(2, len)?, alpha))
+}
+
+// The conversions below use hue in degrees, and all other channels from 0 to 1.
+
+fn hsv_to_rgb([hue, saturation, value]: [f32; 3]) -> [f32; 3] {
+    let channel = |n: f32| {
+        let k = (n + hue / 60.0) % 6.0;
+        value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+
+    [channel(5.0), channel(3.0), channel(1.0)]
+}
+
+fn rgb_to_hsv([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+
+    let saturation = if max > 0.0 { delta / max } else { 0.0 };
+
+    [hue, saturation, max]
+}
+
+fn hsv_to_hsl([hue, saturation, value]: [f32; 3]) -> [f32; 3] {
+    let lightness = value * (1.0 - saturation / 2.0);
+    let min = lightness.min(1.0 - lightness);
+    let saturation = if min > 0.0 { (value - lightness) / min } else { 0.0 };
+
+    [hue, saturation, lightness]
+}
+
+fn hsl_to_hsv([hue, saturation, lightness]: [f32; 3]) -> [f32; 3] {
+    let value = lightness + saturation * lightness.min(1.0 - lightness);
+    let saturation = if value > 0.0 { 2.0 * (1.0 - lightness / value) } else { 0.0 };
+
+    [hue, saturation, value]
+}
+
+// Converts sRGB to OKLCH, which is the polar form of the OKLab color space described at
+// https://bottosson.github.io/posts/oklab/.
+fn rgb_to_oklch(rgb: [f32; 3]) -> [f32; 3] {
+    let [r, g, b] = rgb.map(|channel| {
+        let channel = channel as f64;
+        if channel <= 0.04045 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    });
+
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    let lightness = 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s;
+    let a = 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s;
+    let b = 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s;
+
+    let chroma = a.hypot(b);
+    let hue = if chroma < 1e-4 { 0.0 } else { b.atan2(a).to_degrees().rem_euclid(360.0) };
+
+    [lightness as f32, chroma as f32, hue as f32]
+}
+
+// Converts OKLCH to sRGB, clipping colors which are outside of the sRGB gamut.
+fn oklch_to_rgb([lightness, chroma, hue]: [f32; 3]) -> [f32; 3] {
+    let (lightness, chroma, hue) = (lightness as f64, chroma as f64, (hue as f64).to_radians());
+    let (a, b) = (chroma * hue.cos(), chroma * hue.sin());
+
+    let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    let rgb = [
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    ];
+
+    rgb.map(|channel| {
+        let channel = if channel <= 0.0031308 {
+            12.92 * channel
+        } else {
+            1.055 * channel.powf(1.0 / 2.4) - 0.055
+        };
+        channel.clamp(0.0, 1.0) as f32
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    fn assert_close(a: [f32; 3], b: [f32; 3], epsilon: f32) {
+        for (a, b) in a.into_iter().zip(b) {
+            assert!((a - b).abs() < epsilon, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[derive(Lens)]
+    struct TestData {
+        color: Color,
+    }
+
+    enum TestEvent {
+        SetColor(Color),
+    }
+
+    impl Model for TestData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|TestEvent::SetColor(color), _| self.color = *color);
+        }
+    }
+
+    // Builds a picker which is bound to the color of a model and updates it when a color is picked.
+    fn context(color: Color) -> (Context, Entity) {
+        let mut cx = Context::default();
+        TestData { color }.build(&mut cx);
+        let picker = ColorPicker::new(&mut cx, TestData::color)
+            .oklch(true)
+            .palette(vec![Color::rgb(255, 0, 0), Color::rgb(0, 0, 255)])
+            .on_change(|cx, color| cx.emit(TestEvent::SetColor(color)))
+            .entity();
+        flush(&mut cx);
+        (cx, picker)
+    }
+
+    fn flush(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    fn emit(cx: &mut Context, target: Entity, event: impl Send + 'static) {
+        cx.emit_to(target, event);
+        flush(cx);
+    }
+
+    fn press(cx: &mut Context, entity: Entity) {
+        cx.focused = entity;
+        cx.emit_custom(
+            Event::new(WindowEvent::Press { mouse: false })
+                .target(entity)
+                .origin(entity)
+                .propagate(Propagation::Direct),
+        );
+        flush(cx);
+    }
+
+    fn picker_of(cx: &Context, picker: Entity) -> &ColorPicker {
+        cx.views.get(&picker).and_then(|view| view.downcast_ref()).unwrap()
+    }
+
+    fn model_color(cx: &Context) -> Color {
+        cx.data::<TestData>().unwrap().color
+    }
+
+    fn mode_button(cx: &Context, mode: ColorPickerMode) -> Entity {
+        Entity::root()
+            .tree_iter(&cx.tree)
+            .find(|entity| {
+                cx.views.get(entity).is_some_and(|view| view.downcast_ref::<Button>().is_some())
+                    && entity
+                        .branch_iter(&cx.tree)
+                        .any(|child| cx.style.text.get(child).is_some_and(|t| t == mode.name()))
+            })
+            .unwrap()
+    }
+
+    fn swatches(cx: &Context) -> Vec<Entity> {
+        Entity::root()
+            .tree_iter(&cx.tree)
+            .filter(|entity| {
+                cx.style.classes.get(*entity).is_some_and(|c| c.contains("color-picker-swatch"))
+            })
+            .collect()
+    }
+
+    fn is_checked(cx: &Context, entity: Entity) -> bool {
+        cx.style
+            .pseudo_classes
+            .get(entity)
+            .is_some_and(|pseudo_classes| pseudo_classes.contains(PseudoClassFlags::CHECKED))
+    }
+
+    #[test]
+    fn hsv_and_hsl() {
+        assert_close(hsv_to_rgb([0.0, 1.0, 1.0]), [1.0, 0.0, 0.0], 1e-6);
+        assert_close(hsv_to_rgb([120.0, 1.0, 0.5]), [0.0, 0.5, 0.0], 1e-6);
+        assert_close(hsv_to_rgb([210.0, 0.5, 1.0]), [0.5, 0.75, 1.0], 1e-6);
+        assert_close(rgb_to_hsv([0.5, 0.75, 1.0]), [210.0, 0.5, 1.0], 1e-4);
+        assert_close(rgb_to_hsv([1.0, 0.0, 0.5]), [330.0, 1.0, 1.0], 1e-4);
+
+        assert_close(hsv_to_hsl([0.0, 1.0, 1.0]), [0.0, 1.0, 0.5], 1e-6);
+        assert_close(hsv_to_hsl([0.0, 0.0, 1.0]), [0.0, 0.0, 1.0], 1e-6);
+        assert_close(hsl_to_hsv([210.0, 1.0, 0.75]), [210.0, 0.5, 1.0], 1e-6);
+        assert_close(hsl_to_hsv(hsv_to_hsl([40.0, 0.3, 0.6])), [40.0, 0.3, 0.6], 1e-6);
+    }
+
+    #[test]
+    fn oklch() {
+        assert_close(rgb_to_oklch([1.0, 1.0, 1.0]), [1.0, 0.0, 0.0], 1e-3);
+        assert_close(rgb_to_oklch([1.0, 0.0, 0.0]), [0.62796, 0.25768, 29.2339], 1e-3);
+        assert_close(oklch_to_rgb([0.62796, 0.25768, 29.2339]), [1.0, 0.0, 0.0], 1e-2);
+        assert_close(oklch_to_rgb(rgb_to_oklch([0.2, 0.4, 0.6])), [0.2, 0.4, 0.6], 1e-4);
+
+        // Colors outside of the sRGB gamut are clipped.
+        assert!(oklch_to_rgb([0.5, 0.4, 150.0]).iter().all(|c| (0.0..=1.0).contains(c)));
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(parse_hex("#51afef"), Some(Color::rgb(81, 175, 239)));
+        assert_eq!(parse_hex(" 51AFEF "), Some(Color::rgb(81, 175, 239)));
+        assert_eq!(parse_hex("#f80"), Some(Color::rgb(255, 136, 0)));
+        assert_eq!(parse_hex("#f808"), Some(Color::rgba(255, 136, 0, 136)));
+        assert_eq!(parse_hex("#51afef80"), Some(Color::rgba(81, 175, 239, 128)));
+        assert_eq!(parse_hex("#51afe"), None);
+        assert_eq!(parse_hex("#51afeg"), None);
+        assert_eq!(parse_hex("#+1afef"), None);
+
+        assert_eq!(format_hex(Color::rgb(81, 175, 239)), "#51afef");
+        assert_eq!(format_hex(Color::rgba(81, 175, 239, 128)), "#51afef80");
+    }
+
+    #[test]
+    fn set_color_keeps_hue() {
+        let (mut cx, picker) = context(Color::rgb(255, 0, 0));
+        assert_eq!(picker_of(&cx, picker).color(), Color::rgb(255, 0, 0));
+
+        emit(&mut cx, Entity::root(), TestEvent::SetColor(Color::rgb(128, 128, 128)));
+        assert_eq!((picker_of(&cx, picker).hue, picker_of(&cx, picker).saturation), (0.0, 0.0));
+
+        emit(&mut cx, picker, ColorPickerControlEvent::SetHue(120.0));
+        emit(&mut cx, picker, ColorPickerControlEvent::SetSaturationValue(0.5, 0.5));
+        emit(&mut cx, Entity::root(), TestEvent::SetColor(Color::rgba(0, 0, 0, 51)));
+        let picker_state = picker_of(&cx, picker);
+        assert_eq!(
+            (picker_state.hue, picker_state.saturation, picker_state.value),
+            (120.0, 0.5, 0.0)
+        );
+        assert_eq!(picker_state.alpha, 0.2);
+
+        // Raising the value of black restores the hue and saturation.
+        emit(&mut cx, picker, ColorPickerControlEvent::SetSaturationValue(0.5, 1.0));
+        assert_eq!(model_color(&cx), Color::rgba(128, 255, 128, 51));
+    }
+
+    #[test]
+    fn channels() {
+        let (mut cx, picker) = context(Color::rgba(51, 102, 153, 128));
+        assert_eq!(picker_of(&cx, picker).channel_text(0), "51");
+        assert_eq!(picker_of(&cx, picker).channel_text(2), "153");
+        assert_eq!(picker_of(&cx, picker).channel_text(3), "50");
+
+        emit(&mut cx, picker, ColorPickerControlEvent::SetChannel(1, 0.0));
+        assert_eq!(model_color(&cx), Color::rgba(51, 0, 153, 128));
+
+        press(&mut cx, mode_button(&cx, ColorPickerMode::Hsl));
+        assert_eq!(picker_of(&cx, picker).mode, ColorPickerMode::Hsl);
+        assert_eq!(picker_of(&cx, picker).channel_text(0), "260");
+        assert_eq!(picker_of(&cx, picker).channel_text(1), "100");
+        assert_eq!(picker_of(&cx, picker).channel_text(2), "30");
+
+        emit(&mut cx, picker, ColorPickerControlEvent::SetChannel(2, 100.0));
+        assert_eq!(model_color(&cx), Color::rgba(255, 255, 255, 128));
+        // The hue of white is kept.
+        assert_eq!(picker_of(&cx, picker).channel_text(0), "260");
+
+        press(&mut cx, mode_button(&cx, ColorPickerMode::Oklch));
+        emit(&mut cx, Entity::root(), TestEvent::SetColor(Color::rgb(255, 0, 0)));
+        assert_eq!(picker_of(&cx, picker).channel_text(0), "62.8");
+        assert_eq!(picker_of(&cx, picker).channel_text(1), "0.258");
+        assert_eq!(picker_of(&cx, picker).channel_text(2), "29");
+
+        let channel = ColorPickerMode::Hsl.channels()[0];
+        assert_eq!(channel.parse(" 120° "), Some(120.0));
+        assert_eq!(channel.parse("361"), None);
+        assert_eq!(ColorPickerMode::Hsl.channels()[1].parse("50%"), Some(50.0));
+    }
+
+    #[test]
+    fn pressing_swatch_picks_color() {
+        let (mut cx, _) = context(Color::rgb(255, 0, 0));
+        let swatches = swatches(&cx);
+        assert_eq!(swatches.len(), 2);
+        assert!(is_checked(&cx, swatches[0]));
+
+        press(&mut cx, swatches[1]);
+        assert_eq!(model_color(&cx), Color::rgb(0, 0, 255));
+        assert!(!is_checked(&cx, swatches[0]));
+        assert!(is_checked(&cx, swatches[1]));
+    }
+}
@@ -124,6 +124,7 @@ where
                                                     selected
                                                         .map(move |selected| *selected == index),
                                                 )
+                                                .role(Role::ListBoxOption)
                                                 .navigable(true)
                                                 .toggle_class(
                                                     "nav",
@@ -138,6 +139,7 @@ where
                                         }
                                     })
                                     .height(Auto)
+                                    .role(Role::ListBox)
                                     .class("list");
                                 })
                                 .height(Auto);
@@ -154,6 +156,7 @@ where
             let selected_item = list_lens.idx(selected.get(&handle)).get(&handle);
             handle.modify(|combobox| combobox.placeholder = selected_item.to_string());
         })
+        .role(Role::ComboBox)
     }
 }
 
@@ -167,12 +170,25 @@ where
         Some("combobox")
     }
 
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_value(self.placeholder.as_str());
+        node.set_expanded(self.is_open);
+        if self.is_open {
+            node.add_action(Action::Collapse);
+        } else {
+            node.add_action(Action::Expand);
+        }
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        let was_open = self.is_open;
+
         event.map(|combobox_event, _| match combobox_event {
             ComboBoxEvent::SetOption(index) => {
                 // Set the placeholder text to the selected item.
                 let selected_item = self.list_lens.idx(*index).get(cx);
                 self.placeholder = selected_item.to_string();
+                cx.needs_access_update();
 
                 // Call the on_select callback.
                 if let Some(callback) = &self.on_select {
@@ -204,6 +220,7 @@ where
             ComboBoxEvent::SetFilterText(text) => {
                 self.placeholder.clone_from(text);
                 self.filter_text.clone_from(text);
+                cx.needs_access_update();
 
                 // Reopen the popup in case it was closed with the ESC key.
                 self.is_open = true;
@@ -340,8 +357,26 @@ where
                 _ => {}
             },
 
+            WindowEvent::ActionRequest(action) => match action.action {
+                Action::Expand => {
+                    self.is_open = true;
+                    self.hovered = self.selected.get(cx);
+                }
+
+                Action::Collapse => {
+                    self.is_open = false;
+                    self.hovered = self.selected.get(cx);
+                }
+
+                _ => {}
+            },
+
             _ => {}
         });
+
+        if self.is_open != was_open {
+            cx.needs_access_update();
+        }
     }
 }
 
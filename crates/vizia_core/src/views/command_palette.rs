@@ -0,0 +1,735 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use crate::prelude::*;
+
+// The number of recently executed commands which are shown first in the palette.
+const MAX_RECENT: usize = 8;
+// The number of results shown at once, which are scrolled to keep the selected result visible.
+const MAX_VISIBLE: usize = 10;
+
+type CommandAction = Arc<dyn Fn(&mut EventContext) + Send + Sync>;
+
+/// An action which can be found and executed from the [`CommandPalette`] of a window.
+///
+/// Commands are registered with [`Command::register`], or by emitting [`CommandEvent::Register`] from any view within
+/// the window. A command stays registered until it's unregistered with [`CommandEvent::Unregister`], and registering a
+/// command with the same id replaces it.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     has_changes: bool,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # enum AppEvent {
+/// #     Save,
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { has_changes: false }.build(cx);
+/// #
+/// CommandPalette::new(cx);
+///
+/// Command::new("save", "Save", |cx| cx.emit(AppEvent::Save))
+///     .category("File")
+///     .register(cx, AppData::has_changes);
+/// ```
+#[derive(Clone)]
+pub struct Command {
+    id: String,
+    label: String,
+    category: Option<String>,
+    action: CommandAction,
+}
+
+impl Command {
+    /// Creates a new command with an id, which identifies the command, a label shown in the palette, and the action
+    /// called when the command is executed.
+    pub fn new(
+        id: impl Into<String>,
+        label: impl Into<String>,
+        action: impl 'static + Fn(&mut EventContext) + Send + Sync,
+    ) -> Self {
+        Self { id: id.into(), label: label.into(), category: None, action: Arc::new(action) }
+    }
+
+    /// Sets the category of the command, which is shown before its label and can be matched by the query.
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+
+        self
+    }
+
+    /// Registers the command with the [`CommandPalette`] of the window. The command is only shown and executed while
+    /// it's enabled, which can be bound to a lens.
+    pub fn register(self, cx: &mut Context, enabled: impl Res<bool>) {
+        let id = self.id.clone();
+        let entity = cx.current();
+        cx.emit(CommandEvent::Register(self));
+        enabled.set_or_bind(cx, entity, move |cx, enabled| {
+            let enabled = enabled.get(cx);
+            cx.emit(CommandEvent::SetEnabled(id.clone(), enabled));
+        });
+    }
+
+    // Returns the text shown in the palette, which is matched against the query.
+    fn title(&self) -> String {
+        match &self.category {
+            Some(category) => format!("{}: {}", category, self.label),
+            None => self.label.clone(),
+        }
+    }
+}
+
+/// Creates a command which calls the callback of a keymap entry, using the name of its action as the id and label
+/// of the command, so that the shortcut of the action is shown when the palette is given the keymap with
+/// [`keymap`](Handle::keymap).
+impl<T> From<KeymapEntry<T>> for Command
+where
+    T: 'static + Clone + PartialEq + Send + Sync + Display,
+{
+    fn from(entry: KeymapEntry<T>) -> Self {
+        let name = entry.action().to_string();
+        let on_action = *entry.on_action();
+        Command::new(name.clone(), name, move |cx| (on_action)(cx))
+    }
+}
+
+/// Events which can be emitted by any view within a window to change the commands of its [`CommandPalette`].
+pub enum CommandEvent {
+    /// Registers a command, or replaces the command with the same id. The command is enabled when registered.
+    Register(Command),
+    /// Unregisters the command with the given id.
+    Unregister(String),
+    /// Sets whether the command with the given id is enabled.
+    SetEnabled(String, bool),
+    /// Executes the command with the given id, if it's enabled.
+    Execute(String),
+    /// Opens the command palette.
+    OpenPalette,
+    /// Closes the command palette.
+    ClosePalette,
+    /// Opens the command palette, or closes it if it's open.
+    TogglePalette,
+}
+
+pub(crate) enum CommandPaletteEvent {
+    SetQuery(String),
+    Select(usize),
+    SelectNext,
+    SelectPrevious,
+    Scroll(isize),
+    ExecuteSelected,
+    SetShortcut(KeyChord),
+    SetShortcuts(Vec<(String, Option<String>)>),
+}
+
+#[derive(Debug, Clone, PartialEq, Data)]
+struct CommandResult {
+    id: String,
+    title: String,
+    // The indices of the characters of the title matched by the query.
+    matched: Vec<usize>,
+    shortcut: Option<String>,
+    is_recent: bool,
+}
+
+struct RegisteredCommand {
+    command: Command,
+    enabled: bool,
+}
+
+/// The model, built at the root of a window by a [`CommandPalette`], which holds the commands of the window.
+#[derive(Lens)]
+pub struct CommandRegistry {
+    is_open: bool,
+    query: String,
+    results: Vec<CommandResult>,
+    selected: usize,
+    offset: usize,
+    #[lens(ignore)]
+    commands: Vec<RegisteredCommand>,
+    // The ids of the recently executed commands, the most recent first.
+    #[lens(ignore)]
+    recent: Vec<String>,
+    // The first key sequence bound to the action with the name of each command id.
+    #[lens(ignore)]
+    shortcuts: Vec<(String, String)>,
+    #[lens(ignore)]
+    shortcut: KeyChord,
+}
+
+impl CommandRegistry {
+    fn new() -> Self {
+        Self {
+            is_open: false,
+            query: String::new(),
+            results: Vec::new(),
+            selected: 0,
+            offset: 0,
+            commands: Vec::new(),
+            recent: Vec::new(),
+            shortcuts: Vec::new(),
+            shortcut: KeyChord::new(Modifiers::PRIMARY | Modifiers::SHIFT, Code::KeyP),
+        }
+    }
+
+    fn register(&mut self, command: Command) {
+        match self.commands.iter_mut().find(|registered| registered.command.id == command.id) {
+            Some(registered) => registered.command = command,
+            None => self.commands.push(RegisteredCommand { command, enabled: true }),
+        }
+    }
+
+    fn set_enabled(&mut self, id: &str, enabled: bool) {
+        if let Some(registered) = self.commands.iter_mut().find(|r| r.command.id == id) {
+            registered.enabled = enabled;
+        }
+    }
+
+    fn open(&mut self) {
+        self.is_open = true;
+        self.query.clear();
+        self.selected = 0;
+        self.update_results();
+    }
+
+    // Returns the action of an enabled command, and moves the command to the front of the recent commands.
+    fn execute(&mut self, id: &str) -> Option<CommandAction> {
+        let registered = self.commands.iter().find(|r| r.command.id == id && r.enabled)?;
+        let action = registered.command.action.clone();
+
+        self.recent.retain(|recent| recent != id);
+        self.recent.insert(0, id.to_owned());
+        self.recent.truncate(MAX_RECENT);
+        self.is_open = false;
+
+        Some(action)
+    }
+
+    fn update_results(&mut self) {
+        let query = self.query.trim();
+
+        let mut results = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter(|(_, registered)| registered.enabled)
+            .filter_map(|(index, registered)| {
+                let command = &registered.command;
+                let title = command.title();
+                let (score, matched) = fuzzy_match(query, &title)?;
+                let recent = self.recent.iter().position(|id| *id == command.id);
+                let shortcut = self
+                    .shortcuts
+                    .iter()
+                    .find(|(id, _)| *id == command.id)
+                    .map(|(_, shortcut)| shortcut.clone());
+
+                let result = CommandResult {
+                    id: command.id.clone(),
+                    title,
+                    matched,
+                    shortcut,
+                    is_recent: recent.is_some(),
+                };
+
+                Some(((score, recent.unwrap_or(usize::MAX), index), result))
+            })
+            .collect::<Vec<_>>();
+
+        // Better matches come first, then the most recently executed commands, then the order of registration.
+        results.sort_by(|((a_score, a_recent, a_index), _), ((b_score, b_recent, b_index), _)| {
+            b_score.cmp(a_score).then(a_recent.cmp(b_recent)).then(a_index.cmp(b_index))
+        });
+
+        self.results = results.into_iter().map(|(_, result)| result).collect();
+        self.select(self.selected.min(self.results.len().saturating_sub(1)));
+    }
+
+    // Selects a result, and scrolls the shown results to keep it visible.
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if self.selected >= self.offset + MAX_VISIBLE {
+            self.offset = self.selected + 1 - MAX_VISIBLE;
+        }
+
+        self.offset = self.offset.min(self.results.len().saturating_sub(MAX_VISIBLE));
+    }
+
+    // Returns the shown results with their indices.
+    fn visible_results(&self) -> Vec<(usize, CommandResult)> {
+        self.results.iter().cloned().enumerate().skip(self.offset).take(MAX_VISIBLE).collect()
+    }
+}
+
+impl Model for CommandRegistry {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|command_event, meta| {
+            match command_event {
+                CommandEvent::Register(command) => self.register(command.clone()),
+
+                CommandEvent::Unregister(id) => {
+                    self.commands.retain(|registered| registered.command.id != *id);
+                    self.recent.retain(|recent| recent != id);
+                }
+
+                CommandEvent::SetEnabled(id, enabled) => self.set_enabled(id, *enabled),
+
+                CommandEvent::Execute(id) => {
+                    if let Some(action) = self.execute(id) {
+                        (action)(cx);
+                    }
+                }
+
+                CommandEvent::OpenPalette => self.open(),
+
+                CommandEvent::ClosePalette => self.is_open = false,
+
+                CommandEvent::TogglePalette => {
+                    if self.is_open {
+                        self.is_open = false;
+                    } else {
+                        self.open();
+                    }
+                }
+            }
+
+            if self.is_open {
+                self.update_results();
+            }
+
+            meta.consume();
+        });
+
+        event.map(|palette_event, meta| {
+            match palette_event {
+                CommandPaletteEvent::SetQuery(query) => {
+                    self.query = query.clone();
+                    self.selected = 0;
+                    self.update_results();
+                }
+
+                CommandPaletteEvent::Select(index) => {
+                    if *index < self.results.len() {
+                        self.selected = *index;
+                    }
+                }
+
+                CommandPaletteEvent::SelectNext => {
+                    if !self.results.is_empty() {
+                        self.select((self.selected + 1) % self.results.len());
+                    }
+                }
+
+                CommandPaletteEvent::SelectPrevious => {
+                    if !self.results.is_empty() {
+                        let len = self.results.len();
+                        self.select((self.selected + len - 1) % len);
+                    }
+                }
+
+                CommandPaletteEvent::Scroll(delta) => {
+                    let max_offset = self.results.len().saturating_sub(MAX_VISIBLE);
+                    self.offset = self.offset.saturating_add_signed(*delta).min(max_offset);
+                }
+
+                CommandPaletteEvent::ExecuteSelected => {
+                    if let Some(id) = self.results.get(self.selected).map(|r| r.id.clone()) {
+                        if let Some(action) = self.execute(&id) {
+                            (action)(cx);
+                        }
+                    }
+                }
+
+                CommandPaletteEvent::SetShortcut(shortcut) => self.shortcut = *shortcut,
+
+                CommandPaletteEvent::SetShortcuts(shortcuts) => {
+                    for (id, shortcut) in shortcuts {
+                        self.shortcuts.retain(|(other, _)| other != id);
+                        if let Some(shortcut) = shortcut {
+                            self.shortcuts.push((id.clone(), shortcut.clone()));
+                        }
+                    }
+
+                    self.update_results();
+                }
+            }
+
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| {
+            if let WindowEvent::KeyDown(code, _) = window_event {
+                if KeyChord::new(*cx.modifiers, *code) == self.shortcut {
+                    cx.emit(CommandEvent::TogglePalette);
+                    meta.consume();
+                }
+            }
+        });
+    }
+}
+
+/// An overlay which lets the user search for and execute the registered [`Command`]s of a window.
+///
+/// The palette is opened with <kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>P</kbd>, or
+/// <kbd>Cmd</kbd>+<kbd>Shift</kbd>+<kbd>P</kbd> on macOS, or with [`CommandEvent::OpenPalette`]. The commands are fuzzy
+/// matched against the typed query, and the recently executed commands are shown first. The results are selected with the arrow keys and executed with
+/// <kbd>Enter</kbd>, while <kbd>Escape</kbd> closes the palette.
+///
+/// The palette builds the model which holds the commands in the current view, so it should be added to the root of
+/// a window for any view within the window to be able to register commands.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Debug, Clone, Copy, PartialEq, Data)]
+/// # enum Action {
+/// #     Save,
+/// # }
+/// #
+/// # impl std::fmt::Display for Action {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         write!(f, "{:?}", self)
+/// #     }
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// let entry = KeymapEntry::new(Action::Save, |_| debug!("Save"));
+/// Keymap::from(vec![(KeyChord::new(Modifiers::PRIMARY, Code::KeyS), entry)]).build(cx);
+///
+/// CommandPalette::new(cx).keymap(Keymap::<Action>::bindings);
+///
+/// Command::from(entry).register(cx, true);
+/// ```
+pub struct CommandPalette {}
+
+impl CommandPalette {
+    /// Creates a new [`CommandPalette`], and the model which holds its commands in the current view.
+    pub fn new(cx: &mut Context) -> Handle<Self> {
+        CommandRegistry::new().build(cx);
+
+        Self {}
+            .build(cx, |cx| {
+                // Close the palette when the mouse is pressed outside of it.
+                cx.add_listener(|_: &mut Self, cx, event| {
+                    event.map(|window_event, meta| {
+                        if let WindowEvent::MouseDown(_) = window_event {
+                            if meta.origin != cx.current()
+                                && !cx.hovered.is_descendant_of(cx.tree, cx.current)
+                            {
+                                cx.emit(CommandEvent::ClosePalette);
+                            }
+                        }
+                    });
+                });
+
+                Binding::new(cx, CommandRegistry::is_open, |cx, is_open| {
+                    if is_open.get(cx) {
+                        Self::content(cx);
+                    }
+                });
+            })
+            .display(CommandRegistry::is_open)
+            .role(Role::Dialog)
+            .name(Localized::new("Command palette"))
+    }
+
+    fn content(cx: &mut Context) {
+        Textbox::new(cx, CommandRegistry::query)
+            .placeholder(Localized::new("Type a command"))
+            .on_edit(|cx, query| cx.emit(CommandPaletteEvent::SetQuery(query)))
+            .on_submit(|cx, _, enter| {
+                if enter {
+                    cx.emit(CommandPaletteEvent::ExecuteSelected);
+                }
+            })
+            .on_cancel(|cx| cx.emit(CommandEvent::ClosePalette))
+            .class("command-palette-input")
+            .on_build(|cx| cx.emit(TextEvent::StartEdit));
+
+        VStack::new(cx, |cx| {
+            Binding::new(
+                cx,
+                CommandRegistry::root.map(CommandRegistry::visible_results),
+                |cx, results| {
+                    let results = results.get(cx);
+                    if results.is_empty() {
+                        Label::new(cx, Localized::new("No matching commands"))
+                            .class("command-palette-empty");
+                    }
+
+                    for (index, result) in results {
+                        Self::item(cx, index, result);
+                    }
+                },
+            );
+        })
+        .class("command-palette-results")
+        .role(Role::List);
+    }
+
+    fn item(cx: &mut Context, index: usize, result: CommandResult) {
+        let id = result.id.clone();
+
+        HStack::new(cx, |cx| {
+            Label::rich(cx, "", |cx| {
+                for (text, is_match) in highlight_runs(&result.title, &result.matched) {
+                    TextSpan::new(cx, &text, |_| {}).toggle_class("match", is_match);
+                }
+            })
+            .name(result.title.clone())
+            .class("command-palette-label");
+
+            if let Some(shortcut) = &result.shortcut {
+                Label::new(cx, shortcut.clone()).class("command-palette-shortcut");
+            }
+        })
+        .class("command-palette-item")
+        .role(Role::ListItem)
+        .toggle_class("recent", result.is_recent)
+        .toggle_class("nav", CommandRegistry::selected.map(move |selected| *selected == index))
+        .on_hover(move |cx| cx.emit(CommandPaletteEvent::Select(index)))
+        .on_press(move |cx| cx.emit(CommandEvent::Execute(id.clone())));
+    }
+}
+
+impl View for CommandPalette {
+    fn element(&self) -> Option<&'static str> {
+        Some("command-palette")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(Code::ArrowDown, _) => {
+                cx.emit(CommandPaletteEvent::SelectNext);
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(Code::ArrowUp, _) => {
+                cx.emit(CommandPaletteEvent::SelectPrevious);
+                meta.consume();
+            }
+
+            WindowEvent::MouseScroll(_, y) => {
+                cx.emit(CommandPaletteEvent::Scroll(if *y > 0.0 { -1 } else { 1 }));
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl Handle<'_, CommandPalette> {
+    /// Sets the key chord which opens and closes the palette. Defaults to
+    /// <kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>P</kbd>, or <kbd>Cmd</kbd>+<kbd>Shift</kbd>+<kbd>P</kbd> on macOS.
+    pub fn shortcut(mut self, shortcut: KeyChord) -> Self {
+        let entity = self.entity();
+        self.context().with_current(entity, |cx| {
+            cx.emit(CommandPaletteEvent::SetShortcut(shortcut));
+        });
+
+        self
+    }
+
+    /// Shows the first key sequence bound to each action of a keymap next to the command whose id is the name of the
+    /// action, usually given with the [`Keymap::bindings`] lens.
+    pub fn keymap<L, T>(mut self, lens: L) -> Self
+    where
+        L: Lens<Target = Vec<KeyBinding<T>>>,
+        T: Data + Display,
+    {
+        let entity = self.entity();
+        lens.set_or_bind(self.context(), entity, |cx, bindings| {
+            let shortcuts = bindings
+                .get(cx)
+                .iter()
+                .map(|binding| {
+                    let shortcut = binding.sequences.first().map(ToString::to_string);
+                    (binding.action.to_string(), shortcut)
+                })
+                .collect();
+            cx.emit(CommandPaletteEvent::SetShortcuts(shortcuts));
+        });
+
+        self
+    }
+}
+
+// Returns whether the character at the given index starts a word, which is preceded by a character which isn't
+// alphanumeric or is a lowercase character followed by an uppercase character.
+fn is_word_start(text: &[char], index: usize) -> bool {
+    index == 0
+        || !text[index - 1].is_alphanumeric()
+        || (text[index - 1].is_lowercase() && text[index].is_uppercase())
+}
+
+/// Matches the characters of a query in order against a text, ignoring case and whitespace in the query.
+///
+/// Returns the score of the match, which is higher for consecutive characters and characters at the start of words,
+/// and the indices of the matched characters of the text. Any text matches an empty query with a score of zero.
+fn fuzzy_match(query: &str, text: &str) -> Option<(i32, Vec<usize>)> {
+    let text = text.chars().collect::<Vec<_>>();
+    let mut matched: Vec<usize> = Vec::new();
+    let mut score = 0;
+
+    for query_char in query.chars().filter(|c| !c.is_whitespace()) {
+        let start = matched.last().map_or(0, |last| last + 1);
+        let index = (start..text.len())
+            .find(|index| text[*index].to_lowercase().eq(query_char.to_lowercase()))?;
+
+        score += 1;
+        if is_word_start(&text, index) {
+            score += 6;
+        }
+
+        if matched.last().is_some_and(|last| last + 1 == index) {
+            score += 4;
+        } else {
+            // Skipped characters are penalized, up to a limit so that long texts aren't penalized too much.
+            score -= (index - start).min(3) as i32;
+        }
+
+        matched.push(index);
+    }
+
+    Some((score, matched))
+}
+
+// Splits a text into runs of characters which are either all matched or all unmatched.
+fn highlight_runs(text: &str, matched: &[usize]) -> Vec<(String, bool)> {
+    let mut runs: Vec<(String, bool)> = Vec::new();
+    for (index, c) in text.chars().enumerate() {
+        let is_match = matched.contains(&index);
+        match runs.last_mut() {
+            Some((run, run_is_match)) if *run_is_match == is_match => run.push(c),
+            _ => runs.push((c.to_string(), is_match)),
+        }
+    }
+
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(titles: &[(&str, &str)]) -> CommandRegistry {
+        let mut registry = CommandRegistry::new();
+        for (id, label) in titles {
+            registry.register(Command::new(*id, *label, |_| {}));
+        }
+
+        registry
+    }
+
+    fn result_ids(registry: &CommandRegistry) -> Vec<&str> {
+        registry.results.iter().map(|result| result.id.as_str()).collect()
+    }
+
+    #[test]
+    fn fuzzy_matches() {
+        assert_eq!(fuzzy_match("", "Save"), Some((0, vec![])));
+        assert_eq!(fuzzy_match("sf", "File: Save File").map(|(_, m)| m), Some(vec![6, 11]));
+        assert_eq!(fuzzy_match("SAVE", "save").map(|(_, m)| m), Some(vec![0, 1, 2, 3]));
+        assert_eq!(fuzzy_match("save file", "Save File").map(|(_, m)| m.len()), Some(8));
+        assert_eq!(fuzzy_match("xyz", "Save"), None);
+        assert_eq!(fuzzy_match("evas", "Save"), None);
+
+        let score = |query, text| fuzzy_match(query, text).unwrap().0;
+        assert!(score("open", "Open File") > score("open", "Copy Path Entry Note"));
+        assert!(score("of", "Open File") > score("of", "Go Forward"));
+        assert!(score("tp", "TogglePanel") > score("tp", "Step"));
+    }
+
+    #[test]
+    fn match_highlight_runs() {
+        assert_eq!(
+            highlight_runs("Open File", &[0, 1, 5]),
+            vec![
+                ("Op".to_owned(), true),
+                ("en ".to_owned(), false),
+                ("F".to_owned(), true),
+                ("ile".to_owned(), false),
+            ]
+        );
+        assert_eq!(highlight_runs("", &[]), vec![]);
+    }
+
+    #[test]
+    fn results() {
+        let mut registry =
+            registry(&[("open", "Open File"), ("save", "Save File"), ("close", "Close File")]);
+        registry.open();
+        assert_eq!(result_ids(&registry), ["open", "save", "close"]);
+
+        // Recently executed commands are shown first.
+        assert!(registry.execute("close").is_some());
+        assert!(registry.execute("save").is_some());
+        assert!(!registry.is_open);
+        registry.open();
+        assert_eq!(result_ids(&registry), ["save", "close", "open"]);
+        assert!(registry.results[0].is_recent && !registry.results[2].is_recent);
+
+        // Disabled commands are hidden and can't be executed.
+        registry.set_enabled("save", false);
+        registry.update_results();
+        assert_eq!(result_ids(&registry), ["close", "open"]);
+        assert!(registry.execute("save").is_none());
+
+        // Matches are sorted by score before recency.
+        registry.query = "op".to_owned();
+        registry.update_results();
+        assert_eq!(result_ids(&registry), ["open"]);
+        registry.query = "file".to_owned();
+        registry.update_results();
+        assert_eq!(result_ids(&registry), ["close", "open"]);
+    }
+
+    #[test]
+    fn shortcuts_and_categories() {
+        let mut registry = CommandRegistry::new();
+        registry.register(Command::new("Save", "Save", |_| {}).category("File"));
+        registry.shortcuts.push(("Save".to_owned(), "Ctrl+S".to_owned()));
+        registry.open();
+
+        assert_eq!(registry.results[0].title, "File: Save");
+        assert_eq!(registry.results[0].shortcut.as_deref(), Some("Ctrl+S"));
+
+        // Registering a command with the same id replaces it.
+        registry.register(Command::new("Save", "Save All", |_| {}));
+        registry.update_results();
+        assert_eq!(registry.results.len(), 1);
+        assert_eq!(registry.results[0].title, "Save All");
+    }
+
+    #[test]
+    fn visible_results() {
+        let ids = (0..25).map(|i| (i.to_string(), i.to_string())).collect::<Vec<_>>();
+        let ids = ids.iter().map(|(id, label)| (id.as_str(), label.as_str())).collect::<Vec<_>>();
+        let mut registry = registry(&ids);
+        registry.open();
+        assert_eq!(registry.visible_results().len(), MAX_VISIBLE);
+
+        registry.select(12);
+        assert_eq!(registry.offset, 3);
+        assert_eq!(registry.visible_results().last().map(|(index, _)| *index), Some(12));
+
+        registry.select(24);
+        assert_eq!(registry.offset, 15);
+        registry.select(0);
+        assert_eq!(registry.offset, 0);
+    }
+}
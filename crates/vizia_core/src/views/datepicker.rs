@@ -1,16 +1,44 @@
+use std::sync::Arc;
+
 use chrono::{Datelike, NaiveDate, Weekday};
 
+use crate::localization::locale_region;
 use crate::prelude::*;
 
-/// A control used to select a date.
+type DatePredicate = Arc<dyn Fn(NaiveDate) -> bool + Send + Sync>;
+
+/// A control used to select a date, or a range of dates.
+///
+/// The names of the months and days of the week are localized with [`Localized`], using their English names as the
+/// translation keys, e.g. `January = janvier` and `Monday = lundi`. The first day of the week is derived from the
+/// region of the current locale.
 #[derive(Lens)]
 pub struct Datepicker {
     view_date: NaiveDate,
     months: Vec<Localized>,
     selected_month: usize,
 
+    locale_weekday: Weekday,
+    first_weekday: Option<Weekday>,
+    show_week_numbers: bool,
+
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
+    #[lens(ignore)]
+    is_disabled: Option<DatePredicate>,
+
+    // The selected date, or the start and end of the selected range.
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    is_range: bool,
+    // The first pressed date of a range which is being selected, and the hovered date used to preview the range.
+    anchor: Option<NaiveDate>,
+    hovered: Option<NaiveDate>,
+
     #[lens(ignore)]
     on_select: Option<Box<dyn Fn(&mut EventContext, NaiveDate)>>,
+    #[lens(ignore)]
+    on_select_range: Option<Box<dyn Fn(&mut EventContext, NaiveDate, NaiveDate)>>,
 }
 
 const MONTHS: [&str; 12] = [
@@ -28,9 +56,39 @@ const MONTHS: [&str; 12] = [
     "December",
 ];
 
-const DAYS_HEADER: [&str; 7] =
+const DAYS: [&str; 7] =
     ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
 
+// The regions in which the week starts on a Sunday, Saturday, or Friday, from the CLDR week data. The week starts on
+// a Monday in all other regions.
+const SUNDAY_REGIONS: [&str; 53] = [
+    "AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CO", "DM", "DO", "ET", "GT", "GU", "HK",
+    "HN", "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX", "MZ",
+    "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TT", "TW", "UM",
+    "US", "VE", "VI", "WS", "YE",
+];
+const SATURDAY_REGIONS: [&str; 15] =
+    ["AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY"];
+const FRIDAY_REGIONS: [&str; 1] = ["MV"];
+
+/// Returns the first day of the week in the region of the given locale.
+fn locale_first_weekday(locale: &LanguageIdentifier) -> Weekday {
+    match locale_region(locale) {
+        Some(region) if SUNDAY_REGIONS.contains(&region) => Weekday::Sun,
+        Some(region) if SATURDAY_REGIONS.contains(&region) => Weekday::Sat,
+        Some(region) if FRIDAY_REGIONS.contains(&region) => Weekday::Fri,
+        _ => Weekday::Mon,
+    }
+}
+
+fn to_naive_date(date: &impl Datelike) -> NaiveDate {
+    NaiveDate::from_ymd_opt(date.year(), date.month(), date.day()).unwrap()
+}
+
+fn add_days(date: NaiveDate, days: i32) -> NaiveDate {
+    NaiveDate::from_num_days_from_ce_opt(date.num_days_from_ce() + days).unwrap_or(date)
+}
+
 pub enum DatepickerEvent {
     IncrementMonth,
     DecrementMonth,
@@ -41,87 +99,238 @@ pub enum DatepickerEvent {
     SelectYear(String),
 
     SelectDate(NaiveDate),
+
+    PressDay(usize),
+    HoverDay(Option<usize>),
+
+    SetSelection(Option<NaiveDate>, Option<NaiveDate>),
+    SetLocaleWeekday(Weekday),
+    SetFirstWeekday(Weekday),
+    SetShowWeekNumbers(bool),
+    SetMinDate(NaiveDate),
+    SetMaxDate(NaiveDate),
+    SetDisabledDates(DatePredicate),
+}
+
+// The state of a day in the calendar grid.
+#[derive(Debug, Clone, Copy, PartialEq, Data)]
+struct DayCell {
+    date: NaiveDate,
+    outside: bool,
+    disabled: bool,
+    selected: bool,
+    in_range: bool,
+    range_start: bool,
+    range_end: bool,
 }
 
 impl Datepicker {
-    fn first_day_of_month(year: i32, month: u32) -> Option<Weekday> {
-        NaiveDate::from_ymd_opt(year, month, 1).map(|date| date.weekday())
+    fn first_weekday(&self) -> Weekday {
+        self.first_weekday.unwrap_or(self.locale_weekday)
     }
 
-    fn last_day_of_month(year: i32, month: u32) -> Option<u32> {
-        if month == 12 {
-            NaiveDate::from_ymd_opt(year + 1, 1, 1)
-        } else {
-            NaiveDate::from_ymd_opt(year, month + 1, 1)
-        }
-        .map(|date| {
-            date.signed_duration_since(NaiveDate::from_ymd_opt(year, month, 1).unwrap()).num_days()
-                as u32
-        })
+    // Returns the first date shown in the calendar grid, which is the first day of the week containing the first
+    // day of the viewed month.
+    fn grid_start(&self) -> NaiveDate {
+        let offset = (self.view_date.weekday().num_days_from_monday() + 7
+            - self.first_weekday().num_days_from_monday())
+            % 7;
+        add_days(self.view_date, -(offset as i32))
     }
 
-    // Given a date and a month offset, returns the first day of the month and the number of days in the month
-    fn view_month_info(view_date: &NaiveDate, month_offset: i32) -> (Weekday, u32) {
-        let month = view_date.month();
-        let mut year = view_date.year();
+    fn cell_date(&self, index: usize) -> NaiveDate {
+        add_days(self.grid_start(), index as i32)
+    }
 
-        let mut month = month as i32 + month_offset;
+    fn is_available(&self, date: NaiveDate) -> bool {
+        self.min_date.map_or(true, |min| date >= min)
+            && self.max_date.map_or(true, |max| date <= max)
+            && self.is_disabled.as_ref().map_or(true, |is_disabled| !(is_disabled)(date))
+    }
 
-        if month < 1 {
-            year -= 1;
-            month += 12;
-        } else if month > 12 {
-            year += 1;
-            month -= 12;
-        }
+    // Returns the selected range, or the range previewed between the pressed and hovered dates.
+    fn shown_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        match self.anchor {
+            Some(anchor) => {
+                let hovered = self.hovered.unwrap_or(anchor);
+                Some((anchor.min(hovered), anchor.max(hovered)))
+            }
 
-        let month = month as u32;
+            None => Some((self.start?, self.end?)),
+        }
+    }
 
-        (
-            Self::first_day_of_month(year, month).unwrap(),
-            Self::last_day_of_month(year, month).unwrap(),
-        )
+    fn day_cell(&self, index: usize) -> DayCell {
+        let date = self.cell_date(index);
+        let outside = date.month() != self.view_date.month();
+        let range = self.shown_range().filter(|_| self.is_range && !outside);
+
+        DayCell {
+            date,
+            outside,
+            disabled: outside || !self.is_available(date),
+            selected: !outside
+                && match self.anchor {
+                    Some(anchor) => date == anchor,
+                    None => self.start == Some(date) || self.end == Some(date),
+                },
+            in_range: range.is_some_and(|(start, end)| date >= start && date <= end),
+            range_start: range.is_some_and(|(start, _)| date == start),
+            range_end: range.is_some_and(|(_, end)| date == end),
+        }
     }
 
-    fn get_day_number(y: u32, x: u32, view_date: &NaiveDate) -> (u32, bool) {
-        let (_, days_prev_month) = Self::view_month_info(view_date, -1);
-        let (first_day_this_month, days_this_month) = Self::view_month_info(view_date, 0);
+    // Returns the ISO week number of a row of the calendar grid, which is the week containing its Thursday.
+    fn week_number(&self, row: usize) -> u32 {
+        (0..7)
+            .map(|x| self.cell_date(row * 7 + x))
+            .find(|date| date.weekday() == Weekday::Thu)
+            .map(|date| date.iso_week().week())
+            .unwrap_or_default()
+    }
 
-        let mut fdtm_i = first_day_this_month as usize as u32;
-        if fdtm_i == 0 {
-            fdtm_i = 7;
-        }
+    // Presses a date of a range, returning the selected range when the date is the second to be pressed.
+    fn press_range(&mut self, date: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        match self.anchor.take() {
+            Some(anchor) => {
+                let (start, end) = (anchor.min(date), anchor.max(date));
+
+                // A range can't span an unavailable date, so pressing a date beyond one starts a new range.
+                let mut dates =
+                    (0..=(end - start).num_days() as i32).map(|days| add_days(start, days));
+                if dates.all(|date| self.is_available(date)) {
+                    self.hovered = None;
+                    return Some((start, end));
+                }
 
-        if y == 0 {
-            if x < fdtm_i {
-                (days_prev_month - (fdtm_i - x - 1), true)
-            } else {
-                (x - fdtm_i + 1, false)
+                self.anchor = Some(date);
+                None
             }
-        } else {
-            let day_number = y * 7 + x - fdtm_i + 1;
-            if day_number > days_this_month {
-                (day_number - days_this_month, true)
-            } else {
-                (day_number, false)
+
+            None => {
+                self.anchor = Some(date);
+                None
             }
         }
     }
 
-    pub fn new<L, D>(cx: &mut Context, lens: L) -> Handle<Self>
-    where
-        L: Lens<Target = D>,
-        D: Datelike + Data,
-    {
-        let view_date = lens.get(cx);
+    fn build_calendar(cx: &mut Context) {
+        // Days of the week
+        Binding::new(
+            cx,
+            Datepicker::root.map(|datepicker| datepicker.first_weekday()),
+            |cx, first_weekday| {
+                let first_weekday = first_weekday.get(cx).num_days_from_monday() as usize;
+
+                HStack::new(cx, |cx| {
+                    Element::new(cx)
+                        .class("datepicker-calendar-week-number")
+                        .display(Datepicker::show_week_numbers);
+
+                    for h in DAYS.iter().cycle().skip(first_weekday).take(7) {
+                        Label::new(cx, Localized::new(h).map(|day| day.chars().take(2).collect()))
+                            .role(Role::ColumnHeader)
+                            .name(Localized::new(h))
+                            .class("datepicker-calendar-header");
+                    }
+                })
+                .role(Role::Row)
+                .class("datepicker-calendar-headers");
+            },
+        );
+
+        // Numbered days in a grid
+        VStack::new(cx, move |cx| {
+            for y in 0..6 {
+                HStack::new(cx, |cx| {
+                    Label::new(
+                        cx,
+                        Datepicker::root.map(move |datepicker| datepicker.week_number(y)),
+                    )
+                    .class("datepicker-calendar-week-number")
+                    .display(Datepicker::show_week_numbers);
+
+                    for x in 0..7 {
+                        let index = y * 7 + x;
+                        Label::new(cx, "")
+                            .role(Role::GridCell)
+                            .class("datepicker-calendar-day")
+                            .bind(
+                                Datepicker::root.map(move |datepicker| datepicker.day_cell(index)),
+                                |handle, cell| {
+                                    let cell = cell.get(&handle);
+                                    let day_number = cell.date.day().to_string();
+
+                                    handle
+                                        .text(&day_number)
+                                        .name(day_number)
+                                        .navigable(!cell.disabled)
+                                        .toggle_class(
+                                            "datepicker-calendar-day-disabled",
+                                            cell.disabled,
+                                        )
+                                        .toggle_class(
+                                            "datepicker-calendar-day-outside",
+                                            cell.outside,
+                                        )
+                                        .toggle_class(
+                                            "datepicker-calendar-day-in-range",
+                                            cell.in_range,
+                                        )
+                                        .toggle_class(
+                                            "datepicker-calendar-day-range-start",
+                                            cell.range_start,
+                                        )
+                                        .toggle_class(
+                                            "datepicker-calendar-day-range-end",
+                                            cell.range_end,
+                                        )
+                                        .checked(cell.selected);
+                                },
+                            )
+                            .on_press(move |ex| ex.emit(DatepickerEvent::PressDay(index)))
+                            .on_hover(move |ex| ex.emit(DatepickerEvent::HoverDay(Some(index))));
+                    }
+                })
+                .role(Role::Row);
+            }
+        })
+        .on_hover_out(|ex| ex.emit(DatepickerEvent::HoverDay(None)))
+        .class("datepicker-calendar-days")
+        // This shouldn't be needed but apparently grid size isn't propagated up the tree during layout
+        .width(
+            Datepicker::show_week_numbers.map(|show| Pixels(32.0 * if *show { 8.0 } else { 7.0 })),
+        )
+        .height(Pixels(32.0 * 6.0));
+    }
+
+    fn build_picker(cx: &mut Context, view_date: NaiveDate, is_range: bool) -> Handle<Self> {
+        let locale_weekday = locale_first_weekday(&cx.environment().locale);
 
         Self {
             months: MONTHS.iter().map(|m| Localized::new(m)).collect::<Vec<_>>(),
             selected_month: view_date.month() as usize - 1,
             view_date: NaiveDate::from_ymd_opt(view_date.year(), view_date.month(), 1).unwrap(),
+            locale_weekday,
+            first_weekday: None,
+            show_week_numbers: false,
+            min_date: None,
+            max_date: None,
+            is_disabled: None,
+            start: None,
+            end: None,
+            is_range,
+            anchor: None,
+            hovered: None,
             on_select: None,
+            on_select_range: None,
         }
         .build(cx, move |cx| {
+            Binding::new(cx, Environment::locale, |cx, locale| {
+                let weekday = locale_first_weekday(&locale.get(cx));
+                cx.emit(DatepickerEvent::SetLocaleWeekday(weekday));
+            });
+
             HStack::new(cx, |cx| {
                 Spinbox::custom(cx, |cx| {
                     PickList::new(cx, Datepicker::months, Datepicker::selected_month, false)
@@ -144,71 +353,108 @@ impl Datepicker {
 
             Divider::new(cx);
 
-            VStack::new(cx, move |cx| {
-                // Days of the week
-                HStack::new(cx, |cx| {
-                    for h in DAYS_HEADER {
-                        Label::new(cx, Localized::new(h).map(|day| day[0..2].to_string()))
-                            .class("datepicker-calendar-header");
-                    }
-                })
-                .class("datepicker-calendar-headers");
+            VStack::new(cx, Self::build_calendar).role(Role::Grid).class("datepicker-calendar");
+        })
+        .role(Role::Group)
+    }
 
-                // Numbered days in a grid
-                VStack::new(cx, move |cx| {
-                    for y in 0..6 {
-                        HStack::new(cx, |cx| {
-                            for x in 0..7 {
-                                Label::new(cx, "").bind(
-                                    Datepicker::view_date,
-                                    move |handle, view_date| {
-                                        let view_date = view_date.get(&handle);
-
-                                        let (day_number, disabled) =
-                                            Self::get_day_number(y, x, &view_date);
-
-                                        handle.bind(lens, move |handle, selected_date| {
-                                            let selected_date = selected_date.get(&handle);
-
-                                            handle
-                                                .text(&day_number.to_string())
-                                                .class("datepicker-calendar-day")
-                                                .navigable(!disabled)
-                                                .toggle_class(
-                                                    "datepicker-calendar-day-disabled",
-                                                    disabled,
-                                                )
-                                                .on_press(move |ex| {
-                                                    if !disabled {
-                                                        ex.emit(DatepickerEvent::SelectDate(
-                                                            NaiveDate::from_ymd_opt(
-                                                                view_date.year(),
-                                                                view_date.month(),
-                                                                day_number,
-                                                            )
-                                                            .unwrap(),
-                                                        ))
-                                                    }
-                                                })
-                                                .checked(
-                                                    !disabled
-                                                        && selected_date.day() == day_number
-                                                        && selected_date.month()
-                                                            == view_date.month()
-                                                        && selected_date.year() == view_date.year(),
-                                                );
-                                        });
-                                    },
-                                );
-                            }
-                        });
-                    }
-                })
-                // This shouldn't be needed but apparently grid size isn't propagated up the tree during layout
-                .width(Pixels(32.0 * 7.0))
-                .height(Pixels(32.0 * 6.0));
-            })
-            .class("datepicker-calendar");
+    /// Creates a new [`Datepicker`] which shows the date of the given lens as selected.
+    ///
+    /// The date is not changed when the user selects a date, and should be updated in the callback set with
+    /// [`on_select`](Handle::on_select).
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use chrono::NaiveDate;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     date: NaiveDate,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # enum AppEvent {
+    /// #     SetDate(NaiveDate),
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { date: NaiveDate::from_ymd_opt(2024, 5, 1).unwrap() }.build(cx);
+    /// #
+    /// Datepicker::new(cx, AppData::date)
+    ///     .show_week_numbers(true)
+    ///     .on_select(|cx, date| cx.emit(AppEvent::SetDate(date)));
+    /// ```
+    pub fn new<L, D>(cx: &mut Context, lens: L) -> Handle<Self>
+    where
+        L: Lens<Target = D>,
+        D: Datelike + Data,
+    {
+        let view_date = to_naive_date(&lens.get(cx));
+
+        Self::build_picker(cx, view_date, false).bind(lens, |mut handle, date| {
+            let date = to_naive_date(&date.get(&handle));
+            let entity = handle.entity();
+            handle.context().emit_to(entity, DatepickerEvent::SetSelection(Some(date), Some(date)));
+        })
+    }
+
+    /// Creates a new [`Datepicker`] which selects a range of dates, showing the range between the dates of the given
+    /// lenses as selected.
+    ///
+    /// The user selects a range by pressing its first and last dates, and the range between the first date and the
+    /// hovered date is previewed in between. A range can't span a disabled date. The dates are not changed when the
+    /// user selects a range, and should be updated in the callback set with
+    /// [`on_select_range`](Handle::on_select_range).
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use chrono::NaiveDate;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     check_in: NaiveDate,
+    /// #     check_out: NaiveDate,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # enum AppEvent {
+    /// #     SetStay(NaiveDate, NaiveDate),
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData {
+    /// #     check_in: NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+    /// #     check_out: NaiveDate::from_ymd_opt(2024, 5, 4).unwrap(),
+    /// # }
+    /// # .build(cx);
+    /// #
+    /// Datepicker::range(cx, AppData::check_in, AppData::check_out)
+    ///     .disabled_dates(|date| date.weekday() == chrono::Weekday::Sun)
+    ///     .on_select_range(|cx, start, end| cx.emit(AppEvent::SetStay(start, end)));
+    /// ```
+    pub fn range<L1, L2, D>(cx: &mut Context, start: L1, end: L2) -> Handle<Self>
+    where
+        L1: Lens<Target = D>,
+        L2: Lens<Target = D>,
+        D: Datelike + Data,
+    {
+        let view_date = to_naive_date(&start.get(cx));
+
+        Self::build_picker(cx, view_date, true).bind(start, move |handle, start| {
+            let start = to_naive_date(&start.get(&handle));
+            handle.bind(end, move |mut handle, end| {
+                let end = to_naive_date(&end.get(&handle));
+                let entity = handle.entity();
+                handle
+                    .context()
+                    .emit_to(entity, DatepickerEvent::SetSelection(Some(start), Some(end)));
+            });
         })
     }
 }
@@ -296,12 +542,327 @@ impl View for Datepicker {
                     (callback)(cx, *date);
                 }
             }
+
+            DatepickerEvent::PressDay(index) => {
+                let cell = self.day_cell(*index);
+                if cell.disabled {
+                    return;
+                }
+
+                if !self.is_range {
+                    if let Some(callback) = &self.on_select {
+                        (callback)(cx, cell.date);
+                    }
+                } else if let Some((start, end)) = self.press_range(cell.date) {
+                    if let Some(callback) = &self.on_select_range {
+                        (callback)(cx, start, end);
+                    }
+                }
+            }
+
+            DatepickerEvent::HoverDay(index) => {
+                // The hovered date is only needed to preview a range which is being selected.
+                if self.anchor.is_some() {
+                    self.hovered = index
+                        .map(|index| self.day_cell(index))
+                        .and_then(|cell| (!cell.disabled).then_some(cell.date));
+                }
+            }
+
+            DatepickerEvent::SetSelection(start, end) => {
+                self.start = *start;
+                self.end = *end;
+            }
+
+            DatepickerEvent::SetLocaleWeekday(weekday) => self.locale_weekday = *weekday,
+
+            DatepickerEvent::SetFirstWeekday(weekday) => self.first_weekday = Some(*weekday),
+
+            DatepickerEvent::SetShowWeekNumbers(show) => self.show_week_numbers = *show,
+
+            DatepickerEvent::SetMinDate(date) => self.min_date = Some(*date),
+
+            DatepickerEvent::SetMaxDate(date) => self.max_date = Some(*date),
+
+            DatepickerEvent::SetDisabledDates(is_disabled) => {
+                self.is_disabled = Some(is_disabled.clone())
+            }
         })
     }
 }
 
 impl<'a> Handle<'a, Datepicker> {
+    /// Sets the callback which is called with the pressed date when the user selects a date.
     pub fn on_select<F: 'static + Fn(&mut EventContext, NaiveDate)>(self, callback: F) -> Self {
         self.modify(|datepicker: &mut Datepicker| datepicker.on_select = Some(Box::new(callback)))
     }
+
+    /// Sets the callback which is called with the first and last dates of the range when the user selects a range.
+    pub fn on_select_range<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, NaiveDate, NaiveDate),
+    {
+        self.modify(|datepicker: &mut Datepicker| {
+            datepicker.on_select_range = Some(Box::new(callback))
+        })
+    }
+
+    /// Sets the first day of the week, which otherwise is derived from the region of the current locale.
+    pub fn first_day_of_week(mut self, weekday: impl Res<Weekday>) -> Self {
+        let entity = self.entity();
+        weekday.set_or_bind(self.context(), entity, |cx, weekday| {
+            let weekday = weekday.get(cx);
+            cx.emit(DatepickerEvent::SetFirstWeekday(weekday));
+        });
+
+        self
+    }
+
+    /// Sets whether the ISO week number of each week is shown at the start of the week.
+    pub fn show_week_numbers(mut self, show: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        show.set_or_bind(self.context(), entity, |cx, show| {
+            let show = show.get(cx);
+            cx.emit(DatepickerEvent::SetShowWeekNumbers(show));
+        });
+
+        self
+    }
+
+    /// Sets the earliest date which can be selected.
+    pub fn min_date(mut self, date: impl Res<NaiveDate>) -> Self {
+        let entity = self.entity();
+        date.set_or_bind(self.context(), entity, |cx, date| {
+            let date = date.get(cx);
+            cx.emit(DatepickerEvent::SetMinDate(date));
+        });
+
+        self
+    }
+
+    /// Sets the latest date which can be selected.
+    pub fn max_date(mut self, date: impl Res<NaiveDate>) -> Self {
+        let entity = self.entity();
+        date.set_or_bind(self.context(), entity, |cx, date| {
+            let date = date.get(cx);
+            cx.emit(DatepickerEvent::SetMaxDate(date));
+        });
+
+        self
+    }
+
+    /// Sets a predicate which returns whether a date is disabled, and so can't be selected.
+    pub fn disabled_dates<F>(mut self, is_disabled: F) -> Self
+    where
+        F: 'static + Fn(NaiveDate) -> bool + Send + Sync,
+    {
+        let entity = self.entity();
+        self.context().emit_to(entity, DatepickerEvent::SetDisabledDates(Arc::new(is_disabled)));
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[derive(Lens)]
+    struct TestData {
+        start: NaiveDate,
+        end: NaiveDate,
+    }
+
+    enum TestEvent {
+        Select(NaiveDate, NaiveDate),
+    }
+
+    impl Model for TestData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|TestEvent::Select(start, end), _| (self.start, self.end) = (*start, *end));
+        }
+    }
+
+    // Builds a datepicker showing May 2024 in a locale whose week starts on a Monday, which is bound to the dates of
+    // a model and updates them when dates are selected.
+    fn context(
+        is_range: bool,
+        configure: impl for<'a> FnOnce(Handle<'a, Datepicker>) -> Handle<'a, Datepicker>,
+    ) -> (Context, Entity) {
+        let mut cx = Context::default();
+        cx.emit_to(Entity::root(), EnvironmentEvent::SetLocale("en-GB".parse().unwrap()));
+        flush(&mut cx);
+
+        TestData { start: date(2024, 5, 1), end: date(2024, 5, 1) }.build(&mut cx);
+        let datepicker = if is_range {
+            Datepicker::range(&mut cx, TestData::start, TestData::end)
+                .on_select_range(|cx, start, end| cx.emit(TestEvent::Select(start, end)))
+        } else {
+            Datepicker::new(&mut cx, TestData::start)
+                .on_select(|cx, date| cx.emit(TestEvent::Select(date, date)))
+        };
+        let datepicker = configure(datepicker).entity();
+        flush(&mut cx);
+        (cx, datepicker)
+    }
+
+    fn flush(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    fn send(cx: &mut Context, target: Entity, event: WindowEvent) {
+        cx.emit_custom(
+            Event::new(event).target(target).origin(target).propagate(Propagation::Direct),
+        );
+        flush(cx);
+    }
+
+    fn press(cx: &mut Context, entity: Entity) {
+        cx.focused = entity;
+        send(cx, entity, WindowEvent::Press { mouse: false });
+    }
+
+    fn selection(cx: &Context) -> (NaiveDate, NaiveDate) {
+        let data = cx.data::<TestData>().unwrap();
+        (data.start, data.end)
+    }
+
+    fn with_class(cx: &Context, class: &str) -> Vec<Entity> {
+        Entity::root().tree_iter(&cx.tree).filter(|entity| has_class(cx, *entity, class)).collect()
+    }
+
+    fn has_class(cx: &Context, entity: Entity, class: &str) -> bool {
+        cx.style.classes.get(entity).is_some_and(|classes| classes.contains(class))
+    }
+
+    fn is_checked(cx: &Context, entity: Entity) -> bool {
+        cx.style
+            .pseudo_classes
+            .get(entity)
+            .is_some_and(|pseudo_classes| pseudo_classes.contains(PseudoClassFlags::CHECKED))
+    }
+
+    fn text(cx: &Context, entity: Entity) -> &str {
+        cx.style.text.get(entity).map(String::as_str).unwrap_or_default()
+    }
+
+    // The days of the calendar grid, from the start of the first week shown.
+    fn day_cells(cx: &Context) -> Vec<Entity> {
+        with_class(cx, "datepicker-calendar-day")
+    }
+
+    // The week numbers of the rows of the calendar grid.
+    fn week_numbers(cx: &Context) -> Vec<String> {
+        with_class(cx, "datepicker-calendar-week-number")
+            .into_iter()
+            .filter(|entity| cx.style.text.get(*entity).is_some())
+            .map(|entity| text(cx, entity).to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn first_weekday_of_locale() {
+        assert_eq!(locale_first_weekday(&"en-US".parse().unwrap()), Weekday::Sun);
+        assert_eq!(locale_first_weekday(&"en-GB".parse().unwrap()), Weekday::Mon);
+        assert_eq!(locale_first_weekday(&"en".parse().unwrap()), Weekday::Sun);
+        assert_eq!(locale_first_weekday(&"fr".parse().unwrap()), Weekday::Mon);
+        assert_eq!(locale_first_weekday(&"ar-EG".parse().unwrap()), Weekday::Sat);
+        assert_eq!(locale_first_weekday(&"dv-MV".parse().unwrap()), Weekday::Fri);
+    }
+
+    #[test]
+    fn grid_and_week_numbers() {
+        // May 2024 starts on a Wednesday.
+        let (mut cx, datepicker) = context(false, |handle| handle.show_week_numbers(true));
+        let cells = day_cells(&cx);
+        assert_eq!(cells.len(), 42);
+        assert_eq!(text(&cx, cells[0]), "29");
+        assert!(has_class(&cx, cells[0], "datepicker-calendar-day-outside"));
+        assert_eq!(week_numbers(&cx)[..2], ["18", "19"]);
+        // The bound date is selected.
+        assert!(is_checked(&cx, cells[2]));
+
+        cx.emit_to(datepicker, DatepickerEvent::SetFirstWeekday(Weekday::Sun));
+        flush(&mut cx);
+        assert_eq!(text(&cx, cells[0]), "28");
+        assert_eq!(week_numbers(&cx)[0], "18");
+
+        // December 2024 ends in the first ISO week of 2025.
+        cx.emit_to(datepicker, DatepickerEvent::SetFirstWeekday(Weekday::Mon));
+        for _ in 0..7 {
+            cx.emit_to(datepicker, DatepickerEvent::IncrementMonth);
+        }
+        flush(&mut cx);
+        assert_eq!(week_numbers(&cx)[5], "1");
+    }
+
+    #[test]
+    fn constraints() {
+        let (mut cx, _) = context(false, |handle| {
+            handle
+                .min_date(date(2024, 5, 3))
+                .max_date(date(2024, 5, 20))
+                .disabled_dates(|date| date.weekday() == Weekday::Sun)
+        });
+        let cells = day_cells(&cx);
+        let is_disabled = |cx: &Context, index: usize| {
+            has_class(cx, cells[index], "datepicker-calendar-day-disabled")
+        };
+
+        // The grid starts on April 29th, so May 2nd is its fourth cell.
+        assert!(is_disabled(&cx, 3));
+        assert!(!is_disabled(&cx, 4));
+        assert!(is_disabled(&cx, 6));
+        assert!(is_disabled(&cx, 22));
+
+        // Disabled dates can't be selected.
+        press(&mut cx, cells[3]);
+        assert_eq!(selection(&cx), (date(2024, 5, 1), date(2024, 5, 1)));
+        press(&mut cx, cells[4]);
+        assert_eq!(selection(&cx), (date(2024, 5, 3), date(2024, 5, 3)));
+    }
+
+    #[test]
+    fn range_selection() {
+        let (mut cx, _) =
+            context(true, |handle| handle.disabled_dates(|day| day == date(2024, 5, 15)));
+        let cells = day_cells(&cx);
+        let in_range = |cx: &Context| {
+            (0..cells.len())
+                .filter(|index| has_class(cx, cells[*index], "datepicker-calendar-day-in-range"))
+                .collect::<Vec<_>>()
+        };
+
+        // The grid starts on April 29th, so May 1st is its third cell.
+        press(&mut cx, cells[11]);
+        send(&mut cx, cells[9], WindowEvent::MouseEnter);
+        assert_eq!(in_range(&cx), [9, 10, 11]);
+
+        // The range is selected in either order.
+        press(&mut cx, cells[9]);
+        assert_eq!(selection(&cx), (date(2024, 5, 8), date(2024, 5, 10)));
+
+        // A range spanning a disabled date starts a new range instead.
+        press(&mut cx, cells[13]);
+        press(&mut cx, cells[19]);
+        assert_eq!(selection(&cx), (date(2024, 5, 8), date(2024, 5, 10)));
+        assert_eq!(in_range(&cx), [19]);
+
+        press(&mut cx, cells[5]);
+        press(&mut cx, cells[3]);
+        assert_eq!(selection(&cx), (date(2024, 5, 2), date(2024, 5, 4)));
+        assert_eq!(in_range(&cx), [3, 4, 5]);
+        let cell = cells[4];
+        assert!(!has_class(&cx, cell, "datepicker-calendar-day-range-start"));
+        assert!(!has_class(&cx, cell, "datepicker-calendar-day-range-end"));
+        assert!(!is_checked(&cx, cell));
+        assert!(has_class(&cx, cells[5], "datepicker-calendar-day-range-end"));
+        assert!(is_checked(&cx, cells[5]));
+    }
 }
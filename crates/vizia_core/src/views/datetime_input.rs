@@ -0,0 +1,417 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use super::timepicker::locale_uses_24_hour;
+use crate::icons::ICON_CALENDAR;
+use crate::localization::locale_region;
+use crate::prelude::*;
+
+// The date formats of the regions which don't write dates as day/month/year, which is used in all other regions.
+const DATE_FORMATS: [(&str, &[&str]); 5] = [
+    ("%m/%d/%Y", &["FM", "MH", "PH", "PR", "US"]),
+    ("%Y-%m-%d", &["CA", "KR", "LT", "SE"]),
+    ("%Y/%m/%d", &["CN", "JP", "TW"]),
+    (
+        "%d.%m.%Y",
+        &["AT", "CH", "CZ", "DE", "DK", "FI", "HR", "NO", "PL", "RO", "RU", "SK", "TR", "UA"],
+    ),
+    ("%d-%m-%Y", &["NL"]),
+];
+
+/// Returns the `chrono` format of a date in the region of the given locale.
+fn locale_date_format(locale: &LanguageIdentifier) -> &'static str {
+    locale_region(locale)
+        .and_then(|region| {
+            DATE_FORMATS.iter().find(|(_, regions)| regions.contains(&region)).map(|(f, _)| *f)
+        })
+        .unwrap_or("%d/%m/%Y")
+}
+
+fn time_format(uses_24_hour: bool, show_seconds: bool) -> &'static str {
+    match (uses_24_hour, show_seconds) {
+        (true, true) => "%H:%M:%S",
+        (true, false) => "%H:%M",
+        (false, true) => "%I:%M:%S %p",
+        (false, false) => "%I:%M %p",
+    }
+}
+
+fn parse(text: &str, format: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(text.trim(), format).ok()
+}
+
+pub(crate) enum DateTimeInputEvent {
+    SetValue(NaiveDateTime),
+    SetLocale(LanguageIdentifier),
+    SetUse24Hour(bool),
+    SetShowSeconds(bool),
+    SetOpen(bool),
+    Submit(String),
+    SelectDate(NaiveDate),
+    SelectTime(NaiveTime),
+}
+
+/// A text field used to enter a date and time, which is parsed and formatted in the conventions of the current
+/// locale.
+///
+/// Text which can't be parsed sets the `:invalid` pseudo-class of the text field. The date and time can also be
+/// picked with a [`Datepicker`] and [`Timepicker`], which are shown in a popup by the button of the field.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use chrono::{NaiveDate, NaiveDateTime};
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     deadline: NaiveDateTime,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # enum AppEvent {
+/// #     SetDeadline(NaiveDateTime),
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData {
+/// #     deadline: NaiveDate::from_ymd_opt(2024, 5, 1).unwrap().and_hms_opt(17, 0, 0).unwrap(),
+/// # }
+/// # .build(cx);
+/// #
+/// DateTimeInput::new(cx, AppData::deadline)
+///     .show_seconds(false)
+///     .on_change(|cx, deadline| cx.emit(AppEvent::SetDeadline(deadline)));
+/// ```
+#[derive(Lens)]
+pub struct DateTimeInput {
+    value: NaiveDateTime,
+    // The formatted value, or the text typed by the user until it's submitted.
+    text: String,
+    date_format: &'static str,
+    locale_24_hour: bool,
+    use_24_hour: Option<bool>,
+    show_seconds: bool,
+    is_open: bool,
+
+    #[lens(ignore)]
+    on_change: Option<Box<dyn Fn(&mut EventContext, NaiveDateTime)>>,
+}
+
+impl DateTimeInput {
+    /// Creates a new [`DateTimeInput`] which shows the date and time of the given lens.
+    ///
+    /// The date and time are not changed when the user edits them, and should be updated in the callback set with
+    /// [`on_change`](Handle::on_change).
+    pub fn new<L>(cx: &mut Context, lens: L) -> Handle<Self>
+    where
+        L: Lens<Target = NaiveDateTime>,
+    {
+        let locale = cx.environment().locale.clone();
+        let mut input = Self {
+            value: lens.get(cx),
+            text: String::new(),
+            date_format: locale_date_format(&locale),
+            locale_24_hour: locale_uses_24_hour(&locale),
+            use_24_hour: None,
+            show_seconds: true,
+            is_open: false,
+            on_change: None,
+        };
+        input.text = input.formatted();
+
+        input
+            .build(cx, |cx| {
+                Binding::new(cx, Environment::locale, |cx, locale| {
+                    let locale = locale.get(cx);
+                    cx.emit(DateTimeInputEvent::SetLocale(locale));
+                });
+
+                Binding::new(cx, DateTimeInput::root.map(|input| input.format()), |cx, format| {
+                    let format = format.get(cx);
+                    Textbox::new(cx, DateTimeInput::text)
+                        .validate(move |text: &String| parse(text, &format).is_some())
+                        .on_submit(|cx, text, _| cx.emit(DateTimeInputEvent::Submit(text)))
+                        .class("datetime-input-field");
+                });
+
+                Button::new(cx, |cx| Svg::new(cx, ICON_CALENDAR))
+                    .class("datetime-input-button")
+                    .name(Localized::new("Choose date"))
+                    .checked(DateTimeInput::is_open)
+                    .on_press(|cx| cx.emit(DateTimeInputEvent::SetOpen(true)));
+
+                Binding::new(cx, DateTimeInput::is_open, |cx, is_open| {
+                    if is_open.get(cx) {
+                        Popup::new(cx, |cx| {
+                            VStack::new(cx, |cx| {
+                                Datepicker::new(cx, DateTimeInput::value.map(|value| value.date()))
+                                    .on_select(|cx, date| {
+                                        cx.emit(DateTimeInputEvent::SelectDate(date))
+                                    });
+
+                                Timepicker::new(cx, DateTimeInput::value.map(|value| value.time()))
+                                    .use_24_hour(
+                                        DateTimeInput::root.map(|input| input.uses_24_hour()),
+                                    )
+                                    .show_seconds(DateTimeInput::show_seconds)
+                                    .on_change(|cx, time| {
+                                        cx.emit(DateTimeInputEvent::SelectTime(time))
+                                    });
+                            })
+                            .class("datetime-input-popup");
+                        })
+                        .placement(Placement::BottomEnd)
+                        .arrow(false)
+                        .on_blur(|cx| cx.emit(DateTimeInputEvent::SetOpen(false)));
+                    }
+                });
+            })
+            .role(Role::Group)
+            .bind(lens, |mut handle, value| {
+                let value = value.get(&handle);
+                let entity = handle.entity();
+                handle.context().emit_to(entity, DateTimeInputEvent::SetValue(value));
+            })
+    }
+
+    fn uses_24_hour(&self) -> bool {
+        self.use_24_hour.unwrap_or(self.locale_24_hour)
+    }
+
+    fn format(&self) -> String {
+        format!("{} {}", self.date_format, time_format(self.uses_24_hour(), self.show_seconds))
+    }
+
+    fn formatted(&self) -> String {
+        self.value.format(&self.format()).to_string()
+    }
+
+    fn change(&self, cx: &mut EventContext, value: NaiveDateTime) {
+        if value != self.value {
+            if let Some(callback) = &self.on_change {
+                (callback)(cx, value);
+            }
+        }
+    }
+}
+
+impl View for DateTimeInput {
+    fn element(&self) -> Option<&'static str> {
+        Some("datetime-input")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|input_event, meta| {
+            match input_event {
+                DateTimeInputEvent::SetValue(value) => self.value = *value,
+
+                DateTimeInputEvent::SetLocale(locale) => {
+                    self.date_format = locale_date_format(locale);
+                    self.locale_24_hour = locale_uses_24_hour(locale);
+                }
+
+                DateTimeInputEvent::SetUse24Hour(use_24_hour) => {
+                    self.use_24_hour = Some(*use_24_hour)
+                }
+
+                DateTimeInputEvent::SetShowSeconds(show_seconds) => {
+                    self.show_seconds = *show_seconds
+                }
+
+                DateTimeInputEvent::SetOpen(is_open) => {
+                    self.is_open = *is_open;
+                    meta.consume();
+                    return;
+                }
+
+                DateTimeInputEvent::Submit(text) => {
+                    if let Some(value) = parse(text, &self.format()) {
+                        self.change(cx, value);
+                    }
+                }
+
+                DateTimeInputEvent::SelectDate(date) => {
+                    self.change(cx, date.and_time(self.value.time()));
+                    self.is_open = false;
+                }
+
+                DateTimeInputEvent::SelectTime(time) => {
+                    self.change(cx, self.value.date().and_time(*time));
+                }
+            }
+
+            // Show the value in the current format, replacing any text typed by the user.
+            self.text = self.formatted();
+            meta.consume();
+        });
+    }
+}
+
+impl Handle<'_, DateTimeInput> {
+    /// Sets the callback which is called with the entered date and time when the user changes them.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, NaiveDateTime),
+    {
+        self.modify(|input: &mut DateTimeInput| input.on_change = Some(Box::new(callback)))
+    }
+
+    /// Sets whether a 24-hour clock is used, which otherwise follows the region of the current locale.
+    pub fn use_24_hour(mut self, use_24_hour: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        use_24_hour.set_or_bind(self.context(), entity, |cx, use_24_hour| {
+            let use_24_hour = use_24_hour.get(cx);
+            cx.emit(DateTimeInputEvent::SetUse24Hour(use_24_hour));
+        });
+
+        self
+    }
+
+    /// Sets whether the seconds are shown and can be entered. Defaults to true.
+    pub fn show_seconds(mut self, show_seconds: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        show_seconds.set_or_bind(self.context(), entity, |cx, show_seconds| {
+            let show_seconds = show_seconds.get(cx);
+            cx.emit(DateTimeInputEvent::SetShowSeconds(show_seconds));
+        });
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    #[derive(Lens)]
+    struct TestData {
+        value: NaiveDateTime,
+    }
+
+    enum TestEvent {
+        SetValue(NaiveDateTime),
+    }
+
+    impl Model for TestData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|TestEvent::SetValue(value), _| self.value = *value);
+        }
+    }
+
+    fn value(hour: u32, minute: u32, second: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 5, 1).unwrap().and_hms_opt(hour, minute, second).unwrap()
+    }
+
+    // Builds an input in the given locale which is bound to the value of a model and updates it when it's edited.
+    fn context(locale: &str, show_seconds: bool) -> (Context, Entity) {
+        let mut cx = Context::default();
+        cx.emit_to(Entity::root(), EnvironmentEvent::SetLocale(locale.parse().unwrap()));
+        flush(&mut cx);
+
+        TestData { value: value(15, 4, 5) }.build(&mut cx);
+        let input = DateTimeInput::new(&mut cx, TestData::value)
+            .show_seconds(show_seconds)
+            .on_change(|cx, value| cx.emit(TestEvent::SetValue(value)))
+            .entity();
+        flush(&mut cx);
+        (cx, input)
+    }
+
+    fn flush(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    fn emit(cx: &mut Context, target: Entity, event: impl Send + 'static) {
+        cx.emit_to(target, event);
+        flush(cx);
+    }
+
+    fn input_of(cx: &Context, input: Entity) -> &DateTimeInput {
+        cx.views.get(&input).and_then(|view| view.downcast_ref()).unwrap()
+    }
+
+    fn model_value(cx: &Context) -> NaiveDateTime {
+        cx.data::<TestData>().unwrap().value
+    }
+
+    #[test]
+    fn locale_formats() {
+        for (locale, show_seconds, text) in [
+            ("en-US", false, "05/01/2024 03:04 PM"),
+            ("en-GB", false, "01/05/2024 15:04"),
+            ("de", true, "01.05.2024 15:04:05"),
+            ("ja", true, "2024/05/01 15:04:05"),
+        ] {
+            let (cx, input) = context(locale, show_seconds);
+            assert_eq!(input_of(&cx, input).text, text);
+        }
+
+        let (mut cx, input) = context("en-US", true);
+        emit(&mut cx, input, DateTimeInputEvent::SetUse24Hour(true));
+        assert_eq!(input_of(&cx, input).text, "05/01/2024 15:04:05");
+
+        // The format follows changes to the locale.
+        emit(&mut cx, Entity::root(), EnvironmentEvent::SetLocale("de".parse().unwrap()));
+        assert_eq!(input_of(&cx, input).text, "01.05.2024 15:04:05");
+    }
+
+    #[test]
+    fn submit_parses_text() {
+        for (locale, show_seconds) in [("en-US", false), ("de", true), ("sv-SE", false)] {
+            let (mut cx, input) = context(locale, show_seconds);
+            let text = input_of(&cx, input).text.replace("04", "06");
+            emit(&mut cx, input, DateTimeInputEvent::Submit(text));
+            assert_eq!(model_value(&cx).format("%F %R").to_string(), "2024-05-01 15:06");
+        }
+
+        let (mut cx, input) = context("en-US", false);
+        emit(&mut cx, input, DateTimeInputEvent::Submit(String::from(" 12/31/2024 11:59 am ")));
+        assert_eq!(
+            model_value(&cx),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap().and_hms_opt(11, 59, 0).unwrap()
+        );
+
+        // Text which can't be parsed is replaced by the current value.
+        for text in ["31/12/2024 11:59 AM", "12/31/2024 23:59"] {
+            emit(&mut cx, input, DateTimeInputEvent::Submit(String::from(text)));
+            assert_eq!(input_of(&cx, input).text, "12/31/2024 11:59 AM");
+        }
+    }
+
+    #[test]
+    fn selecting_date_closes_popup() {
+        let (mut cx, input) = context("en-GB", true);
+        let button = Entity::root()
+            .tree_iter(&cx.tree)
+            .find(|entity| {
+                cx.style
+                    .classes
+                    .get(*entity)
+                    .is_some_and(|classes| classes.contains("datetime-input-button"))
+            })
+            .unwrap();
+        cx.emit_custom(
+            Event::new(WindowEvent::Press { mouse: false })
+                .target(button)
+                .origin(button)
+                .propagate(Propagation::Direct),
+        );
+        flush(&mut cx);
+        assert!(input_of(&cx, input).is_open);
+
+        emit(
+            &mut cx,
+            input,
+            DateTimeInputEvent::SelectDate(NaiveDate::from_ymd_opt(2024, 6, 2).unwrap()),
+        );
+        assert!(!input_of(&cx, input).is_open);
+        assert_eq!(
+            model_value(&cx),
+            NaiveDate::from_ymd_opt(2024, 6, 2).unwrap().and_hms_opt(15, 4, 5).unwrap()
+        );
+        assert_eq!(input_of(&cx, input).text, "02/06/2024 15:04:05");
+    }
+}
@@ -0,0 +1,1146 @@
+use std::collections::HashMap;
+use std::fmt::Formatter;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use crate::prelude::*;
+
+use super::tabview::TabHeader;
+
+// The logical distance the cursor must move with a tab pressed before the tab is dragged.
+const TAB_DRAG_THRESHOLD: f32 = 8.0;
+
+/// Where a panel is docked relative to the stack of panels it is dropped onto.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum DockPosition {
+    /// Adds the panel to the stack.
+    Center,
+    /// Splits the stack, placing the panel to its left.
+    Left,
+    /// Splits the stack, placing the panel to its right.
+    Right,
+    /// Splits the stack, placing the panel above it.
+    Top,
+    /// Splits the stack, placing the panel below it.
+    Bottom,
+}
+
+/// A node of a [`DockLayout`], which is either a stack of panels shown as tabs, or nodes split side by side.
+#[derive(Debug, Clone, PartialEq, Data)]
+pub enum DockNode {
+    /// Panels, identified by name, stacked as tabs, and the index of the selected panel.
+    Tabs { panels: Vec<String>, selected: usize },
+    /// Nodes arranged side by side or above each other, and the ratio of the size of each node.
+    Split { orientation: Orientation, ratios: Vec<f32>, children: Vec<DockNode> },
+}
+
+impl DockNode {
+    /// Creates a stack of panels, with the first panel selected.
+    pub fn tabs<S: Into<String>>(panels: impl IntoIterator<Item = S>) -> Self {
+        Self::Tabs { panels: panels.into_iter().map(Into::into).collect(), selected: 0 }
+    }
+
+    /// Creates nodes split side by side, or above each other, with the same size.
+    pub fn split(orientation: Orientation, children: Vec<DockNode>) -> Self {
+        Self::Split { orientation, ratios: vec![1.0; children.len()], children }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Tabs { panels, .. } if panels.is_empty())
+    }
+
+    fn get(&self, path: &[usize]) -> Option<&DockNode> {
+        path.iter().try_fold(self, |node, index| match node {
+            Self::Split { children, .. } => children.get(*index),
+            Self::Tabs { .. } => None,
+        })
+    }
+
+    fn get_mut(&mut self, path: &[usize]) -> Option<&mut DockNode> {
+        path.iter().try_fold(self, |node, index| match node {
+            Self::Split { children, .. } => children.get_mut(*index),
+            Self::Tabs { .. } => None,
+        })
+    }
+
+    // Returns the path of the stack which contains a panel.
+    fn find(&self, panel: &str) -> Option<Vec<usize>> {
+        match self {
+            Self::Tabs { panels, .. } => panels.iter().any(|name| name == panel).then(Vec::new),
+            Self::Split { children, .. } => {
+                children.iter().enumerate().find_map(|(index, child)| {
+                    let mut path = child.find(panel)?;
+                    path.insert(0, index);
+                    Some(path)
+                })
+            }
+        }
+    }
+
+    // Places a node beside, or adds its panels to, the node at a path.
+    fn insert(&mut self, path: &[usize], node: DockNode, position: DockPosition) {
+        let orientation = match position {
+            DockPosition::Center => {
+                if let (Some(Self::Tabs { panels, selected }), Self::Tabs { panels: added, .. }) =
+                    (self.get_mut(path), node)
+                {
+                    *selected = panels.len();
+                    panels.extend(added);
+                }
+                return;
+            }
+            DockPosition::Left | DockPosition::Right => Orientation::Horizontal,
+            DockPosition::Top | DockPosition::Bottom => Orientation::Vertical,
+        };
+        let before = matches!(position, DockPosition::Left | DockPosition::Top);
+
+        // Share the space of the node with the new node if its parent is split in the same orientation.
+        if let Some((index, parent)) = path.split_last() {
+            if let Some(Self::Split { orientation: parent_orientation, ratios, children }) =
+                self.get_mut(parent)
+            {
+                if *parent_orientation == orientation {
+                    let ratio = ratios[*index] / 2.0;
+                    ratios[*index] = ratio;
+                    let index = if before { *index } else { index + 1 };
+                    ratios.insert(index, ratio);
+                    children.insert(index, node);
+                    return;
+                }
+            }
+        }
+
+        if let Some(target) = self.get_mut(path) {
+            let target_node = std::mem::replace(target, DockNode::tabs(Vec::<String>::new()));
+            let children = if before { vec![node, target_node] } else { vec![target_node, node] };
+            *target = DockNode::split(orientation, children);
+        }
+    }
+
+    // Removes empty stacks, replaces splits of a single node with the node, and merges splits within splits of the
+    // same orientation.
+    fn normalize(&mut self) {
+        let Self::Split { orientation, ratios, children } = self else {
+            return;
+        };
+        let orientation = *orientation;
+
+        let mut nodes = Vec::new();
+        for (mut child, ratio) in std::mem::take(children).into_iter().zip(std::mem::take(ratios)) {
+            child.normalize();
+            match child {
+                child if child.is_empty() => {}
+
+                Self::Split { orientation: child_orientation, ratios, children }
+                    if child_orientation == orientation =>
+                {
+                    let total = ratios.iter().sum::<f32>();
+                    let num_children = ratios.len() as f32;
+                    nodes.extend(children.into_iter().zip(ratios).map(|(child, child_ratio)| {
+                        if total > 0.0 {
+                            (child, ratio * child_ratio / total)
+                        } else {
+                            (child, ratio / num_children)
+                        }
+                    }));
+                }
+
+                child => nodes.push((child, ratio)),
+            }
+        }
+
+        *self = if nodes.len() > 1 {
+            let (children, ratios) = nodes.into_iter().unzip();
+            Self::Split { orientation, ratios, children }
+        } else {
+            nodes
+                .pop()
+                .map(|(node, _)| node)
+                .unwrap_or_else(|| DockNode::tabs(Vec::<String>::new()))
+        };
+    }
+
+    // Returns the node without its ratios or selected panels, which changes only when the panels are rearranged.
+    fn structure(&self) -> DockNode {
+        match self {
+            Self::Tabs { panels, .. } => Self::Tabs { panels: panels.clone(), selected: 0 },
+            Self::Split { orientation, children, .. } => Self::Split {
+                orientation: *orientation,
+                ratios: Vec::new(),
+                children: children.iter().map(Self::structure).collect(),
+            },
+        }
+    }
+}
+
+/// The arrangement of the panels of a [`DockArea`].
+///
+/// A layout can be converted to a string with [`ToString`], and parsed from a string with [`FromStr`], so that it
+/// can be saved and restored across sessions.
+#[derive(Debug, Clone, PartialEq, Data)]
+pub struct DockLayout {
+    /// The panels docked within the dock area.
+    pub root: DockNode,
+    /// The panels floating in separate windows.
+    pub floating: Vec<DockNode>,
+}
+
+impl ResGet<DockLayout> for DockLayout {
+    fn get_ref<'a>(&'a self, _: &'a impl DataContext) -> Option<LensValue<'a, DockLayout>> {
+        Some(LensValue::Borrowed(self))
+    }
+
+    fn get(&self, _: &impl DataContext) -> DockLayout {
+        self.clone()
+    }
+}
+
+impl Res<DockLayout> for DockLayout {}
+
+impl DockLayout {
+    /// Creates a new layout with the given docked panels, and no floating panels.
+    pub fn new(root: DockNode) -> Self {
+        Self { root, floating: Vec::new() }
+    }
+
+    fn area(&self, floating: Option<usize>) -> Option<&DockNode> {
+        match floating {
+            Some(index) => self.floating.get(index),
+            None => Some(&self.root),
+        }
+    }
+
+    fn area_mut(&mut self, floating: Option<usize>) -> Option<&mut DockNode> {
+        match floating {
+            Some(index) => self.floating.get_mut(index),
+            None => Some(&mut self.root),
+        }
+    }
+
+    // Returns the floating window, if any, and the path of the stack which contains a panel.
+    fn find(&self, panel: &str) -> Option<(Option<usize>, Vec<usize>)> {
+        if let Some(path) = self.root.find(panel) {
+            return Some((None, path));
+        }
+
+        self.floating
+            .iter()
+            .enumerate()
+            .find_map(|(index, node)| node.find(panel).map(|path| (Some(index), path)))
+    }
+
+    fn node(&self, floating: Option<usize>, path: &[usize]) -> Option<&DockNode> {
+        self.area(floating).and_then(|area| area.get(path))
+    }
+
+    fn ratios(&self, floating: Option<usize>, path: &[usize]) -> Vec<f32> {
+        match self.node(floating, path) {
+            Some(DockNode::Split { ratios, .. }) => ratios.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn selected(&self, floating: Option<usize>, path: &[usize]) -> Option<usize> {
+        match self.node(floating, path) {
+            Some(DockNode::Tabs { panels, selected }) if !panels.is_empty() => {
+                Some((*selected).min(panels.len() - 1))
+            }
+            _ => None,
+        }
+    }
+
+    /// Selects a panel within its stack, returning false if there is no such panel.
+    pub fn select(&mut self, panel: &str) -> bool {
+        let Some((floating, path)) = self.find(panel) else {
+            return false;
+        };
+
+        if let Some(DockNode::Tabs { panels, selected }) =
+            self.area_mut(floating).and_then(|area| area.get_mut(&path))
+        {
+            if let Some(index) = panels.iter().position(|name| name == panel) {
+                *selected = index;
+            }
+        }
+
+        true
+    }
+
+    /// Removes a panel from the layout, returning false if there is no such panel.
+    pub fn remove(&mut self, panel: &str) -> bool {
+        let Some((floating, path)) = self.find(panel) else {
+            return false;
+        };
+
+        let Some(area) = self.area_mut(floating) else {
+            return false;
+        };
+
+        if let Some(DockNode::Tabs { panels, selected }) = area.get_mut(&path) {
+            if let Some(index) = panels.iter().position(|name| name == panel) {
+                panels.remove(index);
+                if index < *selected {
+                    *selected -= 1;
+                }
+                *selected = (*selected).min(panels.len().saturating_sub(1));
+            }
+        }
+        area.normalize();
+
+        // Close floating windows which have no panels left.
+        if let Some(index) = floating {
+            if self.floating[index].is_empty() {
+                self.floating.remove(index);
+            }
+        }
+
+        true
+    }
+
+    /// Moves a panel to the stack containing a target panel, or beside it, returning false if either panel doesn't
+    /// exist or the panel would be placed beside itself.
+    pub fn dock(&mut self, panel: &str, target: &str, position: DockPosition) -> bool {
+        let Some((floating, path)) = self.find(target) else {
+            return false;
+        };
+
+        // The stack of the target is found by another of its panels when the panel is moved beside its own stack.
+        let anchor = if panel == target {
+            if position == DockPosition::Center {
+                return self.select(panel);
+            }
+
+            match self.node(floating, &path) {
+                Some(DockNode::Tabs { panels, .. }) => {
+                    match panels.iter().find(|name| *name != panel) {
+                        Some(name) => name.clone(),
+                        None => return false,
+                    }
+                }
+                _ => return false,
+            }
+        } else {
+            target.to_owned()
+        };
+
+        if !self.remove(panel) {
+            return false;
+        }
+
+        let Some((floating, path)) = self.find(&anchor) else {
+            return false;
+        };
+
+        if let Some(area) = self.area_mut(floating) {
+            area.insert(&path, DockNode::tabs([panel]), position);
+            area.normalize();
+        }
+
+        true
+    }
+
+    /// Moves a panel into a new floating window, returning false if there is no such panel.
+    pub fn float(&mut self, panel: &str) -> bool {
+        if !self.remove(panel) {
+            return false;
+        }
+
+        self.floating.push(DockNode::tabs([panel]));
+        true
+    }
+
+    /// Moves the panels of a floating window to the right of the docked panels, returning false if there is no such
+    /// window.
+    pub fn dock_floating(&mut self, index: usize) -> bool {
+        if index >= self.floating.len() {
+            return false;
+        }
+
+        let node = self.floating.remove(index);
+        if self.root.is_empty() {
+            self.root = node;
+        } else {
+            self.root.insert(&[], node, DockPosition::Right);
+            self.root.normalize();
+        }
+
+        true
+    }
+}
+
+/// An error returned when a [`DockLayout`] can't be parsed from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DockLayoutParseError {
+    position: usize,
+}
+
+impl DockLayoutParseError {
+    /// Returns the byte position within the string at which the layout is invalid.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl std::fmt::Display for DockLayoutParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid dock layout at position {}", self.position)
+    }
+}
+
+impl std::error::Error for DockLayoutParseError {}
+
+// A stack is written as `[selected:"name","name"]`, and a split as `h(ratio:node,ratio:node)` or
+// `v(ratio:node,ratio:node)`. The floating windows follow the docked panels, separated by `|`.
+impl std::fmt::Display for DockNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tabs { panels, selected } => {
+                write!(f, "[{}:", selected)?;
+                for (index, panel) in panels.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    f.write_str("\"")?;
+                    for c in panel.chars() {
+                        if matches!(c, '"' | '\\') {
+                            f.write_str("\\")?;
+                        }
+                        write!(f, "{}", c)?;
+                    }
+                    f.write_str("\"")?;
+                }
+                f.write_str("]")
+            }
+
+            Self::Split { orientation, ratios, children } => {
+                f.write_str(match orientation {
+                    Orientation::Horizontal => "h(",
+                    Orientation::Vertical => "v(",
+                })?;
+                for (index, (ratio, child)) in ratios.iter().zip(children).enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}:{}", ratio, child)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for DockLayout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.root)?;
+        for node in self.floating.iter() {
+            write!(f, "|{}", node)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DockLayout {
+    type Err = DockLayoutParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = LayoutParser { input: s, position: 0 };
+
+        let root = parser.node()?;
+        let mut floating = Vec::new();
+        while parser.peek() == Some('|') {
+            parser.next();
+            floating.push(parser.node()?);
+        }
+
+        if parser.peek().is_some() {
+            return Err(parser.error());
+        }
+
+        Ok(Self { root, floating })
+    }
+}
+
+struct LayoutParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl LayoutParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn error(&self) -> DockLayoutParseError {
+        DockLayoutParseError { position: self.position }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), DockLayoutParseError> {
+        if self.peek() == Some(expected) {
+            self.next();
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    // Parses a number, which is followed by a `:`.
+    fn number<T: FromStr>(&mut self) -> Result<T, DockLayoutParseError> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c != ':') {
+            self.next();
+        }
+
+        self.input[start..self.position]
+            .parse()
+            .map_err(|_| DockLayoutParseError { position: start })
+    }
+
+    fn string(&mut self) -> Result<String, DockLayoutParseError> {
+        self.expect('"')?;
+
+        let mut string = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(string),
+                Some('\\') => match self.next() {
+                    Some(c @ ('"' | '\\')) => string.push(c),
+                    _ => return Err(self.error()),
+                },
+                Some(c) => string.push(c),
+                None => return Err(self.error()),
+            }
+        }
+    }
+
+    fn node(&mut self) -> Result<DockNode, DockLayoutParseError> {
+        match self.peek() {
+            Some('[') => {
+                self.next();
+                let selected: usize = self.number()?;
+                self.expect(':')?;
+
+                let mut panels = Vec::new();
+                if self.peek() != Some(']') {
+                    loop {
+                        panels.push(self.string()?);
+                        if self.peek() != Some(',') {
+                            break;
+                        }
+                        self.next();
+                    }
+                }
+                self.expect(']')?;
+
+                Ok(DockNode::Tabs {
+                    selected: selected.min(panels.len().saturating_sub(1)),
+                    panels,
+                })
+            }
+
+            Some(c @ ('h' | 'v')) => {
+                self.next();
+                self.expect('(')?;
+
+                let mut ratios = Vec::new();
+                let mut children = Vec::new();
+                loop {
+                    ratios.push(self.number()?);
+                    self.expect(':')?;
+                    children.push(self.node()?);
+                    if self.peek() != Some(',') {
+                        break;
+                    }
+                    self.next();
+                }
+                self.expect(')')?;
+
+                let orientation =
+                    if c == 'h' { Orientation::Horizontal } else { Orientation::Vertical };
+                Ok(DockNode::Split { orientation, ratios, children })
+            }
+
+            _ => Err(self.error()),
+        }
+    }
+}
+
+/// Events which can be sent to a [`DockArea`] to rearrange its panels.
+pub enum DockEvent {
+    /// Sets the layout of the panels.
+    SetLayout(DockLayout),
+    /// Selects a panel within its stack.
+    Select(String),
+    /// Moves a panel to the stack containing a target panel, or beside it.
+    Dock { panel: String, target: String, position: DockPosition },
+    /// Moves a panel into a new floating window.
+    Float(String),
+    /// Moves the panels of a floating window, identified by its index, back into the dock area.
+    DockFloating(usize),
+}
+
+pub(crate) enum DockControlEvent {
+    SetRatios(Option<usize>, Vec<usize>, Vec<f32>),
+    SetStackBounds(Vec<usize>, BoundingBox),
+    PressTab(String),
+}
+
+type PanelBuilder = Rc<dyn Fn(&mut Context, &str) -> TabPair>;
+type FloatWindowBuilder = Rc<dyn Fn(&mut Context, usize, &dyn Fn(&mut Context))>;
+
+/// A view which arranges panels in stacks of tabs which can be split side by side, and rearranged by dragging their
+/// tabs.
+///
+/// Dropping a tab onto the edge of a stack splits the stack, dropping it onto the middle of a stack adds the panel
+/// to the stack, and dropping it outside of the dock area moves the panel into a floating window. Floating windows
+/// are created by the callback set with [`float_window`](Handle::float_window).
+///
+/// # Example
+/// ```ignore
+/// DockArea::new(cx, AppData::layout, |cx, panel| {
+///     let name = panel.to_owned();
+///     TabPair::new(move |cx| { Label::new(cx, name.clone()); }, |cx| { Element::new(cx); })
+/// })
+/// .float_window(|cx, index, content| {
+///     Window::new(cx, content).on_close(move |cx| cx.emit(DockEvent::DockFloating(index)));
+/// })
+/// .on_change(|cx, layout| cx.emit(AppEvent::SetLayout(layout.clone())));
+/// ```
+#[derive(Lens)]
+pub struct DockArea {
+    layout: DockLayout,
+    // The logical bounds, relative to the dock area, of where a dragged panel would be docked.
+    drop_indicator: Option<(f32, f32, f32, f32)>,
+    // The physical bounds of each stack of docked panels.
+    #[lens(ignore)]
+    stack_bounds: HashMap<Vec<usize>, BoundingBox>,
+    // The panel whose tab is pressed, the position of the cursor when it was pressed, and whether it is dragged.
+    #[lens(ignore)]
+    press: Option<(String, (f32, f32), bool)>,
+    #[lens(ignore)]
+    float_window: Option<FloatWindowBuilder>,
+    #[lens(ignore)]
+    on_change: Option<Box<dyn Fn(&mut EventContext, &DockLayout)>>,
+}
+
+impl DockArea {
+    /// Creates a new [`DockArea`] from a layout, and a function which returns the tab header and content of a panel
+    /// from its name.
+    pub fn new(
+        cx: &mut Context,
+        layout: impl Res<DockLayout>,
+        panel: impl 'static + Fn(&mut Context, &str) -> TabPair,
+    ) -> Handle<Self> {
+        let panel: PanelBuilder = Rc::new(panel);
+
+        let mut handle = Self {
+            layout: layout.get(cx),
+            drop_indicator: None,
+            stack_bounds: HashMap::new(),
+            press: None,
+            float_window: None,
+            on_change: None,
+        }
+        .build(cx, move |cx| {
+            // Rebuild the panels when they are rearranged, but not when they are resized or selected.
+            let docked = DockArea::layout.map(|layout| layout.root.structure());
+            let docked_panel = panel.clone();
+            Binding::new(cx, docked, move |cx, root| {
+                let root = root.get(cx);
+                build_node(cx, None, Vec::new(), &root, docked_panel.clone());
+            });
+
+            let floating = DockArea::root.map(|dock| {
+                let floating = dock.layout.floating.iter().map(DockNode::structure);
+                (dock.float_window.is_some(), floating.collect::<Vec<_>>())
+            });
+            Binding::new(cx, floating, move |cx, floating| {
+                let (_, floating) = floating.get(cx);
+                let Some(float_window) =
+                    cx.data::<DockArea>().and_then(|dock| dock.float_window.clone())
+                else {
+                    return;
+                };
+
+                for (index, node) in floating.into_iter().enumerate() {
+                    let panel = panel.clone();
+                    (float_window)(cx, index, &move |cx| {
+                        build_node(cx, Some(index), Vec::new(), &node, panel.clone())
+                    });
+                }
+            });
+
+            Element::new(cx).class("dock-drop-indicator").bind(
+                DockArea::drop_indicator,
+                |handle, indicator| {
+                    match indicator.get(&handle) {
+                        Some((x, y, width, height)) => handle
+                            .display(Display::Flex)
+                            .left(Pixels(x))
+                            .top(Pixels(y))
+                            .width(Pixels(width))
+                            .height(Pixels(height)),
+                        None => handle.display(Display::None),
+                    };
+                },
+            );
+        });
+
+        let entity = handle.entity();
+        layout.set_or_bind(handle.context(), entity, |cx, layout| {
+            let layout = layout.get(cx);
+            cx.emit(DockEvent::SetLayout(layout));
+        });
+
+        handle
+    }
+
+    fn notify_change(&self, cx: &mut EventContext) {
+        if let Some(callback) = &self.on_change {
+            (callback)(cx, &self.layout);
+        }
+    }
+
+    // Applies a change which rearranges the panels, which are rebuilt with new bounds.
+    fn rearrange(&mut self, cx: &mut EventContext, change: impl FnOnce(&mut DockLayout) -> bool) {
+        if (change)(&mut self.layout) {
+            self.stack_bounds.clear();
+            self.notify_change(cx);
+        }
+    }
+
+    // Returns the panel of the stack under the cursor, where a dragged panel would be docked, and the physical
+    // bounds of the area it would occupy.
+    fn drop_target(&self, x: f32, y: f32) -> Option<(String, DockPosition, BoundingBox)> {
+        let (path, bounds) =
+            self.stack_bounds.iter().find(|(_, bounds)| bounds.contains_point(x, y))?;
+
+        let target = match self.layout.root.get(path)? {
+            DockNode::Tabs { panels, selected } => panels.get(*selected).or(panels.first())?,
+            DockNode::Split { .. } => return None,
+        };
+
+        let position = drop_position(bounds, x, y);
+        Some((target.clone(), position, drop_zone(bounds, position)))
+    }
+}
+
+impl View for DockArea {
+    fn element(&self) -> Option<&'static str> {
+        Some("dock-area")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|dock_event, meta| {
+            match dock_event {
+                DockEvent::SetLayout(layout) => {
+                    if self.layout != *layout {
+                        self.layout = layout.clone();
+                        self.stack_bounds.clear();
+                    }
+                }
+
+                DockEvent::Select(panel) => {
+                    if self.layout.select(panel) {
+                        self.notify_change(cx);
+                    }
+                }
+
+                DockEvent::Dock { panel, target, position } => {
+                    self.rearrange(cx, |layout| layout.dock(panel, target, *position));
+                }
+
+                DockEvent::Float(panel) => {
+                    self.rearrange(cx, |layout| layout.float(panel));
+                }
+
+                DockEvent::DockFloating(index) => {
+                    self.rearrange(cx, |layout| layout.dock_floating(*index));
+                }
+            }
+            meta.consume();
+        });
+
+        event.map(|control_event, meta| {
+            match control_event {
+                DockControlEvent::SetRatios(floating, path, ratios) => {
+                    if let Some(DockNode::Split { ratios: split_ratios, .. }) =
+                        self.layout.area_mut(*floating).and_then(|area| area.get_mut(path))
+                    {
+                        if split_ratios != ratios {
+                            *split_ratios = ratios.clone();
+                            self.notify_change(cx);
+                        }
+                    }
+                }
+
+                DockControlEvent::SetStackBounds(path, bounds) => {
+                    self.stack_bounds.insert(path.clone(), *bounds);
+                }
+
+                DockControlEvent::PressTab(panel) => {
+                    let mouse = cx.mouse();
+                    self.press = Some((panel.clone(), (mouse.cursor_x, mouse.cursor_y), false));
+                }
+            }
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseMove(x, y) => {
+                let Some((_, (start_x, start_y), dragging)) = &mut self.press else {
+                    return;
+                };
+
+                if !*dragging {
+                    let distance = (x - *start_x).hypot(y - *start_y);
+                    if distance < TAB_DRAG_THRESHOLD * cx.scale_factor() {
+                        return;
+                    }
+
+                    // Capture the mouse so that the tab can be dropped anywhere.
+                    *dragging = true;
+                    cx.capture();
+                }
+
+                let bounds = cx.bounds();
+                let scale_factor = cx.scale_factor();
+                self.drop_indicator = self.drop_target(*x, *y).map(|(_, _, zone)| {
+                    (
+                        (zone.x - bounds.x) / scale_factor,
+                        (zone.y - bounds.y) / scale_factor,
+                        zone.w / scale_factor,
+                        zone.h / scale_factor,
+                    )
+                });
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                let Some((panel, _, dragging)) = self.press.take() else {
+                    return;
+                };
+
+                if dragging {
+                    cx.release();
+                    self.drop_indicator = None;
+
+                    let (x, y) = (cx.mouse().cursor_x, cx.mouse().cursor_y);
+                    if let Some((target, position, _)) = self.drop_target(x, y) {
+                        self.rearrange(cx, |layout| layout.dock(&panel, &target, position));
+                    } else if !cx.bounds().contains_point(x, y) {
+                        self.rearrange(cx, |layout| layout.float(&panel));
+                    }
+
+                    meta.consume();
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl Handle<'_, DockArea> {
+    /// Sets a callback which creates a window for each floating window of panels, given the index of the floating
+    /// window and a function which builds its content.
+    ///
+    /// Emitting [`DockEvent::DockFloating`] when the window is closed moves its panels back into the dock area.
+    /// Without this callback, floating panels are not shown.
+    pub fn float_window(
+        self,
+        callback: impl 'static + Fn(&mut Context, usize, &dyn Fn(&mut Context)),
+    ) -> Self {
+        self.modify(|dock: &mut DockArea| dock.float_window = Some(Rc::new(callback)))
+    }
+
+    /// Sets a callback which is called with the layout of the panels whenever they are rearranged, resized or
+    /// selected.
+    pub fn on_change(self, callback: impl 'static + Fn(&mut EventContext, &DockLayout)) -> Self {
+        self.modify(|dock: &mut DockArea| dock.on_change = Some(Box::new(callback)))
+    }
+}
+
+fn build_node(
+    cx: &mut Context,
+    floating: Option<usize>,
+    path: Vec<usize>,
+    node: &DockNode,
+    panel: PanelBuilder,
+) {
+    match node {
+        DockNode::Tabs { panels, .. } => {
+            DockStack::new(cx, floating, path, panels.clone(), panel);
+        }
+
+        DockNode::Split { orientation, children, .. } => {
+            let children = children.clone();
+            let ratios_path = path.clone();
+            let ratios = DockArea::layout.map(move |layout| layout.ratios(floating, &ratios_path));
+            let change_path = path.clone();
+
+            Splitter::new(cx, ratios, move |cx, index| {
+                if let Some(child) = children.get(index) {
+                    let mut path = path.clone();
+                    path.push(index);
+                    build_node(cx, floating, path, child, panel.clone());
+                }
+            })
+            .orientation(*orientation)
+            .on_change(move |cx, ratios| {
+                cx.emit(DockControlEvent::SetRatios(floating, change_path.clone(), ratios.to_vec()))
+            });
+        }
+    }
+}
+
+// A stack of panels, with a tab header for each panel and the content of the selected panel.
+struct DockStack {
+    panels: Vec<String>,
+}
+
+impl DockStack {
+    fn new(
+        cx: &mut Context,
+        floating: Option<usize>,
+        path: Vec<usize>,
+        panels: Vec<String>,
+        panel: PanelBuilder,
+    ) -> Handle<Self> {
+        let selected_path = path.clone();
+        let selected =
+            DockArea::layout.map(move |layout| layout.selected(floating, &selected_path));
+
+        Self { panels: panels.clone() }
+            .build(cx, move |cx| {
+                HStack::new(cx, |cx| {
+                    for (index, name) in panels.iter().enumerate() {
+                        let header_panel = panel.clone();
+                        let header_name = name.clone();
+                        let header = TabHeader::new(cx, index, move |cx| {
+                            ((header_panel)(cx, &header_name).header)(cx)
+                        })
+                        .checked(selected.map(move |selected| *selected == Some(index)));
+
+                        // Only docked panels can be dragged, as the cursor can't be tracked across windows.
+                        if floating.is_none() {
+                            let name = name.clone();
+                            header.on_mouse_down(move |cx, button| {
+                                if button == MouseButton::Left {
+                                    cx.emit(DockControlEvent::PressTab(name.clone()));
+                                }
+                            });
+                        }
+                    }
+                })
+                .class("dock-tabs")
+                .role(Role::TabList);
+
+                VStack::new(cx, move |cx| {
+                    Binding::new(cx, selected, move |cx, selected| {
+                        if let Some(name) = selected.get(cx).and_then(|index| panels.get(index)) {
+                            ((panel)(cx, name).content)(cx);
+                        }
+                    });
+                })
+                .class("dock-content")
+                .role(Role::TabPanel);
+            })
+            .on_geo_changed(move |cx, geo| {
+                if floating.is_none()
+                    && geo.intersects(
+                        GeoChanged::POSX_CHANGED
+                            | GeoChanged::POSY_CHANGED
+                            | GeoChanged::WIDTH_CHANGED
+                            | GeoChanged::HEIGHT_CHANGED,
+                    )
+                {
+                    cx.emit(DockControlEvent::SetStackBounds(path.clone(), cx.bounds()));
+                }
+            })
+    }
+}
+
+impl View for DockStack {
+    fn element(&self) -> Option<&'static str> {
+        Some("dock-stack")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|tab_event, meta| match tab_event {
+            TabEvent::SetSelected(index) => {
+                if let Some(panel) = self.panels.get(*index) {
+                    cx.emit(DockEvent::Select(panel.clone()));
+                }
+                meta.consume();
+            }
+        });
+    }
+}
+
+// Returns where a panel dropped onto a stack would be docked, which is beside the stack when dropped near one of
+// its edges.
+fn drop_position(bounds: &BoundingBox, x: f32, y: f32) -> DockPosition {
+    let x = (x - bounds.x) / bounds.w;
+    let y = (y - bounds.y) / bounds.h;
+
+    // The distance from the nearest edge, relative to the size of the stack.
+    let edges = [
+        (x, DockPosition::Left),
+        (1.0 - x, DockPosition::Right),
+        (y, DockPosition::Top),
+        (1.0 - y, DockPosition::Bottom),
+    ];
+
+    edges
+        .into_iter()
+        .filter(|(distance, _)| *distance < 0.25)
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map_or(DockPosition::Center, |(_, position)| position)
+}
+
+// Returns the bounds of the area a panel docked onto a stack would occupy.
+fn drop_zone(bounds: &BoundingBox, position: DockPosition) -> BoundingBox {
+    let BoundingBox { x, y, w, h } = *bounds;
+    match position {
+        DockPosition::Center => *bounds,
+        DockPosition::Left => BoundingBox { x, y, w: w / 2.0, h },
+        DockPosition::Right => BoundingBox { x: x + w / 2.0, y, w: w / 2.0, h },
+        DockPosition::Top => BoundingBox { x, y, w, h: h / 2.0 },
+        DockPosition::Bottom => BoundingBox { x, y: y + h / 2.0, w, h: h / 2.0 },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(s: &str) -> DockLayout {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn layout_round_trip() {
+        let layout = DockLayout {
+            root: DockNode::Split {
+                orientation: Orientation::Horizontal,
+                ratios: vec![0.25, 0.75],
+                children: vec![
+                    DockNode::tabs(["Files", "Outline"]),
+                    DockNode::split(
+                        Orientation::Vertical,
+                        vec![DockNode::tabs(["Editor"]), DockNode::tabs(["Say \"hi\" \\o/"])],
+                    ),
+                ],
+            },
+            floating: vec![DockNode::tabs(["Console"])],
+        };
+
+        let string = layout.to_string();
+        assert_eq!(
+            string,
+            r#"h(0.25:[0:"Files","Outline"],0.75:v(1:[0:"Editor"],1:[0:"Say \"hi\" \\o/"]))|[0:"Console"]"#
+        );
+        assert_eq!(string.parse::<DockLayout>(), Ok(layout));
+    }
+
+    #[test]
+    fn layout_parse_errors() {
+        assert_eq!("".parse::<DockLayout>().unwrap_err().position(), 0);
+        assert_eq!("[0:\"a\"".parse::<DockLayout>().unwrap_err().position(), 6);
+        assert_eq!("h(x:[0:])".parse::<DockLayout>().unwrap_err().position(), 2);
+        assert_eq!("[0:\"a\"]]".parse::<DockLayout>().unwrap_err().position(), 7);
+
+        // The selected panel is kept within the stack.
+        assert_eq!(
+            layout("[5:\"a\",\"b\"]").root,
+            DockNode::Tabs { panels: vec!["a".into(), "b".into()], selected: 1 }
+        );
+    }
+
+    #[test]
+    fn dock_beside() {
+        let mut layout = layout(r#"[0:"a","b","c"]"#);
+        assert!(layout.dock("b", "a", DockPosition::Right));
+        assert_eq!(layout.to_string(), r#"h(1:[0:"a","c"],1:[0:"b"])"#);
+
+        // Splitting in the same orientation as the parent shares the space of the target.
+        assert!(layout.dock("c", "b", DockPosition::Left));
+        assert_eq!(layout.to_string(), r#"h(1:[0:"a"],0.5:[0:"c"],0.5:[0:"b"])"#);
+
+        assert!(layout.dock("a", "c", DockPosition::Bottom));
+        assert_eq!(layout.to_string(), r#"h(0.5:v(1:[0:"c"],1:[0:"a"]),0.5:[0:"b"])"#);
+
+        // A panel can't be placed beside a stack containing only itself.
+        assert!(!layout.dock("b", "b", DockPosition::Left));
+    }
+
+    #[test]
+    fn dock_center() {
+        let mut layout = layout(r#"h(1:[0:"a"],1:[0:"b","c"])"#);
+        assert!(layout.dock("a", "c", DockPosition::Center));
+        // Removing the only panel of a stack removes the stack, and the split around it.
+        assert_eq!(layout.to_string(), r#"[2:"b","c","a"]"#);
+
+        assert!(layout.dock("c", "c", DockPosition::Center));
+        assert_eq!(layout.to_string(), r#"[1:"b","c","a"]"#);
+    }
+
+    #[test]
+    fn float_and_dock_floating() {
+        let mut layout = layout(r#"h(1:[0:"a"],1:v(1:[0:"b"],1:[0:"c"]))"#);
+        assert!(layout.float("c"));
+        assert_eq!(layout.to_string(), r#"h(1:[0:"a"],1:[0:"b"])|[0:"c"]"#);
+
+        assert!(layout.dock("a", "c", DockPosition::Center));
+        assert_eq!(layout.to_string(), r#"[0:"b"]|[1:"c","a"]"#);
+
+        // Floating windows without panels are closed.
+        assert!(layout.dock("c", "b", DockPosition::Top));
+        assert!(layout.dock("a", "b", DockPosition::Center));
+        assert_eq!(layout.to_string(), r#"v(1:[0:"c"],1:[1:"b","a"])"#);
+
+        assert!(layout.float("a"));
+        assert!(layout.dock_floating(0));
+        assert_eq!(layout.to_string(), r#"h(1:v(1:[0:"c"],1:[0:"b"]),1:[0:"a"])"#);
+        assert!(!layout.dock_floating(0));
+    }
+
+    #[test]
+    fn normalize_merges_splits() {
+        let mut node = DockNode::split(
+            Orientation::Horizontal,
+            vec![
+                DockNode::split(
+                    Orientation::Horizontal,
+                    vec![DockNode::tabs(["a"]), DockNode::tabs(["b"])],
+                ),
+                DockNode::tabs(Vec::<String>::new()),
+                DockNode::tabs(["c"]),
+            ],
+        );
+        node.normalize();
+        assert_eq!(node.to_string(), r#"h(0.5:[0:"a"],0.5:[0:"b"],1:[0:"c"])"#);
+    }
+
+    #[test]
+    fn drop_position_and_zone() {
+        let bounds = BoundingBox { x: 100.0, y: 100.0, w: 200.0, h: 100.0 };
+        assert_eq!(drop_position(&bounds, 200.0, 150.0), DockPosition::Center);
+        assert_eq!(drop_position(&bounds, 110.0, 150.0), DockPosition::Left);
+        assert_eq!(drop_position(&bounds, 290.0, 150.0), DockPosition::Right);
+        assert_eq!(drop_position(&bounds, 200.0, 105.0), DockPosition::Top);
+        assert_eq!(drop_position(&bounds, 200.0, 195.0), DockPosition::Bottom);
+        // The nearest edge is used in the corners.
+        assert_eq!(drop_position(&bounds, 102.0, 110.0), DockPosition::Left);
+
+        assert_eq!(
+            drop_zone(&bounds, DockPosition::Right),
+            BoundingBox { x: 200.0, y: 100.0, w: 100.0, h: 100.0 }
+        );
+    }
+}
@@ -117,7 +117,9 @@ use crate::prelude::*;
 ///     });
 /// }).width(Pixels(100.0));
 /// ```
-pub struct Dropdown;
+pub struct Dropdown {
+    is_open: bool,
+}
 
 impl Dropdown {
     /// Creates a new dropdown.
@@ -136,45 +138,51 @@ impl Dropdown {
         L: 'static + Fn(&mut Context),
         F: 'static + Fn(&mut Context),
     {
-        Self {}.build(cx, move |cx| {
-            cx.add_listener(move |_dropdown: &mut Self, cx, event| {
-                event.map(|window_event, meta| match window_event {
-                    WindowEvent::PressDown { mouse: _ } => {
-                        if meta.origin != cx.current() {
-                            // Check if the mouse was pressed outside of any descendants
-                            if !cx.hovered.is_descendant_of(cx.tree, cx.current) {
-                                cx.emit(PopupEvent::Close);
+        Self { is_open: false }
+            .build(cx, move |cx| {
+                cx.add_listener(move |_dropdown: &mut Self, cx, event| {
+                    event.map(|window_event, meta| match window_event {
+                        WindowEvent::PressDown { mouse: _ } => {
+                            if meta.origin != cx.current() {
+                                // Check if the mouse was pressed outside of any descendants
+                                if !cx.hovered.is_descendant_of(cx.tree, cx.current) {
+                                    cx.emit(PopupEvent::Close);
+                                }
                             }
                         }
-                    }
 
-                    WindowEvent::KeyDown(code, _) => {
-                        if *code == Code::Escape {
-                            cx.emit(PopupEvent::Close);
+                        WindowEvent::KeyDown(code, _) => {
+                            if *code == Code::Escape {
+                                cx.emit(PopupEvent::Close);
+                            }
                         }
-                    }
 
-                    _ => {}
+                        _ => {}
+                    });
                 });
-            });
 
-            PopupData::default().build(cx);
+                PopupData::default().build(cx);
 
-            (trigger)(cx);
-            // .class("dropdown-title")
-            // .width(Stretch(1.0))
-            // .checked(PopupData::is_open)
-            // .navigable(true)
-            // .on_press(|cx| cx.emit(PopupEvent::Switch));
-            Binding::new(cx, PopupData::is_open, move |cx, is_open| {
-                if is_open.get(cx) {
-                    Popup::new(cx, |cx| {
-                        (content)(cx);
-                    })
-                    .arrow_size(Pixels(4.0));
-                }
+                (trigger)(cx);
+                // .class("dropdown-title")
+                // .width(Stretch(1.0))
+                // .checked(PopupData::is_open)
+                // .navigable(true)
+                // .on_press(|cx| cx.emit(PopupEvent::Switch));
+                Binding::new(cx, PopupData::is_open, move |cx, is_open| {
+                    if is_open.get(cx) {
+                        Popup::new(cx, |cx| {
+                            (content)(cx);
+                        })
+                        .arrow_size(Pixels(4.0));
+                    }
+                })
+            })
+            .role(Role::Group)
+            .bind(PopupData::is_open, |handle, is_open| {
+                let is_open = is_open.get(&handle);
+                handle.modify(|dropdown| dropdown.is_open = is_open);
             })
-        })
     }
 }
 
@@ -182,4 +190,25 @@ impl View for Dropdown {
     fn element(&self) -> Option<&'static str> {
         Some("dropdown")
     }
+
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_expanded(self.is_open);
+        if self.is_open {
+            node.add_action(Action::Collapse);
+        } else {
+            node.add_action(Action::Expand);
+        }
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| match window_event {
+            WindowEvent::ActionRequest(action) => match action.action {
+                Action::Expand => cx.emit(PopupEvent::Open),
+                Action::Collapse => cx.emit(PopupEvent::Close),
+                _ => {}
+            },
+
+            _ => {}
+        });
+    }
 }
@@ -0,0 +1,765 @@
+use crate::prelude::*;
+use crate::vg;
+use crate::views::normalized_map::NormalizedMap;
+
+// The distance, in logical pixels, within which a press selects a breakpoint.
+const HIT_RADIUS: f32 = 8.0;
+// How steeply a segment with a curvature of 1 or -1 bends.
+const CURVE_STEEPNESS: f32 = 6.0;
+const CURVE_STEP: f32 = 0.1;
+const NUDGE_STEP: f32 = 0.01;
+const FINE_NUDGE_STEP: f32 = 0.001;
+
+/// A breakpoint of an envelope edited by an [`EnvelopeEditor`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Data)]
+pub struct Breakpoint {
+    /// The normalized position of the breakpoint along the envelope, from 0.0 to 1.0.
+    pub x: f32,
+    /// The normalized value of the breakpoint, from 0.0 to 1.0.
+    pub y: f32,
+    /// The curvature of the segment from this breakpoint to the next one, from -1.0 to 1.0. Positive values make the
+    /// segment change slowly at first, negative values make it change quickly at first, and 0.0 is a straight line.
+    pub curve: f32,
+}
+
+impl Breakpoint {
+    /// Creates a new breakpoint with a straight segment to the next breakpoint.
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y, curve: 0.0 }
+    }
+
+    /// Sets the curvature of the segment from this breakpoint to the next one.
+    pub fn with_curve(mut self, curve: f32) -> Self {
+        self.curve = curve.clamp(-1.0, 1.0);
+        self
+    }
+
+    /// Returns the normalized value of an envelope, described by breakpoints sorted by position, at the given
+    /// normalized position. The envelope holds the value of its first and last breakpoints outside of them.
+    pub fn value_at(points: &[Self], x: f32) -> f32 {
+        let Some(first) = points.first() else {
+            return 0.0;
+        };
+
+        let index = points.partition_point(|point| point.x <= x);
+        if index == 0 {
+            return first.y;
+        }
+
+        let start = points[index - 1];
+        let Some(end) = points.get(index) else {
+            return start.y;
+        };
+
+        let t = if end.x > start.x { (x - start.x) / (end.x - start.x) } else { 1.0 };
+        start.y + (end.y - start.y) * curve_shape(t, start.curve)
+    }
+}
+
+// Maps the progress through a segment to the progress of its value, bent by the curvature of the segment.
+fn curve_shape(t: f32, curve: f32) -> f32 {
+    if curve.abs() < 1e-3 {
+        return t;
+    }
+
+    let k = curve * CURVE_STEEPNESS;
+    ((k * t).exp() - 1.0) / (k.exp() - 1.0)
+}
+
+/// The kind of edit described by an [`EnvelopeEdit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvelopeEditKind {
+    /// One or more breakpoints were moved.
+    Move,
+    /// The curvature of one or more segments was changed.
+    Curve,
+    /// A breakpoint was inserted.
+    Insert,
+    /// One or more breakpoints were removed.
+    Remove,
+}
+
+/// A completed edit of an envelope, passed to the [`on_edit`](Handle::on_edit) callback of an [`EnvelopeEditor`].
+///
+/// An edit is emitted once per gesture, such as at the end of a drag or for each key press, and holds the breakpoints
+/// from before and after the edit so that it can be pushed onto an undo stack.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvelopeEdit {
+    /// The kind of edit.
+    pub kind: EnvelopeEditKind,
+    /// The breakpoints before the edit.
+    pub before: Vec<Breakpoint>,
+    /// The breakpoints after the edit.
+    pub after: Vec<Breakpoint>,
+}
+
+pub(crate) enum EnvelopeEvent {
+    FocusPoint(usize),
+    Nudge { dx: f32, dy: f32, fine: bool },
+    Curve(f32),
+    Remove,
+    ToggleSelection,
+    SelectAll,
+    ClearSelection,
+}
+
+enum Drag {
+    Points { origin: Vec<Breakpoint>, indices: Vec<usize>, start: (f32, f32) },
+    Curve { origin: Vec<Breakpoint>, segment: usize, start: (f32, f32) },
+}
+
+/// A view for editing a multi-segment envelope, such as an ADSR or automation curve, bound to a list of
+/// [`Breakpoint`]s.
+///
+/// Breakpoints can be dragged with the mouse, and shift-clicking adds breakpoints to the selection so that they can
+/// be moved together. Double-clicking inserts a breakpoint, or removes the breakpoint under the pointer. Dragging a
+/// segment with the alt key held changes its curvature. When focused, a breakpoint can also be edited with the
+/// keyboard:
+///
+/// | Keys                  | Action                                               |
+/// |-----------------------|------------------------------------------------------|
+/// | Arrow keys            | Moves the selected breakpoints, or the focused one.  |
+/// | Shift + arrow keys    | Moves the breakpoints in finer steps.                |
+/// | Ctrl + up/down        | Changes the curvature of the following segments.     |
+/// | Delete / Backspace    | Removes the breakpoints.                             |
+/// | Space                 | Toggles the selection of the focused breakpoint.     |
+/// | Ctrl + A / Escape     | Selects all breakpoints / clears the selection.      |
+///
+/// Positions and values are normalized. The `x_map` and `y_map` are used to describe breakpoints to assistive
+/// technologies and to snap them to the values they can represent, and breakpoints can also be snapped to a
+/// [`grid`](Handle::grid). The editor never changes the bound data itself; use [`on_change`](Handle::on_change) to
+/// apply changes while editing and [`on_edit`](Handle::on_edit) to record completed edits for undo.
+///
+/// # Example
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// # use vizia_core::views::normalized_map::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     envelope: Vec<Breakpoint>,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # enum AppEvent {
+/// #     SetEnvelope(Vec<Breakpoint>),
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { envelope: vec![] }.build(cx);
+/// #
+/// let time = FrequencyMap::new(1.0, 1000.0, ValueScaling::Linear, FrequencyDisplayMode::default(), true);
+/// let level = DecibelMap::new(-60.0, 0.0, ValueScaling::Linear, DisplayDecimals::One, true);
+///
+/// EnvelopeEditor::new(cx, AppData::envelope, time, level)
+///     .grid(16, 8)
+///     .on_change(|cx, points| cx.emit(AppEvent::SetEnvelope(points)));
+/// ```
+#[derive(Lens)]
+pub struct EnvelopeEditor {
+    points: Vec<Breakpoint>,
+    selected: Vec<usize>,
+    focused: Option<usize>,
+    #[lens(ignore)]
+    drag: Option<Drag>,
+    grid: Option<(usize, usize)>,
+
+    map_snap: Box<dyn Fn(f32, f32) -> (f32, f32)>,
+    on_change: Option<Box<dyn Fn(&mut EventContext, Vec<Breakpoint>)>>,
+    on_edit: Option<Box<dyn Fn(&mut EventContext, EnvelopeEdit)>>,
+}
+
+impl EnvelopeEditor {
+    /// Creates a new envelope editor bound to the breakpoints targeted by the lens, which must be sorted by position.
+    /// The maps describe the range of positions and values of the envelope.
+    pub fn new<L, X, Y>(cx: &mut Context, lens: L, x_map: X, y_map: Y) -> Handle<Self>
+    where
+        L: Lens<Target = Vec<Breakpoint>>,
+        X: NormalizedMap,
+        Y: NormalizedMap,
+    {
+        let (snap_x, snap_y) = (x_map.clone(), y_map.clone());
+
+        Self {
+            points: lens.get(cx),
+            selected: Vec::new(),
+            focused: None,
+            drag: None,
+            grid: None,
+
+            map_snap: Box::new(move |x, y| (snap_x.snap(x), snap_y.snap(y))),
+            on_change: None,
+            on_edit: None,
+        }
+        .build(cx, move |cx| {
+            Keymap::from(vec![
+                (
+                    KeyChord::new(Modifiers::empty(), Code::ArrowLeft),
+                    KeymapEntry::new("Move Left", |cx| cx.emit(nudge(-1.0, 0.0, false))),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::ArrowRight),
+                    KeymapEntry::new("Move Right", |cx| cx.emit(nudge(1.0, 0.0, false))),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::ArrowUp),
+                    KeymapEntry::new("Move Up", |cx| cx.emit(nudge(0.0, 1.0, false))),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::ArrowDown),
+                    KeymapEntry::new("Move Down", |cx| cx.emit(nudge(0.0, -1.0, false))),
+                ),
+                (
+                    KeyChord::new(Modifiers::SHIFT, Code::ArrowLeft),
+                    KeymapEntry::new("Move Left Fine", |cx| cx.emit(nudge(-1.0, 0.0, true))),
+                ),
+                (
+                    KeyChord::new(Modifiers::SHIFT, Code::ArrowRight),
+                    KeymapEntry::new("Move Right Fine", |cx| cx.emit(nudge(1.0, 0.0, true))),
+                ),
+                (
+                    KeyChord::new(Modifiers::SHIFT, Code::ArrowUp),
+                    KeymapEntry::new("Move Up Fine", |cx| cx.emit(nudge(0.0, 1.0, true))),
+                ),
+                (
+                    KeyChord::new(Modifiers::SHIFT, Code::ArrowDown),
+                    KeymapEntry::new("Move Down Fine", |cx| cx.emit(nudge(0.0, -1.0, true))),
+                ),
+                (
+                    KeyChord::new(Modifiers::CTRL, Code::ArrowUp),
+                    KeymapEntry::new("Increase Curve", |cx| {
+                        cx.emit(EnvelopeEvent::Curve(CURVE_STEP))
+                    }),
+                ),
+                (
+                    KeyChord::new(Modifiers::CTRL, Code::ArrowDown),
+                    KeymapEntry::new("Decrease Curve", |cx| {
+                        cx.emit(EnvelopeEvent::Curve(-CURVE_STEP))
+                    }),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::Delete),
+                    KeymapEntry::new("Remove", |cx| cx.emit(EnvelopeEvent::Remove)),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::Backspace),
+                    KeymapEntry::new("Remove", |cx| cx.emit(EnvelopeEvent::Remove)),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::Space),
+                    KeymapEntry::new("Toggle Selection", |cx| {
+                        cx.emit(EnvelopeEvent::ToggleSelection)
+                    }),
+                ),
+                (
+                    KeyChord::new(Modifiers::CTRL, Code::KeyA),
+                    KeymapEntry::new("Select All", |cx| cx.emit(EnvelopeEvent::SelectAll)),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::Escape),
+                    KeymapEntry::new("Clear Selection", |cx| {
+                        cx.emit(EnvelopeEvent::ClearSelection)
+                    }),
+                ),
+            ])
+            .build(cx);
+
+            // Each breakpoint is a child view so that it can be focused and exposed to assistive technologies.
+            Binding::new(cx, lens.map(|points| points.len()), move |cx, len| {
+                for index in 0..len.get(cx) {
+                    let point =
+                        lens.map(move |points| points.get(index).copied().unwrap_or_default());
+                    let (x_map, y_map) = (x_map.clone(), y_map.clone());
+
+                    Element::new(cx)
+                        .position_type(PositionType::SelfDirected)
+                        .left(point.map(|point| Percentage(point.x * 100.0)))
+                        .top(point.map(|point| Percentage((1.0 - point.y) * 100.0)))
+                        .translate(Translate::new(
+                            Length::Value(LengthValue::Px(-5.0)),
+                            Length::Value(LengthValue::Px(-5.0)),
+                        ))
+                        .size(Pixels(10.0))
+                        .corner_radius(Percentage(50.0))
+                        .class("point")
+                        .toggle_class(
+                            "selected",
+                            EnvelopeEditor::selected.map(move |selected| selected.contains(&index)),
+                        )
+                        .navigable(true)
+                        .focusable(true)
+                        .role(Role::Slider)
+                        .name(format!("Point {}", index + 1))
+                        .numeric_value(point.map(|point| point.y))
+                        .text_value(point.map(move |point| {
+                            format!(
+                                "{}, {}",
+                                x_map.normalized_to_display(point.x),
+                                y_map.normalized_to_display(point.y)
+                            )
+                        }))
+                        .on_focus_in(move |cx| cx.emit(EnvelopeEvent::FocusPoint(index)));
+                }
+            });
+        })
+        .role(Role::Group)
+        .bind(lens, |handle, lens| {
+            let points = lens.get(&handle);
+            let mut handle = handle.modify(|editor| {
+                editor.selected.retain(|index| *index < points.len());
+                editor.focused = editor.focused.filter(|index| *index < points.len());
+                editor.points = points;
+            });
+            handle.needs_redraw();
+        })
+    }
+
+    // Returns the indices of the breakpoints edited by the keyboard: the selection, or else the focused breakpoint.
+    fn targets(&self) -> Vec<usize> {
+        if self.selected.is_empty() {
+            self.focused.into_iter().collect()
+        } else {
+            self.selected.clone()
+        }
+    }
+
+    fn snap(&self, x: f32, y: f32) -> (f32, f32) {
+        let (x, y) = match self.grid {
+            Some((columns, rows)) => (
+                (x * columns as f32).round() / columns as f32,
+                (y * rows as f32).round() / rows as f32,
+            ),
+            None => (x, y),
+        };
+
+        (self.map_snap)(x.clamp(0.0, 1.0), y.clamp(0.0, 1.0))
+    }
+
+    // Returns the normalized position of a point in physical coordinates.
+    fn normalize(bounds: BoundingBox, x: f32, y: f32) -> (f32, f32) {
+        ((x - bounds.x) / bounds.w, 1.0 - (y - bounds.y) / bounds.h)
+    }
+
+    // Returns the index of the breakpoint closest to a point in physical coordinates, if it is close enough to hit.
+    fn hit_test(&self, bounds: BoundingBox, scale_factor: f32, x: f32, y: f32) -> Option<usize> {
+        let distance = |point: &Breakpoint| {
+            let px = bounds.x + point.x * bounds.w;
+            let py = bounds.y + (1.0 - point.y) * bounds.h;
+            (px - x).hypot(py - y)
+        };
+
+        self.points
+            .iter()
+            .enumerate()
+            .map(|(index, point)| (index, distance(point)))
+            .filter(|(_, distance)| *distance <= HIT_RADIUS * scale_factor)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index)
+    }
+
+    // Returns the index of the breakpoint which starts the segment at a normalized position.
+    fn segment_at(&self, x: f32) -> Option<usize> {
+        self.points.windows(2).position(|segment| segment[0].x <= x && x <= segment[1].x)
+    }
+
+    // Applies new breakpoints while editing.
+    fn change(&mut self, cx: &mut EventContext, points: Vec<Breakpoint>) {
+        if points != self.points {
+            self.points = points.clone();
+            cx.needs_redraw();
+
+            if let Some(callback) = &self.on_change {
+                (callback)(cx, points);
+            }
+        }
+    }
+
+    // Applies new breakpoints and records them as a completed edit.
+    fn edit(&mut self, cx: &mut EventContext, kind: EnvelopeEditKind, points: Vec<Breakpoint>) {
+        let before = self.points.clone();
+        self.change(cx, points);
+        self.finish(cx, kind, before);
+    }
+
+    // Records the change from the given breakpoints to the current ones as a completed edit.
+    fn finish(&mut self, cx: &mut EventContext, kind: EnvelopeEditKind, before: Vec<Breakpoint>) {
+        if before != self.points {
+            if let Some(callback) = &self.on_edit {
+                (callback)(cx, EnvelopeEdit { kind, before, after: self.points.clone() });
+            }
+        }
+    }
+}
+
+fn nudge(dx: f32, dy: f32, fine: bool) -> EnvelopeEvent {
+    EnvelopeEvent::Nudge { dx, dy, fine }
+}
+
+// Moves the breakpoints at the given indices by a normalized offset, keeping the breakpoints sorted by position.
+fn move_points(
+    origin: &[Breakpoint],
+    indices: &[usize],
+    (dx, dy): (f32, f32),
+    snap: impl Fn(f32, f32) -> (f32, f32),
+) -> Vec<Breakpoint> {
+    let mut points = origin.to_vec();
+    let mut indices =
+        indices.iter().copied().filter(|index| *index < points.len()).collect::<Vec<_>>();
+    indices.sort_unstable();
+
+    for index in indices.iter().copied() {
+        let (x, y) = snap(points[index].x + dx, points[index].y + dy);
+        points[index].x = x;
+        points[index].y = y;
+    }
+
+    // Clamp the breakpoints furthest along the direction of movement first, so that the others stop behind them.
+    if dx > 0.0 {
+        indices.reverse();
+    }
+
+    for index in indices {
+        let min = if index > 0 { points[index - 1].x } else { 0.0 };
+        let max = points.get(index + 1).map_or(1.0, |point| point.x);
+        points[index].x = points[index].x.clamp(min, max.max(min));
+    }
+
+    points
+}
+
+// Inserts a breakpoint in position order, returning the index of the new breakpoint. The new breakpoint continues the
+// curvature of the segment it splits.
+fn insert_point(points: &mut Vec<Breakpoint>, mut point: Breakpoint) -> usize {
+    let index = points.partition_point(|other| other.x <= point.x);
+    if index > 0 {
+        point.curve = points[index - 1].curve;
+    }
+
+    points.insert(index, point);
+
+    index
+}
+
+impl Handle<'_, EnvelopeEditor> {
+    /// Snaps breakpoints to a grid with the given number of columns and rows.
+    pub fn grid(self, columns: usize, rows: usize) -> Self {
+        self.modify(|editor| editor.grid = Some((columns.max(1), rows.max(1))))
+    }
+
+    /// Sets the callback triggered with the new breakpoints whenever they change while editing, such as on each
+    /// movement of a drag.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Vec<Breakpoint>),
+    {
+        self.modify(|editor| editor.on_change = Some(Box::new(callback)))
+    }
+
+    /// Sets the callback triggered once for each completed edit, such as at the end of a drag.
+    pub fn on_edit<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, EnvelopeEdit),
+    {
+        self.modify(|editor| editor.on_edit = Some(Box::new(callback)))
+    }
+}
+
+impl View for EnvelopeEditor {
+    fn element(&self) -> Option<&'static str> {
+        Some("envelope-editor")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|envelope_event, _| match envelope_event {
+            EnvelopeEvent::FocusPoint(index) => {
+                self.focused = Some(*index);
+            }
+
+            EnvelopeEvent::Nudge { dx, dy, fine } => {
+                let (step_x, step_y) = match (self.grid, fine) {
+                    (_, true) => (FINE_NUDGE_STEP, FINE_NUDGE_STEP),
+                    (Some((columns, rows)), false) => (1.0 / columns as f32, 1.0 / rows as f32),
+                    (None, false) => (NUDGE_STEP, NUDGE_STEP),
+                };
+
+                let points = move_points(
+                    &self.points,
+                    &self.targets(),
+                    (dx * step_x, dy * step_y),
+                    |x, y| {
+                        if *fine {
+                            (self.map_snap)(x.clamp(0.0, 1.0), y.clamp(0.0, 1.0))
+                        } else {
+                            self.snap(x, y)
+                        }
+                    },
+                );
+                self.edit(cx, EnvelopeEditKind::Move, points);
+            }
+
+            EnvelopeEvent::Curve(delta) => {
+                let mut points = self.points.clone();
+                for index in self.targets() {
+                    if index + 1 < points.len() {
+                        points[index].curve = (points[index].curve + delta).clamp(-1.0, 1.0);
+                    }
+                }
+                self.edit(cx, EnvelopeEditKind::Curve, points);
+            }
+
+            EnvelopeEvent::Remove => {
+                let targets = self.targets();
+                let points = self
+                    .points
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| !targets.contains(index))
+                    .map(|(_, point)| *point)
+                    .collect();
+                self.selected.clear();
+                self.focused = None;
+                self.edit(cx, EnvelopeEditKind::Remove, points);
+            }
+
+            EnvelopeEvent::ToggleSelection => {
+                if let Some(focused) = self.focused {
+                    if let Some(position) = self.selected.iter().position(|index| *index == focused)
+                    {
+                        self.selected.remove(position);
+                    } else {
+                        self.selected.push(focused);
+                    }
+                }
+            }
+
+            EnvelopeEvent::SelectAll => {
+                self.selected = (0..self.points.len()).collect();
+            }
+
+            EnvelopeEvent::ClearSelection => {
+                self.selected.clear();
+            }
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                if cx.is_disabled() {
+                    return;
+                }
+
+                let bounds = cx.bounds();
+                let (x, y) = cx.mouse().left.pos_down;
+                let start = (x, y);
+
+                if let Some(index) = self.hit_test(bounds, cx.scale_factor(), x, y) {
+                    if cx.modifiers().shift() {
+                        if let Some(position) = self.selected.iter().position(|i| *i == index) {
+                            self.selected.remove(position);
+                        } else {
+                            self.selected.push(index);
+                        }
+                    } else if !self.selected.contains(&index) {
+                        self.selected = vec![index];
+                    }
+
+                    if self.selected.contains(&index) {
+                        self.drag = Some(Drag::Points {
+                            origin: self.points.clone(),
+                            indices: self.selected.clone(),
+                            start,
+                        });
+                    }
+                } else if cx.modifiers().alt() {
+                    let (x, _) = Self::normalize(bounds, x, y);
+                    if let Some(segment) = self.segment_at(x) {
+                        self.drag =
+                            Some(Drag::Curve { origin: self.points.clone(), segment, start });
+                    }
+                } else {
+                    self.selected.clear();
+                }
+
+                if self.drag.is_some() {
+                    cx.capture();
+                    cx.set_active(true);
+                }
+
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                let bounds = cx.bounds();
+                let points = match &self.drag {
+                    Some(Drag::Points { origin, indices, start }) => {
+                        let delta = ((x - start.0) / bounds.w, (start.1 - y) / bounds.h);
+                        move_points(origin, indices, delta, |x, y| self.snap(x, y))
+                    }
+
+                    Some(Drag::Curve { origin, segment, start }) => {
+                        let mut points = origin.clone();
+                        let (from, to) = (points[*segment].y, points[*segment + 1].y);
+                        // Dragging upwards bends the segment upwards, whichever way it slopes.
+                        let delta = 2.0 * (start.1 - y) / bounds.h * (from - to).signum();
+                        points[*segment].curve = (points[*segment].curve + delta).clamp(-1.0, 1.0);
+                        points
+                    }
+
+                    None => return,
+                };
+
+                self.change(cx, points);
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if let Some(drag) = self.drag.take() {
+                    cx.release();
+                    cx.set_active(false);
+
+                    match drag {
+                        Drag::Points { origin, .. } => {
+                            self.finish(cx, EnvelopeEditKind::Move, origin)
+                        }
+                        Drag::Curve { origin, .. } => {
+                            self.finish(cx, EnvelopeEditKind::Curve, origin)
+                        }
+                    }
+                }
+            }
+
+            WindowEvent::MouseDoubleClick(MouseButton::Left) => {
+                if cx.is_disabled() {
+                    return;
+                }
+
+                let bounds = cx.bounds();
+                let (x, y) = (cx.mouse().cursor_x, cx.mouse().cursor_y);
+                let mut points = self.points.clone();
+
+                if let Some(index) = self.hit_test(bounds, cx.scale_factor(), x, y) {
+                    points.remove(index);
+                    self.selected.clear();
+                    self.focused = None;
+                    self.edit(cx, EnvelopeEditKind::Remove, points);
+                } else {
+                    let (x, y) = Self::normalize(bounds, x, y);
+                    let (x, y) = self.snap(x, y);
+                    let index = insert_point(&mut points, Breakpoint::new(x, y));
+                    self.selected = vec![index];
+                    self.edit(cx, EnvelopeEditKind::Insert, points);
+                }
+
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let bounds = cx.bounds();
+        if bounds.w == 0.0 || bounds.h == 0.0 {
+            return;
+        }
+
+        cx.draw_background(canvas);
+        cx.draw_border(canvas);
+
+        let color = cx.font_color();
+        let mut paint = vg::Paint::default();
+        paint.set_color(color);
+        paint.set_style(vg::PaintStyle::Stroke);
+        paint.set_anti_alias(true);
+
+        // Grid
+        if let Some((columns, rows)) = self.grid {
+            let mut path = vg::Path::new();
+            for column in 1..columns {
+                let x = bounds.x + bounds.w * column as f32 / columns as f32;
+                path.move_to((x, bounds.top()));
+                path.line_to((x, bounds.bottom()));
+            }
+            for row in 1..rows {
+                let y = bounds.y + bounds.h * row as f32 / rows as f32;
+                path.move_to((bounds.left(), y));
+                path.line_to((bounds.right(), y));
+            }
+
+            paint.set_alpha_f(color.a() as f32 / 255.0 * 0.15);
+            paint.set_stroke_width(1.0);
+            canvas.draw_path(&path, &paint);
+        }
+
+        // Envelope
+        let to_point = |x: f32, y: f32| (bounds.x + x * bounds.w, bounds.y + (1.0 - y) * bounds.h);
+        let mut path = vg::Path::new();
+        if let Some(first) = self.points.first() {
+            path.move_to(to_point(first.x, first.y));
+        }
+        for segment in self.points.windows(2) {
+            let (start, end) = (segment[0], segment[1]);
+            let steps = ((end.x - start.x) * bounds.w / 2.0).ceil().max(1.0) as usize;
+            for step in 1..=steps {
+                let t = step as f32 / steps as f32;
+                let y = start.y + (end.y - start.y) * curve_shape(t, start.curve);
+                path.line_to(to_point(start.x + (end.x - start.x) * t, y));
+            }
+        }
+
+        paint.set_alpha(color.a());
+        paint.set_stroke_width(1.5 * cx.scale_factor());
+        canvas.draw_path(&path, &paint);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn envelope() -> Vec<Breakpoint> {
+        vec![Breakpoint::new(0.0, 0.0), Breakpoint::new(0.5, 1.0), Breakpoint::new(1.0, 0.0)]
+    }
+
+    #[test]
+    fn value_at_follows_segments() {
+        let points = envelope();
+
+        assert_eq!(Breakpoint::value_at(&points, 0.25), 0.5);
+        assert_eq!(Breakpoint::value_at(&points, 0.5), 1.0);
+        assert_eq!(Breakpoint::value_at(&points, 2.0), 0.0);
+        assert_eq!(Breakpoint::value_at(&[], 0.5), 0.0);
+
+        // A positive curvature rises slowly at first.
+        let curved = [Breakpoint::new(0.0, 0.0).with_curve(1.0), Breakpoint::new(1.0, 1.0)];
+        let value = Breakpoint::value_at(&curved, 0.5);
+        assert!(value > 0.0 && value < 0.5);
+    }
+
+    #[test]
+    fn moved_points_stay_sorted() {
+        let points = envelope();
+        let snap = |x: f32, y: f32| (x.clamp(0.0, 1.0), y.clamp(0.0, 1.0));
+
+        let moved = move_points(&points, &[1], (0.8, 0.5), snap);
+        assert_eq!(moved[1], Breakpoint::new(1.0, 1.0));
+
+        // Breakpoints moved together stop at their neighbours without crossing each other.
+        let moved = move_points(&points, &[0, 1], (0.7, 0.0), snap);
+        assert_eq!(moved.iter().map(|point| point.x).collect::<Vec<_>>(), [0.7, 1.0, 1.0]);
+
+        let moved = move_points(&points, &[1, 2], (-0.75, 0.0), snap);
+        assert_eq!(moved.iter().map(|point| point.x).collect::<Vec<_>>(), [0.0, 0.0, 0.25]);
+    }
+
+    #[test]
+    fn inserted_point_continues_segment_curvature() {
+        let mut points = vec![Breakpoint::new(0.0, 0.0).with_curve(0.5), Breakpoint::new(1.0, 1.0)];
+
+        let index = insert_point(&mut points, Breakpoint::new(0.25, 0.5));
+
+        assert_eq!(index, 1);
+        assert_eq!(points[1].curve, 0.5);
+        assert_eq!(points.len(), 3);
+    }
+}
@@ -9,7 +9,7 @@ impl Image {
     pub fn new<T: ToString>(cx: &mut Context, img: impl Res<T>) -> Handle<'_, Self> {
         // TODO: Make this reactive
         let img = BackgroundImage::Url(Url { url: img.get(cx).to_string().into() });
-        Self {}.build(cx, |_| {}).background_image(img)
+        Self {}.build(cx, |_| {}).background_image(img).role(Role::Image)
     }
 }
 
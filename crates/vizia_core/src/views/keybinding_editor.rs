@@ -0,0 +1,385 @@
+use std::fmt::Display;
+
+use crate::icons::{ICON_ALERT_TRIANGLE, ICON_RESTORE, ICON_X};
+use crate::input::binding_conflicts;
+use crate::prelude::*;
+
+// The maximum number of key chords which can be captured for a key sequence.
+const MAX_CHORDS: usize = 4;
+
+pub(crate) enum KeybindingEditorEvent {
+    StartCapture(usize),
+    CancelCapture(usize),
+    Accept,
+    Clear(usize),
+    Reset(usize),
+}
+
+/// A view which lists the bindings of a [`Keymap`] and lets the user change them.
+///
+/// Pressing the shortcut of an action starts capturing the keys pressed by the user, which replace the key sequences
+/// bound to the action when the shortcut is pressed again or <kbd>Enter</kbd> is pressed. Capturing is canceled by
+/// pressing <kbd>Escape</kbd> or moving the focus away. Actions bound to conflicting key sequences are marked with the
+/// `conflict` class.
+///
+/// The editor changes the bindings with [`KeymapEvent`]s, so it must be built inside the view of the keymap.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Debug, Clone, Copy, PartialEq, Data)]
+/// # enum Action {
+/// #     Save,
+/// # }
+/// #
+/// # impl std::fmt::Display for Action {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         write!(f, "{:?}", self)
+/// #     }
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// Keymap::from(vec![(
+///     KeyChord::new(Modifiers::PRIMARY, Code::KeyS),
+///     KeymapEntry::new(Action::Save, |_| debug!("Save")),
+/// )])
+/// .build(cx);
+///
+/// KeybindingEditor::new(cx, Keymap::<Action>::bindings);
+/// ```
+#[derive(Lens)]
+pub struct KeybindingEditor {
+    capturing: Option<usize>,
+    captured: KeySequence,
+
+    #[lens(ignore)]
+    rebind: Box<dyn Fn(&mut EventContext, usize, Vec<KeySequence>)>,
+    #[lens(ignore)]
+    reset: Box<dyn Fn(&mut EventContext, usize)>,
+}
+
+impl KeybindingEditor {
+    /// Creates a new [`KeybindingEditor`] for the bindings of a keymap, usually the [`Keymap::bindings`] lens.
+    pub fn new<L, T>(cx: &mut Context, lens: L) -> Handle<Self>
+    where
+        L: Lens<Target = Vec<KeyBinding<T>>>,
+        T: Data + Display + PartialEq + Send + Sync,
+    {
+        let action = move |cx: &EventContext, index: usize| {
+            lens.get(cx).get(index).map(|binding| binding.action.clone())
+        };
+
+        Self {
+            capturing: None,
+            captured: KeySequence::default(),
+            rebind: Box::new(move |cx, index, sequences| {
+                if let Some(action) = action(cx, index) {
+                    cx.emit(KeymapEvent::SetBindings(action, sequences));
+                }
+            }),
+            reset: Box::new(move |cx, index| {
+                if let Some(action) = action(cx, index) {
+                    cx.emit(KeymapEvent::ResetAction(action));
+                }
+            }),
+        }
+        .build(cx, move |cx| {
+            Binding::new(cx, lens.map(|bindings| bindings.len()), move |cx, len| {
+                for index in 0..len.get(cx) {
+                    Self::row(cx, lens, index);
+                }
+            });
+        })
+        .role(Role::List)
+    }
+
+    fn row<L, T>(cx: &mut Context, lens: L, index: usize)
+    where
+        L: Lens<Target = Vec<KeyBinding<T>>>,
+        T: Data + Display + PartialEq + Send + Sync,
+    {
+        let binding = lens.idx(index);
+        let has_conflict = lens.map(move |bindings| {
+            bindings.get(index).is_some_and(|binding| {
+                binding_conflicts(bindings).iter().any(|conflict| {
+                    conflict.action == binding.action || conflict.other_action == binding.action
+                })
+            })
+        });
+        let is_capturing =
+            KeybindingEditor::capturing.map(move |capturing| *capturing == Some(index));
+
+        HStack::new(cx, |cx| {
+            Label::new(cx, binding.map(|binding| binding.action.to_string()))
+                .class("keybinding-action");
+
+            Button::new(cx, |cx| {
+                Binding::new(cx, is_capturing, move |cx, is_capturing| {
+                    if is_capturing.get(cx) {
+                        Binding::new(cx, KeybindingEditor::captured, |cx, captured| {
+                            let captured = captured.get(cx);
+                            if captured.is_empty() {
+                                Label::new(cx, Localized::new("Press keys…"));
+                            } else {
+                                Label::new(cx, captured.to_string());
+                            }
+                        });
+                    } else {
+                        Binding::new(cx, binding.map(|b| b.sequences.clone()), |cx, sequences| {
+                            let sequences = sequences.get(cx);
+                            if sequences.is_empty() {
+                                Label::new(cx, Localized::new("Unbound")).class("unbound");
+                            } else {
+                                let sequences = sequences.iter().map(|s| s.to_string());
+                                Label::new(cx, sequences.collect::<Vec<_>>().join(", "));
+                            }
+                        });
+                    }
+                });
+            })
+            .class("keybinding-shortcut")
+            .checked(is_capturing)
+            .on_press(move |cx| cx.emit(KeybindingEditorEvent::StartCapture(index)))
+            .on_focus_out(move |cx| cx.emit(KeybindingEditorEvent::CancelCapture(index)));
+
+            Svg::new(cx, ICON_ALERT_TRIANGLE)
+                .class("keybinding-conflict-icon")
+                .name(Localized::new("Conflicting keybinding"))
+                .display(has_conflict);
+
+            Button::new(cx, |cx| Svg::new(cx, ICON_X))
+                .class("keybinding-clear")
+                .name(Localized::new("Remove keybinding"))
+                .disabled(binding.map(|binding| binding.sequences.is_empty()))
+                .on_press(move |cx| cx.emit(KeybindingEditorEvent::Clear(index)));
+
+            Button::new(cx, |cx| Svg::new(cx, ICON_RESTORE))
+                .class("keybinding-reset")
+                .name(Localized::new("Reset keybinding"))
+                .disabled(binding.map(KeyBinding::is_default))
+                .on_press(move |cx| cx.emit(KeybindingEditorEvent::Reset(index)));
+        })
+        .class("keybinding-row")
+        .role(Role::ListItem)
+        .toggle_class("conflict", has_conflict);
+    }
+}
+
+impl View for KeybindingEditor {
+    fn element(&self) -> Option<&'static str> {
+        Some("keybinding-editor")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|editor_event, meta| {
+            match editor_event {
+                KeybindingEditorEvent::StartCapture(index) => {
+                    if self.capturing == Some(*index) {
+                        cx.emit(KeybindingEditorEvent::Accept);
+                    } else {
+                        self.capturing = Some(*index);
+                        self.captured = KeySequence::default();
+                    }
+                }
+
+                KeybindingEditorEvent::CancelCapture(index) => {
+                    if self.capturing == Some(*index) {
+                        self.capturing = None;
+                    }
+                }
+
+                KeybindingEditorEvent::Accept => {
+                    if let Some(index) = self.capturing.take() {
+                        let captured = std::mem::take(&mut self.captured);
+                        if !captured.is_empty() {
+                            (self.rebind)(cx, index, vec![captured]);
+                        }
+                    }
+                }
+
+                KeybindingEditorEvent::Clear(index) => (self.rebind)(cx, *index, Vec::new()),
+
+                KeybindingEditorEvent::Reset(index) => (self.reset)(cx, *index),
+            }
+
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| {
+            if let WindowEvent::KeyDown(code, _) = window_event {
+                if self.capturing.is_none() {
+                    return;
+                }
+
+                // Keys pressed while capturing don't trigger the actions of any keymap.
+                meta.consume();
+
+                let modifiers = *cx.modifiers;
+                match code {
+                    Code::ShiftLeft
+                    | Code::ShiftRight
+                    | Code::ControlLeft
+                    | Code::ControlRight
+                    | Code::AltLeft
+                    | Code::AltRight
+                    | Code::MetaLeft
+                    | Code::MetaRight => {}
+
+                    // Accepted when the shortcut is pressed by the release of the key.
+                    Code::Enter | Code::NumpadEnter if modifiers.is_empty() => {}
+
+                    Code::Escape if modifiers.is_empty() => self.capturing = None,
+
+                    _ => {
+                        if self.captured.chords().len() < MAX_CHORDS {
+                            self.captured.0.push(KeyChord::new(modifiers, *code));
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    fn sequence(sequence: &str) -> KeySequence {
+        sequence.parse().unwrap()
+    }
+
+    fn keymap() -> Keymap<&'static str> {
+        Keymap::from(vec![
+            (sequence("Ctrl+S"), KeymapEntry::new("Save", |_| {})),
+            (sequence("Ctrl+O"), KeymapEntry::new("Open", |_| {})),
+        ])
+    }
+
+    fn context(keymap: Keymap<&'static str>) -> (Context, Entity) {
+        let mut cx = Context::default();
+        keymap.build(&mut cx);
+        let editor = KeybindingEditor::new(&mut cx, Keymap::<&'static str>::bindings).entity();
+        flush(&mut cx);
+        (cx, editor)
+    }
+
+    fn flush(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    fn keymap_of(cx: &Context) -> &Keymap<&'static str> {
+        cx.data().unwrap()
+    }
+
+    fn with_class(cx: &Context, class: &str) -> Vec<Entity> {
+        Entity::root()
+            .tree_iter(&cx.tree)
+            .filter(|entity| cx.style.classes.get(*entity).is_some_and(|c| c.contains(class)))
+            .collect()
+    }
+
+    fn shortcut_text(cx: &Context, index: usize) -> Option<String> {
+        let shortcut = with_class(cx, "keybinding-shortcut")[index];
+        shortcut.branch_iter(&cx.tree).find_map(|entity| cx.style.text.get(entity).cloned())
+    }
+
+    // Presses the shortcut of an action, then the keys of the chord, then the shortcut again to accept the chord.
+    fn capture(cx: &mut Context, editor: Entity, index: usize, chord: KeyChord) {
+        cx.emit_to(editor, KeybindingEditorEvent::StartCapture(index));
+        flush(cx);
+
+        cx.modifiers = chord.modifiers;
+        cx.emit_custom(
+            Event::new(WindowEvent::KeyDown(chord.code, None))
+                .target(editor)
+                .origin(editor)
+                .propagate(Propagation::Direct),
+        );
+        flush(cx);
+        cx.modifiers = Modifiers::empty();
+
+        cx.emit_to(editor, KeybindingEditorEvent::StartCapture(index));
+        flush(cx);
+    }
+
+    #[test]
+    fn rebind_updates_keymap() {
+        let (mut cx, editor) = context(keymap());
+        assert_eq!(shortcut_text(&cx, 0), Some(sequence("Ctrl+S").to_string()));
+
+        capture(&mut cx, editor, 0, KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::KeyS));
+
+        let binding = &keymap_of(&cx).bindings[0];
+        assert_eq!(binding.action, "Save");
+        assert_eq!(binding.sequences, [sequence("Ctrl+Shift+S")]);
+        assert!(!binding.is_default());
+        assert_eq!(shortcut_text(&cx, 0), Some(sequence("Ctrl+Shift+S").to_string()));
+
+        // Canceling the capture leaves the binding unchanged.
+        cx.emit_to(editor, KeybindingEditorEvent::StartCapture(1));
+        cx.emit_to(editor, KeybindingEditorEvent::CancelCapture(1));
+        cx.emit_to(editor, KeybindingEditorEvent::Accept);
+        flush(&mut cx);
+        assert_eq!(keymap_of(&cx).bindings[1].sequences, [sequence("Ctrl+O")]);
+
+        cx.emit_to(editor, KeybindingEditorEvent::Clear(1));
+        flush(&mut cx);
+        assert!(keymap_of(&cx).bindings[1].sequences.is_empty());
+
+        cx.emit_to(editor, KeybindingEditorEvent::Reset(0));
+        flush(&mut cx);
+        assert!(keymap_of(&cx).bindings[0].is_default());
+    }
+
+    #[test]
+    fn conflicting_binding_is_reported() {
+        let (mut cx, editor) = context(keymap());
+        assert!(with_class(&cx, "conflict").is_empty());
+
+        capture(&mut cx, editor, 1, KeyChord::new(Modifiers::CTRL, Code::KeyS));
+
+        assert_eq!(
+            keymap_of(&cx).conflicts(),
+            [KeymapConflict {
+                action: "Save",
+                sequence: sequence("Ctrl+S"),
+                other_action: "Open",
+                other_sequence: sequence("Ctrl+S"),
+            }]
+        );
+        assert_eq!(with_class(&cx, "conflict"), with_class(&cx, "keybinding-row"));
+
+        cx.emit_to(editor, KeybindingEditorEvent::Reset(1));
+        flush(&mut cx);
+        assert!(keymap_of(&cx).conflicts().is_empty());
+        assert!(with_class(&cx, "conflict").is_empty());
+    }
+
+    #[test]
+    fn config_round_trip() {
+        let (mut cx, editor) = context(keymap());
+        capture(&mut cx, editor, 0, KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::KeyS));
+        cx.emit_to(editor, KeybindingEditorEvent::Clear(1));
+        flush(&mut cx);
+
+        let config = keymap_of(&cx).to_config();
+        assert_eq!(config, "Save = Ctrl+Shift+S\nOpen =\n");
+
+        let mut loaded = keymap();
+        loaded.load_config(&config).unwrap();
+        let (mut other, other_editor) = context(loaded);
+        assert_eq!(keymap_of(&other).bindings, keymap_of(&cx).bindings);
+        assert_eq!(shortcut_text(&other, 0), Some(sequence("Ctrl+Shift+S").to_string()));
+
+        // Resetting the loaded bindings in the editor leaves nothing to persist.
+        other.emit_to(other_editor, KeybindingEditorEvent::Reset(0));
+        other.emit_to(other_editor, KeybindingEditorEvent::Reset(1));
+        flush(&mut other);
+        assert_eq!(keymap_of(&other).to_config(), "");
+    }
+}
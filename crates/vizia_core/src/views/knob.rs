@@ -2,8 +2,10 @@
 #![allow(unused_imports)]
 #![allow(unused_variables)]
 use crate::vg;
+use accesskit::ActionData;
 use morphorm::Units;
 
+use crate::param::emit_param_gesture;
 use crate::prelude::*;
 
 static DEFAULT_DRAG_SCALAR: f32 = 0.0042;
@@ -27,6 +29,7 @@ pub struct Knob<L> {
     modifier_scalar: f32,
 
     on_changing: Option<Box<dyn Fn(&mut EventContext, f32)>>,
+    param: Option<ParamId>,
 }
 
 impl<L: Lens<Target = f32>> Knob<L> {
@@ -50,6 +53,7 @@ impl<L: Lens<Target = f32>> Knob<L> {
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
 
             on_changing: None,
+            param: None,
         }
         .build(cx, move |cx| {
             ZStack::new(cx, move |cx| {
@@ -72,6 +76,8 @@ impl<L: Lens<Target = f32>> Knob<L> {
                 .class("knob-head");
             });
         })
+        .role(Role::Slider)
+        .numeric_value(lens)
         .navigable(true)
     }
 
@@ -98,12 +104,15 @@ impl<L: Lens<Target = f32>> Knob<L> {
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
 
             on_changing: None,
+            param: None,
         }
         .build(cx, move |cx| {
             ZStack::new(cx, move |cx| {
                 (content)(cx, lens).width(Percentage(100.0)).height(Percentage(100.0));
             });
         })
+        .role(Role::Slider)
+        .numeric_value(lens)
     }
 }
 
@@ -120,6 +129,11 @@ impl<'a, L: Lens<Target = f32>> Handle<'a, Knob<L>> {
 
         self
     }
+
+    /// Binds the knob to a plugin parameter, so that edits by the user emit [`ParamEvent`] gestures.
+    pub fn param(self, param: ParamId) -> Self {
+        self.modify(|knob| knob.param = Some(param))
+    }
 }
 
 impl<L: Lens<Target = f32>> View for Knob<L> {
@@ -127,6 +141,15 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
         Some("knob")
     }
 
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_min_numeric_value(0.0);
+        node.set_max_numeric_value(1.0);
+        node.set_numeric_value_step(self.arrow_scalar as f64);
+        node.add_action(Action::Increment);
+        node.add_action(Action::Decrement);
+        node.add_action(Action::SetValue);
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         let move_virtual_slider = |self_ref: &mut Self, cx: &mut EventContext, new_normal: f32| {
             self_ref.continuous_normal = new_normal.clamp(0.0, 1.0);
@@ -134,6 +157,14 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
             if let Some(callback) = &self_ref.on_changing {
                 (callback)(cx, self_ref.continuous_normal);
             }
+
+            if self_ref.is_dragging {
+                if let Some(param) = self_ref.param {
+                    cx.emit(ParamEvent::SetNormalized(param, self_ref.continuous_normal));
+                }
+            } else {
+                emit_param_gesture(cx, self_ref.param, self_ref.continuous_normal);
+            }
         };
 
         event.map(|window_event, _| match window_event {
@@ -145,9 +176,19 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
                 cx.focus_with_visibility(false);
 
                 self.continuous_normal = self.lens.get(cx);
+
+                if let Some(param) = self.param {
+                    cx.emit(ParamEvent::BeginEdit(param));
+                }
             }
 
             WindowEvent::MouseUp(button) if *button == MouseButton::Left => {
+                if self.is_dragging {
+                    if let Some(param) = self.param {
+                        cx.emit(ParamEvent::EndEdit(param));
+                    }
+                }
+
                 self.is_dragging = false;
 
                 self.continuous_normal = self.lens.get(cx);
@@ -182,6 +223,12 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
             }
 
             WindowEvent::MouseDoubleClick(button) if *button == MouseButton::Left => {
+                if self.is_dragging {
+                    if let Some(param) = self.param {
+                        cx.emit(ParamEvent::EndEdit(param));
+                    }
+                }
+
                 self.is_dragging = false;
 
                 move_virtual_slider(self, cx, self.default_normal);
@@ -197,6 +244,26 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
                 move_virtual_slider(self, cx, self.continuous_normal - self.arrow_scalar);
             }
 
+            WindowEvent::ActionRequest(action) => match action.action {
+                Action::Increment => {
+                    self.continuous_normal = self.lens.get(cx);
+                    move_virtual_slider(self, cx, self.continuous_normal + self.arrow_scalar);
+                }
+
+                Action::Decrement => {
+                    self.continuous_normal = self.lens.get(cx);
+                    move_virtual_slider(self, cx, self.continuous_normal - self.arrow_scalar);
+                }
+
+                Action::SetValue => {
+                    if let Some(ActionData::NumericValue(val)) = action.data {
+                        move_virtual_slider(self, cx, val as f32);
+                    }
+                }
+
+                _ => {}
+            },
+
             _ => {}
         });
     }
@@ -1,6 +1,7 @@
 use std::ops::Deref;
 
 use crate::prelude::*;
+use crate::systems::{checked_state, CheckedState};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Selectable {
@@ -21,10 +22,16 @@ pub enum ListEvent {
     ClearSelection,
 }
 
+pub(crate) enum ListSelectionEvent {
+    SetSelected(Vec<usize>),
+}
+
 /// A view for creating a list of items from a binding to a `Vec<T>`
 #[derive(Lens)]
 pub struct List {
     list_len: usize,
+    // The indices of the selected items, if the selection is set by the list.
+    selected: Option<Vec<usize>>,
 }
 
 impl List {
@@ -48,7 +55,7 @@ impl List {
         item_content: impl 'static + Fn(&mut Context, usize, MapRef<L, T>),
     ) -> Handle<Self> {
         let num_items = list.map(list_len);
-        Self { list_len: num_items.get(cx) }
+        Self { list_len: num_items.get(cx), selected: None }
             .build(cx, move |cx| {
                 Keymap::from(vec![
                     (
@@ -96,7 +103,15 @@ impl List {
 
                     for index in 0..num_items.get(cx) {
                         let item = list.map_ref(move |list| list_index(list, index));
+                        let start = cx.current().child_iter(&cx.tree).count();
                         item_content(cx, index, item);
+                        let items = cx
+                            .current()
+                            .child_iter(&cx.tree)
+                            .skip(start)
+                            .filter(|entity| !cx.tree.is_ignored(*entity))
+                            .collect::<Vec<_>>();
+                        List::list_items(cx, items, index);
                     }
                 });
                 // });
@@ -104,10 +119,63 @@ impl List {
             .width(Stretch(1.0))
             .role(Role::List)
     }
+
+    // Exposes the views built for an item as list items, and sets their checked state from the selection of the list.
+    fn list_items(cx: &mut Context, items: Vec<Entity>, index: usize) {
+        for entity in items.iter() {
+            // Items which already have a selectable role, such as a list box option, keep it.
+            let role = cx.style.role.get(*entity).copied().unwrap_or(Role::Unknown);
+            if checked_state(role) != CheckedState::Selected {
+                cx.style.role.insert(*entity, Role::ListItem);
+                cx.style.needs_access_update(*entity);
+            }
+        }
+
+        Binding::new(cx, List::selected, move |cx, selected| {
+            let Some(selected) = selected.get(cx) else {
+                return;
+            };
+
+            let is_selected = selected.contains(&index);
+            for entity in items.iter().copied() {
+                if let Some(abilities) = cx.style.abilities.get_mut(entity) {
+                    abilities.set(Abilities::CHECKABLE, true);
+                }
+                if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(entity) {
+                    pseudo_classes.set(PseudoClassFlags::CHECKED, is_selected);
+                }
+                cx.needs_restyle(entity);
+                cx.style.needs_access_update(entity);
+            }
+        });
+    }
 }
 
 impl View for List {
     fn element(&self) -> Option<&'static str> {
         Some("list")
     }
+
+    fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
+        event.map(|selection_event, meta| match selection_event {
+            ListSelectionEvent::SetSelected(selected) => {
+                self.selected = Some(selected.clone());
+                meta.consume();
+            }
+        });
+    }
+}
+
+impl Handle<'_, List> {
+    /// Sets the indices of the selected items, which are given the `:checked` pseudo-class and exposed as selected
+    /// to assistive technologies.
+    pub fn selected(mut self, selected: impl Res<Vec<usize>>) -> Self {
+        let entity = self.entity();
+        selected.set_or_bind(self.context(), entity, |cx, selected| {
+            let selected = selected.get(cx);
+            cx.emit(ListSelectionEvent::SetSelected(selected));
+        });
+
+        self
+    }
 }
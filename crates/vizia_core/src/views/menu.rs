@@ -30,6 +30,7 @@ impl MenuBar {
 
                 (content)(cx);
             })
+            .role(Role::MenuBar)
             .layout_type(LayoutType::Row)
     }
 }
@@ -121,6 +122,7 @@ impl Submenu {
                         }))
                         .arrow_size(Pixels(0.0))
                         .checked(Submenu::is_open)
+                        .role(Role::Menu)
                         .on_hover(|cx| {
                             cx.emit_custom(
                                 Event::new(MenuEvent::Close)
@@ -135,6 +137,7 @@ impl Submenu {
             })
             .navigable(true)
             .checked(Submenu::is_open)
+            .role(Role::MenuItem)
             .layout_type(LayoutType::Row)
             .on_press(|cx| cx.emit(MenuEvent::ToggleOpen));
 
@@ -154,7 +157,18 @@ impl View for Submenu {
         Some("submenu")
     }
 
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_expanded(self.is_open);
+        if self.is_open {
+            node.add_action(Action::Collapse);
+        } else {
+            node.add_action(Action::Expand);
+        }
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        let was_open = self.is_open;
+
         event.map(|window_event, meta| match window_event {
             WindowEvent::MouseEnter => {
                 if meta.target == cx.current {
@@ -195,6 +209,12 @@ impl View for Submenu {
                 _ => {}
             },
 
+            WindowEvent::ActionRequest(action) => match action.action {
+                Action::Expand if !self.is_open => cx.emit(MenuEvent::ToggleOpen),
+                Action::Collapse if self.is_open => cx.emit(MenuEvent::ToggleOpen),
+                _ => {}
+            },
+
             _ => {}
         });
 
@@ -227,6 +247,10 @@ impl View for Submenu {
 
             _ => {}
         });
+
+        if self.is_open != was_open {
+            cx.needs_access_update();
+        }
     }
 }
 
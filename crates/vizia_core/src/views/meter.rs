@@ -0,0 +1,291 @@
+use crate::prelude::*;
+use crate::vg;
+use crate::views::audio_buffer::{poll_audio, PollAudio};
+use crate::views::normalized_map::{amplitude_to_db, DecibelMap, DisplayDecimals, ValueScaling};
+
+const DEFAULT_HOLD_TIME: Duration = Duration::from_millis(1500);
+const DEFAULT_FALLOFF: f32 = 24.0;
+const DEFAULT_RMS_WINDOW: usize = 14400;
+
+/// The levels shown by a meter, in decibels.
+#[derive(Debug, Clone, PartialEq)]
+struct MeterLevels {
+    peak: f32,
+    rms: f32,
+    hold: f32,
+    hold_remaining: Duration,
+    mean_square: f32,
+}
+
+impl MeterLevels {
+    fn new(floor: f32) -> Self {
+        Self {
+            peak: floor,
+            rms: floor,
+            hold: floor,
+            hold_remaining: Duration::ZERO,
+            mean_square: 0.0,
+        }
+    }
+
+    // Updates the levels with the samples received since the last update, returning true if the levels changed.
+    fn process(&mut self, samples: &[f32], elapsed: Duration, meter: &Meter) -> bool {
+        let previous = self.clone();
+        let floor = meter.map.min_db();
+        let falloff = meter.falloff * elapsed.as_secs_f32();
+
+        let alpha = 1.0 / meter.rms_window.max(1) as f32;
+        let mut block_peak = 0.0f32;
+        for sample in samples {
+            block_peak = block_peak.max(sample.abs());
+            self.mean_square += (sample * sample - self.mean_square) * alpha;
+        }
+
+        self.peak = amplitude_to_db(block_peak).max(self.peak - falloff).max(floor);
+
+        self.rms = if samples.is_empty() {
+            (self.rms - falloff).max(floor)
+        } else {
+            amplitude_to_db(self.mean_square.sqrt()).max(floor)
+        };
+
+        if self.peak >= self.hold {
+            self.hold = self.peak;
+            self.hold_remaining = meter.hold_time;
+        } else if self.hold_remaining > elapsed {
+            self.hold_remaining -= elapsed;
+        } else {
+            self.hold = self.peak;
+            self.hold_remaining = Duration::ZERO;
+        }
+
+        *self != previous
+    }
+}
+
+/// A level meter which shows the peak and RMS levels of an audio signal, along with a peak hold indicator.
+///
+/// Samples are sent to the meter from the audio thread through an [`audio_ring_buffer`]. The meter polls the buffer
+/// and only redraws when the levels change. Levels are scaled to the meter using a [`DecibelMap`], which defaults
+/// to a linear scale from -60 dB to +6 dB.
+///
+/// The meter is vertical when it is taller than it is wide, and horizontal otherwise. The background color of the
+/// meter is used for the track and the font color for the levels.
+///
+/// # Example
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// let (producer, consumer) = audio_ring_buffer(4096);
+///
+/// Meter::new(cx, consumer).hold_time(Duration::from_secs(2)).falloff(12.0);
+/// ```
+pub struct Meter {
+    consumer: AudioConsumer,
+    samples: Vec<f32>,
+    levels: MeterLevels,
+    last_poll: Option<Instant>,
+
+    map: DecibelMap,
+    hold_time: Duration,
+    falloff: f32,
+    rms_window: usize,
+}
+
+impl Meter {
+    /// Creates a new meter which shows the levels of the samples received by the `consumer`.
+    pub fn new(cx: &mut Context, consumer: AudioConsumer) -> Handle<Self> {
+        let map = DecibelMap::new(-60.0, 6.0, ValueScaling::Linear, DisplayDecimals::One, true);
+
+        let handle = Self {
+            consumer,
+            samples: Vec::new(),
+            levels: MeterLevels::new(map.min_db()),
+            last_poll: None,
+
+            map,
+            hold_time: DEFAULT_HOLD_TIME,
+            falloff: DEFAULT_FALLOFF,
+            rms_window: DEFAULT_RMS_WINDOW,
+        }
+        .build(cx, |_| {})
+        .role(Role::Meter);
+
+        poll_audio(handle)
+    }
+}
+
+impl Handle<'_, Meter> {
+    /// Sets the map used to scale levels in decibels to the meter.
+    pub fn db_map(self, map: DecibelMap) -> Self {
+        self.modify(|meter| {
+            meter.levels = MeterLevels::new(map.min_db());
+            meter.map = map;
+        })
+    }
+
+    /// Sets how long the peak hold indicator stays at the highest peak before falling back to the current peak.
+    pub fn hold_time(self, hold_time: Duration) -> Self {
+        self.modify(|meter| meter.hold_time = hold_time)
+    }
+
+    /// Sets the rate, in decibels per second, at which the peak level falls when the signal gets quieter.
+    pub fn falloff(self, falloff: f32) -> Self {
+        self.modify(|meter| meter.falloff = falloff)
+    }
+
+    /// Sets the number of samples averaged to compute the RMS level.
+    pub fn rms_window(self, rms_window: usize) -> Self {
+        self.modify(|meter| meter.rms_window = rms_window)
+    }
+}
+
+impl View for Meter {
+    fn element(&self) -> Option<&'static str> {
+        Some("meter")
+    }
+
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_min_numeric_value(self.map.min_db() as f64);
+        node.set_max_numeric_value(self.map.max_db() as f64);
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.take(|_: PollAudio, _| {
+            let now = Instant::now();
+            let elapsed = self.last_poll.map_or(Duration::ZERO, |last_poll| now - last_poll);
+            self.last_poll = Some(now);
+
+            let samples = &mut self.samples;
+            samples.clear();
+            self.consumer.drain(|sample| samples.push(sample));
+
+            let mut levels = self.levels.clone();
+            if levels.process(&self.samples, elapsed, self) {
+                self.levels = levels;
+                cx.needs_redraw();
+            }
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let bounds = cx.bounds();
+        if bounds.w == 0.0 || bounds.h == 0.0 {
+            return;
+        }
+
+        cx.draw_background(canvas);
+        cx.draw_border(canvas);
+
+        let vertical = bounds.h > bounds.w;
+        let level_rect = |db: f32| {
+            let normalized = self.map.db_to_normalized(db).clamp(0.0, 1.0);
+            if vertical {
+                let height = bounds.h * normalized;
+                vg::Rect::from_xywh(bounds.x, bounds.bottom() - height, bounds.w, height)
+            } else {
+                vg::Rect::from_xywh(bounds.x, bounds.y, bounds.w * normalized, bounds.h)
+            }
+        };
+
+        let color = cx.font_color();
+        let mut paint = vg::Paint::default();
+        paint.set_color(color);
+        paint.set_anti_alias(false);
+
+        // Peak level
+        paint.set_alpha_f(color.a() as f32 / 510.0);
+        canvas.draw_rect(level_rect(self.levels.peak), &paint);
+
+        // RMS level
+        paint.set_alpha(color.a());
+        canvas.draw_rect(level_rect(self.levels.rms), &paint);
+
+        // Peak hold indicator
+        if self.levels.hold > self.map.min_db() {
+            let hold = level_rect(self.levels.hold);
+            let indicator = if vertical {
+                vg::Rect::from_xywh(bounds.x, hold.top, bounds.w, 2.0 * cx.scale_factor())
+            } else {
+                let width = 2.0 * cx.scale_factor();
+                vg::Rect::from_xywh(hold.right - width, bounds.y, width, bounds.h)
+            };
+            canvas.draw_rect(indicator, &paint);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    fn context() -> (Context, Entity, AudioProducer) {
+        let mut cx = Context::default();
+        let (producer, consumer) = audio_ring_buffer(16);
+        let meter = Meter::new(&mut cx, consumer)
+            .hold_time(Duration::from_secs(1))
+            .falloff(20.0)
+            .rms_window(4)
+            .entity();
+        (cx, meter, producer)
+    }
+
+    fn meter_of(cx: &Context, meter: Entity) -> &Meter {
+        cx.views.get(&meter).and_then(|view| view.downcast_ref()).unwrap()
+    }
+
+    #[test]
+    fn peak_follows_signal_and_falls_off() {
+        let (cx, meter, _) = context();
+        let meter = meter_of(&cx, meter);
+        let mut levels = MeterLevels::new(-60.0);
+
+        assert!(levels.process(&[0.5, -1.0, 0.25], Duration::ZERO, meter));
+        assert!(levels.peak.abs() < 1e-4);
+        assert!(levels.rms < levels.peak);
+
+        // The peak falls at 20 dB per second once the signal gets quieter.
+        levels.process(&[0.0], Duration::from_millis(500), meter);
+        assert!((levels.peak + 10.0).abs() < 1e-3);
+
+        // Silence eventually settles at the floor of the map and stops changing.
+        for _ in 0..10 {
+            levels.process(&[], Duration::from_secs(1), meter);
+        }
+        assert_eq!(levels.peak, -60.0);
+        assert_eq!(levels.rms, -60.0);
+        assert!(!levels.process(&[], Duration::from_secs(1), meter));
+    }
+
+    #[test]
+    fn hold_stays_until_hold_time_elapses() {
+        let (cx, meter, _) = context();
+        let meter = meter_of(&cx, meter);
+        let mut levels = MeterLevels::new(-60.0);
+
+        levels.process(&[1.0], Duration::ZERO, meter);
+        levels.process(&[0.1], Duration::from_millis(600), meter);
+        assert!(levels.hold.abs() < 1e-4);
+        assert!(levels.peak < levels.hold);
+
+        levels.process(&[0.1], Duration::from_millis(600), meter);
+        assert_eq!(levels.hold, levels.peak);
+    }
+
+    #[test]
+    fn polling_drains_received_samples() {
+        let (mut cx, meter, mut producer) = context();
+        producer.push_slice(&[0.5, -1.0, 0.25]);
+
+        cx.emit_to(meter, PollAudio);
+        EventManager::new().flush_events(&mut cx);
+        assert!(meter_of(&cx, meter).levels.peak.abs() < 1e-4);
+        assert_eq!(meter_of(&cx, meter).samples, [0.5, -1.0, 0.25]);
+
+        // Samples are only processed once.
+        cx.emit_to(meter, PollAudio);
+        EventManager::new().flush_events(&mut cx);
+        assert!(meter_of(&cx, meter).samples.is_empty());
+    }
+}
@@ -1,78 +1,114 @@
 //! Built-in views provided by vizia.
 
+mod audio_buffer;
 mod avatar;
 mod badge;
 mod button;
 mod checkbox;
 mod chip;
+mod color_picker;
 mod combobox;
+mod command_palette;
 mod datepicker;
+mod datetime_input;
 mod dialog;
 mod divider;
+mod dock;
 mod dropdown;
 mod element;
+mod envelope;
 mod image;
+mod keybinding_editor;
 mod knob;
 mod label;
 mod list;
 mod markdown;
 mod menu;
+mod meter;
 pub mod normalized_map;
+mod piano_keyboard;
 mod picklist;
 mod popup;
 mod progressbar;
 mod radio;
 mod rating;
+mod resize_handle;
 mod scrollbar;
 mod scrollview;
 mod slider;
+mod spectrum;
 mod spinbox;
+mod splitter;
 mod stack;
 mod switch;
+mod table;
 mod tabview;
 mod textbox;
+mod timepicker;
+mod toast;
 mod toggle_button;
 mod tooltip;
+mod tree_view;
 mod virtual_list;
+mod waveform;
 mod xypad;
 
 pub use crate::binding::Binding;
+pub use audio_buffer::{audio_ring_buffer, AudioConsumer, AudioProducer};
 pub use avatar::*;
 pub use badge::*;
 pub use button::{Button, ButtonGroup, ButtonModifiers, ButtonVariant};
 pub use checkbox::Checkbox;
 pub use chip::*;
+pub use color_picker::{ColorPicker, ColorPickerEvent, ColorPickerMode};
 pub use combobox::*;
+pub use command_palette::{Command, CommandEvent, CommandPalette};
 pub use datepicker::Datepicker;
+pub use datetime_input::DateTimeInput;
 pub use dialog::*;
 pub use divider::*;
+pub use dock::{DockArea, DockEvent, DockLayout, DockLayoutParseError, DockNode, DockPosition};
 pub use dropdown::Dropdown;
 pub use element::Element;
+pub use envelope::{Breakpoint, EnvelopeEdit, EnvelopeEditKind, EnvelopeEditor};
 pub use image::*;
+pub use keybinding_editor::KeybindingEditor;
 pub use knob::{ArcTrack, Knob, KnobMode, TickKnob, Ticks};
 pub use label::Label;
 pub use list::*;
 pub use markdown::*;
 pub use menu::*;
+pub use meter::Meter;
+pub use piano_keyboard::{NoteEvent, PianoKeyboard, PianoKeyboardAction};
 pub use picklist::{PickList, ScrollList};
 pub use popup::*;
 pub use progressbar::ProgressBar;
 pub use radio::RadioButton;
 pub use rating::Rating;
+pub use resize_handle::ResizeHandle;
 pub use scrollbar::Scrollbar;
 pub use scrollview::{ScrollEvent, ScrollView};
 pub use slider::{NamedSlider, Slider};
+pub use spectrum::Spectrum;
 pub use spinbox::{Spinbox, SpinboxEvent, SpinboxIcons};
+pub use splitter::{Splitter, SplitterEvent};
 pub use stack::{HStack, VStack, ZStack};
 pub use switch::Switch;
+pub use table::{
+    SortDirection, Table, TableColumn, TableColumns, TableEvent, TableIndex, TableSelectionMode,
+};
 pub use tabview::{TabEvent, TabPair, TabView};
 pub use textbox::{TextEvent, Textbox};
+pub use timepicker::Timepicker;
+pub use toast::{Toast, ToastArea, ToastEvent, ToastSeverity};
 pub use toggle_button::{ToggleButton, ToggleButtonModifiers};
 pub use tooltip::Tooltip;
+pub use tree_view::{TreeView, TreeViewEvent};
 pub use virtual_list::*;
+pub use waveform::Waveform;
 pub use xypad::XYPad;
 
-use crate::prelude::Data;
+use crate::prelude::{impl_res_simple, Data, DataContext, LensValue, Res, ResGet};
 
 /// The orientation of a widget, such as a slider or scrollbar
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Data)]
@@ -81,3 +117,5 @@ pub enum Orientation {
     Horizontal,
     Vertical,
 }
+
+impl_res_simple!(Orientation);
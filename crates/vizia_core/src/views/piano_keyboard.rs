@@ -0,0 +1,529 @@
+use std::ops::RangeInclusive;
+
+use crate::prelude::*;
+use crate::vg;
+
+// The width of a black key relative to a white key.
+const BLACK_KEY_WIDTH: f32 = 0.6;
+// The height of a black key relative to a white key.
+const BLACK_KEY_HEIGHT: f32 = 0.62;
+const DEFAULT_KEYBOARD_VELOCITY: f32 = 0.8;
+
+// The computer keys used to play notes, as offsets from the C of the current octave, in the layout used by most
+// digital audio workstations.
+const COMPUTER_KEYS: [(Code, u8); 17] = [
+    (Code::KeyA, 0),
+    (Code::KeyW, 1),
+    (Code::KeyS, 2),
+    (Code::KeyE, 3),
+    (Code::KeyD, 4),
+    (Code::KeyF, 5),
+    (Code::KeyT, 6),
+    (Code::KeyG, 7),
+    (Code::KeyY, 8),
+    (Code::KeyH, 9),
+    (Code::KeyU, 10),
+    (Code::KeyJ, 11),
+    (Code::KeyK, 12),
+    (Code::KeyO, 13),
+    (Code::KeyL, 14),
+    (Code::KeyP, 15),
+    (Code::Semicolon, 16),
+];
+
+/// Events emitted by a [`PianoKeyboard`] when notes are played.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NoteEvent {
+    /// A note was pressed with a velocity from 0.0 to 1.0.
+    NoteOn { note: u8, velocity: f32 },
+    /// A note was released.
+    NoteOff { note: u8 },
+}
+
+/// The actions of the [`Keymap`] used by a [`PianoKeyboard`] to play notes from the computer keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PianoKeyboardAction {
+    /// Plays the note at the given offset from the C of the current octave.
+    Note(u8),
+    /// Moves the notes played from the computer keyboard down an octave.
+    OctaveDown,
+    /// Moves the notes played from the computer keyboard up an octave.
+    OctaveUp,
+}
+
+pub(crate) enum PianoKeyboardEvent {
+    PressKey(u8),
+    Octave(i8),
+}
+
+/// Returns true if the MIDI note is a black key.
+fn is_black(note: u8) -> bool {
+    matches!(note % 12, 1 | 3 | 6 | 8 | 10)
+}
+
+/// An on-screen piano keyboard which plays notes with the mouse or the computer keyboard.
+///
+/// Pressing a key emits a [`NoteEvent::NoteOn`], with a velocity which increases towards the bottom of the key, and
+/// releasing it emits a [`NoteEvent::NoteOff`]. Dragging across the keyboard plays each key in turn. When the
+/// keyboard is focused, notes can also be played from the computer keyboard, with the `A` to `;` keys playing the
+/// white keys and the row above playing the black keys, while `Z` and `X` change octave. These keys are bound with a
+/// [`Keymap`] of [`PianoKeyboardAction`]s, which can be changed with [`KeymapEvent`]s.
+///
+/// Notes played elsewhere, such as by a MIDI controller, are highlighted from the notes targeted by a lens.
+///
+/// The background color of the keyboard is used for the white keys, the font color for the black keys, the selection
+/// color for playing notes, and the border color for the lines between the white keys.
+///
+/// # Example
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     notes: Vec<u8>,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { notes: vec![] }.build(cx);
+/// #
+/// PianoKeyboard::new(cx, 48..=72, AppData::notes);
+/// ```
+pub struct PianoKeyboard {
+    range: RangeInclusive<u8>,
+    external_notes: Vec<u8>,
+    mouse_note: Option<u8>,
+    is_dragging: bool,
+    // The notes played from the computer keyboard, along with the offset of the key which played them.
+    computer_notes: Vec<(u8, u8)>,
+    octave: u8,
+    keyboard_velocity: f32,
+}
+
+impl PianoKeyboard {
+    /// Creates a new piano keyboard showing the given range of MIDI notes, which highlights the notes targeted by the
+    /// lens. The range is extended to start and end on white keys.
+    pub fn new<L>(cx: &mut Context, range: RangeInclusive<u8>, notes: L) -> Handle<Self>
+    where
+        L: Lens<Target = Vec<u8>>,
+    {
+        let range = Self::extend_range(range);
+        // Start the computer keyboard at the lowest C within the range.
+        let octave = range.start().div_ceil(12).min(127 / 12);
+
+        Self {
+            range,
+            external_notes: notes.get(cx),
+            mouse_note: None,
+            is_dragging: false,
+            computer_notes: Vec::new(),
+            octave,
+            keyboard_velocity: DEFAULT_KEYBOARD_VELOCITY,
+        }
+        .build(cx, |cx| {
+            macro_rules! note_key {
+                ($code:ident, $offset:literal) => {
+                    (
+                        KeyChord::new(Modifiers::empty(), Code::$code),
+                        KeymapEntry::new(PianoKeyboardAction::Note($offset), |cx| {
+                            cx.emit(PianoKeyboardEvent::PressKey($offset))
+                        }),
+                    )
+                };
+            }
+
+            Keymap::from(vec![
+                note_key!(KeyA, 0),
+                note_key!(KeyW, 1),
+                note_key!(KeyS, 2),
+                note_key!(KeyE, 3),
+                note_key!(KeyD, 4),
+                note_key!(KeyF, 5),
+                note_key!(KeyT, 6),
+                note_key!(KeyG, 7),
+                note_key!(KeyY, 8),
+                note_key!(KeyH, 9),
+                note_key!(KeyU, 10),
+                note_key!(KeyJ, 11),
+                note_key!(KeyK, 12),
+                note_key!(KeyO, 13),
+                note_key!(KeyL, 14),
+                note_key!(KeyP, 15),
+                note_key!(Semicolon, 16),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::KeyZ),
+                    KeymapEntry::new(PianoKeyboardAction::OctaveDown, |cx| {
+                        cx.emit(PianoKeyboardEvent::Octave(-1))
+                    }),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::KeyX),
+                    KeymapEntry::new(PianoKeyboardAction::OctaveUp, |cx| {
+                        cx.emit(PianoKeyboardEvent::Octave(1))
+                    }),
+                ),
+            ])
+            .build(cx);
+        })
+        .navigable(true)
+        .focusable(true)
+        .role(Role::Group)
+        .bind(notes, |handle, notes| {
+            let notes = notes.get(&handle);
+            let mut handle = handle.modify(|keyboard| keyboard.external_notes = notes);
+            handle.needs_redraw();
+        })
+    }
+
+    fn extend_range(range: RangeInclusive<u8>) -> RangeInclusive<u8> {
+        let start = (*range.start()).min(127);
+        let end = (*range.end()).clamp(start, 127);
+        let start = if is_black(start) { start - 1 } else { start };
+        let end = if is_black(end) { end + 1 } else { end };
+
+        start..=end
+    }
+
+    fn white_keys(&self) -> usize {
+        self.range.clone().filter(|note| !is_black(*note)).count()
+    }
+
+    // Returns the horizontal position and width of a key, relative to the keyboard and in units of white keys.
+    fn key_extent(&self, note: u8) -> (f32, f32) {
+        let white_keys_before =
+            (*self.range.start()..note).filter(|note| !is_black(*note)).count() as f32;
+
+        if is_black(note) {
+            (white_keys_before - BLACK_KEY_WIDTH / 2.0, BLACK_KEY_WIDTH)
+        } else {
+            (white_keys_before, 1.0)
+        }
+    }
+
+    // Returns the note at a position relative to the keyboard, along with the velocity for a press at that position.
+    fn key_at(&self, width: f32, height: f32, x: f32, y: f32) -> Option<(u8, f32)> {
+        if x < 0.0 || x >= width || y < 0.0 || y >= height {
+            return None;
+        }
+
+        let white_key_width = width / self.white_keys() as f32;
+        let x = x / white_key_width;
+
+        let note = self
+            .range
+            .clone()
+            .filter(|note| is_black(*note))
+            .find(|note| {
+                let (left, width) = self.key_extent(*note);
+                y < height * BLACK_KEY_HEIGHT && x >= left && x < left + width
+            })
+            .or_else(|| {
+                self.range.clone().filter(|note| !is_black(*note)).nth(x.floor() as usize)
+            })?;
+
+        let key_height = if is_black(note) { height * BLACK_KEY_HEIGHT } else { height };
+        let velocity = (y / key_height).clamp(1.0 / 127.0, 1.0);
+
+        Some((note, velocity))
+    }
+
+    fn is_playing(&self, note: u8) -> bool {
+        self.mouse_note == Some(note)
+            || self.computer_notes.iter().any(|(_, playing)| *playing == note)
+            || self.external_notes.contains(&note)
+    }
+
+    // Plays the note under the mouse, releasing the previous one, as the mouse presses or glides over the keys.
+    fn play_mouse_note(&mut self, cx: &mut EventContext, x: f32, y: f32) {
+        let bounds = cx.bounds();
+        let key = self.key_at(bounds.w, bounds.h, x - bounds.x, y - bounds.y);
+
+        if key.map(|(note, _)| note) == self.mouse_note {
+            return;
+        }
+
+        if let Some(note) = self.mouse_note.take() {
+            cx.emit(NoteEvent::NoteOff { note });
+        }
+
+        if let Some((note, velocity)) = key {
+            self.mouse_note = Some(note);
+            cx.emit(NoteEvent::NoteOn { note, velocity });
+        }
+
+        cx.needs_redraw();
+    }
+}
+
+impl Handle<'_, PianoKeyboard> {
+    /// Sets the velocity, from 0.0 to 1.0, of notes played from the computer keyboard.
+    pub fn keyboard_velocity(self, velocity: f32) -> Self {
+        self.modify(|keyboard| keyboard.keyboard_velocity = velocity.clamp(0.0, 1.0))
+    }
+}
+
+impl View for PianoKeyboard {
+    fn element(&self) -> Option<&'static str> {
+        Some("piano-keyboard")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|keyboard_event, _| match keyboard_event {
+            PianoKeyboardEvent::PressKey(offset) => {
+                // Ignore key repeats while the key is held.
+                if self.computer_notes.iter().any(|(held, _)| held == offset) {
+                    return;
+                }
+
+                let note = self.octave * 12 + offset;
+                if note <= 127 {
+                    self.computer_notes.push((*offset, note));
+                    cx.emit(NoteEvent::NoteOn { note, velocity: self.keyboard_velocity });
+                    cx.needs_redraw();
+                }
+            }
+
+            PianoKeyboardEvent::Octave(delta) => {
+                self.octave = self.octave.saturating_add_signed(*delta).min(127 / 12);
+            }
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                if cx.is_disabled() {
+                    return;
+                }
+
+                self.is_dragging = true;
+                cx.capture();
+                cx.set_active(true);
+                let (x, y) = cx.mouse().left.pos_down;
+                self.play_mouse_note(cx, x, y);
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                if self.is_dragging {
+                    self.play_mouse_note(cx, *x, *y);
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.is_dragging {
+                    self.is_dragging = false;
+                    cx.release();
+                    cx.set_active(false);
+
+                    if let Some(note) = self.mouse_note.take() {
+                        cx.emit(NoteEvent::NoteOff { note });
+                        cx.needs_redraw();
+                    }
+                }
+            }
+
+            WindowEvent::KeyUp(code, _) => {
+                let Some((_, offset)) = COMPUTER_KEYS.iter().find(|(key, _)| key == code) else {
+                    return;
+                };
+
+                if let Some(index) = self.computer_notes.iter().position(|(held, _)| held == offset)
+                {
+                    let (_, note) = self.computer_notes.remove(index);
+                    cx.emit(NoteEvent::NoteOff { note });
+                    cx.needs_redraw();
+                }
+            }
+
+            // Release held notes so that they don't hang when the keyboard loses focus.
+            WindowEvent::FocusOut => {
+                for (_, note) in self.computer_notes.drain(..) {
+                    cx.emit(NoteEvent::NoteOff { note });
+                }
+                cx.needs_redraw();
+            }
+
+            _ => {}
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let bounds = cx.bounds();
+        if bounds.w == 0.0 || bounds.h == 0.0 {
+            return;
+        }
+
+        let white_key_width = bounds.w / self.white_keys() as f32;
+        let key_rect = |note: u8| {
+            let (left, width) = self.key_extent(note);
+            let height = if is_black(note) { bounds.h * BLACK_KEY_HEIGHT } else { bounds.h };
+            vg::Rect::from_xywh(
+                bounds.x + left * white_key_width,
+                bounds.y,
+                width * white_key_width,
+                height,
+            )
+        };
+
+        let white_color = cx.background_color();
+        let black_color = cx.font_color();
+        let playing_color = cx.selection_color();
+        let line_color = cx.border_color();
+
+        let mut fill = vg::Paint::default();
+        fill.set_anti_alias(true);
+
+        let mut stroke = vg::Paint::default();
+        stroke.set_color(line_color);
+        stroke.set_style(vg::PaintStyle::Stroke);
+        stroke.set_stroke_width(1.0);
+        stroke.set_anti_alias(true);
+
+        for note in self.range.clone().filter(|note| !is_black(*note)) {
+            let rect = key_rect(note);
+            fill.set_color(if self.is_playing(note) { playing_color } else { white_color });
+            canvas.draw_rect(rect, &fill);
+            canvas.draw_rect(rect, &stroke);
+        }
+
+        for note in self.range.clone().filter(|note| is_black(*note)) {
+            fill.set_color(if self.is_playing(note) { playing_color } else { black_color });
+            canvas.draw_rect(key_rect(note), &fill);
+        }
+
+        cx.draw_border(canvas);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    #[derive(Lens)]
+    struct TestData {
+        notes: Vec<u8>,
+        #[lens(ignore)]
+        events: Vec<NoteEvent>,
+    }
+
+    impl Model for TestData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|note_event: &NoteEvent, _| self.events.push(*note_event));
+        }
+    }
+
+    // Builds a keyboard showing the given range, and a model which records the notes it plays.
+    fn context(range: RangeInclusive<u8>) -> (Context, Entity) {
+        let mut cx = Context::default();
+        TestData { notes: Vec::new(), events: Vec::new() }.build(&mut cx);
+        let keyboard = PianoKeyboard::new(&mut cx, range, TestData::notes).entity();
+        flush(&mut cx);
+        (cx, keyboard)
+    }
+
+    fn flush(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    fn send(cx: &mut Context, target: Entity, event: WindowEvent) {
+        cx.emit_custom(
+            Event::new(event).target(target).origin(target).propagate(Propagation::Direct),
+        );
+        flush(cx);
+    }
+
+    fn keyboard_of(cx: &Context, keyboard: Entity) -> &PianoKeyboard {
+        cx.views.get(&keyboard).and_then(|view| view.downcast_ref()).unwrap()
+    }
+
+    fn events(cx: &Context) -> &[NoteEvent] {
+        &cx.data::<TestData>().unwrap().events
+    }
+
+    #[test]
+    fn range_is_extended_to_white_keys() {
+        assert_eq!(PianoKeyboard::extend_range(61..=70), 60..=71);
+        assert_eq!(PianoKeyboard::extend_range(60..=72), 60..=72);
+        let (cx, keyboard) = context(60..=71);
+        assert_eq!(keyboard_of(&cx, keyboard).white_keys(), 7);
+    }
+
+    #[test]
+    fn black_keys_are_hit_above_white_keys() {
+        // One octave from C4 with white keys 10 pixels wide.
+        let (cx, keyboard) = context(60..=71);
+        let keyboard = keyboard_of(&cx, keyboard);
+
+        // The top of the boundary between C and D is C#.
+        assert_eq!(keyboard.key_at(70.0, 100.0, 10.0, 10.0).map(|(note, _)| note), Some(61));
+        // The bottom of the same boundary is D.
+        assert_eq!(keyboard.key_at(70.0, 100.0, 10.0, 90.0).map(|(note, _)| note), Some(62));
+        // There is no black key between E and F.
+        assert_eq!(keyboard.key_at(70.0, 100.0, 30.0, 10.0).map(|(note, _)| note), Some(65));
+        assert_eq!(keyboard.key_at(70.0, 100.0, 75.0, 10.0), None);
+    }
+
+    #[test]
+    fn velocity_increases_down_the_key() {
+        let (cx, keyboard) = context(60..=71);
+        let keyboard = keyboard_of(&cx, keyboard);
+
+        let (_, soft) = keyboard.key_at(70.0, 100.0, 5.0, 10.0).unwrap();
+        let (_, loud) = keyboard.key_at(70.0, 100.0, 5.0, 99.0).unwrap();
+
+        assert!(soft < loud);
+        assert!((loud - 0.99).abs() < 1e-4);
+    }
+
+    #[test]
+    fn computer_keys_play_notes() {
+        let (mut cx, keyboard) = context(60..=71);
+
+        send(&mut cx, keyboard, WindowEvent::KeyDown(Code::KeyA, None));
+        // Key repeats don't play the note again.
+        send(&mut cx, keyboard, WindowEvent::KeyDown(Code::KeyA, None));
+        send(&mut cx, keyboard, WindowEvent::KeyDown(Code::KeyX, None));
+        send(&mut cx, keyboard, WindowEvent::KeyDown(Code::KeyW, None));
+        send(&mut cx, keyboard, WindowEvent::KeyUp(Code::KeyA, None));
+        assert!(keyboard_of(&cx, keyboard).is_playing(73));
+
+        // Held notes are released when the keyboard loses focus.
+        send(&mut cx, keyboard, WindowEvent::FocusOut);
+        assert_eq!(
+            events(&cx),
+            [
+                NoteEvent::NoteOn { note: 60, velocity: DEFAULT_KEYBOARD_VELOCITY },
+                NoteEvent::NoteOn { note: 73, velocity: DEFAULT_KEYBOARD_VELOCITY },
+                NoteEvent::NoteOff { note: 60 },
+                NoteEvent::NoteOff { note: 73 },
+            ]
+        );
+        assert!(!keyboard_of(&cx, keyboard).is_playing(73));
+    }
+
+    #[test]
+    fn dragging_mouse_glides_between_keys() {
+        let (mut cx, keyboard) = context(60..=71);
+        cx.cache.set_bounds(keyboard, BoundingBox { x: 0.0, y: 0.0, w: 70.0, h: 100.0 });
+
+        cx.mouse.left.pos_down = (10.0, 10.0);
+        send(&mut cx, keyboard, WindowEvent::MouseDown(MouseButton::Left));
+        send(&mut cx, keyboard, WindowEvent::MouseMove(10.0, 20.0));
+        send(&mut cx, keyboard, WindowEvent::MouseMove(10.0, 90.0));
+        send(&mut cx, keyboard, WindowEvent::MouseUp(MouseButton::Left));
+        // Moving the mouse after it's released doesn't play notes.
+        send(&mut cx, keyboard, WindowEvent::MouseMove(30.0, 90.0));
+
+        let notes = events(&cx)
+            .iter()
+            .map(|event| match event {
+                NoteEvent::NoteOn { note, .. } => (true, *note),
+                NoteEvent::NoteOff { note } => (false, *note),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(notes, [(true, 61), (false, 61), (true, 62), (false, 62)]);
+    }
+}
@@ -62,16 +62,25 @@ impl PickList {
                                 .child_top(Stretch(1.0))
                                 .child_bottom(Stretch(1.0))
                                 .checked(selected.map(move |selected| *selected == index))
+                                .role(Role::ListBoxOption)
                                 .navigable(true)
                                 .on_press(move |cx| {
                                     cx.emit(PickListEvent::SetOption(index));
                                     cx.emit(PopupEvent::Close);
                                 });
-                        });
+                        })
+                        .role(Role::ListBox);
                     })
                     .height(Auto);
                 },
             )
+            .role(Role::ComboBox)
+            .bind(list_lens, move |handle, list| {
+                handle.bind(selected, move |handle, sel| {
+                    let selected_index = sel.get(&handle);
+                    handle.text_value(list.idx(selected_index));
+                });
+            })
             .width(Stretch(1.0));
         })
     }
@@ -126,12 +135,14 @@ impl ScrollList {
                             .child_top(Stretch(1.0))
                             .child_bottom(Stretch(1.0))
                             .checked(selected.map(move |selected| *selected == index))
+                            .role(Role::ListBoxOption)
                             .navigable(true)
                             .on_press(move |cx| {
                                 cx.emit(PickListEvent::SetOption(index));
                                 // cx.emit(PopupEvent::Close);s
                             });
-                    });
+                    })
+                    .role(Role::ListBox);
                 })
                 .height(Auto)
                 .max_height(Pixels(window_height / scale));
@@ -46,6 +46,14 @@ impl View for Rating {
         Some("rating")
     }
 
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_min_numeric_value(0.0);
+        node.set_max_numeric_value(self.max_rating as f64);
+        node.set_numeric_value_step(1.0);
+        node.add_action(Action::Increment);
+        node.add_action(Action::Decrement);
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|rating_event, _| match rating_event {
             RatingEvent::SetRating(val) => self.rating = *val,
@@ -79,6 +87,12 @@ impl View for Rating {
                 _ => {}
             },
 
+            WindowEvent::ActionRequest(action) => match action.action {
+                Action::Increment => cx.emit(RatingEvent::Increment),
+                Action::Decrement => cx.emit(RatingEvent::Decrement),
+                _ => {}
+            },
+
             _ => {}
         });
     }
@@ -0,0 +1,98 @@
+use crate::prelude::*;
+use crate::vg;
+
+/// A handle which resizes the window containing it when dragged.
+///
+/// The handle is placed in the bottom right corner of its parent, which is usually the root of a window, and
+/// emits [`WindowEvent::SetSize`] with the new logical size of the window while it is dragged. This allows plugin
+/// editors, whose windows have no decorations, to be resized by the user.
+///
+/// # Example
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// ResizeHandle::new(cx);
+/// ```
+pub struct ResizeHandle {
+    // The logical size of the window and the position of the cursor when the drag started.
+    drag_start: Option<(WindowSize, (f32, f32))>,
+}
+
+impl ResizeHandle {
+    /// Creates a new resize handle.
+    pub fn new(cx: &mut Context) -> Handle<Self> {
+        Self { drag_start: None }.build(cx, |_| {}).role(Role::Splitter)
+    }
+}
+
+impl View for ResizeHandle {
+    fn element(&self) -> Option<&'static str> {
+        Some("resize-handle")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                let size = cx.window_size();
+                let scale_factor = cx.scale_factor();
+                let logical_size = WindowSize::new(
+                    (size.width as f32 / scale_factor).round() as u32,
+                    (size.height as f32 / scale_factor).round() as u32,
+                );
+
+                self.drag_start = Some((logical_size, (cx.mouse().cursor_x, cx.mouse().cursor_y)));
+                cx.capture();
+                cx.set_active(true);
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                if let Some((size, (start_x, start_y))) = self.drag_start {
+                    let scale_factor = cx.scale_factor();
+                    let width = size.width as f32 + (x - start_x) / scale_factor;
+                    let height = size.height as f32 + (y - start_y) / scale_factor;
+
+                    cx.emit(WindowEvent::SetSize(WindowSize::new(
+                        width.round().max(1.0) as u32,
+                        height.round().max(1.0) as u32,
+                    )));
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.drag_start.take().is_some() {
+                    cx.release();
+                    cx.set_active(false);
+                    meta.consume();
+                }
+            }
+
+            _ => {}
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let bounds = cx.bounds();
+        if bounds.w == 0.0 || bounds.h == 0.0 {
+            return;
+        }
+
+        cx.draw_background(canvas);
+
+        // Three diagonal grip lines in the bottom right corner.
+        let mut path = vg::Path::new();
+        let size = bounds.w.min(bounds.h);
+        for step in 1..=3 {
+            let offset = size * step as f32 / 4.0;
+            path.move_to((bounds.right() - offset, bounds.bottom()));
+            path.line_to((bounds.right(), bounds.bottom() - offset));
+        }
+
+        let mut paint = vg::Paint::default();
+        paint.set_color(cx.font_color());
+        paint.set_style(vg::PaintStyle::Stroke);
+        paint.set_stroke_width(cx.scale_factor());
+        paint.set_anti_alias(true);
+        canvas.draw_path(&path, &paint);
+    }
+}
@@ -60,6 +60,8 @@ impl<L1: Lens<Target = f32>> Scrollbar<L1> {
                 });
         })
         .pointer_events(PointerEvents::Auto)
+        .role(Role::ScrollBar)
+        .numeric_value(value)
         .class(match orientation {
             Orientation::Horizontal => "horizontal",
             Orientation::Vertical => "vertical",
@@ -106,6 +108,11 @@ impl<L1: 'static + Lens<Target = f32>> View for Scrollbar<L1> {
         Some("scrollbar")
     }
 
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_min_numeric_value(0.0);
+        node.set_max_numeric_value(1.0);
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|scrollbar_event, _| match scrollbar_event {
             ScrollBarEvent::SetScrollToCursor(flag) => {
@@ -2,6 +2,7 @@ use std::ops::Range;
 
 use accesskit::ActionData;
 
+use crate::param::emit_param_gesture;
 use crate::prelude::*;
 
 #[derive(Debug)]
@@ -75,6 +76,7 @@ pub struct Slider<L: Lens> {
     is_dragging: bool,
     internal: SliderDataInternal,
     on_changing: Option<Box<dyn Fn(&mut EventContext, f32)>>,
+    param: Option<ParamId>,
 }
 
 impl<L> Slider<L>
@@ -114,6 +116,7 @@ where
             },
 
             on_changing: None,
+            param: None,
         }
         .build(cx, move |cx| {
             Binding::new(cx, Slider::<L>::internal, move |cx, slider_data| {
@@ -207,6 +210,7 @@ where
             },
 
             on_changing: None,
+            param: None,
         }
         .build(cx, move |cx| {
             (content)(cx);
@@ -215,6 +219,23 @@ where
     }
 }
 
+impl<L: Lens> Slider<L> {
+    // Converts a value within the range of the slider to a normalized value in the range 0 to 1.
+    fn normalized(&self, value: f32) -> f32 {
+        normalize(value, &self.internal.range)
+    }
+}
+
+fn normalize(value: f32, range: &Range<f32>) -> f32 {
+    let span = range.end - range.start;
+    // An empty range has no position within it, so avoid producing NaN.
+    if span == 0.0 {
+        return 0.0;
+    }
+
+    ((value - range.start) / span).clamp(0.0, 1.0)
+}
+
 impl<L: Lens<Target = f32>> View for Slider<L> {
     fn element(&self) -> Option<&'static str> {
         Some("slider")
@@ -224,6 +245,9 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
         node.set_numeric_value_step(self.internal.step as f64);
         node.set_min_numeric_value(self.internal.range.start as f64);
         node.set_max_numeric_value(self.internal.range.end as f64);
+        node.add_action(Action::Increment);
+        node.add_action(Action::Decrement);
+        node.add_action(Action::SetValue);
     }
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
@@ -306,10 +330,21 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
 
                         self.on_changing = Some(callback);
                     }
+
+                    if let Some(param) = self.param {
+                        cx.emit(ParamEvent::BeginEdit(param));
+                        cx.emit(ParamEvent::SetNormalized(param, self.normalized(val)));
+                    }
                 }
             }
 
             WindowEvent::MouseUp(button) if *button == MouseButton::Left => {
+                if self.is_dragging {
+                    if let Some(param) = self.param {
+                        cx.emit(ParamEvent::EndEdit(param));
+                    }
+                }
+
                 self.is_dragging = false;
                 cx.focus_with_visibility(false);
                 cx.release();
@@ -352,6 +387,10 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                     if let Some(callback) = &self.on_changing {
                         (callback)(cx, val);
                     }
+
+                    if let Some(param) = self.param {
+                        cx.emit(ParamEvent::SetNormalized(param, self.normalized(val)));
+                    }
                 }
             }
 
@@ -365,6 +404,7 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                 if let Some(callback) = &self.on_changing {
                     (callback)(cx, val);
                 }
+                emit_param_gesture(cx, self.param, self.normalized(val));
             }
 
             WindowEvent::KeyDown(Code::ArrowDown | Code::ArrowLeft, _) => {
@@ -377,6 +417,7 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                 if let Some(callback) = &self.on_changing {
                     (callback)(cx, val);
                 }
+                emit_param_gesture(cx, self.param, self.normalized(val));
             }
 
             WindowEvent::ActionRequest(action) => match action.action {
@@ -390,6 +431,7 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                     if let Some(callback) = &self.on_changing {
                         (callback)(cx, val);
                     }
+                    emit_param_gesture(cx, self.param, self.normalized(val));
                 }
 
                 Action::Decrement => {
@@ -402,6 +444,7 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                     if let Some(callback) = &self.on_changing {
                         (callback)(cx, val);
                     }
+                    emit_param_gesture(cx, self.param, self.normalized(val));
                 }
 
                 Action::SetValue => {
@@ -413,6 +456,7 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                         if let Some(callback) = &self.on_changing {
                             (callback)(cx, v);
                         }
+                        emit_param_gesture(cx, self.param, self.normalized(v));
                     }
                 }
 
@@ -454,6 +498,11 @@ impl<L: Lens> Handle<'_, Slider<L>> {
         self.modify(|slider| slider.on_changing = Some(Box::new(callback)))
     }
 
+    /// Binds the slider to a plugin parameter, so that edits by the user emit [`ParamEvent`] gestures.
+    pub fn param(self, param: ParamId) -> Self {
+        self.modify(|slider| slider.param = Some(param))
+    }
+
     /// Sets the range of the slider.
     ///
     /// If the bound data is outside of the range then the slider will clip to min/max of the range.
@@ -616,3 +665,22 @@ impl Handle<'_, NamedSlider> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_within_range() {
+        assert_eq!(normalize(5.0, &(0.0..10.0)), 0.5);
+        assert_eq!(normalize(-1.0, &(-2.0..2.0)), 0.25);
+        assert_eq!(normalize(20.0, &(0.0..10.0)), 1.0);
+        assert_eq!(normalize(-5.0, &(0.0..10.0)), 0.0);
+    }
+
+    #[test]
+    fn normalize_empty_range() {
+        assert_eq!(normalize(3.0, &(3.0..3.0)), 0.0);
+        assert_eq!(normalize(5.0, &(3.0..3.0)), 0.0);
+    }
+}
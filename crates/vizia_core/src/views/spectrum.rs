@@ -0,0 +1,288 @@
+use std::collections::VecDeque;
+use std::f32::consts::PI;
+
+use crate::prelude::*;
+use crate::vg;
+use crate::views::audio_buffer::{poll_audio, PollAudio};
+use crate::views::normalized_map::{
+    amplitude_to_db, DecibelMap, DisplayDecimals, FrequencyDisplayMode, FrequencyMap, ValueScaling,
+};
+
+// The number of samples in each FFT frame. Must be a power of two.
+const FFT_SIZE: usize = 2048;
+const DEFAULT_SMOOTHING: f32 = 0.8;
+
+// Computes the discrete Fourier transform of the signal in place using the iterative radix-2 algorithm.
+fn fft(re: &mut [f32], im: &mut [f32]) {
+    let n = re.len();
+    debug_assert!(n.is_power_of_two() && im.len() == n);
+
+    // Bit reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * PI / len as f32;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (w_im, w_re) = (angle * k as f32).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let t_re = re[b] * w_re - im[b] * w_im;
+                let t_im = re[b] * w_im + im[b] * w_re;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+// Computes the magnitude in decibels of each frequency bin of a frame of samples, relative to a full scale sine wave.
+struct Analyzer {
+    window: Vec<f32>,
+    re: Vec<f32>,
+    im: Vec<f32>,
+}
+
+impl Analyzer {
+    fn new() -> Self {
+        // Hann window
+        let window = (0..FFT_SIZE)
+            .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / FFT_SIZE as f32).cos())
+            .collect();
+
+        Self { window, re: vec![0.0; FFT_SIZE], im: vec![0.0; FFT_SIZE] }
+    }
+
+    fn analyze<'a>(
+        &mut self,
+        frame: impl Iterator<Item = &'a f32>,
+    ) -> impl Iterator<Item = f32> + '_ {
+        for ((re, im), (sample, window)) in
+            self.re.iter_mut().zip(self.im.iter_mut()).zip(frame.zip(&self.window))
+        {
+            *re = sample * window;
+            *im = 0.0;
+        }
+
+        fft(&mut self.re, &mut self.im);
+
+        let gain = 2.0 / self.window.iter().sum::<f32>();
+        self.re
+            .iter()
+            .zip(&self.im)
+            .take(FFT_SIZE / 2 + 1)
+            .map(move |(re, im)| amplitude_to_db((re * re + im * im).sqrt() * gain))
+    }
+}
+
+/// A spectrum analyser which shows the frequency content of an audio signal.
+///
+/// Samples are sent to the spectrum from the audio thread through an [`audio_ring_buffer`]. The spectrum is drawn
+/// on a logarithmic frequency axis from 20 Hz up to 20 kHz, or the Nyquist frequency if lower, and levels are scaled
+/// using a [`DecibelMap`] which defaults to a linear scale from -90 dB to 0 dB.
+///
+/// Rising levels are shown immediately while falling levels are smoothed, which can be adjusted with the
+/// [`smoothing`](Handle::smoothing) modifier. The font color of the view is used to draw the spectrum.
+///
+/// # Example
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// let (producer, consumer) = audio_ring_buffer(8192);
+///
+/// Spectrum::new(cx, consumer, 48000.0).smoothing(0.9);
+/// ```
+pub struct Spectrum {
+    consumer: AudioConsumer,
+    frame: VecDeque<f32>,
+    analyzer: Analyzer,
+    // The smoothed magnitude in decibels of each frequency bin.
+    magnitudes: Vec<f32>,
+
+    sample_rate: f32,
+    smoothing: f32,
+    db_map: DecibelMap,
+    frequency_map: FrequencyMap,
+}
+
+impl Spectrum {
+    /// Creates a new spectrum which shows the frequency content of the samples received by the `consumer`, which
+    /// have the given sample rate.
+    pub fn new(cx: &mut Context, consumer: AudioConsumer, sample_rate: f32) -> Handle<Self> {
+        let db_map = DecibelMap::new(-90.0, 0.0, ValueScaling::Linear, DisplayDecimals::One, true);
+        let frequency_map = FrequencyMap::new(
+            20.0,
+            (sample_rate / 2.0).clamp(40.0, 20000.0),
+            ValueScaling::Frequency,
+            FrequencyDisplayMode::default(),
+            true,
+        );
+
+        let handle = Self {
+            consumer,
+            frame: std::iter::repeat(0.0).take(FFT_SIZE).collect(),
+            analyzer: Analyzer::new(),
+            magnitudes: vec![db_map.min_db(); FFT_SIZE / 2 + 1],
+
+            sample_rate,
+            smoothing: DEFAULT_SMOOTHING,
+            db_map,
+            frequency_map,
+        }
+        .build(cx, |_| {})
+        .role(Role::Canvas);
+
+        poll_audio(handle)
+    }
+
+    // Updates the smoothed magnitudes from the current frame, returning true if any of them changed.
+    fn update(&mut self) -> bool {
+        let floor = self.db_map.min_db();
+        let smoothing = self.smoothing;
+        let mut changed = false;
+
+        for (magnitude, db) in
+            self.magnitudes.iter_mut().zip(self.analyzer.analyze(self.frame.iter()))
+        {
+            let db = db.max(floor);
+            let smoothed =
+                if db >= *magnitude { db } else { *magnitude * smoothing + db * (1.0 - smoothing) };
+            // Snap to the floor so that a silent signal stops causing redraws.
+            let smoothed = if smoothed - floor < 0.01 { floor } else { smoothed };
+
+            changed |= smoothed != *magnitude;
+            *magnitude = smoothed;
+        }
+
+        changed
+    }
+
+    // Returns the smoothed magnitude in decibels at the given frequency, interpolating between bins.
+    fn magnitude_at(&self, hz: f32) -> f32 {
+        let bin = (hz * FFT_SIZE as f32 / self.sample_rate)
+            .clamp(0.0, (self.magnitudes.len() - 1) as f32);
+        let index = bin.floor() as usize;
+        let next = (index + 1).min(self.magnitudes.len() - 1);
+        let t = bin - index as f32;
+
+        self.magnitudes[index] * (1.0 - t) + self.magnitudes[next] * t
+    }
+}
+
+impl Handle<'_, Spectrum> {
+    /// Sets how slowly falling levels decay, from 0.0 for no smoothing up to 1.0 where levels never fall.
+    pub fn smoothing(self, smoothing: f32) -> Self {
+        self.modify(|spectrum| spectrum.smoothing = smoothing.clamp(0.0, 1.0))
+    }
+
+    /// Sets the map used to scale levels in decibels to the height of the spectrum.
+    pub fn db_map(self, db_map: DecibelMap) -> Self {
+        self.modify(|spectrum| {
+            spectrum.magnitudes.fill(db_map.min_db());
+            spectrum.db_map = db_map;
+        })
+    }
+}
+
+impl View for Spectrum {
+    fn element(&self) -> Option<&'static str> {
+        Some("spectrum")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.take(|_: PollAudio, _| {
+            let frame = &mut self.frame;
+            let count = self.consumer.drain(|sample| {
+                frame.pop_front();
+                frame.push_back(sample);
+            });
+
+            if count > 0 && self.update() {
+                cx.needs_redraw();
+            }
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let bounds = cx.bounds();
+        if bounds.w == 0.0 || bounds.h == 0.0 {
+            return;
+        }
+
+        cx.draw_background(canvas);
+        cx.draw_border(canvas);
+
+        let mut path = vg::Path::new();
+        let count = bounds.w.ceil() as usize;
+        for column in 0..=count {
+            let x = (column as f32).min(bounds.w);
+            let hz = self.frequency_map.normalized_to_hz(x / bounds.w);
+            let normalized = self.db_map.db_to_normalized(self.magnitude_at(hz)).clamp(0.0, 1.0);
+            let point = (bounds.x + x, bounds.bottom() - normalized * bounds.h);
+
+            if column == 0 {
+                path.move_to(point);
+            } else {
+                path.line_to(point);
+            }
+        }
+
+        let mut paint = vg::Paint::default();
+        paint.set_color(cx.font_color());
+        paint.set_style(vg::PaintStyle::Stroke);
+        paint.set_stroke_width(cx.scale_factor());
+        paint.set_anti_alias(true);
+        canvas.draw_path(&path, &paint);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fft_of_impulse_is_flat() {
+        let mut re = [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let mut im = [0.0; 8];
+        fft(&mut re, &mut im);
+
+        assert!(re.iter().all(|re| (re - 1.0).abs() < 1e-6));
+        assert!(im.iter().all(|im| im.abs() < 1e-6));
+    }
+
+    #[test]
+    fn sine_peaks_at_its_frequency_bin() {
+        let bin = 100;
+        let frame = (0..FFT_SIZE)
+            .map(|i| (2.0 * PI * bin as f32 * i as f32 / FFT_SIZE as f32).sin())
+            .collect::<Vec<_>>();
+
+        let magnitudes = Analyzer::new().analyze(frame.iter()).collect::<Vec<_>>();
+        let peak = magnitudes
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(index, _)| index)
+            .unwrap();
+
+        assert_eq!(peak, bin);
+        // A full scale sine wave reads as 0 dB.
+        assert!(magnitudes[bin].abs() < 0.1);
+    }
+}
@@ -31,7 +31,7 @@ impl Spinbox {
     where
         L: Lens<Target: Data + ToStringLocalized>,
     {
-        Self::custom(cx, move |cx| Label::new(cx, lens))
+        Self::custom(cx, move |cx| Label::new(cx, lens)).text_value(lens)
     }
 
     pub fn custom<F, V>(cx: &mut Context, content: F) -> Handle<Spinbox>
@@ -59,6 +59,7 @@ impl Spinbox {
                             )
                         })
                         .on_press(|ex| ex.emit(SpinboxEvent::Decrement))
+                        .name(Localized::new("Decrement"))
                         .navigable(true)
                         .class("spinbox-button");
                     }
@@ -74,6 +75,7 @@ impl Spinbox {
                             )
                         })
                         .on_press(|ex| ex.emit(SpinboxEvent::Increment))
+                        .name(Localized::new("Increment"))
                         .navigable(true)
                         .class("spinbox-button");
                     }
@@ -93,6 +95,7 @@ impl Spinbox {
                             )
                         })
                         .on_press(|ex| ex.emit(SpinboxEvent::Increment))
+                        .name(Localized::new("Increment"))
                         .navigable(true)
                         .class("spinbox-button");
                     }
@@ -108,6 +111,7 @@ impl Spinbox {
                             )
                         })
                         .on_press(|ex| ex.emit(SpinboxEvent::Decrement))
+                        .name(Localized::new("Decrement"))
                         .navigable(true)
                         .class("spinbox-button");
                     }
@@ -116,6 +120,7 @@ impl Spinbox {
         })
         .toggle_class("horizontal", Spinbox::orientation.map(|o| o == &Orientation::Horizontal))
         .toggle_class("vertical", Spinbox::orientation.map(|o| o == &Orientation::Vertical))
+        .role(Role::SpinButton)
         .navigable(true)
     }
 }
@@ -155,6 +160,16 @@ impl View for Spinbox {
         Some("spinbox")
     }
 
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        if self.on_increment.is_some() {
+            node.add_action(Action::Increment);
+        }
+
+        if self.on_decrement.is_some() {
+            node.add_action(Action::Decrement);
+        }
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|spinbox_event, _| match spinbox_event {
             SpinboxEvent::Increment => {
@@ -169,5 +184,15 @@ impl View for Spinbox {
                 }
             }
         });
+
+        event.map(|window_event, _| match window_event {
+            WindowEvent::ActionRequest(action) => match action.action {
+                Action::Increment => cx.emit(SpinboxEvent::Increment),
+                Action::Decrement => cx.emit(SpinboxEvent::Decrement),
+                _ => {}
+            },
+
+            _ => {}
+        });
     }
 }
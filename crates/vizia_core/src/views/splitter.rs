@@ -0,0 +1,522 @@
+use crate::prelude::*;
+
+/// Events which can be sent to a [`Splitter`] to change the size of its panes.
+pub enum SplitterEvent {
+    /// Sets the ratio of the size of each pane, which also sets the number of panes.
+    SetRatios(Vec<f32>),
+    /// Collapses the pane before a divider, giving its space to the pane after it, or restores it if it is collapsed.
+    ToggleCollapsed(usize),
+}
+
+pub(crate) enum SplitterControlEvent {
+    SetPaneSize(usize, f32, f32),
+    DragStart,
+    Drag(usize, f32, f32),
+}
+
+/// A view which arranges panes side by side, or above each other, with dividers between them which can be dragged
+/// to resize the panes.
+///
+/// The panes share the space of the splitter in proportion to their ratios, which are usually bound to a lens so
+/// that they can be persisted, and updated with the [`on_change`](Handle::on_change) callback. Double clicking a
+/// divider collapses the pane before it.
+///
+/// # Example
+/// ```ignore
+/// Splitter::new(cx, AppData::ratios, |cx, index| {
+///     Label::new(cx, format!("Pane {}", index));
+/// })
+/// .pane_min_size(0, 100.0)
+/// .on_change(|cx, ratios| cx.emit(AppEvent::SetRatios(ratios.to_vec())));
+/// ```
+#[derive(Lens)]
+pub struct Splitter {
+    orientation: Orientation,
+    ratios: Vec<f32>,
+    // The minimum and maximum logical size of each pane.
+    limits: Vec<(f32, Option<f32>)>,
+    // The ratio of each collapsed pane before it was collapsed.
+    #[lens(ignore)]
+    collapsed: Vec<Option<f32>>,
+    // The logical size of each pane along the orientation of the splitter.
+    #[lens(ignore)]
+    sizes: Vec<f32>,
+    // The ratios and sizes of the panes when a divider started being dragged.
+    #[lens(ignore)]
+    drag_start: Option<(Vec<f32>, Vec<f32>)>,
+    #[lens(ignore)]
+    on_change: Option<Box<dyn Fn(&mut EventContext, &[f32])>>,
+}
+
+impl Splitter {
+    /// Creates a new [`Splitter`] with a pane for each of the given ratios, and a template for the content of each
+    /// pane, which is given the index of the pane.
+    pub fn new(
+        cx: &mut Context,
+        ratios: impl Res<Vec<f32>>,
+        content: impl 'static + Fn(&mut Context, usize),
+    ) -> Handle<Self> {
+        let initial_ratios = ratios.get(cx);
+        let num_panes = initial_ratios.len();
+
+        let mut handle = Self {
+            orientation: Orientation::Horizontal,
+            ratios: initial_ratios,
+            limits: Vec::new(),
+            collapsed: vec![None; num_panes],
+            sizes: vec![0.0; num_panes],
+            drag_start: None,
+            on_change: None,
+        }
+        .build(cx, move |cx| {
+            Binding::new(cx, Splitter::ratios.map(Vec::len), move |cx, num_panes| {
+                for index in 0..num_panes.get(cx) {
+                    if index > 0 {
+                        SplitterDivider::new(cx, index - 1);
+                    }
+
+                    Self::build_pane(cx, index, &content);
+                }
+            });
+        })
+        .toggle_class(
+            "vertical",
+            Splitter::orientation.map(|orientation| *orientation == Orientation::Vertical),
+        );
+
+        let entity = handle.entity();
+        ratios.set_or_bind(handle.context(), entity, |cx, ratios| {
+            let ratios = ratios.get(cx);
+            cx.emit(SplitterEvent::SetRatios(ratios));
+        });
+
+        handle
+    }
+
+    fn build_pane(cx: &mut Context, index: usize, content: &dyn Fn(&mut Context, usize)) {
+        VStack::new(cx, |cx| (content)(cx, index))
+            .class("splitter-pane")
+            .bind(
+                Splitter::root.map(move |splitter| splitter.pane_layout(index)),
+                |handle, layout| {
+                    let (orientation, ratio, min, max) = layout.get(&handle);
+                    let handle = match orientation {
+                        Orientation::Horizontal => handle
+                            .width(Stretch(ratio))
+                            .height(Stretch(1.0))
+                            .min_width(Pixels(min))
+                            .min_height(Pixels(0.0)),
+                        Orientation::Vertical => handle
+                            .width(Stretch(1.0))
+                            .height(Stretch(ratio))
+                            .min_width(Pixels(0.0))
+                            .min_height(Pixels(min)),
+                    };
+
+                    if let Some(max) = max {
+                        match orientation {
+                            Orientation::Horizontal => handle.max_width(Pixels(max)),
+                            Orientation::Vertical => handle.max_height(Pixels(max)),
+                        };
+                    }
+                },
+            )
+            .on_geo_changed(move |cx, geo| {
+                if geo.intersects(GeoChanged::WIDTH_CHANGED | GeoChanged::HEIGHT_CHANGED) {
+                    let bounds = cx.bounds();
+                    let scale_factor = cx.scale_factor();
+                    cx.emit(SplitterControlEvent::SetPaneSize(
+                        index,
+                        bounds.w / scale_factor,
+                        bounds.h / scale_factor,
+                    ));
+                }
+            });
+    }
+
+    // The orientation, ratio, and minimum and maximum size of a pane.
+    fn pane_layout(&self, index: usize) -> (Orientation, f32, f32, Option<f32>) {
+        let ratio = self.ratios.get(index).copied().unwrap_or_default();
+        let (min, max) = self.limits(index);
+        // A collapsed pane has no minimum size.
+        let min = if ratio > 0.0 { min } else { 0.0 };
+        (self.orientation, ratio, min, max)
+    }
+
+    fn limits(&self, index: usize) -> (f32, Option<f32>) {
+        self.limits.get(index).copied().unwrap_or((0.0, None))
+    }
+
+    fn limits_mut(&mut self, index: usize) -> &mut (f32, Option<f32>) {
+        if self.limits.len() <= index {
+            self.limits.resize(index + 1, (0.0, None));
+        }
+        &mut self.limits[index]
+    }
+
+    fn set_ratios(&mut self, ratios: Vec<f32>) {
+        if ratios.len() == self.ratios.len() {
+            // Forget the previous ratio of panes which are no longer collapsed.
+            for (collapsed, ratio) in self.collapsed.iter_mut().zip(&ratios) {
+                if *ratio > 0.0 {
+                    *collapsed = None;
+                }
+            }
+        } else {
+            self.collapsed = vec![None; ratios.len()];
+            self.sizes = vec![0.0; ratios.len()];
+            self.drag_start = None;
+        }
+
+        self.ratios = ratios;
+    }
+
+    // Collapses the pane before a divider, or restores it, returning false if there is nothing to change.
+    fn toggle_collapsed(&mut self, divider: usize) -> bool {
+        let (before, after) = (divider, divider + 1);
+        if after >= self.ratios.len() {
+            return false;
+        }
+
+        if let Some(ratio) = self.collapsed[before].take() {
+            let ratio = ratio.min(self.ratios[after]);
+            self.ratios[before] = ratio;
+            self.ratios[after] -= ratio;
+        } else if self.ratios[before] > 0.0 {
+            self.collapsed[before] = Some(self.ratios[before]);
+            self.ratios[after] += self.ratios[before];
+            self.ratios[before] = 0.0;
+        } else {
+            return false;
+        }
+
+        true
+    }
+
+    fn notify_change(&self, cx: &mut EventContext) {
+        if let Some(callback) = &self.on_change {
+            (callback)(cx, &self.ratios);
+        }
+    }
+}
+
+impl View for Splitter {
+    fn element(&self) -> Option<&'static str> {
+        Some("splitter")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|splitter_event, meta| match splitter_event {
+            SplitterEvent::SetRatios(ratios) => {
+                self.set_ratios(ratios.clone());
+                meta.consume();
+            }
+
+            SplitterEvent::ToggleCollapsed(divider) => {
+                if self.toggle_collapsed(*divider) {
+                    self.notify_change(cx);
+                }
+                meta.consume();
+            }
+        });
+
+        event.map(|control_event, meta| match control_event {
+            SplitterControlEvent::SetPaneSize(index, width, height) => {
+                if let Some(size) = self.sizes.get_mut(*index) {
+                    *size = match self.orientation {
+                        Orientation::Horizontal => *width,
+                        Orientation::Vertical => *height,
+                    };
+                }
+                meta.consume();
+            }
+
+            SplitterControlEvent::DragStart => {
+                self.drag_start = Some((self.ratios.clone(), self.sizes.clone()));
+                meta.consume();
+            }
+
+            SplitterControlEvent::Drag(divider, x, y) => {
+                if let Some((ratios, sizes)) = &self.drag_start {
+                    let delta = match self.orientation {
+                        Orientation::Horizontal => *x,
+                        Orientation::Vertical => *y,
+                    };
+                    let ratios = resize_panes(ratios, sizes, &self.limits, *divider, delta);
+                    if ratios != self.ratios {
+                        self.set_ratios(ratios);
+                        self.notify_change(cx);
+                    }
+                }
+                meta.consume();
+            }
+        });
+    }
+}
+
+impl Handle<'_, Splitter> {
+    /// Sets whether the panes are arranged side by side or above each other. Defaults to
+    /// [`Orientation::Horizontal`].
+    pub fn orientation(self, orientation: impl Res<Orientation>) -> Self {
+        self.bind(orientation, |handle, orientation| {
+            let orientation = orientation.get(&handle);
+            handle.modify(|splitter: &mut Splitter| splitter.orientation = orientation);
+        })
+    }
+
+    /// Sets the minimum logical size of a pane, which it can't be resized below unless it is collapsed.
+    pub fn pane_min_size(self, index: usize, size: f32) -> Self {
+        self.modify(|splitter: &mut Splitter| splitter.limits_mut(index).0 = size)
+    }
+
+    /// Sets the maximum logical size of a pane.
+    pub fn pane_max_size(self, index: usize, size: f32) -> Self {
+        self.modify(|splitter: &mut Splitter| splitter.limits_mut(index).1 = Some(size))
+    }
+
+    /// Sets a callback which is called with the ratios of the panes when they are resized or collapsed.
+    pub fn on_change(self, callback: impl 'static + Fn(&mut EventContext, &[f32])) -> Self {
+        self.modify(|splitter: &mut Splitter| splitter.on_change = Some(Box::new(callback)))
+    }
+}
+
+// The divider between two panes, which resizes them when dragged.
+struct SplitterDivider {
+    index: usize,
+    // The position of the cursor when the drag started.
+    drag_start: Option<(f32, f32)>,
+}
+
+impl SplitterDivider {
+    fn new(cx: &mut Context, index: usize) -> Handle<Self> {
+        Self { index, drag_start: None }.build(cx, |_| {}).role(Role::Splitter)
+    }
+}
+
+impl View for SplitterDivider {
+    fn element(&self) -> Option<&'static str> {
+        Some("splitter-divider")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                self.drag_start = Some((cx.mouse().cursor_x, cx.mouse().cursor_y));
+                cx.capture();
+                cx.set_active(true);
+                cx.emit(SplitterControlEvent::DragStart);
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                if let Some((start_x, start_y)) = self.drag_start {
+                    let scale_factor = cx.scale_factor();
+                    cx.emit(SplitterControlEvent::Drag(
+                        self.index,
+                        (x - start_x) / scale_factor,
+                        (y - start_y) / scale_factor,
+                    ));
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.drag_start.take().is_some() {
+                    cx.release();
+                    cx.set_active(false);
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::MouseDoubleClick(MouseButton::Left) => {
+                cx.emit(SplitterEvent::ToggleCollapsed(self.index));
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+// Moves the divider between two panes by a logical distance from where it started, keeping both panes within their
+// limits, and returns the new ratios of the panes.
+fn resize_panes(
+    ratios: &[f32],
+    sizes: &[f32],
+    limits: &[(f32, Option<f32>)],
+    divider: usize,
+    delta: f32,
+) -> Vec<f32> {
+    let mut ratios = ratios.to_vec();
+    let (before, after) = (divider, divider + 1);
+    if after >= ratios.len() || after >= sizes.len() {
+        return ratios;
+    }
+
+    let limits = |index: usize| limits.get(index).copied().unwrap_or((0.0, None));
+    let (min_before, max_before) = limits(before);
+    let (min_after, max_after) = limits(after);
+
+    let total = sizes[before] + sizes[after];
+    let lower = min_before.max(max_after.map_or(0.0, |max| total - max));
+    let upper = max_before.unwrap_or(total).min(total - min_after);
+    if total <= 0.0 || lower > upper {
+        return ratios;
+    }
+
+    let size = (sizes[before] + delta).clamp(lower, upper);
+    let pair = ratios[before] + ratios[after];
+    ratios[before] = pair * size / total;
+    ratios[after] = pair - ratios[before];
+
+    ratios
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    #[derive(Lens)]
+    struct TestData {
+        ratios: Vec<f32>,
+    }
+
+    enum TestEvent {
+        SetRatios(Vec<f32>),
+    }
+
+    impl Model for TestData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|TestEvent::SetRatios(ratios), _| self.ratios = ratios.clone());
+        }
+    }
+
+    // Builds a splitter which is bound to the ratios of a model and updates them when it changes.
+    fn context(ratios: Vec<f32>) -> (Context, Entity) {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        TestData { ratios }.build(&mut cx);
+        let splitter = Splitter::new(&mut cx, TestData::ratios, |_, _| {})
+            .pane_min_size(0, 25.0)
+            .on_change(|cx, ratios| cx.emit(TestEvent::SetRatios(ratios.to_vec())))
+            .entity();
+        flush(&mut cx);
+        (cx, splitter)
+    }
+
+    fn flush(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    fn send(cx: &mut Context, target: Entity, event: WindowEvent) {
+        cx.emit_custom(
+            Event::new(event).target(target).origin(target).propagate(Propagation::Direct),
+        );
+        flush(cx);
+    }
+
+    fn splitter_of(cx: &Context, splitter: Entity) -> &Splitter {
+        cx.views.get(&splitter).and_then(|view| view.downcast_ref()).unwrap()
+    }
+
+    fn model_ratios(cx: &Context) -> Vec<f32> {
+        cx.data::<TestData>().unwrap().ratios.clone()
+    }
+
+    fn dividers(cx: &Context) -> Vec<Entity> {
+        Entity::root()
+            .tree_iter(&cx.tree)
+            .filter(|entity| {
+                cx.views
+                    .get(entity)
+                    .is_some_and(|view| view.downcast_ref::<SplitterDivider>().is_some())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn resize_panes_within_limits() {
+        let ratios = [1.0, 1.0, 2.0];
+        let sizes = [100.0, 100.0, 200.0];
+        assert_eq!(resize_panes(&ratios, &sizes, &[], 0, 50.0), [1.5, 0.5, 2.0]);
+        assert_eq!(resize_panes(&ratios, &sizes, &[], 1, -100.0), [1.0, 0.0, 3.0]);
+        // Panes can't be resized beyond the space of both panes.
+        assert_eq!(resize_panes(&ratios, &sizes, &[], 0, 500.0), [2.0, 0.0, 2.0]);
+
+        let limits = [(75.0, Some(150.0)), (25.0, None)];
+        assert_eq!(resize_panes(&ratios, &sizes, &limits, 0, -50.0), [0.75, 1.25, 2.0]);
+        assert_eq!(resize_panes(&ratios, &sizes, &limits, 0, 80.0), [1.5, 0.5, 2.0]);
+
+        // Panes which can't both fit within their limits are left as they are.
+        let limits = [(150.0, None), (100.0, None)];
+        assert_eq!(resize_panes(&ratios, &sizes, &limits, 0, 10.0), ratios);
+    }
+
+    #[test]
+    fn dragging_divider_resizes_panes() {
+        let (mut cx, splitter) = context(vec![1.0, 1.0]);
+        // The panes report their size when they are laid out.
+        for index in 0..2 {
+            cx.emit_to(splitter, SplitterControlEvent::SetPaneSize(index, 100.0, 50.0));
+        }
+        flush(&mut cx);
+
+        let divider = dividers(&cx)[0];
+        cx.mouse.cursor_x = 100.0;
+        send(&mut cx, divider, WindowEvent::MouseDown(MouseButton::Left));
+        send(&mut cx, divider, WindowEvent::MouseMove(150.0, 0.0));
+        assert_eq!(model_ratios(&cx), [1.5, 0.5]);
+
+        // The first pane can't be made smaller than its minimum size.
+        send(&mut cx, divider, WindowEvent::MouseMove(0.0, 0.0));
+        assert_eq!(model_ratios(&cx), [0.25, 1.75]);
+
+        send(&mut cx, divider, WindowEvent::MouseUp(MouseButton::Left));
+        send(&mut cx, divider, WindowEvent::MouseMove(150.0, 0.0));
+        assert_eq!(model_ratios(&cx), [0.25, 1.75]);
+    }
+
+    #[test]
+    fn double_clicking_divider_toggles_collapsed() {
+        let (mut cx, splitter) = context(vec![1.0, 3.0]);
+        let divider = dividers(&cx)[0];
+
+        send(&mut cx, divider, WindowEvent::MouseDoubleClick(MouseButton::Left));
+        assert_eq!(model_ratios(&cx), [0.0, 4.0]);
+        assert_eq!(splitter_of(&cx, splitter).ratios, [0.0, 4.0]);
+
+        send(&mut cx, divider, WindowEvent::MouseDoubleClick(MouseButton::Left));
+        assert_eq!(model_ratios(&cx), [1.0, 3.0]);
+
+        // There is no pane after the last divider.
+        cx.emit_to(splitter, SplitterEvent::ToggleCollapsed(1));
+        flush(&mut cx);
+        assert_eq!(model_ratios(&cx), [1.0, 3.0]);
+    }
+
+    #[test]
+    fn bound_ratios_restore_collapsed() {
+        let (mut cx, splitter) = context(vec![1.0, 3.0]);
+        cx.emit_to(splitter, SplitterEvent::ToggleCollapsed(0));
+        flush(&mut cx);
+
+        // Ratios which are set from bound data after collapsing keep the pane collapsed.
+        assert_eq!(splitter_of(&cx, splitter).collapsed, [Some(1.0), None]);
+
+        // The pane is no longer collapsed once it has been resized.
+        cx.emit_to(Entity::root(), TestEvent::SetRatios(vec![0.5, 3.5]));
+        flush(&mut cx);
+        assert_eq!(splitter_of(&cx, splitter).collapsed, [None, None]);
+        cx.emit_to(splitter, SplitterEvent::ToggleCollapsed(1));
+        flush(&mut cx);
+        assert_eq!(model_ratios(&cx), [0.5, 3.5]);
+
+        // Changing the number of panes rebuilds the dividers.
+        cx.emit_to(Entity::root(), TestEvent::SetRatios(vec![1.0, 1.0, 1.0]));
+        flush(&mut cx);
+        assert_eq!(dividers(&cx).len(), 2);
+        assert_eq!(splitter_of(&cx, splitter).collapsed, [None, None, None]);
+        assert_eq!(splitter_of(&cx, splitter).sizes, [0.0, 0.0, 0.0]);
+    }
+}
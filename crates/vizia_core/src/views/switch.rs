@@ -1,3 +1,4 @@
+use crate::param::emit_param_gesture;
 use crate::prelude::*;
 
 /// A Switch used to display and toggle a boolean state.
@@ -81,6 +82,7 @@ use crate::prelude::*;
 /// ```
 pub struct Switch {
     on_toggle: Option<Box<dyn Fn(&mut EventContext)>>,
+    param: Option<ParamId>,
 }
 
 impl Switch {
@@ -105,7 +107,7 @@ impl Switch {
     /// Switch::new(cx, AppData::value);
     /// ```
     pub fn new(cx: &mut Context, checked: impl Lens<Target = bool>) -> Handle<Self> {
-        Self { on_toggle: None }
+        Self { on_toggle: None, param: None }
             .build(cx, |cx| {
                 Element::new(cx)
                     .class("switch-handle-bg")
@@ -117,6 +119,8 @@ impl Switch {
                     .position_type(PositionType::SelfDirected);
             })
             .checked(checked)
+            .role(Role::Switch)
+            .default_action_verb(DefaultActionVerb::Click)
             .navigable(true)
     }
 }
@@ -152,6 +156,24 @@ impl Handle<'_, Switch> {
     {
         self.modify(|switch| switch.on_toggle = Some(Box::new(callback)))
     }
+
+    /// Binds the switch to a plugin parameter, so that toggling the switch emits a [`ParamEvent`] gesture
+    /// which sets the parameter to 1.0 when switched on and 0.0 when switched off.
+    pub fn param(self, param: ParamId) -> Self {
+        self.modify(|switch| switch.param = Some(param))
+    }
+}
+
+impl Switch {
+    fn toggle(&self, cx: &mut EventContext) {
+        let normalized = if cx.is_checked() { 0.0 } else { 1.0 };
+
+        if let Some(callback) = &self.on_toggle {
+            (callback)(cx);
+        }
+
+        emit_param_gesture(cx, self.param, normalized);
+    }
 }
 
 impl View for Switch {
@@ -164,12 +186,20 @@ impl View for Switch {
             WindowEvent::Press { mouse } => {
                 let over = if *mouse { cx.mouse.left.pressed } else { cx.focused() };
                 if over == cx.current() && meta.target == cx.current() && !cx.is_disabled() {
-                    if let Some(callback) = &self.on_toggle {
-                        (callback)(cx);
-                    }
+                    self.toggle(cx);
                 }
             }
 
+            WindowEvent::ActionRequest(action) => match action.action {
+                Action::Default => {
+                    if !cx.is_disabled() {
+                        self.toggle(cx);
+                    }
+                }
+
+                _ => {}
+            },
+
             _ => {}
         });
     }
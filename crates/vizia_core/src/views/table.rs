@@ -0,0 +1,1473 @@
+use std::cmp::Ordering;
+use std::ops::{Deref, Range};
+use std::rc::Rc;
+
+use super::virtual_list::VirtualListData;
+use crate::icons::{ICON_CHEVRON_DOWN, ICON_CHEVRON_UP};
+use crate::prelude::*;
+
+// The minimum width of a column in logical pixels, unless changed with `TableColumn::min_width`.
+const DEFAULT_MIN_COLUMN_WIDTH: f32 = 40.0;
+// The distance in logical pixels the cursor must move after pressing a header before the column is dragged.
+const COLUMN_DRAG_THRESHOLD: f32 = 4.0;
+
+/// The order in which the rows of a [`Table`] are sorted by a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// Whether whole rows, or individual cells, of a [`Table`] are selected.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TableSelectionMode {
+    #[default]
+    Row,
+    Cell,
+}
+
+impl_res_simple!(TableSelectionMode);
+
+/// A row of a [`Table`], or a cell within the row when `column` is `Some`.
+///
+/// Rows are identified by their index in the list bound to the table, and columns by the order in which they were
+/// added, so indices are not affected by sorting the rows or reordering the columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Data)]
+pub struct TableIndex {
+    pub row: usize,
+    pub column: Option<usize>,
+}
+
+/// Events which can be sent to a [`Table`].
+pub enum TableEvent {
+    /// Sorts the rows by the column with the given index, or restores the order of the list when `None`.
+    SortBy(Option<(usize, SortDirection)>),
+    /// Sorts the rows again, such as after the contents of the list have changed.
+    Refresh,
+    /// Sets the width of the column with the given index in logical pixels.
+    SetColumnWidth(usize, f32),
+    /// Moves the column with the given index to the given position.
+    MoveColumn(usize, usize),
+    /// Replaces the selection with the given rows or cells.
+    Select(Vec<TableIndex>),
+    /// Clears the selection.
+    ClearSelection,
+}
+
+pub(crate) enum TableControlEvent {
+    SetViewport(f32, f32),
+    Scroll(f32, f32),
+    Press(usize, usize),
+    ToggleSort(usize),
+    DragColumn(usize),
+    MoveCursor { rows: isize, columns: isize, extend: bool },
+    MovePage(isize, bool),
+    ToggleCursor,
+    SelectAll,
+}
+
+type HeaderBuilder = Rc<dyn Fn(&mut Context)>;
+type CellBuilder<L, T> = Rc<dyn Fn(&mut Context, usize, MapRef<L, T>)>;
+type Compare<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+type OrderRows = Box<dyn Fn(&EventContext, Option<(usize, SortDirection)>) -> Vec<usize>>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ColumnLayout {
+    width: Units,
+    min_width: f32,
+    resizable: bool,
+    sortable: bool,
+}
+
+/// A column of a [`Table`], returned by [`TableColumns::add`] to set its width and how rows are sorted by it.
+pub struct TableColumn<L: Lens, T: 'static> {
+    header: HeaderBuilder,
+    cell: CellBuilder<L, T>,
+    width: Units,
+    min_width: f32,
+    resizable: bool,
+    compare: Option<Compare<T>>,
+}
+
+impl<L: Lens, T: 'static> TableColumn<L, T> {
+    /// Sets the width of the column, either in `Pixels` or as a `Stretch` factor of the space left by the other
+    /// columns. Defaults to `Stretch(1.0)`.
+    pub fn width(&mut self, width: Units) -> &mut Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the minimum width of the column in logical pixels, which also limits resizing. Defaults to 40.
+    pub fn min_width(&mut self, min_width: f32) -> &mut Self {
+        self.min_width = min_width.max(0.0);
+        self
+    }
+
+    /// Sets whether the column can be resized by dragging the edge of its header. Defaults to true.
+    pub fn resizable(&mut self, flag: bool) -> &mut Self {
+        self.resizable = flag;
+        self
+    }
+
+    /// Allows the rows to be sorted by the column, by clicking its header, with the given comparison.
+    pub fn sort_by(&mut self, compare: impl 'static + Fn(&T, &T) -> Ordering) -> &mut Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    fn layout(&self) -> ColumnLayout {
+        ColumnLayout {
+            width: self.width,
+            min_width: self.min_width,
+            resizable: self.resizable,
+            sortable: self.compare.is_some(),
+        }
+    }
+}
+
+/// The columns of a [`Table`], which are added within the closure passed to [`Table::new`].
+pub struct TableColumns<L: Lens, T: 'static> {
+    columns: Vec<TableColumn<L, T>>,
+}
+
+impl<L: Lens, T: 'static> TableColumns<L, T> {
+    /// Adds a column with the given header content and a template for the cell of each row, which receives the index
+    /// of the row within the list and a lens to its item.
+    pub fn add<H: View, V: View>(
+        &mut self,
+        header: impl 'static + Fn(&mut Context) -> Handle<H>,
+        cell: impl 'static + Fn(&mut Context, usize, MapRef<L, T>) -> Handle<V>,
+    ) -> &mut TableColumn<L, T> {
+        self.columns.push(TableColumn {
+            header: Rc::new(move |cx| {
+                header(cx);
+            }),
+            cell: Rc::new(move |cx, index, item| {
+                cell(cx, index, item).height(Stretch(1.0));
+            }),
+            width: Stretch(1.0),
+            min_width: DEFAULT_MIN_COLUMN_WIDTH,
+            resizable: true,
+            compare: None,
+        });
+
+        self.columns.last_mut().unwrap()
+    }
+}
+
+/// A view for displaying a list of items as rows of a table, with a column for each property of the items.
+///
+/// Clicking the header of a sortable column sorts the rows, dragging the edge of a header resizes its column, and
+/// dragging a header moves its column. Rows, or cells, can be selected with the mouse or the arrow keys, depending on
+/// the [`Selectable`] and [`TableSelectionMode`] of the table. Only the rows and columns which are visible are built,
+/// so tables with many rows and columns remain fast, and the header stays in place as the rows are scrolled.
+///
+/// # Example
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Clone)]
+/// # struct Person {
+/// #     name: String,
+/// #     age: u32,
+/// # }
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     people: Vec<Person>,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { people: vec![] }.build(cx);
+/// #
+/// Table::new(cx, AppData::people, 32.0, |columns| {
+///     columns
+///         .add(|cx| Label::new(cx, "Name"), |cx, _, person| {
+///             Label::new(cx, person.map(|person| person.name.clone()))
+///         })
+///         .sort_by(|a, b| a.name.cmp(&b.name));
+///
+///     columns
+///         .add(|cx| Label::new(cx, "Age"), |cx, _, person| Label::new(cx, person.map(|person| person.age)))
+///         .width(Pixels(80.0))
+///         .sort_by(|a, b| a.age.cmp(&b.age));
+/// })
+/// .selectable(Selectable::Multi);
+/// ```
+#[derive(Lens)]
+pub struct Table {
+    row_height: f32,
+    // The indices of the rows within the list in the order they are displayed.
+    row_order: Vec<usize>,
+    #[lens(ignore)]
+    columns: Vec<ColumnLayout>,
+    // The indices of the columns in the order they are displayed.
+    column_order: Vec<usize>,
+    // The width of each column in logical pixels.
+    column_widths: Vec<f32>,
+    sort: Option<(usize, SortDirection)>,
+    // The logical size of the area in which the rows are displayed, and the distance it has been scrolled.
+    viewport: (f32, f32),
+    scroll_x: f32,
+    scroll_y: f32,
+    // The ranges of the displayed rows and columns which are visible.
+    visible_rows: Range<usize>,
+    visible_columns: Range<usize>,
+    selectable: Selectable,
+    selection_mode: TableSelectionMode,
+    selected: Vec<TableIndex>,
+    // The displayed row and column which is moved with the arrow keys, and the one from which a range is selected.
+    cursor: Option<(usize, usize)>,
+    #[lens(ignore)]
+    anchor: Option<(usize, usize)>,
+    #[lens(ignore)]
+    body: Entity,
+    #[lens(ignore)]
+    order_rows: OrderRows,
+    #[lens(ignore)]
+    on_select: Option<Box<dyn Fn(&mut EventContext, &[TableIndex])>>,
+    #[lens(ignore)]
+    on_sort: Option<Box<dyn Fn(&mut EventContext, Option<(usize, SortDirection)>)>>,
+}
+
+impl Table {
+    /// Creates a new table with a row of the given height in logical pixels for each item of the list, and the
+    /// columns added by the closure.
+    pub fn new<L: Lens, T: 'static>(
+        cx: &mut Context,
+        list: L,
+        row_height: f32,
+        columns: impl FnOnce(&mut TableColumns<L, T>),
+    ) -> Handle<Self>
+    where
+        L::Target: Deref<Target = [T]>,
+    {
+        let mut table_columns = TableColumns { columns: Vec::new() };
+        (columns)(&mut table_columns);
+        let columns = table_columns.columns;
+
+        let layouts = columns.iter().map(TableColumn::layout).collect::<Vec<_>>();
+        let compares = columns.iter().map(|column| column.compare.clone()).collect::<Vec<_>>();
+        let headers: Rc<[HeaderBuilder]> =
+            columns.iter().map(|column| column.header.clone()).collect();
+        let cells: Rc<[CellBuilder<L, T>]> =
+            columns.iter().map(|column| column.cell.clone()).collect();
+
+        let num_rows = list.map(|list| list.len());
+
+        Self {
+            row_height,
+            row_order: Vec::new(),
+            column_order: (0..layouts.len()).collect(),
+            column_widths: resolve_column_widths(&layouts, 0.0),
+            columns: layouts,
+            sort: None,
+            viewport: (0.0, 0.0),
+            scroll_x: 0.0,
+            scroll_y: 0.0,
+            visible_rows: 0..0,
+            visible_columns: 0..0,
+            selectable: Selectable::None,
+            selection_mode: TableSelectionMode::Row,
+            selected: Vec::new(),
+            cursor: None,
+            anchor: None,
+            body: Entity::null(),
+            order_rows: order_rows(list, compares),
+            on_select: None,
+            on_sort: None,
+        }
+        .build(cx, move |cx| {
+            let table = cx.current();
+
+            Self::keymap().build(cx);
+            Self::build_header(cx, headers, row_height);
+
+            let body = ScrollView::new(cx, 0.0, 0.0, true, true, move |cx| {
+                // Rebuild the rows when the number of items changes, so that no row refers to a removed item.
+                Binding::new(cx, num_rows, move |cx, lens| {
+                    let num_rows = lens.get(cx);
+                    cx.emit_to(table, TableEvent::Refresh);
+
+                    let cells = cells.clone();
+                    VStack::new(cx, move |cx| {
+                        let num_visible_rows = Table::visible_rows.map(Range::len);
+                        Binding::new(cx, num_visible_rows, move |cx, lens| {
+                            for slot in 0..lens.get(cx) {
+                                let cells = cells.clone();
+                                // Each slot shows one of the visible rows, and is rebuilt when scrolling changes
+                                // the row it shows, as in a `VirtualList`.
+                                let row = Table::root.map(move |table| table.visible_row(slot));
+                                Binding::new(cx, row, move |cx, row| {
+                                    if let Some((display_row, index)) = row.get(cx) {
+                                        if index < num_rows {
+                                            let cells = cells.clone();
+                                            Self::build_row(
+                                                cx,
+                                                list,
+                                                cells,
+                                                row_height,
+                                                display_row,
+                                                index,
+                                            );
+                                        }
+                                    }
+                                });
+                            }
+                        });
+                    })
+                    .class("table-body")
+                    .bind(
+                        Table::root.map(Table::content_size),
+                        |handle, size| {
+                            let (width, height) = size.get(&handle);
+                            handle.width(Pixels(width)).height(Pixels(height));
+                        },
+                    );
+                });
+            })
+            .on_scroll(|cx, x, y| {
+                if x.is_finite() && y.is_finite() {
+                    cx.emit(TableControlEvent::Scroll(x, y));
+                }
+            })
+            .on_geo_changed(|cx, geo| {
+                if geo.intersects(GeoChanged::WIDTH_CHANGED | GeoChanged::HEIGHT_CHANGED) {
+                    let bounds = cx.bounds();
+                    let scale_factor = cx.scale_factor();
+                    cx.emit(TableControlEvent::SetViewport(
+                        bounds.w / scale_factor,
+                        bounds.h / scale_factor,
+                    ));
+                }
+            })
+            .height(Stretch(1.0))
+            .entity();
+
+            if let Some(view) = cx.views.get_mut(&table) {
+                if let Some(table) = view.downcast_mut::<Table>() {
+                    table.body = body;
+                }
+            }
+        })
+        .role(Role::Grid)
+        .navigable(true)
+    }
+
+    fn keymap() -> Keymap<&'static str> {
+        macro_rules! move_cursor {
+            (
+                $modifiers:expr,
+                $code:ident,
+                $action:literal,
+                $rows:expr,
+                $columns:expr,
+                $extend:literal
+            ) => {
+                (
+                    KeyChord::new($modifiers, Code::$code),
+                    KeymapEntry::new($action, |cx| {
+                        cx.emit(TableControlEvent::MoveCursor {
+                            rows: $rows,
+                            columns: $columns,
+                            extend: $extend,
+                        })
+                    }),
+                )
+            };
+        }
+
+        Keymap::from(vec![
+            move_cursor!(Modifiers::empty(), ArrowDown, "Focus Next Row", 1, 0, false),
+            move_cursor!(Modifiers::empty(), ArrowUp, "Focus Previous Row", -1, 0, false),
+            move_cursor!(Modifiers::empty(), ArrowRight, "Focus Next Column", 0, 1, false),
+            move_cursor!(Modifiers::empty(), ArrowLeft, "Focus Previous Column", 0, -1, false),
+            move_cursor!(Modifiers::SHIFT, ArrowDown, "Select Next Row", 1, 0, true),
+            move_cursor!(Modifiers::SHIFT, ArrowUp, "Select Previous Row", -1, 0, true),
+            move_cursor!(Modifiers::SHIFT, ArrowRight, "Select Next Column", 0, 1, true),
+            move_cursor!(Modifiers::SHIFT, ArrowLeft, "Select Previous Column", 0, -1, true),
+            move_cursor!(Modifiers::empty(), Home, "Focus First Column", 0, isize::MIN, false),
+            move_cursor!(Modifiers::empty(), End, "Focus Last Column", 0, isize::MAX, false),
+            move_cursor!(Modifiers::CTRL, Home, "Focus First Row", isize::MIN, 0, false),
+            move_cursor!(Modifiers::CTRL, End, "Focus Last Row", isize::MAX, 0, false),
+            (
+                KeyChord::new(Modifiers::empty(), Code::PageDown),
+                KeymapEntry::new("Focus Next Page", |cx| {
+                    cx.emit(TableControlEvent::MovePage(1, false))
+                }),
+            ),
+            (
+                KeyChord::new(Modifiers::empty(), Code::PageUp),
+                KeymapEntry::new("Focus Previous Page", |cx| {
+                    cx.emit(TableControlEvent::MovePage(-1, false))
+                }),
+            ),
+            (
+                KeyChord::new(Modifiers::empty(), Code::Space),
+                KeymapEntry::new("Toggle Selection", |cx| cx.emit(TableControlEvent::ToggleCursor)),
+            ),
+            (
+                KeyChord::new(Modifiers::CTRL, Code::KeyA),
+                KeymapEntry::new("Select All", |cx| cx.emit(TableControlEvent::SelectAll)),
+            ),
+            (
+                KeyChord::new(Modifiers::empty(), Code::Escape),
+                KeymapEntry::new("Clear Selection", |cx| cx.emit(TableEvent::ClearSelection)),
+            ),
+        ])
+    }
+
+    // The header is outside of the scrollview so that it stays in place as the rows are scrolled vertically, and is
+    // offset to match the horizontal scroll of the rows.
+    fn build_header(cx: &mut Context, headers: Rc<[HeaderBuilder]>, row_height: f32) {
+        HStack::new(cx, move |cx| {
+            HStack::new(cx, move |cx| {
+                let num_visible_columns = Table::visible_columns.map(Range::len);
+                Binding::new(cx, num_visible_columns, move |cx, lens| {
+                    for slot in 0..lens.get(cx) {
+                        let headers = headers.clone();
+                        let column = Table::root.map(move |table| table.visible_column(slot));
+                        Binding::new(cx, column, move |cx, column| {
+                            if let Some(column) = column.get(cx) {
+                                TableColumnHeader::new(cx, column, headers[column].clone()).bind(
+                                    Table::column_span_lens(column),
+                                    |handle, span| {
+                                        let (left, width) = span.get(&handle);
+                                        handle.left(Pixels(left)).width(Pixels(width));
+                                    },
+                                );
+                            }
+                        });
+                    }
+                });
+            })
+            .class("table-header-content")
+            .height(Stretch(1.0))
+            .bind(Table::root.map(Table::content_size), |handle, size| {
+                let (width, _) = size.get(&handle);
+                handle.width(Pixels(width));
+            })
+            .bind(Table::scroll_x, |handle, scroll_x| {
+                let scroll_x = scroll_x.get(&handle);
+                handle.left(Pixels(-scroll_x));
+            });
+        })
+        .class("table-header")
+        .height(Pixels(row_height))
+        .role(Role::Row);
+    }
+
+    fn build_row<L: Lens, T: 'static>(
+        cx: &mut Context,
+        list: L,
+        cells: Rc<[CellBuilder<L, T>]>,
+        row_height: f32,
+        display_row: usize,
+        index: usize,
+    ) where
+        L::Target: Deref<Target = [T]>,
+    {
+        HStack::new(cx, move |cx| {
+            let num_visible_columns = Table::visible_columns.map(Range::len);
+            Binding::new(cx, num_visible_columns, move |cx, lens| {
+                for slot in 0..lens.get(cx) {
+                    let cells = cells.clone();
+                    let column = Table::root.map(move |table| table.visible_column(slot));
+                    Binding::new(cx, column, move |cx, column| {
+                        if let Some(column) = column.get(cx) {
+                            let cell = cells[column].clone();
+                            Self::build_cell(cx, list, cell, display_row, index, column);
+                        }
+                    });
+                }
+            });
+        })
+        .class("table-row")
+        .position_type(PositionType::SelfDirected)
+        .top(Pixels(display_row as f32 * row_height))
+        .height(Pixels(row_height))
+        .width(Stretch(1.0))
+        .role(Role::Row)
+        .checked(
+            Table::selected
+                .map(move |selected| selected.contains(&TableIndex { row: index, column: None })),
+        )
+        .toggle_class(
+            "cursor",
+            Table::root.map(move |table| {
+                table.selection_mode == TableSelectionMode::Row
+                    && table.cursor.is_some_and(|(row, _)| row == display_row)
+            }),
+        );
+    }
+
+    fn build_cell<L: Lens, T: 'static>(
+        cx: &mut Context,
+        list: L,
+        cell: CellBuilder<L, T>,
+        display_row: usize,
+        index: usize,
+        column: usize,
+    ) where
+        L::Target: Deref<Target = [T]>,
+    {
+        HStack::new(cx, move |cx| {
+            let item = list.map_ref(move |list| &list[index]);
+            (cell)(cx, index, item);
+        })
+        .class("table-cell")
+        .position_type(PositionType::SelfDirected)
+        .height(Stretch(1.0))
+        .role(Role::GridCell)
+        .checked(Table::selected.map(move |selected| {
+            selected.contains(&TableIndex { row: index, column: Some(column) })
+        }))
+        .toggle_class(
+            "cursor",
+            Table::root.map(move |table| {
+                table.selection_mode == TableSelectionMode::Cell
+                    && table.cursor_at(display_row, column)
+            }),
+        )
+        .on_press_down(move |cx| cx.emit(TableControlEvent::Press(display_row, column)))
+        .bind(Table::column_span_lens(column), |handle, span| {
+            let (left, width) = span.get(&handle);
+            handle.left(Pixels(left)).width(Pixels(width));
+        });
+    }
+
+    fn column_span_lens(column: usize) -> impl Lens<Target = (f32, f32)> {
+        Table::root.map(move |table| table.column_span(column))
+    }
+
+    // The logical width of all columns and height of all rows.
+    fn content_size(&self) -> (f32, f32) {
+        (self.column_widths.iter().sum(), self.row_order.len() as f32 * self.row_height)
+    }
+
+    // The logical position and width of a column within the rows.
+    fn column_span(&self, column: usize) -> (f32, f32) {
+        let mut left = 0.0;
+        for index in self.column_order.iter().copied() {
+            if index == column {
+                return (left, self.column_widths[index]);
+            }
+
+            left += self.column_widths[index];
+        }
+
+        (left, 0.0)
+    }
+
+    // The displayed row, and the index of its item, shown in a slot of the visible rows.
+    fn visible_row(&self, slot: usize) -> Option<(usize, usize)> {
+        let range = &self.visible_rows;
+        if slot >= range.len() {
+            return None;
+        }
+
+        let row = VirtualListData::evaluate_index(slot, range.start, range.end);
+        self.row_order.get(row).map(|index| (row, *index))
+    }
+
+    // The column shown in a slot of the visible columns.
+    fn visible_column(&self, slot: usize) -> Option<usize> {
+        let range = &self.visible_columns;
+        if slot >= range.len() {
+            return None;
+        }
+
+        let position = VirtualListData::evaluate_index(slot, range.start, range.end);
+        self.column_order.get(position).copied()
+    }
+
+    fn cursor_at(&self, row: usize, column: usize) -> bool {
+        self.cursor.is_some_and(|(cursor_row, cursor_column)| {
+            cursor_row == row && self.column_order.get(cursor_column) == Some(&column)
+        })
+    }
+
+    // Returns the row or cell at a displayed row and column, depending on the selection mode.
+    fn index_at(&self, (row, column): (usize, usize)) -> TableIndex {
+        TableIndex {
+            row: self.row_order[row],
+            column: match self.selection_mode {
+                TableSelectionMode::Row => None,
+                TableSelectionMode::Cell => Some(self.column_order[column]),
+            },
+        }
+    }
+
+    // Returns the rows or cells between two displayed rows and columns.
+    fn indices_between(&self, from: (usize, usize), to: (usize, usize)) -> Vec<TableIndex> {
+        let rows = from.0.min(to.0)..=from.0.max(to.0);
+        let columns = from.1.min(to.1)..=from.1.max(to.1);
+
+        match self.selection_mode {
+            TableSelectionMode::Row => rows.map(|row| self.index_at((row, 0))).collect(),
+            TableSelectionMode::Cell => rows
+                .flat_map(|row| columns.clone().map(move |column| (row, column)))
+                .map(|position| self.index_at(position))
+                .collect(),
+        }
+    }
+
+    // Moves the cursor by a number of displayed rows and columns, returning false if there is nothing to move to.
+    fn move_cursor(&mut self, rows: isize, columns: isize) -> bool {
+        let (num_rows, num_columns) = (self.row_order.len(), self.column_order.len());
+        if num_rows == 0 || num_columns == 0 {
+            return false;
+        }
+
+        self.cursor = Some(match self.cursor {
+            Some((row, column)) => (
+                row.saturating_add_signed(rows).min(num_rows - 1),
+                column.saturating_add_signed(columns).min(num_columns - 1),
+            ),
+            None => (0, 0),
+        });
+
+        true
+    }
+
+    // Updates the selection after the cursor has moved, extending it from the anchor or toggling the cursor.
+    fn select_cursor(&mut self, extend: bool, toggle: bool) -> bool {
+        let Some(cursor) = self.cursor else {
+            return false;
+        };
+
+        match self.selectable {
+            Selectable::None => return false,
+
+            Selectable::Multi if extend => {
+                let anchor = *self.anchor.get_or_insert(cursor);
+                self.selected = self.indices_between(anchor, cursor);
+            }
+
+            Selectable::Multi if toggle => {
+                let index = self.index_at(cursor);
+                if let Some(position) = self.selected.iter().position(|selected| *selected == index)
+                {
+                    self.selected.remove(position);
+                } else {
+                    self.selected.push(index);
+                }
+                self.anchor = Some(cursor);
+            }
+
+            Selectable::Single if toggle && self.selected == [self.index_at(cursor)] => {
+                self.selected.clear();
+                self.anchor = Some(cursor);
+            }
+
+            _ => {
+                self.selected = vec![self.index_at(cursor)];
+                self.anchor = Some(cursor);
+            }
+        }
+
+        true
+    }
+
+    fn notify_select(&self, cx: &mut EventContext) {
+        if let Some(callback) = &self.on_select {
+            (callback)(cx, &self.selected);
+        }
+    }
+
+    fn sort_rows(&mut self, cx: &mut EventContext) {
+        self.row_order = (self.order_rows)(cx, self.sort);
+
+        // Keep the selection within the list, which may have fewer items than before.
+        let num_rows = self.row_order.len();
+        let len = self.selected.len();
+        self.selected.retain(|index| index.row < num_rows);
+        if self.selected.len() != len {
+            self.notify_select(cx);
+        }
+
+        if num_rows == 0 {
+            self.cursor = None;
+            self.anchor = None;
+        } else if let Some((row, _)) = &mut self.cursor {
+            *row = (*row).min(num_rows - 1);
+        }
+
+        self.recalc();
+    }
+
+    fn layout_columns(&mut self) {
+        self.column_widths = resolve_column_widths(&self.columns, self.viewport.0);
+        self.recalc();
+    }
+
+    fn recalc(&mut self) {
+        let (width, height) = self.content_size();
+        self.scroll_x = self.scroll_x.clamp(0.0, (width - self.viewport.0).max(0.0));
+        self.scroll_y = self.scroll_y.clamp(0.0, (height - self.viewport.1).max(0.0));
+
+        self.visible_rows =
+            visible_rows(self.row_order.len(), self.row_height, self.scroll_y, self.viewport.1);
+
+        let widths = self.column_order.iter().map(|column| self.column_widths[*column]);
+        self.visible_columns = visible_range(widths, self.scroll_x, self.viewport.0);
+    }
+
+    // Scrolls the rows so that the cursor is visible.
+    fn scroll_to_cursor(&self, cx: &mut EventContext) {
+        let Some((row, column)) = self.cursor else {
+            return;
+        };
+
+        let (width, height) = self.content_size();
+        let (viewport_width, viewport_height) = self.viewport;
+
+        let top = row as f32 * self.row_height;
+        let scroll_y = scroll_into_view(self.scroll_y, viewport_height, top, self.row_height);
+        if scroll_y != self.scroll_y && height > viewport_height {
+            cx.emit_to(self.body, ScrollEvent::SetY(scroll_y / (height - viewport_height)));
+        }
+
+        let (left, column_width) = self.column_span(self.column_order[column]);
+        let scroll_x = scroll_into_view(self.scroll_x, viewport_width, left, column_width);
+        if scroll_x != self.scroll_x && width > viewport_width {
+            cx.emit_to(self.body, ScrollEvent::SetX(scroll_x / (width - viewport_width)));
+        }
+    }
+
+    fn set_sort(&mut self, cx: &mut EventContext, sort: Option<(usize, SortDirection)>) {
+        self.sort = sort
+            .filter(|(column, _)| self.columns.get(*column).is_some_and(|layout| layout.sortable));
+        self.sort_rows(cx);
+
+        if let Some(callback) = &self.on_sort {
+            (callback)(cx, self.sort);
+        }
+    }
+
+    fn move_column(&mut self, column: usize, position: usize) {
+        let Some(from) = self.column_order.iter().position(|index| *index == column) else {
+            return;
+        };
+
+        // Keep the cursor on the same column.
+        let cursor_column = self.cursor.map(|(_, position)| self.column_order[position]);
+
+        let position = position.min(self.column_order.len() - 1);
+        let column = self.column_order.remove(from);
+        self.column_order.insert(position, column);
+
+        if let (Some((_, position)), Some(cursor_column)) = (&mut self.cursor, cursor_column) {
+            *position = self.column_order.iter().position(|index| *index == cursor_column).unwrap();
+        }
+        self.anchor = None;
+
+        self.recalc();
+    }
+}
+
+impl View for Table {
+    fn element(&self) -> Option<&'static str> {
+        Some("table")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|table_event, _| match table_event {
+            TableEvent::SortBy(sort) => {
+                self.set_sort(cx, *sort);
+            }
+
+            TableEvent::Refresh => {
+                self.sort_rows(cx);
+            }
+
+            TableEvent::SetColumnWidth(column, width) => {
+                if let Some(layout) = self.columns.get_mut(*column) {
+                    layout.width = Pixels(width.max(layout.min_width));
+                    self.layout_columns();
+                }
+            }
+
+            TableEvent::MoveColumn(column, position) => {
+                self.move_column(*column, *position);
+            }
+
+            TableEvent::Select(selection) => {
+                self.selected = selection.clone();
+                self.anchor = None;
+                self.notify_select(cx);
+            }
+
+            TableEvent::ClearSelection => {
+                if !self.selected.is_empty() {
+                    self.selected.clear();
+                    self.notify_select(cx);
+                }
+                self.anchor = None;
+            }
+        });
+
+        event.map(|control_event, meta| match control_event {
+            TableControlEvent::SetViewport(width, height) => {
+                self.viewport = (*width, *height);
+                self.layout_columns();
+            }
+
+            TableControlEvent::Scroll(x, y) => {
+                let (width, height) = self.content_size();
+                self.scroll_x = (width - self.viewport.0).max(0.0) * x;
+                self.scroll_y = (height - self.viewport.1).max(0.0) * y;
+                self.recalc();
+            }
+
+            TableControlEvent::Press(row, column) => {
+                cx.focus();
+
+                let Some(position) = self.column_order.iter().position(|index| index == column)
+                else {
+                    return;
+                };
+
+                if *row < self.row_order.len() {
+                    self.cursor = Some((*row, position));
+                    let modifiers = *cx.modifiers();
+                    if self.select_cursor(modifiers.shift(), modifiers.ctrl() || modifiers.logo()) {
+                        self.notify_select(cx);
+                    }
+                }
+                meta.consume();
+            }
+
+            TableControlEvent::ToggleSort(column) => {
+                let sort = match self.sort {
+                    Some((sorted, SortDirection::Ascending)) if sorted == *column => {
+                        Some((*column, SortDirection::Descending))
+                    }
+                    Some((sorted, SortDirection::Descending)) if sorted == *column => None,
+                    _ => Some((*column, SortDirection::Ascending)),
+                };
+                self.set_sort(cx, sort);
+            }
+
+            TableControlEvent::DragColumn(column) => {
+                let bounds = cx.bounds();
+                let x = (cx.mouse().cursor_x - bounds.x) / cx.scale_factor() + self.scroll_x;
+                let widths = self.column_order.iter().map(|index| self.column_widths[*index]);
+                let position = item_at(widths, x);
+                self.move_column(*column, position);
+            }
+
+            TableControlEvent::MoveCursor { rows, columns, extend } => {
+                if self.move_cursor(*rows, *columns) {
+                    if self.select_cursor(*extend, false) {
+                        self.notify_select(cx);
+                    }
+                    self.scroll_to_cursor(cx);
+                }
+                meta.consume();
+            }
+
+            TableControlEvent::MovePage(direction, extend) => {
+                let page = (self.viewport.1 / self.row_height).floor().max(1.0) as isize;
+                if self.move_cursor(page * *direction, 0) {
+                    if self.select_cursor(*extend, false) {
+                        self.notify_select(cx);
+                    }
+                    self.scroll_to_cursor(cx);
+                }
+                meta.consume();
+            }
+
+            TableControlEvent::ToggleCursor => {
+                if self.select_cursor(false, true) {
+                    self.notify_select(cx);
+                }
+                meta.consume();
+            }
+
+            TableControlEvent::SelectAll => {
+                if self.selectable == Selectable::Multi
+                    && !self.row_order.is_empty()
+                    && !self.column_order.is_empty()
+                {
+                    let last = (self.row_order.len() - 1, self.column_order.len() - 1);
+                    self.selected = self.indices_between((0, 0), last);
+                    self.notify_select(cx);
+                }
+                meta.consume();
+            }
+        });
+    }
+}
+
+impl Handle<'_, Table> {
+    /// Sets whether no rows or cells, a single row or cell, or multiple rows or cells can be selected. Defaults to
+    /// [`Selectable::None`].
+    pub fn selectable(self, selectable: impl Res<Selectable>) -> Self {
+        self.bind(selectable, |handle, selectable| {
+            let selectable = selectable.get(&handle);
+            handle.modify(|table: &mut Table| {
+                table.selectable = selectable;
+                if selectable == Selectable::None {
+                    table.selected.clear();
+                }
+            });
+        })
+    }
+
+    /// Sets whether whole rows, or individual cells, are selected. Defaults to [`TableSelectionMode::Row`].
+    pub fn selection_mode(self, mode: impl Res<TableSelectionMode>) -> Self {
+        self.bind(mode, |handle, mode| {
+            let mode = mode.get(&handle);
+            handle.modify(|table: &mut Table| {
+                if table.selection_mode != mode {
+                    table.selection_mode = mode;
+                    table.selected.clear();
+                    table.anchor = None;
+                }
+            });
+        })
+    }
+
+    /// Sets a callback which is called with the selected rows, or cells, whenever the selection changes.
+    pub fn on_select(self, callback: impl 'static + Fn(&mut EventContext, &[TableIndex])) -> Self {
+        self.modify(|table: &mut Table| table.on_select = Some(Box::new(callback)))
+    }
+
+    /// Sets a callback which is called with the column and direction by which the rows are sorted whenever it
+    /// changes.
+    pub fn on_sort(
+        self,
+        callback: impl 'static + Fn(&mut EventContext, Option<(usize, SortDirection)>),
+    ) -> Self {
+        self.modify(|table: &mut Table| table.on_sort = Some(Box::new(callback)))
+    }
+}
+
+// The header of a column, which sorts the rows when clicked and moves the column when dragged.
+struct TableColumnHeader {
+    column: usize,
+    // The horizontal position of the cursor when the header was pressed, and whether the column has been dragged.
+    press: Option<(f32, bool)>,
+}
+
+impl TableColumnHeader {
+    fn new(cx: &mut Context, column: usize, content: HeaderBuilder) -> Handle<Self> {
+        Self { column, press: None }
+            .build(cx, move |cx| {
+                (content)(cx);
+
+                Binding::new(cx, Table::sort, move |cx, sort| {
+                    if let Some((sorted, direction)) = sort.get(cx) {
+                        if sorted == column {
+                            Svg::new(
+                                cx,
+                                match direction {
+                                    SortDirection::Ascending => ICON_CHEVRON_UP,
+                                    SortDirection::Descending => ICON_CHEVRON_DOWN,
+                                },
+                            )
+                            .class("sort-indicator");
+                        }
+                    }
+                });
+
+                Binding::new(
+                    cx,
+                    Table::root.map(move |table| table.columns[column].resizable),
+                    move |cx, resizable| {
+                        if resizable.get(cx) {
+                            TableColumnResizer::new(cx, column);
+                        }
+                    },
+                );
+            })
+            .position_type(PositionType::SelfDirected)
+            .height(Stretch(1.0))
+            .role(Role::ColumnHeader)
+            .toggle_class("sortable", Table::root.map(move |table| table.columns[column].sortable))
+    }
+}
+
+impl View for TableColumnHeader {
+    fn element(&self) -> Option<&'static str> {
+        Some("table-column-header")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                self.press = Some((cx.mouse().cursor_x, false));
+                cx.capture();
+                cx.set_active(true);
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(x, _) => {
+                if let Some((start_x, dragged)) = &mut self.press {
+                    if *dragged || (x - *start_x).abs() > COLUMN_DRAG_THRESHOLD * cx.scale_factor()
+                    {
+                        *dragged = true;
+                        cx.emit(TableControlEvent::DragColumn(self.column));
+                    }
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if let Some((_, dragged)) = self.press.take() {
+                    cx.release();
+                    cx.set_active(false);
+                    if !dragged {
+                        cx.emit(TableControlEvent::ToggleSort(self.column));
+                    }
+                    meta.consume();
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+// The edge of a column header, which resizes the column when dragged.
+struct TableColumnResizer {
+    column: usize,
+    // The logical width of the column and the horizontal position of the cursor when the drag started.
+    drag_start: Option<(f32, f32)>,
+}
+
+impl TableColumnResizer {
+    fn new(cx: &mut Context, column: usize) -> Handle<Self> {
+        Self { column, drag_start: None }
+            .build(cx, |_| {})
+            .position_type(PositionType::SelfDirected)
+            .role(Role::Splitter)
+    }
+}
+
+impl View for TableColumnResizer {
+    fn element(&self) -> Option<&'static str> {
+        Some("table-column-resizer")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                let width = cx.data::<Table>().map(|table| table.column_widths[self.column]);
+                if let Some(width) = width {
+                    self.drag_start = Some((width, cx.mouse().cursor_x));
+                    cx.capture();
+                    cx.set_active(true);
+                }
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(x, _) => {
+                if let Some((width, start_x)) = self.drag_start {
+                    let width = width + (x - start_x) / cx.scale_factor();
+                    cx.emit(TableEvent::SetColumnWidth(self.column, width));
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.drag_start.take().is_some() {
+                    cx.release();
+                    cx.set_active(false);
+                    meta.consume();
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+// Returns a closure which orders the rows of the list, sorting them by a column if it has a comparison.
+fn order_rows<L: Lens, T: 'static>(list: L, compares: Vec<Option<Compare<T>>>) -> OrderRows
+where
+    L::Target: Deref<Target = [T]>,
+{
+    Box::new(move |cx: &EventContext, sort: Option<(usize, SortDirection)>| {
+        let Some(list) = cx.data().and_then(|source| list.view(source)) else {
+            return Vec::new();
+        };
+
+        let mut order = (0..list.len()).collect::<Vec<_>>();
+        if let Some((column, direction)) = sort {
+            if let Some(Some(compare)) = compares.get(column) {
+                order.sort_by(|a, b| {
+                    let ordering = compare(&list[*a], &list[*b]);
+                    match direction {
+                        SortDirection::Ascending => ordering,
+                        SortDirection::Descending => ordering.reverse(),
+                    }
+                });
+            }
+        }
+
+        order
+    })
+}
+
+/// Returns the widths of the columns in logical pixels, sharing the available width left by columns with a fixed
+/// width between stretch columns.
+fn resolve_column_widths(columns: &[ColumnLayout], available: f32) -> Vec<f32> {
+    let fixed = |layout: &ColumnLayout| match layout.width {
+        Pixels(width) => Some(width),
+        Percentage(percentage) => Some(available * percentage / 100.0),
+        Stretch(_) => None,
+        Auto => Some(layout.min_width),
+    };
+
+    let fixed_width = columns.iter().filter_map(fixed).sum::<f32>();
+    let stretch_factor = columns
+        .iter()
+        .filter_map(|layout| match layout.width {
+            Stretch(factor) => Some(factor),
+            _ => None,
+        })
+        .sum::<f32>();
+    let free_width = (available - fixed_width).max(0.0);
+
+    columns
+        .iter()
+        .map(|layout| {
+            let width = match layout.width {
+                Stretch(factor) if stretch_factor > 0.0 => free_width * factor / stretch_factor,
+                _ => fixed(layout).unwrap_or(0.0),
+            };
+
+            width.max(layout.min_width)
+        })
+        .collect()
+}
+
+/// Returns the range of displayed rows which overlap the visible area.
+fn visible_rows(num_rows: usize, row_height: f32, scroll: f32, viewport: f32) -> Range<usize> {
+    if row_height <= 0.0 {
+        return 0..0;
+    }
+
+    let start = (scroll / row_height).floor() as usize;
+    let end = ((scroll + viewport) / row_height).ceil() as usize;
+
+    start.min(num_rows)..end.min(num_rows)
+}
+
+/// Returns the range of items, with the given sizes, which overlap the area of the given size starting at `start`.
+fn visible_range(sizes: impl Iterator<Item = f32>, start: f32, size: f32) -> Range<usize> {
+    let mut range = 0..0;
+    let mut offset = 0.0;
+    for (index, item_size) in sizes.enumerate() {
+        let end = offset + item_size;
+        if end > start && offset < start + size {
+            if range.is_empty() {
+                range.start = index;
+            }
+            range.end = index + 1;
+        }
+
+        offset = end;
+    }
+
+    range
+}
+
+/// Returns the index of the item, with the given sizes, which contains the offset, or the nearest item if the offset
+/// is outside of all items.
+fn item_at(sizes: impl Iterator<Item = f32>, offset: f32) -> usize {
+    let mut end = 0.0;
+    let mut last = 0;
+    for (index, item_size) in sizes.enumerate() {
+        end += item_size;
+        last = index;
+        if offset < end {
+            break;
+        }
+    }
+
+    last
+}
+
+/// Returns the scroll offset which makes the item, at the given offset and with the given size, visible.
+fn scroll_into_view(scroll: f32, viewport: f32, offset: f32, size: f32) -> f32 {
+    if offset < scroll {
+        offset
+    } else if offset + size > scroll + viewport {
+        (offset + size - viewport).min(offset)
+    } else {
+        scroll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    #[derive(Lens)]
+    struct TestData {
+        items: Vec<u32>,
+    }
+
+    enum TestEvent {
+        Truncate(usize),
+    }
+
+    impl Model for TestData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|TestEvent::Truncate(len), _| self.items.truncate(*len));
+        }
+    }
+
+    fn layout(width: Units) -> ColumnLayout {
+        ColumnLayout { width, min_width: 10.0, resizable: true, sortable: false }
+    }
+
+    // Builds a table with a sortable column of the items and a column of their doubles, with room for five rows.
+    fn context(items: Vec<u32>, selection_mode: TableSelectionMode) -> (Context, Entity) {
+        let mut cx = Context::default();
+        TestData { items }.build(&mut cx);
+        let table = Table::new(&mut cx, TestData::items, 20.0, |columns| {
+            columns
+                .add(
+                    |cx| Label::new(cx, "Value"),
+                    |cx, _, item| Label::new(cx, item.map(|item| *item)),
+                )
+                .sort_by(|a, b| a.cmp(b));
+            columns.add(
+                |cx| Label::new(cx, "Double"),
+                |cx, _, item| Label::new(cx, item.map(|item| item * 2)),
+            );
+        })
+        .selectable(Selectable::Multi)
+        .selection_mode(selection_mode)
+        .entity();
+
+        cx.emit_to(table, TableControlEvent::SetViewport(100.0, 100.0));
+        flush(&mut cx);
+        (cx, table)
+    }
+
+    fn flush(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    fn send(cx: &mut Context, target: Entity, event: WindowEvent) {
+        cx.emit_custom(
+            Event::new(event).target(target).origin(target).propagate(Propagation::Direct),
+        );
+        flush(cx);
+    }
+
+    fn press_key(cx: &mut Context, table: Entity, modifiers: Modifiers, code: Code) {
+        cx.modifiers = modifiers;
+        send(cx, table, WindowEvent::KeyDown(code, None));
+        cx.modifiers = Modifiers::empty();
+    }
+
+    fn click(cx: &mut Context, entity: Entity) {
+        send(cx, entity, WindowEvent::MouseDown(MouseButton::Left));
+        send(cx, entity, WindowEvent::MouseUp(MouseButton::Left));
+    }
+
+    fn table_of(cx: &Context, table: Entity) -> &Table {
+        cx.views.get(&table).and_then(|view| view.downcast_ref()).unwrap()
+    }
+
+    fn with_class(cx: &Context, class: &str) -> Vec<Entity> {
+        Entity::root()
+            .tree_iter(&cx.tree)
+            .filter(|entity| cx.style.classes.get(*entity).is_some_and(|c| c.contains(class)))
+            .collect()
+    }
+
+    fn headers(cx: &Context) -> Vec<Entity> {
+        Entity::root()
+            .tree_iter(&cx.tree)
+            .filter(|entity| {
+                cx.views
+                    .get(entity)
+                    .is_some_and(|view| view.downcast_ref::<TableColumnHeader>().is_some())
+            })
+            .collect()
+    }
+
+    // The text of the first displayed cell of each row.
+    fn first_cells(cx: &Context) -> Vec<String> {
+        with_class(cx, "table-row")
+            .into_iter()
+            .filter_map(|row| {
+                row.branch_iter(&cx.tree).find_map(|entity| cx.style.text.get(entity).cloned())
+            })
+            .collect()
+    }
+
+    fn rows(rows: &[usize]) -> Vec<TableIndex> {
+        rows.iter().map(|row| TableIndex { row: *row, column: None }).collect()
+    }
+
+    #[test]
+    fn column_widths() {
+        let columns = [layout(Pixels(100.0)), layout(Stretch(1.0)), layout(Stretch(3.0))];
+        assert_eq!(resolve_column_widths(&columns, 500.0), [100.0, 100.0, 300.0]);
+        // Stretch columns are never narrower than their minimum width.
+        assert_eq!(resolve_column_widths(&columns, 50.0), [100.0, 10.0, 10.0]);
+
+        let columns = [layout(Percentage(25.0)), layout(Stretch(1.0))];
+        assert_eq!(resolve_column_widths(&columns, 200.0), [50.0, 150.0]);
+    }
+
+    #[test]
+    fn visible_row_range() {
+        assert_eq!(visible_rows(100, 20.0, 0.0, 100.0), 0..5);
+        // Partially visible rows are included.
+        assert_eq!(visible_rows(100, 20.0, 10.0, 100.0), 0..6);
+        assert_eq!(visible_rows(100, 20.0, 1990.0, 100.0), 99..100);
+        assert_eq!(visible_rows(0, 20.0, 0.0, 100.0), 0..0);
+    }
+
+    #[test]
+    fn visible_column_range() {
+        let widths = [50.0, 100.0, 50.0, 200.0];
+        assert_eq!(visible_range(widths.into_iter(), 0.0, 100.0), 0..2);
+        assert_eq!(visible_range(widths.into_iter(), 160.0, 50.0), 2..4);
+        assert_eq!(visible_range(widths.into_iter(), 1000.0, 100.0), 0..0);
+        assert_eq!(visible_range(widths.into_iter(), 0.0, 0.0), 0..0);
+    }
+
+    #[test]
+    fn item_at_offset() {
+        let widths = [50.0, 100.0, 50.0];
+        assert_eq!(item_at(widths.into_iter(), -10.0), 0);
+        assert_eq!(item_at(widths.into_iter(), 120.0), 1);
+        assert_eq!(item_at(widths.into_iter(), 150.0), 2);
+        assert_eq!(item_at(widths.into_iter(), 1000.0), 2);
+    }
+
+    #[test]
+    fn scroll_offset_into_view() {
+        assert_eq!(scroll_into_view(0.0, 100.0, 40.0, 20.0), 0.0);
+        assert_eq!(scroll_into_view(0.0, 100.0, 100.0, 20.0), 20.0);
+        assert_eq!(scroll_into_view(50.0, 100.0, 20.0, 20.0), 20.0);
+    }
+
+    #[test]
+    fn clicking_header_sorts_rows() {
+        let (mut cx, table) = context(vec![3, 1, 2], TableSelectionMode::Row);
+        assert_eq!(first_cells(&cx), ["3", "1", "2"]);
+
+        let header = headers(&cx)[0];
+        click(&mut cx, header);
+        assert_eq!(table_of(&cx, table).sort, Some((0, SortDirection::Ascending)));
+        assert_eq!(first_cells(&cx), ["1", "2", "3"]);
+        assert_eq!(with_class(&cx, "sort-indicator").len(), 1);
+
+        click(&mut cx, header);
+        assert_eq!(table_of(&cx, table).sort, Some((0, SortDirection::Descending)));
+        assert_eq!(first_cells(&cx), ["3", "2", "1"]);
+
+        // A third click restores the order of the list.
+        click(&mut cx, header);
+        assert_eq!(table_of(&cx, table).sort, None);
+        assert_eq!(first_cells(&cx), ["3", "1", "2"]);
+        assert!(with_class(&cx, "sort-indicator").is_empty());
+
+        // The second column has no comparison, so clicking its header doesn't sort the rows.
+        let header = headers(&cx)[1];
+        click(&mut cx, header);
+        assert_eq!(table_of(&cx, table).sort, None);
+    }
+
+    #[test]
+    fn keyboard_moves_cursor_and_selection() {
+        let (mut cx, table) = context((0..10).collect(), TableSelectionMode::Row);
+
+        press_key(&mut cx, table, Modifiers::empty(), Code::ArrowDown);
+        assert_eq!(table_of(&cx, table).cursor, Some((0, 0)));
+        assert_eq!(table_of(&cx, table).selected, rows(&[0]));
+        assert_eq!(with_class(&cx, "cursor"), with_class(&cx, "table-row")[..1]);
+
+        press_key(&mut cx, table, Modifiers::SHIFT, Code::ArrowDown);
+        press_key(&mut cx, table, Modifiers::SHIFT, Code::ArrowDown);
+        assert_eq!(table_of(&cx, table).selected, rows(&[0, 1, 2]));
+
+        // Toggling the cursor removes its row from the selection.
+        press_key(&mut cx, table, Modifiers::empty(), Code::Space);
+        assert_eq!(table_of(&cx, table).selected, rows(&[0, 1]));
+
+        press_key(&mut cx, table, Modifiers::CTRL, Code::KeyA);
+        assert_eq!(table_of(&cx, table).selected.len(), 10);
+
+        press_key(&mut cx, table, Modifiers::empty(), Code::Escape);
+        assert!(table_of(&cx, table).selected.is_empty());
+
+        press_key(&mut cx, table, Modifiers::CTRL, Code::End);
+        press_key(&mut cx, table, Modifiers::empty(), Code::ArrowRight);
+        assert_eq!(table_of(&cx, table).cursor, Some((9, 1)));
+        assert_eq!(table_of(&cx, table).selected, rows(&[9]));
+    }
+
+    #[test]
+    fn keyboard_selects_cells() {
+        let (mut cx, table) = context(vec![1, 2, 3], TableSelectionMode::Cell);
+
+        press_key(&mut cx, table, Modifiers::empty(), Code::ArrowDown);
+        press_key(&mut cx, table, Modifiers::SHIFT, Code::ArrowRight);
+        press_key(&mut cx, table, Modifiers::SHIFT, Code::ArrowDown);
+
+        assert_eq!(
+            table_of(&cx, table).selected,
+            [
+                TableIndex { row: 0, column: Some(0) },
+                TableIndex { row: 0, column: Some(1) },
+                TableIndex { row: 1, column: Some(0) },
+                TableIndex { row: 1, column: Some(1) },
+            ]
+        );
+
+        // Only the cell under the cursor is marked, rather than its row.
+        let cursor = with_class(&cx, "cursor");
+        assert_eq!(cursor.len(), 1);
+        assert!(cx
+            .style
+            .classes
+            .get(cursor[0])
+            .is_some_and(|classes| classes.contains("table-cell")));
+    }
+
+    #[test]
+    fn moving_column_keeps_cursor() {
+        let (mut cx, table) = context(vec![1, 2, 3], TableSelectionMode::Cell);
+        press_key(&mut cx, table, Modifiers::empty(), Code::ArrowDown);
+
+        cx.emit_to(table, TableEvent::MoveColumn(0, 1));
+        flush(&mut cx);
+        assert_eq!(table_of(&cx, table).column_order, [1, 0]);
+        assert_eq!(first_cells(&cx), ["2", "4", "6"]);
+        // The cursor stays on the column which was moved.
+        assert_eq!(table_of(&cx, table).cursor, Some((0, 1)));
+        assert_eq!(table_of(&cx, table).column_span(0), (50.0, 50.0));
+    }
+
+    #[test]
+    fn rows_are_rebuilt_when_list_shrinks() {
+        let (mut cx, table) = context((0..10).collect(), TableSelectionMode::Row);
+        assert_eq!(first_cells(&cx), ["0", "1", "2", "3", "4"]);
+
+        press_key(&mut cx, table, Modifiers::CTRL, Code::End);
+        assert_eq!(table_of(&cx, table).cursor, Some((9, 0)));
+        cx.emit_to(table, TableEvent::Select(rows(&[1, 8])));
+
+        cx.emit_to(Entity::root(), TestEvent::Truncate(3));
+        flush(&mut cx);
+        assert_eq!(first_cells(&cx), ["0", "1", "2"]);
+        // The selection and cursor are kept within the list.
+        assert_eq!(table_of(&cx, table).selected, rows(&[1]));
+        assert_eq!(table_of(&cx, table).cursor, Some((2, 0)));
+    }
+}
@@ -44,6 +44,7 @@ impl TabView {
                     //.class("tabview-tabheader-wrapper");
                 })
                 .class("tabview-header")
+                .role(Role::TabList)
                 .z_index(1)
                 .toggle_class("vertical", TabView::is_vertical);
 
@@ -58,6 +59,7 @@ impl TabView {
                     });
                 })
                 .overflow(Overflow::Hidden)
+                .role(Role::TabPanel)
                 .class("tabview-content-wrapper");
             })
             .toggle_class("vertical", TabView::is_vertical)
@@ -126,7 +128,11 @@ impl TabHeader {
     where
         F: 'static + Fn(&mut Context),
     {
-        Self { index }.build(cx, |cx| (content)(cx)).navigable(true)
+        Self { index }
+            .build(cx, |cx| (content)(cx))
+            .role(Role::Tab)
+            .default_action_verb(DefaultActionVerb::Click)
+            .navigable(true)
     }
 }
 
@@ -141,6 +147,14 @@ impl View for TabHeader {
                 cx.emit(TabEvent::SetSelected(self.index));
             }
 
+            WindowEvent::ActionRequest(action) => match action.action {
+                Action::Default => {
+                    cx.emit(TabEvent::SetSelected(self.index));
+                }
+
+                _ => {}
+            },
+
             _ => {}
         });
     }
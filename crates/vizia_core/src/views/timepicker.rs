@@ -0,0 +1,521 @@
+use chrono::{NaiveTime, Timelike};
+
+use crate::localization::locale_region;
+use crate::prelude::*;
+
+// The regions which prefer a 12-hour clock, from the CLDR time data. A 24-hour clock is used in all other regions.
+const TWELVE_HOUR_REGIONS: [&str; 18] = [
+    "AE", "AU", "BD", "CA", "CO", "EG", "IN", "JO", "KR", "KW", "MY", "NZ", "PH", "PK", "QA", "SA",
+    "TW", "US",
+];
+
+/// Returns whether a 24-hour clock is used in the region of the given locale.
+pub(crate) fn locale_uses_24_hour(locale: &LanguageIdentifier) -> bool {
+    !locale_region(locale).is_some_and(|region| TWELVE_HOUR_REGIONS.contains(&region))
+}
+
+/// A segment of a [`Timepicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub(crate) enum TimeSegment {
+    Hour,
+    Minute,
+    Second,
+    Period,
+}
+
+impl TimeSegment {
+    fn name(&self) -> &'static str {
+        match self {
+            TimeSegment::Hour => "Hours",
+            TimeSegment::Minute => "Minutes",
+            TimeSegment::Second => "Seconds",
+            TimeSegment::Period => "AM/PM",
+        }
+    }
+}
+
+pub(crate) enum TimepickerEvent {
+    SetTime(NaiveTime),
+    SetLocale24Hour(bool),
+    SetUse24Hour(bool),
+    SetShowSeconds(bool),
+    Focus(TimeSegment),
+    Step(Option<TimeSegment>, i32),
+    Type(TimeSegment, char),
+}
+
+/// A control used to select a time, which is shown as hour, minute and second segments.
+///
+/// A segment is edited by typing digits, or by stepping it with the arrow keys, the mouse wheel, or the spin buttons,
+/// which step the last focused segment. Whether a 12-hour or 24-hour clock is used follows the current locale.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use chrono::NaiveTime;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     alarm: NaiveTime,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # enum AppEvent {
+/// #     SetAlarm(NaiveTime),
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { alarm: NaiveTime::from_hms_opt(7, 30, 0).unwrap() }.build(cx);
+/// #
+/// Timepicker::new(cx, AppData::alarm)
+///     .show_seconds(false)
+///     .on_change(|cx, time| cx.emit(AppEvent::SetAlarm(time)));
+/// ```
+#[derive(Lens)]
+pub struct Timepicker {
+    time: NaiveTime,
+    locale_24_hour: bool,
+    use_24_hour: Option<bool>,
+    show_seconds: bool,
+    // The segment stepped by the spin buttons.
+    #[lens(ignore)]
+    active: TimeSegment,
+    // The digits typed into the active segment, until the segment can't take another digit.
+    #[lens(ignore)]
+    typed: String,
+
+    #[lens(ignore)]
+    on_change: Option<Box<dyn Fn(&mut EventContext, NaiveTime)>>,
+}
+
+impl Timepicker {
+    /// Creates a new [`Timepicker`] which shows the time of the given lens.
+    ///
+    /// The time is not changed when the user edits it, and should be updated in the callback set with
+    /// [`on_change`](Handle::on_change).
+    pub fn new<L>(cx: &mut Context, lens: L) -> Handle<Self>
+    where
+        L: Lens<Target = NaiveTime>,
+    {
+        Self {
+            time: lens.get(cx),
+            locale_24_hour: locale_uses_24_hour(&cx.environment().locale),
+            use_24_hour: None,
+            show_seconds: true,
+            active: TimeSegment::Hour,
+            typed: String::new(),
+            on_change: None,
+        }
+        .build(cx, |cx| {
+            Binding::new(cx, Environment::locale, |cx, locale| {
+                let uses_24_hour = locale_uses_24_hour(&locale.get(cx));
+                cx.emit(TimepickerEvent::SetLocale24Hour(uses_24_hour));
+            });
+
+            Spinbox::custom(cx, |cx| {
+                HStack::new(cx, |cx| {
+                    TimeSegmentView::new(cx, TimeSegment::Hour);
+                    Label::new(cx, ":").class("timepicker-separator");
+                    TimeSegmentView::new(cx, TimeSegment::Minute);
+                    Label::new(cx, ":")
+                        .class("timepicker-separator")
+                        .display(Timepicker::show_seconds);
+                    TimeSegmentView::new(cx, TimeSegment::Second).display(Timepicker::show_seconds);
+                    TimeSegmentView::new(cx, TimeSegment::Period)
+                        .display(Timepicker::root.map(|timepicker| !timepicker.uses_24_hour()));
+                })
+                .class("timepicker-segments")
+            })
+            .on_increment(|ex| ex.emit(TimepickerEvent::Step(None, 1)))
+            .on_decrement(|ex| ex.emit(TimepickerEvent::Step(None, -1)));
+        })
+        .role(Role::Group)
+        .bind(lens, |mut handle, time| {
+            let time = time.get(&handle);
+            let entity = handle.entity();
+            handle.context().emit_to(entity, TimepickerEvent::SetTime(time));
+        })
+    }
+
+    fn uses_24_hour(&self) -> bool {
+        self.use_24_hour.unwrap_or(self.locale_24_hour)
+    }
+
+    fn segment_text(&self, segment: TimeSegment) -> String {
+        match segment {
+            TimeSegment::Hour if self.uses_24_hour() => format!("{:02}", self.time.hour()),
+            TimeSegment::Hour => format!("{:02}", self.time.hour12().1),
+            TimeSegment::Minute => format!("{:02}", self.time.minute()),
+            TimeSegment::Second => format!("{:02}", self.time.second()),
+            TimeSegment::Period if self.time.hour() < 12 => String::from("AM"),
+            TimeSegment::Period => String::from("PM"),
+        }
+    }
+
+    // Returns the time with a segment set to the given value, where the value of the hour segment is in the clock
+    // used by the timepicker.
+    fn with_segment(&self, segment: TimeSegment, value: u32) -> NaiveTime {
+        let time = self.time;
+        match segment {
+            TimeSegment::Hour if self.uses_24_hour() => time.with_hour(value),
+            TimeSegment::Hour => time.with_hour(value % 12 + if time.hour() < 12 { 0 } else { 12 }),
+            TimeSegment::Minute => time.with_minute(value),
+            TimeSegment::Second => time.with_second(value),
+            TimeSegment::Period => time.with_hour((time.hour() + 12) % 24),
+        }
+        .unwrap_or(time)
+    }
+
+    // Returns the time with a segment stepped by the given delta, wrapping around without carrying into the other
+    // segments.
+    fn step(&self, segment: TimeSegment, delta: i32) -> NaiveTime {
+        let (value, count) = match segment {
+            TimeSegment::Hour => (self.time.hour(), 24),
+            TimeSegment::Minute => (self.time.minute(), 60),
+            TimeSegment::Second => (self.time.second(), 60),
+            TimeSegment::Period => return self.with_segment(TimeSegment::Period, 0),
+        };
+
+        let value = (value as i32 + delta).rem_euclid(count) as u32;
+        match segment {
+            TimeSegment::Hour => self.time.with_hour(value).unwrap_or(self.time),
+            _ => self.with_segment(segment, value),
+        }
+    }
+
+    // Types a character into a segment, returning the edited time if the character changes it.
+    fn type_char(&mut self, segment: TimeSegment, c: char) -> Option<NaiveTime> {
+        if segment == TimeSegment::Period {
+            let is_pm = match c.to_ascii_lowercase() {
+                'a' => false,
+                'p' => true,
+                _ => return None,
+            };
+            return (is_pm != (self.time.hour() >= 12))
+                .then(|| self.with_segment(TimeSegment::Period, 0));
+        }
+
+        let digit = c.to_digit(10)?;
+        let max = match segment {
+            TimeSegment::Hour if self.uses_24_hour() => 23,
+            TimeSegment::Hour => 12,
+            _ => 59,
+        };
+
+        self.typed.push(c);
+        let mut value = self.typed.parse::<u32>().unwrap_or(digit);
+        if value > max {
+            self.typed = c.to_string();
+            value = digit;
+        }
+
+        // The segment is complete when another digit can't be typed into it.
+        if self.typed.len() >= 2 || value * 10 > max {
+            self.typed.clear();
+        }
+
+        // There's no zero hour on a 12-hour clock, so a typed zero waits for the next digit.
+        if segment == TimeSegment::Hour && !self.uses_24_hour() && value == 0 {
+            return None;
+        }
+
+        Some(self.with_segment(segment, value))
+    }
+}
+
+impl View for Timepicker {
+    fn element(&self) -> Option<&'static str> {
+        Some("timepicker")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|timepicker_event, meta| {
+            let time = match timepicker_event {
+                TimepickerEvent::SetTime(time) => {
+                    self.time = *time;
+                    None
+                }
+
+                TimepickerEvent::SetLocale24Hour(uses_24_hour) => {
+                    self.locale_24_hour = *uses_24_hour;
+                    None
+                }
+
+                TimepickerEvent::SetUse24Hour(uses_24_hour) => {
+                    self.use_24_hour = Some(*uses_24_hour);
+                    None
+                }
+
+                TimepickerEvent::SetShowSeconds(show_seconds) => {
+                    self.show_seconds = *show_seconds;
+                    None
+                }
+
+                TimepickerEvent::Focus(segment) => {
+                    self.active = *segment;
+                    self.typed.clear();
+                    None
+                }
+
+                TimepickerEvent::Step(segment, delta) => {
+                    self.typed.clear();
+                    Some(self.step(segment.unwrap_or(self.active), *delta))
+                }
+
+                TimepickerEvent::Type(segment, c) => self.type_char(*segment, *c),
+            };
+
+            if let Some(time) = time.filter(|time| *time != self.time) {
+                if let Some(callback) = &self.on_change {
+                    (callback)(cx, time);
+                }
+            }
+
+            meta.consume();
+        });
+    }
+}
+
+impl Handle<'_, Timepicker> {
+    /// Sets the callback which is called with the edited time when the user changes the time.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, NaiveTime),
+    {
+        self.modify(|timepicker: &mut Timepicker| timepicker.on_change = Some(Box::new(callback)))
+    }
+
+    /// Sets whether a 24-hour clock is used, which otherwise follows the region of the current locale.
+    pub fn use_24_hour(mut self, use_24_hour: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        use_24_hour.set_or_bind(self.context(), entity, |cx, use_24_hour| {
+            let use_24_hour = use_24_hour.get(cx);
+            cx.emit(TimepickerEvent::SetUse24Hour(use_24_hour));
+        });
+
+        self
+    }
+
+    /// Sets whether the seconds segment is shown. Defaults to true.
+    pub fn show_seconds(mut self, show_seconds: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        show_seconds.set_or_bind(self.context(), entity, |cx, show_seconds| {
+            let show_seconds = show_seconds.get(cx);
+            cx.emit(TimepickerEvent::SetShowSeconds(show_seconds));
+        });
+
+        self
+    }
+}
+
+// An editable segment of a timepicker.
+struct TimeSegmentView {
+    segment: TimeSegment,
+}
+
+impl TimeSegmentView {
+    fn new(cx: &mut Context, segment: TimeSegment) -> Handle<Self> {
+        Self { segment }
+            .build(cx, |_| {})
+            .text(Timepicker::root.map(move |timepicker| timepicker.segment_text(segment)))
+            .class("timepicker-segment")
+            .role(Role::SpinButton)
+            .name(Localized::new(segment.name()))
+            .navigable(true)
+    }
+}
+
+impl View for TimeSegmentView {
+    fn element(&self) -> Option<&'static str> {
+        Some("time-segment")
+    }
+
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.add_action(Action::Increment);
+        node.add_action(Action::Decrement);
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        let segment = self.segment;
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                cx.focus_with_visibility(false);
+            }
+
+            WindowEvent::FocusIn => cx.emit(TimepickerEvent::Focus(segment)),
+
+            WindowEvent::KeyDown(Code::ArrowUp, _) => {
+                cx.emit(TimepickerEvent::Step(Some(segment), 1));
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(Code::ArrowDown, _) => {
+                cx.emit(TimepickerEvent::Step(Some(segment), -1));
+                meta.consume();
+            }
+
+            WindowEvent::CharInput(c) => {
+                cx.emit(TimepickerEvent::Type(segment, *c));
+                meta.consume();
+            }
+
+            WindowEvent::MouseScroll(_, y) if *y != 0.0 => {
+                cx.emit(TimepickerEvent::Step(Some(segment), y.signum() as i32));
+                meta.consume();
+            }
+
+            WindowEvent::ActionRequest(action) => match action.action {
+                Action::Increment => cx.emit(TimepickerEvent::Step(Some(segment), 1)),
+                Action::Decrement => cx.emit(TimepickerEvent::Step(Some(segment), -1)),
+                _ => {}
+            },
+
+            _ => {}
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    fn time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+    }
+
+    #[derive(Lens)]
+    struct TestData {
+        time: NaiveTime,
+    }
+
+    enum TestEvent {
+        SetTime(NaiveTime),
+    }
+
+    impl Model for TestData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|TestEvent::SetTime(time), _| self.time = *time);
+        }
+    }
+
+    // Builds a timepicker which is bound to the time of a model and updates it when the time is edited.
+    fn context(time: NaiveTime, use_24_hour: bool) -> (Context, Entity) {
+        let mut cx = Context::default();
+        TestData { time }.build(&mut cx);
+        let timepicker = Timepicker::new(&mut cx, TestData::time)
+            .use_24_hour(use_24_hour)
+            .on_change(|cx, time| cx.emit(TestEvent::SetTime(time)))
+            .entity();
+        flush(&mut cx);
+        (cx, timepicker)
+    }
+
+    fn flush(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    fn send(cx: &mut Context, target: Entity, event: WindowEvent) {
+        cx.emit_custom(
+            Event::new(event).target(target).origin(target).propagate(Propagation::Direct),
+        );
+        flush(cx);
+    }
+
+    fn model_time(cx: &Context) -> NaiveTime {
+        cx.data::<TestData>().unwrap().time
+    }
+
+    // The hour, minute, second and period segments.
+    fn segments(cx: &Context) -> Vec<Entity> {
+        Entity::root()
+            .tree_iter(&cx.tree)
+            .filter(|entity| {
+                cx.views
+                    .get(entity)
+                    .is_some_and(|view| view.downcast_ref::<TimeSegmentView>().is_some())
+            })
+            .collect()
+    }
+
+    fn segment_texts(cx: &Context) -> Vec<String> {
+        segments(cx)
+            .into_iter()
+            .map(|segment| cx.style.text.get(segment).cloned().unwrap())
+            .collect()
+    }
+
+    fn type_chars(cx: &mut Context, segment: Entity, chars: &str) {
+        for c in chars.chars() {
+            send(cx, segment, WindowEvent::CharInput(c));
+        }
+    }
+
+    #[test]
+    fn locale_hour_cycle() {
+        assert!(!locale_uses_24_hour(&"en-US".parse().unwrap()));
+        assert!(!locale_uses_24_hour(&"en".parse().unwrap()));
+        assert!(locale_uses_24_hour(&"en-GB".parse().unwrap()));
+        assert!(locale_uses_24_hour(&"de".parse().unwrap()));
+    }
+
+    #[test]
+    fn segment_text() {
+        let (cx, _) = context(time(0, 5, 9), false);
+        assert_eq!(segment_texts(&cx), ["12", "05", "09", "AM"]);
+
+        let (cx, _) = context(time(13, 5, 9), true);
+        assert_eq!(segment_texts(&cx), ["13", "05", "09", "PM"]);
+    }
+
+    #[test]
+    fn step() {
+        let (mut cx, timepicker) = context(time(23, 59, 0), true);
+        let [hour, minute, second, period] = segments(&cx)[..] else { panic!() };
+
+        send(&mut cx, hour, WindowEvent::KeyDown(Code::ArrowUp, None));
+        assert_eq!(model_time(&cx), time(0, 59, 0));
+        send(&mut cx, hour, WindowEvent::KeyDown(Code::ArrowDown, None));
+        send(&mut cx, minute, WindowEvent::KeyDown(Code::ArrowUp, None));
+        assert_eq!(model_time(&cx), time(23, 0, 0));
+        send(&mut cx, second, WindowEvent::KeyDown(Code::ArrowDown, None));
+        assert_eq!(model_time(&cx), time(23, 0, 59));
+        send(&mut cx, period, WindowEvent::KeyDown(Code::ArrowUp, None));
+        assert_eq!(model_time(&cx), time(11, 0, 59));
+
+        // The spin buttons step the last focused segment.
+        send(&mut cx, minute, WindowEvent::FocusIn);
+        cx.emit_to(timepicker, TimepickerEvent::Step(None, -1));
+        flush(&mut cx);
+        assert_eq!(model_time(&cx), time(11, 59, 59));
+    }
+
+    #[test]
+    fn type_char() {
+        let (mut cx, _) = context(time(10, 30, 0), true);
+        let hour = segments(&cx)[0];
+        type_chars(&mut cx, hour, "1");
+        assert_eq!(model_time(&cx), time(1, 30, 0));
+        type_chars(&mut cx, hour, "8");
+        assert_eq!(model_time(&cx), time(18, 30, 0));
+        // The segment is complete, so the next digit starts over.
+        type_chars(&mut cx, hour, "7");
+        assert_eq!(model_time(&cx), time(7, 30, 0));
+
+        let (mut cx, _) = context(time(15, 0, 0), false);
+        let [hour, minute, _, period] = segments(&cx)[..] else { panic!() };
+        // There's no zero hour on a 12-hour clock.
+        type_chars(&mut cx, hour, "0");
+        assert_eq!(model_time(&cx), time(15, 0, 0));
+        type_chars(&mut cx, hour, "9");
+        assert_eq!(model_time(&cx), time(21, 0, 0));
+        type_chars(&mut cx, period, "a");
+        assert_eq!(model_time(&cx), time(9, 0, 0));
+        type_chars(&mut cx, period, "a");
+        type_chars(&mut cx, minute, "x");
+        assert_eq!(model_time(&cx), time(9, 0, 0));
+    }
+}
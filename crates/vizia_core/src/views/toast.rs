@@ -0,0 +1,604 @@
+use std::sync::Arc;
+
+use crate::icons::{
+    ICON_ALERT_CIRCLE, ICON_ALERT_TRIANGLE, ICON_CIRCLE_CHECK, ICON_INFO_CIRCLE, ICON_X,
+};
+use crate::prelude::*;
+
+// The interval at which the timeouts of the shown toasts are counted down.
+const TICK_INTERVAL: Duration = Duration::from_millis(100);
+// The duration of the transition of a dismissed toast before it is removed, which should match the theme.
+const EXIT_DURATION: Duration = Duration::from_millis(200);
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The severity of a [`Toast`], which determines its icon and style.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Data)]
+pub enum ToastSeverity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    fn class(&self) -> &'static str {
+        match self {
+            ToastSeverity::Info => "info",
+            ToastSeverity::Success => "success",
+            ToastSeverity::Warning => "warning",
+            ToastSeverity::Error => "error",
+        }
+    }
+
+    fn icon(&self) -> &'static str {
+        match self {
+            ToastSeverity::Info => ICON_INFO_CIRCLE,
+            ToastSeverity::Success => ICON_CIRCLE_CHECK,
+            ToastSeverity::Warning => ICON_ALERT_TRIANGLE,
+            ToastSeverity::Error => ICON_ALERT_CIRCLE,
+        }
+    }
+}
+
+type ToastAction = Arc<dyn Fn(&mut EventContext) + Send + Sync>;
+
+/// A transient message, which is shown by a [`ToastArea`] when sent with [`ToastEvent::Show`].
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # enum AppEvent {
+/// #     Undo,
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// Button::new(cx, |cx| Label::new(cx, "Delete")).on_press(|cx| {
+///     cx.emit(ToastEvent::Show(
+///         Toast::new("File deleted")
+///             .severity(ToastSeverity::Warning)
+///             .action("Undo", |cx| cx.emit(AppEvent::Undo))
+///             .key("delete"),
+///     ));
+/// });
+/// ```
+#[derive(Clone)]
+pub struct Toast {
+    message: String,
+    severity: ToastSeverity,
+    timeout: Option<Duration>,
+    actions: Vec<(String, ToastAction)>,
+    key: Option<String>,
+}
+
+impl Toast {
+    /// Creates a new informational toast with the given message, which is dismissed after five seconds.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            severity: ToastSeverity::default(),
+            timeout: Some(DEFAULT_TIMEOUT),
+            actions: Vec::new(),
+            key: None,
+        }
+    }
+
+    /// Sets the severity of the toast.
+    pub fn severity(mut self, severity: ToastSeverity) -> Self {
+        self.severity = severity;
+
+        self
+    }
+
+    /// Sets how long the toast is shown before it is dismissed. The timeout is paused while the toast is hovered.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Shows the toast until it is dismissed by the user or with [`ToastEvent::Dismiss`].
+    pub fn persistent(mut self) -> Self {
+        self.timeout = None;
+
+        self
+    }
+
+    /// Adds a button to the toast which calls the given action and dismisses the toast when pressed.
+    pub fn action(
+        mut self,
+        label: impl Into<String>,
+        action: impl 'static + Fn(&mut EventContext) + Send + Sync,
+    ) -> Self {
+        self.actions.push((label.into(), Arc::new(action)));
+
+        self
+    }
+
+    /// Sets a key which identifies the toast, so that showing a toast with the same key replaces it instead of
+    /// showing another toast, and so that it can be dismissed with [`ToastEvent::Dismiss`].
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+
+        self
+    }
+}
+
+/// Events which can be emitted by any view within a window to show and dismiss the toasts of its [`ToastArea`].
+pub enum ToastEvent {
+    /// Shows a toast, or replaces the toast with the same key.
+    Show(Toast),
+    /// Dismisses the toast with the given key.
+    Dismiss(String),
+    /// Dismisses all toasts, including those waiting to be shown.
+    DismissAll,
+}
+
+pub(crate) enum ToastControlEvent {
+    Tick,
+    SetHovered(usize, bool),
+    Dismiss(usize),
+    SetPlacement(Placement),
+    SetMaxVisible(usize),
+}
+
+#[derive(Clone)]
+struct ToastEntry {
+    id: usize,
+    toast: Toast,
+    // The time left before the toast is dismissed, which isn't counted down while the toast is waiting to be shown.
+    remaining: Option<Duration>,
+    hovered: bool,
+    // The time left in the exit transition of a dismissed toast.
+    exit: Option<Duration>,
+    // Whether the toast hasn't been shown yet, and should be animated in when it is.
+    is_new: bool,
+}
+
+/// The model, built at the root of a window by a [`ToastArea`], which queues the toasts of the window.
+#[derive(Lens)]
+pub struct Toaster {
+    entries: Vec<ToastEntry>,
+    placement: Placement,
+    max_visible: usize,
+    #[lens(ignore)]
+    next_id: usize,
+    #[lens(ignore)]
+    timer: Timer,
+}
+
+impl Toaster {
+    fn new(cx: &mut Context) -> Self {
+        let timer = cx.add_timer(TICK_INTERVAL, None, |cx, action| {
+            if matches!(action, TimerAction::Tick(_)) {
+                cx.emit(ToastControlEvent::Tick);
+            }
+        });
+
+        Self {
+            entries: Vec::new(),
+            placement: Placement::BottomEnd,
+            max_visible: 5,
+            next_id: 0,
+            timer,
+        }
+    }
+
+    fn show(&mut self, toast: Toast) {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let entry = ToastEntry {
+            id,
+            remaining: toast.timeout,
+            toast,
+            hovered: false,
+            exit: None,
+            is_new: true,
+        };
+
+        // A toast with the same key is replaced in place, and animated in again.
+        let existing = entry.toast.key.as_ref().and_then(|key| {
+            self.entries.iter_mut().find(|existing| {
+                existing.exit.is_none() && existing.toast.key.as_ref() == Some(key)
+            })
+        });
+
+        match existing {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    fn dismiss(&mut self, id: usize) {
+        let Some(index) = self.entries.iter().position(|entry| entry.id == id) else {
+            return;
+        };
+
+        // Toasts waiting to be shown are removed without a transition.
+        if index >= self.max_visible {
+            self.entries.remove(index);
+        } else if self.entries[index].exit.is_none() {
+            self.entries[index].exit = Some(EXIT_DURATION);
+        }
+    }
+
+    fn tick(&mut self, delta: Duration) {
+        for entry in self.entries.iter_mut().take(self.max_visible) {
+            entry.is_new = false;
+
+            if let Some(exit) = &mut entry.exit {
+                *exit = exit.saturating_sub(delta);
+            } else if !entry.hovered {
+                if let Some(remaining) = &mut entry.remaining {
+                    *remaining = remaining.saturating_sub(delta);
+                    if remaining.is_zero() {
+                        entry.exit = Some(EXIT_DURATION);
+                    }
+                }
+            }
+        }
+
+        let len = self.entries.len();
+        self.entries.retain(|entry| entry.exit != Some(Duration::ZERO));
+
+        // The shown toasts are rebuilt when one is removed, after which they are no longer hovered.
+        if self.entries.len() != len {
+            for entry in self.entries.iter_mut() {
+                entry.hovered = false;
+            }
+        }
+    }
+
+    // Returns the ids of the shown toasts, in the order they are stacked from the top.
+    fn visible_ids(&self) -> Vec<usize> {
+        let ids = self.entries.iter().take(self.max_visible).map(|entry| entry.id);
+        match self.placement {
+            Placement::TopStart | Placement::Top | Placement::TopEnd => ids.rev().collect(),
+            _ => ids.collect(),
+        }
+    }
+}
+
+impl Model for Toaster {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        let mut changed = false;
+
+        event.map(|toast_event, meta| {
+            match toast_event {
+                ToastEvent::Show(toast) => self.show(toast.clone()),
+
+                ToastEvent::Dismiss(key) => {
+                    let ids = self
+                        .entries
+                        .iter()
+                        .filter(|entry| entry.toast.key.as_ref() == Some(key))
+                        .map(|entry| entry.id)
+                        .collect::<Vec<_>>();
+                    for id in ids {
+                        self.dismiss(id);
+                    }
+                }
+
+                ToastEvent::DismissAll => {
+                    self.entries.truncate(self.max_visible);
+                    for entry in self.entries.iter_mut() {
+                        entry.exit.get_or_insert(EXIT_DURATION);
+                    }
+                }
+            }
+
+            changed = true;
+            meta.consume();
+        });
+
+        event.map(|control_event, meta| {
+            match control_event {
+                ToastControlEvent::Tick => self.tick(TICK_INTERVAL),
+
+                ToastControlEvent::SetHovered(id, hovered) => {
+                    if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == *id) {
+                        entry.hovered = *hovered;
+                    }
+                }
+
+                ToastControlEvent::Dismiss(id) => self.dismiss(*id),
+
+                ToastControlEvent::SetPlacement(placement) => self.placement = *placement,
+
+                ToastControlEvent::SetMaxVisible(max_visible) => {
+                    self.max_visible = (*max_visible).max(1)
+                }
+            }
+
+            changed = true;
+            meta.consume();
+        });
+
+        // Only count down the timeouts while there are toasts.
+        if changed {
+            if self.entries.is_empty() {
+                cx.stop_timer(self.timer);
+            } else if !cx.timer_is_running(self.timer) {
+                cx.start_timer(self.timer);
+            }
+        }
+    }
+}
+
+/// An overlay which stacks the toasts shown with [`ToastEvent::Show`] in a corner of the window.
+///
+/// The toast area builds the model which queues the toasts in the current view, so it should be added to the root
+/// of a window for any view within the window to be able to show toasts. New toasts are announced by assistive
+/// technologies.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// ToastArea::new(cx).placement(Placement::TopEnd).max_visible(3);
+///
+/// Button::new(cx, |cx| Label::new(cx, "Save")).on_press(|cx| {
+///     cx.emit(ToastEvent::Show(Toast::new("Saved").severity(ToastSeverity::Success)));
+/// });
+/// ```
+pub struct ToastArea {}
+
+impl ToastArea {
+    /// Creates a new [`ToastArea`], and the model which queues its toasts in the current view.
+    pub fn new(cx: &mut Context) -> Handle<Self> {
+        Toaster::new(cx).build(cx);
+
+        Self {}
+            .build(cx, |cx| {
+                Binding::new(cx, Toaster::root.map(Toaster::visible_ids), |cx, ids| {
+                    for id in ids.get(cx) {
+                        ToastItem::new(cx, id);
+                    }
+                });
+            })
+            .live(Live::Polite)
+            .bind(Toaster::placement, |handle, placement| {
+                let (left, right) = match placement.get(&handle) {
+                    Placement::TopStart | Placement::BottomStart => (Pixels(0.0), Stretch(1.0)),
+                    Placement::Top | Placement::Bottom => (Stretch(1.0), Stretch(1.0)),
+                    _ => (Stretch(1.0), Pixels(0.0)),
+                };
+                let (top, bottom) = match placement.get(&handle) {
+                    Placement::TopStart | Placement::Top | Placement::TopEnd => {
+                        (Pixels(0.0), Stretch(1.0))
+                    }
+                    _ => (Stretch(1.0), Pixels(0.0)),
+                };
+
+                handle.left(left).right(right).top(top).bottom(bottom);
+            })
+    }
+}
+
+impl View for ToastArea {
+    fn element(&self) -> Option<&'static str> {
+        Some("toast-area")
+    }
+}
+
+impl Handle<'_, ToastArea> {
+    /// Sets the corner, or edge, of the window in which the toasts are stacked. Defaults to [`Placement::BottomEnd`].
+    ///
+    /// Toasts can be placed at the start, center, or end of the top or bottom of the window.
+    pub fn placement(mut self, placement: impl Res<Placement>) -> Self {
+        let entity = self.entity();
+        placement.set_or_bind(self.context(), entity, |cx, placement| {
+            let placement = placement.get(cx);
+            cx.emit(ToastControlEvent::SetPlacement(placement));
+        });
+
+        self
+    }
+
+    /// Sets the maximum number of toasts shown at once, after which toasts wait to be shown. Defaults to 5.
+    pub fn max_visible(mut self, max_visible: impl Res<usize>) -> Self {
+        let entity = self.entity();
+        max_visible.set_or_bind(self.context(), entity, |cx, max_visible| {
+            let max_visible = max_visible.get(cx);
+            cx.emit(ToastControlEvent::SetMaxVisible(max_visible));
+        });
+
+        self
+    }
+}
+
+struct ToastItem {}
+
+impl ToastItem {
+    fn new(cx: &mut Context, id: usize) -> Option<Handle<Self>> {
+        let (toast, is_new) = cx.data::<Toaster>().and_then(|toaster| {
+            let entry = toaster.entries.iter().find(|entry| entry.id == id)?;
+            Some((entry.toast.clone(), entry.is_new))
+        })?;
+
+        let handle = Self {}
+            .build(cx, |cx| {
+                Svg::new(cx, toast.severity.icon()).class("toast-icon");
+
+                Label::new(cx, toast.message.clone()).class("toast-message");
+
+                for (label, action) in toast.actions {
+                    Button::new(cx, |cx| Label::new(cx, label)).class("toast-action").on_press(
+                        move |cx| {
+                            (action)(cx);
+                            cx.emit(ToastControlEvent::Dismiss(id));
+                        },
+                    );
+                }
+
+                Button::new(cx, |cx| Svg::new(cx, ICON_X))
+                    .class("toast-close")
+                    .name("Dismiss")
+                    .on_press(move |cx| cx.emit(ToastControlEvent::Dismiss(id)));
+            })
+            .class(toast.severity.class())
+            .role(Role::Status)
+            .toggle_class(
+                "dismissing",
+                Toaster::entries.map(move |entries| {
+                    entries.iter().any(|entry| entry.id == id && entry.exit.is_some())
+                }),
+            )
+            .on_hover(move |cx| cx.emit(ToastControlEvent::SetHovered(id, true)))
+            .on_hover_out(move |cx| cx.emit(ToastControlEvent::SetHovered(id, false)));
+
+        Some(if is_new {
+            handle.on_build(|cx| cx.play_animation("toast_in", EXIT_DURATION, Duration::ZERO))
+        } else {
+            handle
+        })
+    }
+}
+
+impl View for ToastItem {
+    fn element(&self) -> Option<&'static str> {
+        Some("toast")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    // Builds a toast area which shows up to two toasts at once.
+    fn context() -> Context {
+        let mut cx = Context::default();
+        ToastArea::new(&mut cx).max_visible(2);
+        flush(&mut cx);
+        cx
+    }
+
+    fn flush(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    fn send(cx: &mut Context, target: Entity, event: WindowEvent) {
+        cx.emit_custom(
+            Event::new(event).target(target).origin(target).propagate(Propagation::Direct),
+        );
+        flush(cx);
+    }
+
+    fn emit(cx: &mut Context, event: impl Send + 'static) {
+        cx.emit_to(Entity::root(), event);
+        flush(cx);
+    }
+
+    fn tick(cx: &mut Context, duration: Duration) {
+        for _ in 0..(duration.as_millis() / TICK_INTERVAL.as_millis()) {
+            emit(cx, ToastControlEvent::Tick);
+        }
+    }
+
+    fn toaster(cx: &Context) -> &Toaster {
+        cx.data::<Toaster>().unwrap()
+    }
+
+    // The messages of all toasts, including those waiting to be shown.
+    fn messages(cx: &Context) -> Vec<&str> {
+        toaster(cx).entries.iter().map(|entry| entry.toast.message.as_str()).collect()
+    }
+
+    fn toast_items(cx: &Context) -> Vec<Entity> {
+        Entity::root()
+            .tree_iter(&cx.tree)
+            .filter(|entity| {
+                cx.views.get(entity).is_some_and(|view| view.downcast_ref::<ToastItem>().is_some())
+            })
+            .collect()
+    }
+
+    fn descendant_with_class(cx: &Context, entity: Entity, class: &str) -> Entity {
+        entity
+            .branch_iter(&cx.tree)
+            .find(|entity| cx.style.classes.get(*entity).is_some_and(|c| c.contains(class)))
+            .unwrap()
+    }
+
+    // The messages of the shown toasts, in the order they are stacked from the top.
+    fn shown(cx: &Context) -> Vec<String> {
+        toast_items(cx)
+            .into_iter()
+            .map(|item| {
+                let message = descendant_with_class(cx, item, "toast-message");
+                cx.style.text.get(message).cloned().unwrap()
+            })
+            .collect()
+    }
+
+    fn is_dismissing(cx: &Context, item: Entity) -> bool {
+        cx.style.classes.get(item).is_some_and(|classes| classes.contains("dismissing"))
+    }
+
+    #[test]
+    fn timeout_and_queue() {
+        let mut cx = context();
+        emit(&mut cx, ToastEvent::Show(Toast::new("a").timeout(Duration::from_millis(200))));
+        emit(&mut cx, ToastEvent::Show(Toast::new("b").persistent()));
+        emit(&mut cx, ToastEvent::Show(Toast::new("c").timeout(Duration::from_millis(100))));
+        assert_eq!(shown(&cx), ["a", "b"]);
+
+        tick(&mut cx, Duration::from_millis(200));
+        assert!(is_dismissing(&cx, toast_items(&cx)[0]));
+        // Toasts waiting to be shown don't time out.
+        assert_eq!(toaster(&cx).entries[2].remaining, Some(Duration::from_millis(100)));
+        assert!(toaster(&cx).entries[2].is_new);
+
+        tick(&mut cx, EXIT_DURATION);
+        assert_eq!(messages(&cx), ["b", "c"]);
+        assert_eq!(shown(&cx), ["b", "c"]);
+
+        emit(&mut cx, ToastControlEvent::SetPlacement(Placement::Top));
+        assert_eq!(shown(&cx), ["c", "b"]);
+    }
+
+    #[test]
+    fn hover_pauses_timeout() {
+        let mut cx = context();
+        emit(&mut cx, ToastEvent::Show(Toast::new("a").timeout(Duration::from_millis(100))));
+        let item = toast_items(&cx)[0];
+
+        send(&mut cx, item, WindowEvent::MouseEnter);
+        tick(&mut cx, TICK_INTERVAL);
+        assert!(!is_dismissing(&cx, item));
+
+        send(&mut cx, item, WindowEvent::MouseLeave);
+        tick(&mut cx, TICK_INTERVAL);
+        assert!(is_dismissing(&cx, item));
+    }
+
+    #[test]
+    fn dedup_and_dismiss() {
+        let mut cx = context();
+        emit(&mut cx, ToastEvent::Show(Toast::new("Saving").key("save")));
+        emit(&mut cx, ToastEvent::Show(Toast::new("Other")));
+        emit(&mut cx, ToastEvent::Show(Toast::new("Saved").key("save")));
+        assert_eq!(shown(&cx), ["Saved", "Other"]);
+
+        // Toasts waiting to be shown are removed straight away.
+        emit(&mut cx, ToastEvent::Show(Toast::new("Queued").key("queued")));
+        emit(&mut cx, ToastEvent::Dismiss(String::from("queued")));
+        assert_eq!(messages(&cx), ["Saved", "Other"]);
+
+        let item = toast_items(&cx)[0];
+        let close = descendant_with_class(&cx, item, "toast-close");
+        send(&mut cx, close, WindowEvent::Press { mouse: false });
+        assert!(is_dismissing(&cx, item));
+
+        // A dismissed toast isn't replaced by a toast with the same key.
+        emit(&mut cx, ToastEvent::Show(Toast::new("Saved again").key("save")));
+        assert_eq!(messages(&cx), ["Saved", "Other", "Saved again"]);
+    }
+}
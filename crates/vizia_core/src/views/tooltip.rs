@@ -69,6 +69,7 @@ impl Tooltip {
             (content)(cx);
         })
         .z_index(110)
+        .role(Role::Tooltip)
         .hoverable(false)
         .position_type(PositionType::SelfDirected)
         .space(Pixels(0.0))
@@ -0,0 +1,846 @@
+use std::collections::HashSet;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use crate::icons::{ICON_CHEVRON_DOWN, ICON_CHEVRON_RIGHT};
+use crate::prelude::*;
+
+// The logical width by which each level of the tree is indented.
+const INDENT: f32 = 16.0;
+
+/// Events which can be sent to a [`TreeView`] to expand, collapse or select its items.
+///
+/// Items are identified by their path, which is the index of the item within its parent, preceded by the path of its
+/// parent.
+pub enum TreeViewEvent {
+    /// Expands an item, loading its children if it has none and a callback is set with
+    /// [`on_load_children`](Handle::on_load_children).
+    Expand(Vec<usize>),
+    /// Collapses an item, hiding its descendants.
+    Collapse(Vec<usize>),
+    /// Expands an item if it is collapsed, or collapses it if it is expanded.
+    ToggleExpanded(Vec<usize>),
+    /// Notifies the tree view that the children of an item have been loaded after a call to the callback set with
+    /// [`on_load_children`](Handle::on_load_children). This is usually sent through a [`ContextProxy`] once the
+    /// children have been loaded on another thread.
+    ChildrenLoaded(Vec<usize>),
+    /// Sets the selected items.
+    Select(Vec<Vec<usize>>),
+    /// Deselects all of the items.
+    ClearSelection,
+}
+
+pub(crate) enum TreeViewControlEvent {
+    SetNodes(Vec<TreeNode>),
+    Press(Vec<usize>),
+    MoveCursor { rows: isize, extend: bool },
+    CollapseCursor,
+    ExpandCursor,
+    ToggleCursor,
+    SelectAll,
+    DragStart(Entity, Vec<usize>),
+    Drop(Entity, Vec<usize>),
+}
+
+type ItemBuilder<L, T> = Rc<dyn Fn(&mut Context, &[usize], MapRef<L, T>)>;
+
+// An item of the tree, and the number of children it has, or `None` if it can't have any.
+#[derive(Debug, Clone, PartialEq, Data)]
+pub(crate) struct TreeNode {
+    path: Vec<usize>,
+    num_children: Option<usize>,
+}
+
+// An item which is shown because all of its ancestors are expanded.
+#[derive(Debug, Clone, PartialEq, Data)]
+struct TreeRow {
+    path: Vec<usize>,
+    expandable: bool,
+    expanded: bool,
+}
+
+/// A view for displaying hierarchical data, such as a file system or a scene graph, as a tree of items which can be
+/// expanded and collapsed.
+///
+/// The tree is built from a lens to a list of root items and a function which returns the children of an item.
+/// Returning `None` marks an item as a leaf, while returning an empty slice marks an item which can be expanded but
+/// whose children have not been loaded yet.
+///
+/// # Example
+/// ```ignore
+/// TreeView::new(cx, AppData::nodes, |node: &Node| node.children.as_deref(), |cx, _path, node| {
+///     Label::new(cx, node.map(|node| node.name.clone()));
+/// })
+/// .selectable(Selectable::Multi)
+/// .on_load_children(|cx, path| {
+///     let path = path.to_vec();
+///     cx.spawn(move |proxy| {
+///         let children = load_children(&path);
+///         proxy.emit(AppEvent::SetChildren(path.clone(), children)).unwrap();
+///         proxy.emit(TreeViewEvent::ChildrenLoaded(path)).unwrap();
+///     });
+/// });
+/// ```
+#[derive(Lens)]
+pub struct TreeView {
+    // Every item of the tree in depth-first order.
+    #[lens(ignore)]
+    nodes: Vec<TreeNode>,
+    rows: Vec<TreeRow>,
+    #[lens(ignore)]
+    expanded: HashSet<Vec<usize>>,
+    // The items whose children have been requested but have not been loaded yet.
+    loading: HashSet<Vec<usize>>,
+    #[lens(ignore)]
+    loaded: HashSet<Vec<usize>>,
+    selectable: Selectable,
+    selected: Vec<Vec<usize>>,
+    // The item which is moved with the arrow keys, and the one from which a range is selected.
+    cursor: Option<Vec<usize>>,
+    #[lens(ignore)]
+    anchor: Option<Vec<usize>>,
+    // The item being dragged, and the view from which it is dragged.
+    #[lens(ignore)]
+    dragging: Option<(Entity, Vec<usize>)>,
+    #[lens(ignore)]
+    on_load_children: Option<Box<dyn Fn(&mut EventContext, &[usize])>>,
+    #[lens(ignore)]
+    on_select: Option<Box<dyn Fn(&mut EventContext, &[Vec<usize>])>>,
+    #[lens(ignore)]
+    on_reparent: Option<Box<dyn Fn(&mut EventContext, &[usize], &[usize])>>,
+}
+
+impl TreeView {
+    /// Creates a new [`TreeView`] from a lens to a list of root items, a function which returns the children of an
+    /// item, and a template for the content of each item, which is given the path of the item.
+    pub fn new<L: Lens, T: 'static>(
+        cx: &mut Context,
+        roots: L,
+        children: impl 'static + Copy + Fn(&T) -> Option<&[T]>,
+        item_content: impl 'static + Fn(&mut Context, &[usize], MapRef<L, T>),
+    ) -> Handle<Self>
+    where
+        L::Target: Deref<Target = [T]>,
+    {
+        let item_content: ItemBuilder<L, T> = Rc::new(item_content);
+        let nodes = roots.map(move |roots| tree_nodes(roots.deref(), children));
+
+        Self {
+            nodes: Vec::new(),
+            rows: Vec::new(),
+            expanded: HashSet::new(),
+            loading: HashSet::new(),
+            loaded: HashSet::new(),
+            selectable: Selectable::None,
+            selected: Vec::new(),
+            cursor: None,
+            anchor: None,
+            dragging: None,
+            on_load_children: None,
+            on_select: None,
+            on_reparent: None,
+        }
+        .build(cx, move |cx| {
+            let tree = cx.current();
+
+            Self::keymap().build(cx);
+
+            // Rebuild the items when the structure of the tree changes, so that no item refers to a removed node.
+            Binding::new(cx, nodes, move |cx, lens| {
+                let nodes = lens.get(cx);
+                let paths: Rc<HashSet<Vec<usize>>> =
+                    Rc::new(nodes.iter().map(|node| node.path.clone()).collect());
+                cx.emit_to(tree, TreeViewControlEvent::SetNodes(nodes));
+
+                let item_content = item_content.clone();
+                Binding::new(cx, TreeView::rows, move |cx, rows| {
+                    for row in rows.get(cx) {
+                        // The rows are only updated for the new structure once the nodes have been set.
+                        if paths.contains(&row.path) {
+                            TreeViewItem::new(cx, roots, children, row, item_content.clone());
+                        }
+                    }
+                });
+            });
+        })
+        .role(Role::Tree)
+        .navigable(true)
+    }
+
+    fn keymap() -> Keymap<&'static str> {
+        macro_rules! move_cursor {
+            ($modifiers:expr, $code:ident, $action:literal, $rows:expr, $extend:literal) => {
+                (
+                    KeyChord::new($modifiers, Code::$code),
+                    KeymapEntry::new($action, |cx| {
+                        cx.emit(TreeViewControlEvent::MoveCursor { rows: $rows, extend: $extend })
+                    }),
+                )
+            };
+        }
+
+        Keymap::from(vec![
+            move_cursor!(Modifiers::empty(), ArrowDown, "Focus Next Item", 1, false),
+            move_cursor!(Modifiers::empty(), ArrowUp, "Focus Previous Item", -1, false),
+            move_cursor!(Modifiers::SHIFT, ArrowDown, "Select Next Item", 1, true),
+            move_cursor!(Modifiers::SHIFT, ArrowUp, "Select Previous Item", -1, true),
+            move_cursor!(Modifiers::empty(), Home, "Focus First Item", isize::MIN, false),
+            move_cursor!(Modifiers::empty(), End, "Focus Last Item", isize::MAX, false),
+            (
+                KeyChord::new(Modifiers::empty(), Code::ArrowRight),
+                KeymapEntry::new("Expand Item", |cx| cx.emit(TreeViewControlEvent::ExpandCursor)),
+            ),
+            (
+                KeyChord::new(Modifiers::empty(), Code::ArrowLeft),
+                KeymapEntry::new("Collapse Item", |cx| {
+                    cx.emit(TreeViewControlEvent::CollapseCursor)
+                }),
+            ),
+            (
+                KeyChord::new(Modifiers::empty(), Code::Space),
+                KeymapEntry::new("Toggle Selection", |cx| {
+                    cx.emit(TreeViewControlEvent::ToggleCursor)
+                }),
+            ),
+            (
+                KeyChord::new(Modifiers::CTRL, Code::KeyA),
+                KeymapEntry::new("Select All", |cx| cx.emit(TreeViewControlEvent::SelectAll)),
+            ),
+            (
+                KeyChord::new(Modifiers::empty(), Code::Escape),
+                KeymapEntry::new("Clear Selection", |cx| cx.emit(TreeViewEvent::ClearSelection)),
+            ),
+        ])
+    }
+
+    fn node(&self, path: &[usize]) -> Option<&TreeNode> {
+        self.nodes.iter().find(|node| node.path == path)
+    }
+
+    fn cursor_row(&self) -> Option<usize> {
+        let cursor = self.cursor.as_ref()?;
+        self.rows.iter().position(|row| row.path == *cursor)
+    }
+
+    // Updates the shown items after items have been expanded or collapsed, or the structure of the tree has changed.
+    fn refresh(&mut self) {
+        self.rows = visible_rows(&self.nodes, &self.expanded);
+
+        // Move the cursor to the closest shown ancestor if it has been hidden.
+        if let Some(cursor) = &mut self.cursor {
+            while !cursor.is_empty() && !self.rows.iter().any(|row| row.path == *cursor) {
+                cursor.pop();
+            }
+            if cursor.is_empty() {
+                self.cursor = None;
+            }
+        }
+    }
+
+    fn set_nodes(&mut self, cx: &mut EventContext, nodes: Vec<TreeNode>) {
+        self.nodes = nodes;
+
+        // Forget about items which no longer exist, and stop waiting for items whose children have arrived.
+        let paths = self.nodes.iter().map(|node| &node.path).collect::<HashSet<_>>();
+        self.expanded.retain(|path| paths.contains(path));
+        self.loaded.retain(|path| paths.contains(path));
+        for node in self.nodes.iter().filter(|node| node.num_children.is_some_and(|num| num > 0)) {
+            if self.loading.remove(&node.path) {
+                self.loaded.insert(node.path.clone());
+            }
+        }
+        self.loading.retain(|path| paths.contains(path));
+        if self.anchor.as_ref().is_some_and(|anchor| !paths.contains(anchor)) {
+            self.anchor = None;
+        }
+
+        let len = self.selected.len();
+        self.selected.retain(|path| paths.contains(path));
+        if self.selected.len() != len {
+            self.notify_select(cx);
+        }
+
+        self.refresh();
+    }
+
+    fn expand(&mut self, cx: &mut EventContext, path: &[usize]) {
+        let Some(num_children) = self.node(path).and_then(|node| node.num_children) else {
+            return;
+        };
+
+        self.expanded.insert(path.to_vec());
+
+        if num_children == 0 && !self.loaded.contains(path) && !self.loading.contains(path) {
+            if let Some(callback) = &self.on_load_children {
+                self.loading.insert(path.to_vec());
+                (callback)(cx, path);
+            }
+        }
+
+        self.refresh();
+    }
+
+    fn collapse(&mut self, path: &[usize]) {
+        if self.expanded.remove(path) {
+            self.refresh();
+        }
+    }
+
+    // Moves the cursor by a number of shown items, returning false if there is nothing to move to.
+    fn move_cursor(&mut self, rows: isize) -> bool {
+        if self.rows.is_empty() {
+            return false;
+        }
+
+        let row = match self.cursor_row() {
+            Some(row) => row.saturating_add_signed(rows).min(self.rows.len() - 1),
+            None => 0,
+        };
+        self.cursor = Some(self.rows[row].path.clone());
+
+        true
+    }
+
+    // Updates the selection after the cursor has moved, extending it from the anchor or toggling the cursor.
+    fn select_cursor(&mut self, extend: bool, toggle: bool) -> bool {
+        let Some(cursor) = self.cursor.clone() else {
+            return false;
+        };
+
+        match self.selectable {
+            Selectable::None => return false,
+
+            Selectable::Multi if extend => {
+                let anchor = self.anchor.get_or_insert(cursor.clone()).clone();
+                let anchor_row = self.rows.iter().position(|row| row.path == anchor);
+                match (anchor_row, self.cursor_row()) {
+                    (Some(from), Some(to)) => {
+                        let rows = from.min(to)..=from.max(to);
+                        self.selected =
+                            self.rows[rows].iter().map(|row| row.path.clone()).collect();
+                    }
+                    _ => {
+                        self.selected = vec![cursor.clone()];
+                        self.anchor = Some(cursor);
+                    }
+                }
+            }
+
+            Selectable::Multi if toggle => {
+                if let Some(position) = self.selected.iter().position(|path| *path == cursor) {
+                    self.selected.remove(position);
+                } else {
+                    self.selected.push(cursor.clone());
+                }
+                self.anchor = Some(cursor);
+            }
+
+            Selectable::Single if toggle && self.selected == [cursor.clone()] => {
+                self.selected.clear();
+                self.anchor = Some(cursor);
+            }
+
+            _ => {
+                self.selected = vec![cursor.clone()];
+                self.anchor = Some(cursor);
+            }
+        }
+
+        true
+    }
+
+    fn notify_select(&self, cx: &mut EventContext) {
+        if let Some(callback) = &self.on_select {
+            (callback)(cx, &self.selected);
+        }
+    }
+}
+
+impl View for TreeView {
+    fn element(&self) -> Option<&'static str> {
+        Some("treeview")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|tree_event, _| match tree_event {
+            TreeViewEvent::Expand(path) => {
+                self.expand(cx, path);
+            }
+
+            TreeViewEvent::Collapse(path) => {
+                self.collapse(path);
+            }
+
+            TreeViewEvent::ToggleExpanded(path) => {
+                if self.expanded.contains(path) {
+                    self.collapse(path);
+                } else {
+                    self.expand(cx, path);
+                }
+            }
+
+            TreeViewEvent::ChildrenLoaded(path) => {
+                if self.loading.remove(path) {
+                    self.loaded.insert(path.clone());
+                }
+            }
+
+            TreeViewEvent::Select(selection) => {
+                self.selected = selection.clone();
+                self.anchor = None;
+                self.notify_select(cx);
+            }
+
+            TreeViewEvent::ClearSelection => {
+                if !self.selected.is_empty() {
+                    self.selected.clear();
+                    self.notify_select(cx);
+                }
+                self.anchor = None;
+            }
+        });
+
+        event.map(|control_event, meta| match control_event {
+            TreeViewControlEvent::SetNodes(nodes) => {
+                self.set_nodes(cx, nodes.clone());
+            }
+
+            TreeViewControlEvent::Press(path) => {
+                cx.focus();
+                self.cursor = Some(path.clone());
+                let modifiers = *cx.modifiers();
+                if self.select_cursor(modifiers.shift(), modifiers.ctrl() || modifiers.logo()) {
+                    self.notify_select(cx);
+                }
+                meta.consume();
+            }
+
+            TreeViewControlEvent::MoveCursor { rows, extend } => {
+                if self.move_cursor(*rows) && self.select_cursor(*extend, false) {
+                    self.notify_select(cx);
+                }
+                meta.consume();
+            }
+
+            TreeViewControlEvent::ExpandCursor => {
+                if let Some(row) = self.cursor_row() {
+                    let TreeRow { path, expandable, expanded } = self.rows[row].clone();
+                    if expandable && !expanded {
+                        self.expand(cx, &path);
+                    } else if self
+                        .rows
+                        .get(row + 1)
+                        .is_some_and(|next| next.path.len() > path.len())
+                    {
+                        // Move to the first child of an expanded item.
+                        self.cursor = Some(self.rows[row + 1].path.clone());
+                        if self.select_cursor(false, false) {
+                            self.notify_select(cx);
+                        }
+                    }
+                }
+                meta.consume();
+            }
+
+            TreeViewControlEvent::CollapseCursor => {
+                if let Some(row) = self.cursor_row() {
+                    let TreeRow { path, expanded, .. } = self.rows[row].clone();
+                    if expanded {
+                        self.collapse(&path);
+                    } else if path.len() > 1 {
+                        // Move to the parent of a collapsed item.
+                        self.cursor = Some(path[..path.len() - 1].to_vec());
+                        if self.select_cursor(false, false) {
+                            self.notify_select(cx);
+                        }
+                    }
+                }
+                meta.consume();
+            }
+
+            TreeViewControlEvent::ToggleCursor => {
+                if self.select_cursor(false, true) {
+                    self.notify_select(cx);
+                }
+                meta.consume();
+            }
+
+            TreeViewControlEvent::SelectAll => {
+                if self.selectable == Selectable::Multi && !self.rows.is_empty() {
+                    self.selected = self.rows.iter().map(|row| row.path.clone()).collect();
+                    self.notify_select(cx);
+                }
+                meta.consume();
+            }
+
+            TreeViewControlEvent::DragStart(source, path) => {
+                self.dragging = Some((*source, path.clone()));
+                meta.consume();
+            }
+
+            TreeViewControlEvent::Drop(source, target) => {
+                if let Some((dragged, path)) = self.dragging.take() {
+                    // An item can't be moved into itself or one of its descendants.
+                    if dragged == *source && !target.starts_with(&path) {
+                        if let Some(callback) = &self.on_reparent {
+                            (callback)(cx, &path, target);
+                        }
+                    }
+                }
+                meta.consume();
+            }
+        });
+    }
+}
+
+impl Handle<'_, TreeView> {
+    /// Sets whether no items, a single item, or multiple items can be selected. Defaults to [`Selectable::None`].
+    pub fn selectable(self, selectable: impl Res<Selectable>) -> Self {
+        self.bind(selectable, |handle, selectable| {
+            let selectable = selectable.get(&handle);
+            handle.modify(|tree: &mut TreeView| {
+                tree.selectable = selectable;
+                if selectable == Selectable::None {
+                    tree.selected.clear();
+                }
+            });
+        })
+    }
+
+    /// Sets a callback which is called with the paths of the selected items whenever the selection changes.
+    pub fn on_select(self, callback: impl 'static + Fn(&mut EventContext, &[Vec<usize>])) -> Self {
+        self.modify(|tree: &mut TreeView| tree.on_select = Some(Box::new(callback)))
+    }
+
+    /// Sets a callback which is called with the path of an item when it is expanded for the first time without
+    /// having any children. The callback should add the children of the item to the bound data, which can be done
+    /// asynchronously, and then send a [`TreeViewEvent::ChildrenLoaded`] event to the tree view.
+    pub fn on_load_children(
+        self,
+        callback: impl 'static + Fn(&mut EventContext, &[usize]),
+    ) -> Self {
+        self.modify(|tree: &mut TreeView| tree.on_load_children = Some(Box::new(callback)))
+    }
+
+    /// Sets a callback which is called with the path of an item, and the path of the item it is dropped onto, when an
+    /// item is dragged onto another item. The callback should move the item in the bound data to become a child of
+    /// the item it was dropped onto.
+    pub fn on_reparent(
+        self,
+        callback: impl 'static + Fn(&mut EventContext, &[usize], &[usize]),
+    ) -> Self {
+        self.modify(|tree: &mut TreeView| tree.on_reparent = Some(Box::new(callback)))
+    }
+}
+
+// A shown item of the tree, with a disclosure arrow to expand and collapse it if it can have children.
+struct TreeViewItem {
+    path: Vec<usize>,
+    expandable: bool,
+    expanded: bool,
+}
+
+impl TreeViewItem {
+    fn new<L: Lens, T: 'static>(
+        cx: &mut Context,
+        roots: L,
+        children: impl 'static + Copy + Fn(&T) -> Option<&[T]>,
+        row: TreeRow,
+        content: ItemBuilder<L, T>,
+    ) -> Handle<Self>
+    where
+        L::Target: Deref<Target = [T]>,
+    {
+        let TreeRow { path, expandable, expanded } = row;
+
+        Self { path: path.clone(), expandable, expanded }
+            .build(cx, |cx| {
+                let level = path.len() - 1;
+                if level > 0 {
+                    Element::new(cx).class("treeview-indent").width(Pixels(level as f32 * INDENT));
+                }
+
+                if expandable {
+                    let path = path.clone();
+                    Svg::new(cx, if expanded { ICON_CHEVRON_DOWN } else { ICON_CHEVRON_RIGHT })
+                        .class("treeview-disclosure")
+                        .on_press_down(move |cx| {
+                            cx.emit(TreeViewEvent::ToggleExpanded(path.clone()))
+                        });
+                } else {
+                    // Leaves are aligned with the items which have a disclosure arrow.
+                    Element::new(cx).class("treeview-disclosure");
+                }
+
+                // The content isn't hoverable so that presses and drags are received by the item itself.
+                let path = path.clone();
+                HStack::new(cx, move |cx| {
+                    let node_path = path.clone();
+                    let node = roots.map_ref(move |roots| {
+                        node_at(roots.deref(), &node_path, children)
+                            .expect("Tree view item refers to a removed node")
+                    });
+                    (content)(cx, &path, node);
+                })
+                .class("treeview-item-content")
+                .hoverable(false);
+            })
+            .role(Role::TreeItem)
+            .checked(TreeView::selected.map({
+                let path = path.clone();
+                move |selected| selected.contains(&path)
+            }))
+            .toggle_class(
+                "cursor",
+                TreeView::cursor.map({
+                    let path = path.clone();
+                    move |cursor| cursor.as_ref() == Some(&path)
+                }),
+            )
+            .toggle_class(
+                "loading",
+                TreeView::loading.map({
+                    let path = path.clone();
+                    move |loading| loading.contains(&path)
+                }),
+            )
+            .on_press_down({
+                let path = path.clone();
+                move |cx| cx.emit(TreeViewControlEvent::Press(path.clone()))
+            })
+            .on_drag({
+                let path = path.clone();
+                move |cx| {
+                    let item = cx.current();
+                    cx.set_drop_data(item);
+                    cx.emit(TreeViewControlEvent::DragStart(item, path.clone()));
+                }
+            })
+            .on_drop(move |cx, data| {
+                if let DropData::Id(source) = data {
+                    cx.emit(TreeViewControlEvent::Drop(source, path.clone()));
+                }
+            })
+    }
+}
+
+impl View for TreeViewItem {
+    fn element(&self) -> Option<&'static str> {
+        Some("treeview-item")
+    }
+
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_hierarchical_level(self.path.len());
+        if self.expandable {
+            node.set_expanded(self.expanded);
+        }
+    }
+}
+
+// Returns every item of the tree in depth-first order.
+fn tree_nodes<T>(roots: &[T], children: impl Fn(&T) -> Option<&[T]>) -> Vec<TreeNode> {
+    fn visit<T>(
+        nodes: &mut Vec<TreeNode>,
+        path: &mut Vec<usize>,
+        items: &[T],
+        children: &impl Fn(&T) -> Option<&[T]>,
+    ) {
+        for (index, item) in items.iter().enumerate() {
+            path.push(index);
+            let item_children = children(item);
+            nodes
+                .push(TreeNode { path: path.clone(), num_children: item_children.map(<[T]>::len) });
+            if let Some(item_children) = item_children {
+                visit(nodes, path, item_children, children);
+            }
+            path.pop();
+        }
+    }
+
+    let mut nodes = Vec::new();
+    visit(&mut nodes, &mut Vec::new(), roots, &children);
+    nodes
+}
+
+// Returns the item of the tree at a path.
+fn node_at<'a, T>(
+    roots: &'a [T],
+    path: &[usize],
+    children: impl Fn(&T) -> Option<&[T]>,
+) -> Option<&'a T> {
+    let (first, rest) = path.split_first()?;
+    let mut node = roots.get(*first)?;
+    for index in rest {
+        node = children(node)?.get(*index)?;
+    }
+    Some(node)
+}
+
+// Returns the items which are shown because all of their ancestors are expanded.
+fn visible_rows(nodes: &[TreeNode], expanded: &HashSet<Vec<usize>>) -> Vec<TreeRow> {
+    let mut rows = Vec::new();
+    // The last shown item which is collapsed, whose descendants are skipped.
+    let mut collapsed: Option<&[usize]> = None;
+
+    for node in nodes {
+        if collapsed.is_some_and(|collapsed| node.path.starts_with(collapsed)) {
+            continue;
+        }
+
+        let expandable = node.num_children.is_some();
+        let is_expanded = expandable && expanded.contains(&node.path);
+        if !is_expanded {
+            collapsed = Some(&node.path);
+        }
+
+        rows.push(TreeRow { path: node.path.clone(), expandable, expanded: is_expanded });
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Node {
+        children: Option<Vec<Node>>,
+    }
+
+    fn leaf() -> Node {
+        Node { children: None }
+    }
+
+    fn branch(children: Vec<Node>) -> Node {
+        Node { children: Some(children) }
+    }
+
+    fn children(node: &Node) -> Option<&[Node]> {
+        node.children.as_deref()
+    }
+
+    // [0] -> [[0, 0] -> [[0, 0, 0]], [0, 1]], [1] (not loaded), [2]
+    fn roots() -> Vec<Node> {
+        vec![branch(vec![branch(vec![leaf()]), leaf()]), branch(Vec::new()), leaf()]
+    }
+
+    fn tree(expanded: &[&[usize]]) -> TreeView {
+        let expanded = expanded.iter().map(|path| path.to_vec()).collect::<HashSet<_>>();
+        let nodes = tree_nodes(&roots(), children);
+        TreeView {
+            rows: visible_rows(&nodes, &expanded),
+            nodes,
+            expanded,
+            loading: HashSet::new(),
+            loaded: HashSet::new(),
+            selectable: Selectable::Multi,
+            selected: Vec::new(),
+            cursor: None,
+            anchor: None,
+            dragging: None,
+            on_load_children: None,
+            on_select: None,
+            on_reparent: None,
+        }
+    }
+
+    fn row_paths(tree: &TreeView) -> Vec<Vec<usize>> {
+        tree.rows.iter().map(|row| row.path.clone()).collect()
+    }
+
+    #[test]
+    fn flattened_tree_nodes() {
+        let nodes = tree_nodes(&roots(), children);
+        let nodes =
+            nodes.iter().map(|node| (node.path.clone(), node.num_children)).collect::<Vec<_>>();
+        assert_eq!(
+            nodes,
+            [
+                (vec![0], Some(2)),
+                (vec![0, 0], Some(1)),
+                (vec![0, 0, 0], None),
+                (vec![0, 1], None),
+                (vec![1], Some(0)),
+                (vec![2], None),
+            ]
+        );
+    }
+
+    #[test]
+    fn node_at_path() {
+        let roots = roots();
+        assert!(node_at(&roots, &[0, 0, 0], children).is_some_and(|node| node.children.is_none()));
+        assert!(node_at(&roots, &[1], children).is_some_and(|node| node.children.is_some()));
+        assert!(node_at(&roots, &[0, 2], children).is_none());
+        assert!(node_at(&roots, &[2, 0], children).is_none());
+        assert!(node_at(&roots, &[], children).is_none());
+    }
+
+    #[test]
+    fn visible_row_paths() {
+        assert_eq!(row_paths(&tree(&[])), [vec![0], vec![1], vec![2]]);
+        assert_eq!(row_paths(&tree(&[&[0]])), [vec![0], vec![0, 0], vec![0, 1], vec![1], vec![2]]);
+        // The descendants of a collapsed item are hidden even if they are expanded.
+        assert_eq!(row_paths(&tree(&[&[0, 0]])), [vec![0], vec![1], vec![2]]);
+        assert_eq!(
+            row_paths(&tree(&[&[0], &[0, 0]])),
+            [vec![0], vec![0, 0], vec![0, 0, 0], vec![0, 1], vec![1], vec![2]]
+        );
+
+        let rows = tree(&[&[0], &[1]]).rows;
+        assert!(rows[0].expandable && rows[0].expanded);
+        assert!(!rows[2].expandable && !rows[2].expanded);
+        assert!(rows[3].expandable && rows[3].expanded);
+    }
+
+    #[test]
+    fn collapse_moves_cursor_to_ancestor() {
+        let mut tree = tree(&[&[0], &[0, 0]]);
+        tree.cursor = Some(vec![0, 0, 0]);
+        tree.collapse(&[0]);
+        assert_eq!(tree.cursor, Some(vec![0]));
+        assert_eq!(row_paths(&tree), [vec![0], vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn move_cursor() {
+        let mut tree = tree(&[&[0]]);
+        assert!(tree.move_cursor(1));
+        assert_eq!(tree.cursor, Some(vec![0]));
+        assert!(tree.move_cursor(2));
+        assert_eq!(tree.cursor, Some(vec![0, 1]));
+        assert!(tree.move_cursor(isize::MAX));
+        assert_eq!(tree.cursor, Some(vec![2]));
+        assert!(tree.move_cursor(isize::MIN));
+        assert_eq!(tree.cursor, Some(vec![0]));
+
+        tree.rows.clear();
+        assert!(!tree.move_cursor(1));
+    }
+
+    #[test]
+    fn select_cursor() {
+        let mut tree = tree(&[&[0]]);
+        tree.cursor = Some(vec![0, 0]);
+        assert!(tree.select_cursor(false, false));
+        assert_eq!(tree.selected, [vec![0, 0]]);
+
+        // Extending the selection selects every shown item between the anchor and the cursor.
+        tree.cursor = Some(vec![1]);
+        assert!(tree.select_cursor(true, false));
+        assert_eq!(tree.selected, [vec![0, 0], vec![0, 1], vec![1]]);
+
+        tree.cursor = Some(vec![0, 1]);
+        assert!(tree.select_cursor(false, true));
+        assert_eq!(tree.selected, [vec![0, 0], vec![1]]);
+
+        tree.selectable = Selectable::Single;
+        assert!(tree.select_cursor(true, false));
+        assert_eq!(tree.selected, [vec![0, 1]]);
+        assert!(tree.select_cursor(false, true));
+        assert!(tree.selected.is_empty());
+
+        tree.selectable = Selectable::None;
+        assert!(!tree.select_cursor(false, false));
+    }
+}
@@ -13,7 +13,7 @@ pub enum VirtualListEvent {
 }
 
 #[derive(Lens)]
-struct VirtualListData {
+pub(crate) struct VirtualListData {
     num_items: usize,
     item_height: f32,
     visible_range: Range<usize>,
@@ -21,7 +21,7 @@ struct VirtualListData {
 }
 
 impl VirtualListData {
-    fn evaluate_index(index: usize, start: usize, end: usize) -> usize {
+    pub(crate) fn evaluate_index(index: usize, start: usize, end: usize) -> usize {
         match end - start {
             0 => 0,
             len => start + (len - (start % len) + index) % len,
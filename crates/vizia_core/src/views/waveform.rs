@@ -0,0 +1,289 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use crate::prelude::*;
+use crate::vg;
+use crate::views::audio_buffer::{poll_audio, PollAudio};
+
+// The number of samples summarized by each block of the history.
+const BLOCK_SIZE: usize = 256;
+const DEFAULT_CAPACITY: usize = 1 << 20;
+const DEFAULT_SAMPLES_PER_PIXEL: f32 = 64.0;
+// The factor by which one step of the mouse wheel zooms the waveform.
+const ZOOM_STEP: f32 = 1.25;
+// The number of pixels by which one step of the mouse wheel scrolls the waveform.
+const SCROLL_STEP: f32 = 40.0;
+
+// The samples shown by a waveform, along with the minimum and maximum of each block of samples so that large ranges
+// can be decimated without visiting every sample.
+struct WaveformHistory {
+    samples: VecDeque<f32>,
+    blocks: VecDeque<(f32, f32)>,
+    capacity: usize,
+}
+
+impl WaveformHistory {
+    fn new(capacity: usize) -> Self {
+        Self { samples: VecDeque::new(), blocks: VecDeque::new(), capacity }
+    }
+
+    fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    fn push(&mut self, sample: f32) {
+        self.samples.push_back(sample);
+
+        if self.samples.len() % BLOCK_SIZE == 0 {
+            let block = self
+                .samples
+                .range(self.samples.len() - BLOCK_SIZE..)
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), sample| {
+                    (min.min(*sample), max.max(*sample))
+                });
+            self.blocks.push_back(block);
+        }
+
+        // Whole blocks are dropped so that the blocks stay aligned with the samples.
+        if self.samples.len() >= self.capacity + BLOCK_SIZE {
+            self.samples.drain(..BLOCK_SIZE);
+            self.blocks.pop_front();
+        }
+    }
+
+    // Returns the minimum and maximum of the samples in the range, or `None` if the range is empty.
+    fn min_max(&self, range: Range<usize>) -> Option<(f32, f32)> {
+        if range.is_empty() {
+            return None;
+        }
+
+        let mut min_max = (f32::INFINITY, f32::NEG_INFINITY);
+        let mut index = range.start;
+        while index < range.end {
+            let (min, max) = if index % BLOCK_SIZE == 0 && index + BLOCK_SIZE <= range.end {
+                index += BLOCK_SIZE;
+                self.blocks[index / BLOCK_SIZE - 1]
+            } else {
+                index += 1;
+                (self.samples[index - 1], self.samples[index - 1])
+            };
+
+            min_max = (min_max.0.min(min), min_max.1.max(max));
+        }
+
+        Some(min_max)
+    }
+
+    // Returns the minimum and maximum of each of `count` columns ending `offset` samples before the newest sample.
+    fn columns(
+        &self,
+        offset: usize,
+        samples_per_column: f32,
+        count: usize,
+    ) -> Vec<Option<(f32, f32)>> {
+        let end = self.len().saturating_sub(offset) as f32;
+
+        (0..count)
+            .map(|column| {
+                let start = (end - (count - column) as f32 * samples_per_column).round();
+                let stop = (start + samples_per_column).round();
+                if stop <= 0.0 {
+                    return None;
+                }
+
+                let start = start.max(0.0) as usize;
+                self.min_max(start..(stop as usize).max(start + 1).min(self.len()))
+            })
+            .collect()
+    }
+}
+
+/// A view which draws the waveform of an audio signal.
+///
+/// Samples are sent to the waveform from the audio thread through an [`audio_ring_buffer`], and are kept in a
+/// history of up to [`capacity`](Handle::capacity) samples. Each pixel column shows the minimum and maximum of the
+/// samples it covers, which are computed from precomputed block summaries so that zoomed out views of large histories
+/// stay cheap to draw.
+///
+/// Scrolling the mouse wheel moves back through the history, and scrolling with the control key held zooms in and
+/// out. While the newest samples are shown the waveform follows the signal as it arrives.
+///
+/// # Example
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// let (producer, consumer) = audio_ring_buffer(8192);
+///
+/// Waveform::new(cx, consumer).samples_per_pixel(128.0);
+/// ```
+pub struct Waveform {
+    consumer: AudioConsumer,
+    history: WaveformHistory,
+    samples_per_pixel: f32,
+    // The number of samples between the newest sample and the right edge of the view.
+    offset: usize,
+}
+
+impl Waveform {
+    /// Creates a new waveform which shows the samples received by the `consumer`.
+    pub fn new(cx: &mut Context, consumer: AudioConsumer) -> Handle<Self> {
+        let handle = Self {
+            consumer,
+            history: WaveformHistory::new(DEFAULT_CAPACITY),
+            samples_per_pixel: DEFAULT_SAMPLES_PER_PIXEL,
+            offset: 0,
+        }
+        .build(cx, |_| {})
+        .role(Role::Canvas);
+
+        poll_audio(handle)
+    }
+}
+
+impl Handle<'_, Waveform> {
+    /// Sets the maximum number of samples kept in the history of the waveform.
+    pub fn capacity(self, capacity: usize) -> Self {
+        self.modify(|waveform| waveform.history = WaveformHistory::new(capacity.max(BLOCK_SIZE)))
+    }
+
+    /// Sets the zoom level of the waveform as the number of samples shown by each pixel.
+    pub fn samples_per_pixel(self, samples_per_pixel: f32) -> Self {
+        self.modify(|waveform| waveform.samples_per_pixel = samples_per_pixel.max(1.0))
+    }
+}
+
+impl View for Waveform {
+    fn element(&self) -> Option<&'static str> {
+        Some("waveform")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.take(|_: PollAudio, _| {
+            let history = &mut self.history;
+            let count = self.consumer.drain(|sample| history.push(sample));
+
+            if count > 0 {
+                if self.offset == 0 {
+                    cx.needs_redraw();
+                } else {
+                    // Keep the visible samples still while scrolled back through the history.
+                    self.offset = (self.offset + count).min(self.history.len());
+                }
+            }
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseScroll(_, y) if *y != 0.0 => {
+                if cx.modifiers.ctrl() {
+                    self.samples_per_pixel = (self.samples_per_pixel * ZOOM_STEP.powf(-*y))
+                        .clamp(1.0, self.history.capacity as f32);
+                } else {
+                    let delta = (*y * SCROLL_STEP * self.samples_per_pixel) as isize;
+                    self.offset = self.offset.saturating_add_signed(delta).min(self.history.len());
+                }
+
+                cx.needs_redraw();
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let bounds = cx.bounds();
+        if bounds.w == 0.0 || bounds.h == 0.0 {
+            return;
+        }
+
+        cx.draw_background(canvas);
+        cx.draw_border(canvas);
+
+        let scale_factor = cx.scale_factor();
+        let count = bounds.w.floor() as usize;
+        let columns =
+            self.history.columns(self.offset, self.samples_per_pixel / scale_factor, count);
+
+        let center = bounds.center().1;
+        let half_height = bounds.h / 2.0;
+        let to_y = |sample: f32| center - sample.clamp(-1.0, 1.0) * half_height;
+
+        let mut path = vg::Path::new();
+        for (column, min_max) in columns.into_iter().enumerate() {
+            if let Some((min, max)) = min_max {
+                let x = bounds.x + column as f32 + 0.5;
+                // Columns are at least one pixel tall so that silence is still drawn.
+                let top = to_y(max);
+                let bottom = to_y(min).max(top + 1.0);
+                path.move_to((x, top));
+                path.line_to((x, bottom));
+            }
+        }
+
+        let mut paint = vg::Paint::default();
+        paint.set_color(cx.font_color());
+        paint.set_style(vg::PaintStyle::Stroke);
+        paint.set_stroke_width(1.0);
+        paint.set_anti_alias(false);
+        canvas.draw_path(&path, &paint);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(samples: impl IntoIterator<Item = f32>, capacity: usize) -> WaveformHistory {
+        let mut history = WaveformHistory::new(capacity);
+        for sample in samples {
+            history.push(sample);
+        }
+        history
+    }
+
+    #[test]
+    fn min_max_matches_samples() {
+        let samples =
+            (0..1000).map(|i| ((i * 7919) % 1000) as f32 / 500.0 - 1.0).collect::<Vec<_>>();
+        let history = history(samples.iter().copied(), 4096);
+
+        for range in [0..1000, 3..700, 256..512, 255..513, 999..1000] {
+            let expected = samples[range.clone()]
+                .iter()
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), sample| {
+                    (min.min(*sample), max.max(*sample))
+                });
+            assert_eq!(history.min_max(range), Some(expected));
+        }
+
+        assert_eq!(history.min_max(10..10), None);
+    }
+
+    #[test]
+    fn history_drops_whole_blocks() {
+        let history = history((0..5 * BLOCK_SIZE).map(|i| i as f32), 2 * BLOCK_SIZE);
+
+        assert!(history.len() < 3 * BLOCK_SIZE);
+        assert_eq!(history.blocks.len(), history.len() / BLOCK_SIZE);
+        assert_eq!(history.samples[0] as usize % BLOCK_SIZE, 0);
+        assert_eq!(
+            history.min_max(0..BLOCK_SIZE),
+            Some((history.samples[0], history.samples[BLOCK_SIZE - 1]))
+        );
+    }
+
+    #[test]
+    fn columns_decimate_from_the_newest_sample() {
+        let history = history((0..1024).map(|i| i as f32), 4096);
+
+        let columns = history.columns(0, 256.0, 4);
+        assert_eq!(
+            columns,
+            [Some((0.0, 255.0)), Some((256.0, 511.0)), Some((512.0, 767.0)), Some((768.0, 1023.0))]
+        );
+
+        // Columns before the start of the history are empty.
+        let columns = history.columns(512, 256.0, 4);
+        assert_eq!(columns, [None, None, Some((0.0, 255.0)), Some((256.0, 511.0))]);
+    }
+}
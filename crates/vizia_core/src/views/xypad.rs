@@ -4,11 +4,12 @@ pub struct XYPad {
     is_dragging: bool,
 
     on_change: Option<Box<dyn Fn(&mut EventContext, f32, f32)>>,
+    params: Option<(ParamId, ParamId)>,
 }
 
 impl XYPad {
     pub fn new<L: Lens<Target = (f32, f32)>>(cx: &mut Context, lens: L) -> Handle<Self> {
-        Self { is_dragging: false, on_change: None }
+        Self { is_dragging: false, on_change: None, params: None }
             .build(cx, |cx| {
                 // Thumb
                 Element::new(cx)
@@ -62,12 +63,25 @@ impl View for XYPad {
                     if let Some(callback) = &self.on_change {
                         (callback)(cx, dx, 1.0 - dy);
                     }
+
+                    if let Some((param_x, param_y)) = self.params {
+                        cx.emit(ParamEvent::BeginEdit(param_x));
+                        cx.emit(ParamEvent::BeginEdit(param_y));
+                        cx.emit(ParamEvent::SetNormalized(param_x, dx));
+                        cx.emit(ParamEvent::SetNormalized(param_y, 1.0 - dy));
+                    }
                 }
             }
 
             WindowEvent::MouseUp(button) if *button == MouseButton::Left => {
                 cx.set_active(false);
                 cx.release();
+                if self.is_dragging {
+                    if let Some((param_x, param_y)) = self.params {
+                        cx.emit(ParamEvent::EndEdit(param_x));
+                        cx.emit(ParamEvent::EndEdit(param_y));
+                    }
+                }
                 self.is_dragging = false;
                 if meta.target == cx.current() {
                     cx.release();
@@ -86,6 +100,11 @@ impl View for XYPad {
                     if let Some(callback) = &self.on_change {
                         (callback)(cx, dx, 1.0 - dy);
                     }
+
+                    if let Some((param_x, param_y)) = self.params {
+                        cx.emit(ParamEvent::SetNormalized(param_x, dx));
+                        cx.emit(ParamEvent::SetNormalized(param_y, 1.0 - dy));
+                    }
                 }
             }
 
@@ -98,4 +117,10 @@ impl Handle<'_, XYPad> {
     pub fn on_change<F: Fn(&mut EventContext, f32, f32) + 'static>(self, callback: F) -> Self {
         self.modify(|xypad| xypad.on_change = Some(Box::new(callback)))
     }
+
+    /// Binds the horizontal and vertical axes of the pad to plugin parameters, so that edits by the user
+    /// emit [`ParamEvent`] gestures.
+    pub fn params(self, param_x: ParamId, param_y: ParamId) -> Self {
+        self.modify(|xypad| xypad.params = Some((param_x, param_y)))
+    }
 }
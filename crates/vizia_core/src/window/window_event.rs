@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 
-use crate::{entity::Entity, environment::ThemeMode, layout::cache::GeoChanged};
+use crate::{
+    animation::Animation, entity::Entity, environment::ThemeMode, layout::cache::GeoChanged,
+};
 use vizia_input::{Code, Key, MouseButton};
 use vizia_style::CursorIcon;
 use vizia_window::{WindowPosition, WindowSize};
@@ -106,6 +108,12 @@ pub enum WindowEvent {
     // TODO: check if this includes margins + borders.
     /// Emitted when an entity changes position or size.
     GeometryChanged(GeoChanged),
+    /// Emitted to a view when an animation played on it starts, after any delay.
+    AnimationStart(Animation),
+    /// Emitted to a view when an animation played on it repeats.
+    AnimationIteration(Animation),
+    /// Emitted to a view when an animation played on it finishes.
+    AnimationEnd(Animation),
     /// Requests a redraw of the window contents.
     Redraw,
     /// Request a restyle.
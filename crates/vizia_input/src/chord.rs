@@ -1,7 +1,12 @@
 use crate::{Code, Modifiers};
+use std::fmt;
 use std::hash::Hash;
+use std::str::FromStr;
 
 /// A key chord used inside of a `Keymap`.
+///
+/// A key chord can be parsed from, and formatted as, a string of `+` separated modifiers followed by a key, such as
+/// `"Ctrl+Shift+P"`. The `CmdOrCtrl` modifier is parsed as `Cmd` on macOS and `Ctrl` on other platforms.
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct KeyChord {
     /// The modifiers that have to be pressed in order to active its associated actions.
@@ -24,3 +29,390 @@ impl KeyChord {
         Self { modifiers, code }
     }
 }
+
+/// An error returned when a [`KeyChord`] or [`KeySequence`] can't be parsed from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKeyChordError {
+    /// The part of the string which isn't a modifier or key.
+    pub token: String,
+}
+
+impl fmt::Display for ParseKeyChordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.token.is_empty() {
+            write!(f, "Missing key in key chord")
+        } else {
+            write!(f, "Unknown key or modifier '{}' in key chord", self.token)
+        }
+    }
+}
+
+impl std::error::Error for ParseKeyChordError {}
+
+const LETTERS: [Code; 26] = [
+    Code::KeyA,
+    Code::KeyB,
+    Code::KeyC,
+    Code::KeyD,
+    Code::KeyE,
+    Code::KeyF,
+    Code::KeyG,
+    Code::KeyH,
+    Code::KeyI,
+    Code::KeyJ,
+    Code::KeyK,
+    Code::KeyL,
+    Code::KeyM,
+    Code::KeyN,
+    Code::KeyO,
+    Code::KeyP,
+    Code::KeyQ,
+    Code::KeyR,
+    Code::KeyS,
+    Code::KeyT,
+    Code::KeyU,
+    Code::KeyV,
+    Code::KeyW,
+    Code::KeyX,
+    Code::KeyY,
+    Code::KeyZ,
+];
+
+const DIGITS: [Code; 10] = [
+    Code::Digit0,
+    Code::Digit1,
+    Code::Digit2,
+    Code::Digit3,
+    Code::Digit4,
+    Code::Digit5,
+    Code::Digit6,
+    Code::Digit7,
+    Code::Digit8,
+    Code::Digit9,
+];
+
+// The names of the other keys, where the first name of a key is used when it's formatted.
+const KEY_NAMES: [(Code, &str); 69] = [
+    (Code::F1, "F1"),
+    (Code::F2, "F2"),
+    (Code::F3, "F3"),
+    (Code::F4, "F4"),
+    (Code::F5, "F5"),
+    (Code::F6, "F6"),
+    (Code::F7, "F7"),
+    (Code::F8, "F8"),
+    (Code::F9, "F9"),
+    (Code::F10, "F10"),
+    (Code::F11, "F11"),
+    (Code::F12, "F12"),
+    (Code::Enter, "Enter"),
+    (Code::Enter, "Return"),
+    (Code::Escape, "Escape"),
+    (Code::Escape, "Esc"),
+    (Code::Tab, "Tab"),
+    (Code::Space, "Space"),
+    (Code::Backspace, "Backspace"),
+    (Code::Delete, "Delete"),
+    (Code::Delete, "Del"),
+    (Code::Insert, "Insert"),
+    (Code::Home, "Home"),
+    (Code::End, "End"),
+    (Code::PageUp, "PageUp"),
+    (Code::PageDown, "PageDown"),
+    (Code::ArrowUp, "Up"),
+    (Code::ArrowUp, "ArrowUp"),
+    (Code::ArrowDown, "Down"),
+    (Code::ArrowDown, "ArrowDown"),
+    (Code::ArrowLeft, "Left"),
+    (Code::ArrowLeft, "ArrowLeft"),
+    (Code::ArrowRight, "Right"),
+    (Code::ArrowRight, "ArrowRight"),
+    (Code::Minus, "-"),
+    (Code::Minus, "Minus"),
+    (Code::Equal, "="),
+    (Code::Equal, "Equal"),
+    (Code::BracketLeft, "["),
+    (Code::BracketRight, "]"),
+    (Code::Backslash, "\\"),
+    (Code::Semicolon, ";"),
+    (Code::Quote, "'"),
+    (Code::Comma, ","),
+    (Code::Period, "."),
+    (Code::Slash, "/"),
+    (Code::Backquote, "`"),
+    (Code::Numpad0, "Numpad0"),
+    (Code::Numpad1, "Numpad1"),
+    (Code::Numpad2, "Numpad2"),
+    (Code::Numpad3, "Numpad3"),
+    (Code::Numpad4, "Numpad4"),
+    (Code::Numpad5, "Numpad5"),
+    (Code::Numpad6, "Numpad6"),
+    (Code::Numpad7, "Numpad7"),
+    (Code::Numpad8, "Numpad8"),
+    (Code::Numpad9, "Numpad9"),
+    (Code::NumpadAdd, "NumpadAdd"),
+    (Code::NumpadSubtract, "NumpadSubtract"),
+    (Code::NumpadMultiply, "NumpadMultiply"),
+    (Code::NumpadDivide, "NumpadDivide"),
+    (Code::NumpadDecimal, "NumpadDecimal"),
+    (Code::NumpadEnter, "NumpadEnter"),
+    (Code::CapsLock, "CapsLock"),
+    (Code::PrintScreen, "PrintScreen"),
+    (Code::ScrollLock, "ScrollLock"),
+    (Code::Pause, "Pause"),
+    (Code::ContextMenu, "ContextMenu"),
+    (Code::ContextMenu, "Menu"),
+];
+
+fn parse_modifier(token: &str) -> Option<Modifiers> {
+    Some(match token.to_ascii_lowercase().as_str() {
+        "ctrl" | "control" => Modifiers::CTRL,
+        "shift" => Modifiers::SHIFT,
+        "alt" | "option" | "opt" => Modifiers::ALT,
+        "cmd" | "command" | "super" | "meta" | "win" | "logo" => Modifiers::SUPER,
+        "cmdorctrl" | "commandorcontrol" | "mod" | "primary" => Modifiers::PRIMARY,
+        _ => return None,
+    })
+}
+
+fn parse_code(token: &str) -> Option<Code> {
+    let mut chars = token.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c.is_ascii_alphabetic() {
+            return Some(LETTERS[(c.to_ascii_uppercase() as u8 - b'A') as usize]);
+        } else if let Some(digit) = c.to_digit(10) {
+            return Some(DIGITS[digit as usize]);
+        }
+    }
+
+    KEY_NAMES.iter().find(|(_, name)| name.eq_ignore_ascii_case(token)).map(|(code, _)| *code)
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.ctrl() {
+            write!(f, "Ctrl+")?;
+        }
+
+        if self.modifiers.alt() {
+            write!(f, "{}+", if cfg!(target_os = "macos") { "Option" } else { "Alt" })?;
+        }
+
+        if self.modifiers.shift() {
+            write!(f, "Shift+")?;
+        }
+
+        if self.modifiers.logo() {
+            write!(f, "{}+", if cfg!(target_os = "macos") { "Cmd" } else { "Super" })?;
+        }
+
+        if let Some(index) = LETTERS.iter().position(|code| *code == self.code) {
+            write!(f, "{}", (b'A' + index as u8) as char)
+        } else if let Some(index) = DIGITS.iter().position(|code| *code == self.code) {
+            write!(f, "{}", index)
+        } else if let Some((_, name)) = KEY_NAMES.iter().find(|(code, _)| *code == self.code) {
+            write!(f, "{}", name)
+        } else {
+            write!(f, "{:?}", self.code)
+        }
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = ParseKeyChordError;
+
+    /// Parses a key chord from a string of `+` separated modifiers followed by a key, ignoring case and whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_input::*;
+    /// #
+    /// let key_chord: KeyChord = "Ctrl+Shift+P".parse().unwrap();
+    /// assert_eq!(key_chord, KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::KeyP));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        // A trailing `+` is the plus key, which is typed as `Shift+=`.
+        let (s, plus) = match s.strip_suffix("++").or_else(|| (s == "+").then_some("")) {
+            Some(rest) => (rest, true),
+            None => (s, false),
+        };
+
+        // A trailing `+` without a key after it.
+        if !plus && s.ends_with('+') {
+            return Err(ParseKeyChordError { token: String::new() });
+        }
+
+        let mut tokens = s.split('+').map(str::trim).filter(|token| !token.is_empty()).peekable();
+        let mut modifiers = Modifiers::empty();
+
+        while let Some(token) = tokens.next() {
+            if tokens.peek().is_none() && !plus {
+                let code = parse_code(token)
+                    .ok_or_else(|| ParseKeyChordError { token: token.to_string() })?;
+                return Ok(KeyChord::new(modifiers, code));
+            }
+
+            modifiers |= parse_modifier(token)
+                .ok_or_else(|| ParseKeyChordError { token: token.to_string() })?;
+        }
+
+        if plus {
+            Ok(KeyChord::new(modifiers | Modifiers::SHIFT, Code::Equal))
+        } else {
+            Err(ParseKeyChordError { token: String::new() })
+        }
+    }
+}
+
+/// A sequence of key chords which are pressed one after another, such as `Ctrl+K Ctrl+S`.
+///
+/// A key sequence is parsed from, and formatted as, a string of whitespace separated [`KeyChord`]s.
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeySequence(pub Vec<KeyChord>);
+
+impl KeySequence {
+    /// Creates a new key sequence from the given key chords.
+    pub fn new(chords: impl Into<Vec<KeyChord>>) -> Self {
+        Self(chords.into())
+    }
+
+    /// Returns the key chords of the sequence.
+    pub fn chords(&self) -> &[KeyChord] {
+        &self.0
+    }
+
+    /// Returns whether the sequence has no key chords.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns whether the sequence starts with the key chords of the given sequence.
+    pub fn starts_with(&self, prefix: &[KeyChord]) -> bool {
+        self.0.starts_with(prefix)
+    }
+}
+
+impl From<KeyChord> for KeySequence {
+    fn from(chord: KeyChord) -> Self {
+        Self(vec![chord])
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, chord) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+
+            write!(f, "{}", chord)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for KeySequence {
+    type Err = ParseKeyChordError;
+
+    /// Parses a key sequence from a string of whitespace separated key chords.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_input::*;
+    /// #
+    /// let key_sequence: KeySequence = "Ctrl+K Ctrl+S".parse().unwrap();
+    /// assert_eq!(key_sequence.chords()[1], KeyChord::new(Modifiers::CTRL, Code::KeyS));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Whitespace around the `+` of a chord doesn't separate chords. A chord ending with a single `+` is still
+        // missing its key, while a chord ending with `++` ends with the plus key.
+        let is_incomplete =
+            |chord: &str| chord.ends_with('+') && !chord.ends_with("++") && chord != "+";
+
+        let mut chords = Vec::<String>::new();
+        for token in s.split_whitespace() {
+            match chords.last_mut() {
+                Some(chord) if is_incomplete(chord) || token.starts_with('+') => {
+                    chord.push_str(token)
+                }
+                _ => chords.push(token.to_string()),
+            }
+        }
+
+        if chords.is_empty() {
+            return Err(ParseKeyChordError { token: String::new() });
+        }
+
+        chords.iter().map(|chord| chord.parse()).collect::<Result<Vec<_>, _>>().map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_key_chord() {
+        assert_eq!("a".parse(), Ok(KeyChord::new(Modifiers::empty(), Code::KeyA)));
+        assert_eq!(
+            "ctrl + shift + p".parse(),
+            Ok(KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::KeyP))
+        );
+        assert_eq!("Alt+F4".parse(), Ok(KeyChord::new(Modifiers::ALT, Code::F4)));
+        assert_eq!("Ctrl+/".parse(), Ok(KeyChord::new(Modifiers::CTRL, Code::Slash)));
+        assert_eq!(
+            "Ctrl++".parse(),
+            Ok(KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::Equal))
+        );
+        assert_eq!("CmdOrCtrl+S".parse(), Ok(KeyChord::new(Modifiers::PRIMARY, Code::KeyS)));
+
+        assert_eq!(
+            "Ctrl+Hyper+A".parse::<KeyChord>(),
+            Err(ParseKeyChordError { token: String::from("Hyper") })
+        );
+        assert_eq!("Ctrl+".parse::<KeyChord>(), Err(ParseKeyChordError { token: String::new() }));
+    }
+
+    #[test]
+    fn format_key_chord() {
+        for chord in ["A", "Ctrl+Shift+P", "Escape", "Ctrl+7", "F12", "Shift+Up", "Ctrl+-"] {
+            assert_eq!(chord.parse::<KeyChord>().unwrap().to_string(), chord);
+        }
+
+        let chord = KeyChord::new(Modifiers::all(), Code::KeyZ);
+        assert_eq!(chord.to_string().parse(), Ok(chord));
+    }
+
+    #[test]
+    fn key_sequence() {
+        let sequence: KeySequence = "Ctrl+K  Ctrl + S".parse().unwrap();
+        assert_eq!(
+            sequence,
+            KeySequence::new([
+                KeyChord::new(Modifiers::CTRL, Code::KeyK),
+                KeyChord::new(Modifiers::CTRL, Code::KeyS),
+            ])
+        );
+        assert_eq!(sequence.to_string(), "Ctrl+K Ctrl+S");
+        assert!(sequence.starts_with(&[KeyChord::new(Modifiers::CTRL, Code::KeyK)]));
+
+        assert_eq!(
+            "Ctrl + + Ctrl +K".parse(),
+            Ok(KeySequence::new([
+                KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::Equal),
+                KeyChord::new(Modifiers::CTRL, Code::KeyK),
+            ]))
+        );
+
+        assert!("".parse::<KeySequence>().is_err());
+        assert_eq!(
+            "Ctrl+K Ctrl +".parse::<KeySequence>(),
+            Err(ParseKeyChordError { token: String::new() })
+        );
+        assert!("Ctrl+K Nope".parse::<KeySequence>().is_err());
+    }
+}
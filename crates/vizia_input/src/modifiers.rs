@@ -12,6 +12,10 @@ bitflags! {
 }
 
 impl Modifiers {
+    /// The primary modifier of the platform used for shortcuts, which is `SUPER` (Cmd) on macOS and `CTRL` otherwise.
+    pub const PRIMARY: Modifiers =
+        if cfg!(target_os = "macos") { Modifiers::SUPER } else { Modifiers::CTRL };
+
     pub fn shift(&self) -> bool {
         self.contains(Modifiers::SHIFT)
     }
@@ -1,11 +1,12 @@
 use crate::{
-    define_property, Angle, BackgroundImage, BackgroundSize, BlendMode, Border, BorderStyle,
+    define_property, Angle, AnimationDirection, AnimationFillMode, AnimationIterationCount,
+    AnimationPlayState, BackgroundImage, BackgroundSize, BlendMode, Border, BorderStyle,
     BorderWidth, ClipPath, Color, CornerRadius, CornerShape, CursorIcon, CustomParseError,
-    CustomProperty, Display, Filter, FontFamily, FontSize, FontSlant, FontVariation, FontWeight,
-    FontWidth, LayoutType, LengthOrPercentage, LineClamp, Opacity, Outline, Overflow, Parse,
-    PointerEvents, Position, PositionType, Rect, Scale, Shadow, TextAlign, TextDecoration,
-    TextDecorationLine, TextDecorationStyle, TextOverflow, Transform, Transition, Translate, Units,
-    UnparsedProperty, Visibility,
+    CustomProperty, Display, EasingFunction, Filter, FontFamily, FontSize, FontSlant,
+    FontVariation, FontWeight, FontWidth, LayoutType, LengthOrPercentage, LineClamp, Opacity,
+    Outline, Overflow, Parse, PointerEvents, Position, PositionType, Rect, Scale, Shadow,
+    TextAlign, TextDecoration, TextDecorationLine, TextDecorationStyle, TextOverflow, Transform,
+    Transition, Translate, Units, UnparsedProperty, Visibility, WillChange,
 };
 use cssparser::Parser;
 
@@ -21,6 +22,7 @@ define_property! {
         "opacity": Opacity(Opacity),
         "z-index": ZIndex(i32),
         "blend-mode": BlendMode(BlendMode),
+        "will-change": WillChange(WillChange),
 
         // Positioning
         "layout-type": LayoutType(LayoutType),
@@ -173,11 +175,20 @@ define_property! {
         // Shadow
         "shadow": Shadow(Vec<Shadow>),
 
+        // Filter
+        "filter": Filter(Vec<Filter>),
+
         // Backdrop Filter
         "backdrop-filter": BackdropFilter(Filter),
 
         // Animations
         "transition": Transition(Vec<Transition>),
+        "transition-timing-function": TransitionTimingFunction(Vec<EasingFunction>),
+        "animation-timing-function": AnimationTimingFunction(EasingFunction),
+        "animation-iteration-count": AnimationIterationCount(AnimationIterationCount),
+        "animation-direction": AnimationDirection(AnimationDirection),
+        "animation-fill-mode": AnimationFillMode(AnimationFillMode),
+        "animation-play-state": AnimationPlayState(AnimationPlayState),
 
         // Transform
         "transform": Transform(Vec<Transform>),
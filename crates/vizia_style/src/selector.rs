@@ -106,6 +106,7 @@ impl<'a, 'i> selectors::parser::Parser<'i> for SelectorParser<'a, 'i> {
             "optional" => Optional,
             "user-valid" => UserValid,
             "user-invalid" => UserInvalid,
+            "exiting" => Exiting,
 
             _ => Custom(name.to_string())
 
@@ -316,6 +317,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_element_exiting() {
+        assert_eq!(
+            parse("foo:exiting"),
+            Ok(SelectorList::from_vec(vec![Selector::from_vec(
+                vec![
+                    Component::LocalName(LocalName {
+                        name: SelectorIdent("foo".into()),
+                        lower_name: SelectorIdent("foo".into()),
+                    }),
+                    Component::NonTSPseudoClass(PseudoClass::Exiting),
+                ],
+                specificity(0, 1, 1),
+                Default::default(),
+            )]))
+        );
+    }
+
     // TODO - Add more tests for selectors
     // TODO - Add tests for selector matching
     //   NOTE - Requires creating a dummy node for testing purposes (and also modification to selectors crate to allow properties from external store)
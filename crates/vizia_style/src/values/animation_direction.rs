@@ -0,0 +1,17 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines whether an animation plays forwards, backwards, or alternates between the two on each iteration.
+    #[derive(Default)]
+    pub enum AnimationDirection {
+        /// The animation plays forwards on each iteration.
+        #[default]
+        "normal": Normal,
+        /// The animation plays backwards on each iteration.
+        "reverse": Reverse,
+        /// The animation plays forwards on the first iteration and then alternates direction.
+        "alternate": Alternate,
+        /// The animation plays backwards on the first iteration and then alternates direction.
+        "alternate-reverse": AlternateReverse,
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines whether the values of an animation are applied before it starts and after it ends.
+    #[derive(Default)]
+    pub enum AnimationFillMode {
+        /// The animation does not affect the entity before it starts or after it ends.
+        #[default]
+        "none": None,
+        /// The entity keeps the values of the last keyframe after the animation ends.
+        "forwards": Forwards,
+        /// The entity takes the values of the first keyframe during the animation delay.
+        "backwards": Backwards,
+        /// Applies both forwards and backwards fill.
+        "both": Both,
+    }
+}
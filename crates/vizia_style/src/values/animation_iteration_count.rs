@@ -0,0 +1,78 @@
+use crate::{CustomParseError, Parse};
+use cssparser::*;
+
+/// The number of times an animation plays before stopping.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationIterationCount {
+    /// The animation plays the given number of times. Fractional values play part of the final iteration.
+    Count(f32),
+    /// The animation repeats forever.
+    Infinite,
+}
+
+impl Default for AnimationIterationCount {
+    fn default() -> Self {
+        AnimationIterationCount::Count(1.0)
+    }
+}
+
+impl AnimationIterationCount {
+    /// Returns the number of iterations, where an infinite count is `f32::INFINITY`.
+    pub fn count(&self) -> f32 {
+        match self {
+            AnimationIterationCount::Count(count) => *count,
+            AnimationIterationCount::Infinite => f32::INFINITY,
+        }
+    }
+}
+
+impl<'i> Parse<'i> for AnimationIterationCount {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("infinite")).is_ok() {
+            return Ok(AnimationIterationCount::Infinite);
+        }
+
+        let location = input.current_source_location();
+        let count = input.expect_number()?;
+        if count < 0.0 {
+            return Err(location.new_custom_error(CustomParseError::InvalidValue));
+        }
+
+        Ok(AnimationIterationCount::Count(count))
+    }
+}
+
+impl From<f32> for AnimationIterationCount {
+    fn from(count: f32) -> Self {
+        AnimationIterationCount::Count(count)
+    }
+}
+
+impl From<u32> for AnimationIterationCount {
+    fn from(count: u32) -> Self {
+        AnimationIterationCount::Count(count as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        AnimationIterationCount, assert_animation_iteration_count,
+
+        custom {
+            success {
+                "infinite" => AnimationIterationCount::Infinite,
+                "3" => AnimationIterationCount::Count(3.0),
+                "0.5" => AnimationIterationCount::Count(0.5),
+            }
+
+            failure {
+                "-1",
+                "forever",
+            }
+        }
+    }
+}
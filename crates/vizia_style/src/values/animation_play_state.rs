@@ -0,0 +1,23 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines whether an animation is running or paused.
+    #[derive(Default)]
+    pub enum AnimationPlayState {
+        /// The animation is playing.
+        #[default]
+        "running": Running,
+        /// The animation is paused.
+        "paused": Paused,
+    }
+}
+
+impl From<bool> for AnimationPlayState {
+    fn from(boolean: bool) -> Self {
+        if boolean {
+            AnimationPlayState::Running
+        } else {
+            AnimationPlayState::Paused
+        }
+    }
+}
@@ -1,6 +1,34 @@
 use crate::{CustomParseError, Parse};
 use cssparser::*;
 
+/// Determines when the jumps of a `steps()` easing function occur.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StepPosition {
+    /// The first jump happens when the animation begins.
+    Start,
+    /// The last jump happens when the animation ends.
+    #[default]
+    End,
+    /// There is no jump at the beginning or the end of the animation.
+    JumpNone,
+    /// There is a jump at both the beginning and the end of the animation.
+    JumpBoth,
+}
+
+impl<'i> Parse<'i> for StepPosition {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        let ident = input.expect_ident()?;
+        Ok(match_ignore_ascii_case! { &ident,
+            "start" | "jump-start" => StepPosition::Start,
+            "end" | "jump-end" => StepPosition::End,
+            "jump-none" => StepPosition::JumpNone,
+            "jump-both" => StepPosition::JumpBoth,
+            _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+        })
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum EasingFunction {
     #[default]
@@ -10,7 +38,9 @@ pub enum EasingFunction {
     EaseOut,
     EaseInOut,
     CubicBezier(f32, f32, f32, f32),
-    // TODO: Steps
+    Steps(u32, StepPosition),
+    /// A damped spring with a stiffness, damping, and mass.
+    Spring(f32, f32, f32),
 }
 
 impl<'i> Parse<'i> for EasingFunction {
@@ -23,8 +53,8 @@ impl<'i> Parse<'i> for EasingFunction {
               "ease-in" => EasingFunction::EaseIn,
               "ease-out" => EasingFunction::EaseOut,
               "ease-in-out" => EasingFunction::EaseInOut,
-            //   "step-start" => EasingFunction::Steps { count: 1, position: StepPosition::Start },
-            //   "step-end" => EasingFunction::Steps { count: 1, position: StepPosition::End },
+              "step-start" => EasingFunction::Steps(1, StepPosition::Start),
+              "step-end" => EasingFunction::Steps(1, StepPosition::End),
               _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
             };
             return Ok(keyword);
@@ -44,16 +74,68 @@ impl<'i> Parse<'i> for EasingFunction {
                 let y2 = input.try_parse(|input| input.expect_number())?;
                 Ok(EasingFunction::CubicBezier(x1, y1, x2, y2))
               },
-            //   "steps" => {
-            //     let count = CSSInteger::parse(input)?;
-            //     let position = input.try_parse(|input| {
-            //       input.expect_comma()?;
-            //       StepPosition::parse(input)
-            //     }).unwrap_or_default();
-            //     Ok(EasingFunction::Steps { count, position })
-            //   },
+              "steps" => {
+                let count_location = input.current_source_location();
+                let count = input.expect_integer()?;
+                let position = input.try_parse(|input| {
+                  input.expect_comma()?;
+                  StepPosition::parse(input)
+                }).unwrap_or_default();
+                // `jump-none` removes the jumps at both ends, so it requires at least two steps.
+                let min_count = if position == StepPosition::JumpNone { 2 } else { 1 };
+                if count < min_count {
+                    return Err(count_location.new_custom_error(CustomParseError::InvalidValue));
+                }
+                Ok(EasingFunction::Steps(count as u32, position))
+              },
+              "spring" => {
+                let stiffness = input.expect_number()?;
+                input.expect_comma()?;
+                let damping = input.expect_number()?;
+                let mass = input.try_parse(|input| {
+                    input.expect_comma()?;
+                    input.expect_number()
+                }).unwrap_or(1.0);
+                Ok(EasingFunction::Spring(stiffness, damping, mass))
+              },
               _ => return Err(location.new_unexpected_token_error(Token::Ident(function.clone())))
             }
         })
     }
 }
+
+impl<'i> Parse<'i> for Vec<EasingFunction> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(EasingFunction::parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        EasingFunction, assert_easing_function,
+
+        custom {
+            success {
+                "ease-in" => EasingFunction::EaseIn,
+                "step-start" => EasingFunction::Steps(1, StepPosition::Start),
+                "steps(4)" => EasingFunction::Steps(4, StepPosition::End),
+                "steps(3, jump-both)" => EasingFunction::Steps(3, StepPosition::JumpBoth),
+                "steps(2, jump-none)" => EasingFunction::Steps(2, StepPosition::JumpNone),
+                "cubic-bezier(0.1, 0.2, 0.3, 0.4)" => EasingFunction::CubicBezier(0.1, 0.2, 0.3, 0.4),
+                "spring(100, 10)" => EasingFunction::Spring(100.0, 10.0, 1.0),
+                "spring(170, 26, 2)" => EasingFunction::Spring(170.0, 26.0, 2.0),
+            }
+
+            failure {
+                "steps(0)",
+                "steps(1, jump-none)",
+                "steps(2, middle)",
+                "spring(100)",
+            }
+        }
+    }
+}
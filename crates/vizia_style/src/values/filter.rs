@@ -0,0 +1,236 @@
+use crate::{Angle, Color, CustomParseError, Length, Parse, PercentageOrNumber};
+use cssparser::*;
+
+/// A graphical effect applied to a view by the `filter` property, or to the area behind a view by
+/// the `backdrop-filter` property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    /// Applies a gaussian blur with the given radius.
+    Blur(Length),
+    /// Scales the brightness, where 0.0 is black and 1.0 is unchanged.
+    Brightness(f32),
+    /// Scales the contrast, where 0.0 is completely gray and 1.0 is unchanged.
+    Contrast(f32),
+    /// Converts to grayscale, where 1.0 is completely grayscale and 0.0 is unchanged.
+    Grayscale(f32),
+    /// Rotates the hue by the given angle.
+    HueRotate(Angle),
+    /// Inverts the colors, where 1.0 is completely inverted and 0.0 is unchanged.
+    Invert(f32),
+    /// Scales the saturation, where 0.0 is completely unsaturated and 1.0 is unchanged.
+    Saturate(f32),
+    /// Converts to sepia, where 1.0 is completely sepia and 0.0 is unchanged.
+    Sepia(f32),
+    /// Draws a blurred and offset shadow of the alpha mask of the view.
+    DropShadow(DropShadow),
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter::Blur(Length::default())
+    }
+}
+
+impl Filter {
+    /// Returns a filter of the same kind which leaves its input unchanged.
+    pub fn identity(&self) -> Self {
+        match self {
+            Filter::Blur(_) => Filter::Blur(Length::px(0.0)),
+            Filter::Brightness(_) => Filter::Brightness(1.0),
+            Filter::Contrast(_) => Filter::Contrast(1.0),
+            Filter::Grayscale(_) => Filter::Grayscale(0.0),
+            Filter::HueRotate(_) => Filter::HueRotate(Angle::Deg(0.0)),
+            Filter::Invert(_) => Filter::Invert(0.0),
+            Filter::Saturate(_) => Filter::Saturate(1.0),
+            Filter::Sepia(_) => Filter::Sepia(0.0),
+            Filter::DropShadow(shadow) => {
+                let color = shadow.color.unwrap_or(Color::rgb(0, 0, 0));
+                Filter::DropShadow(DropShadow::new(
+                    Length::px(0.0),
+                    Length::px(0.0),
+                    None,
+                    Some(Color::rgba(color.r(), color.g(), color.b(), 0)),
+                ))
+            }
+        }
+    }
+
+    /// Returns true if both filters are the same kind of filter function.
+    pub fn is_same_kind(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+/// The parameters of a `drop-shadow()` filter function.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DropShadow {
+    /// The horizontal offset of the shadow.
+    pub x_offset: Length,
+    /// The vertical offset of the shadow.
+    pub y_offset: Length,
+    /// The blur radius of the shadow.
+    pub blur_radius: Option<Length>,
+    /// The color of the shadow. Defaults to black.
+    pub color: Option<Color>,
+}
+
+impl DropShadow {
+    /// Creates a new drop shadow.
+    pub fn new(
+        x_offset: impl Into<Length>,
+        y_offset: impl Into<Length>,
+        blur_radius: Option<Length>,
+        color: Option<Color>,
+    ) -> Self {
+        Self { x_offset: x_offset.into(), y_offset: y_offset.into(), blur_radius, color }
+    }
+}
+
+impl<'i> Parse<'i> for DropShadow {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let mut color = input.try_parse(Color::parse).ok();
+        let x_offset = Length::parse(input)?;
+        let y_offset = Length::parse(input)?;
+        let blur_radius = input.try_parse(Length::parse).ok();
+        if color.is_none() {
+            color = input.try_parse(Color::parse).ok();
+        }
+
+        Ok(DropShadow::new(x_offset, y_offset, blur_radius, color))
+    }
+}
+
+// Parses the optional number or percentage argument of a filter function.
+fn parse_amount<'i>(
+    input: &mut Parser<'i, '_>,
+    default: f32,
+    max: Option<f32>,
+) -> Result<f32, ParseError<'i, CustomParseError<'i>>> {
+    let location = input.current_source_location();
+    let Ok(amount) = input.try_parse(PercentageOrNumber::parse) else {
+        return Ok(default);
+    };
+
+    let amount = amount.to_factor();
+    if amount < 0.0 {
+        return Err(location.new_custom_error(CustomParseError::InvalidValue));
+    }
+
+    Ok(max.map_or(amount, |max| amount.min(max)))
+}
+
+impl<'i> Parse<'i> for Filter {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let function = input.expect_function()?.clone();
+
+        input.parse_nested_block(|input| {
+            let location = input.current_source_location();
+            match_ignore_ascii_case! { &function,
+                "blur" => {
+                    Ok(Filter::Blur(input.try_parse(Length::parse).unwrap_or(Length::px(0.0))))
+                },
+
+                "brightness" => Ok(Filter::Brightness(parse_amount(input, 1.0, None)?)),
+                "contrast" => Ok(Filter::Contrast(parse_amount(input, 1.0, None)?)),
+                "grayscale" => Ok(Filter::Grayscale(parse_amount(input, 1.0, Some(1.0))?)),
+                "invert" => Ok(Filter::Invert(parse_amount(input, 1.0, Some(1.0))?)),
+                "saturate" => Ok(Filter::Saturate(parse_amount(input, 1.0, None)?)),
+                "sepia" => Ok(Filter::Sepia(parse_amount(input, 1.0, Some(1.0))?)),
+
+                "hue-rotate" => {
+                    Ok(Filter::HueRotate(input.try_parse(Angle::parse).unwrap_or(Angle::Deg(0.0))))
+                },
+
+                "drop-shadow" => Ok(Filter::DropShadow(DropShadow::parse(input)?)),
+
+                _ => {
+                    Err(location.new_unexpected_token_error(Token::Ident(function)))
+                }
+            }
+        })
+    }
+}
+
+impl<'i> Parse<'i> for Vec<Filter> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+            return Ok(Vec::new());
+        }
+
+        let mut results = vec![Filter::parse(input)?];
+        loop {
+            if input.is_exhausted() {
+                return Ok(results);
+            }
+
+            input.skip_whitespace();
+            let location = input.current_source_location();
+
+            if let Ok(filter) = input.try_parse(Filter::parse) {
+                results.push(filter);
+            } else {
+                return Err(cssparser::ParseError {
+                    kind: cssparser::ParseErrorKind::Custom(CustomParseError::InvalidDeclaration),
+                    location,
+                });
+            }
+        }
+    }
+}
+
+impl From<Filter> for Vec<Filter> {
+    fn from(value: Filter) -> Self {
+        vec![value]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Filter, assert_filter,
+
+        custom {
+            success {
+                "blur(4px)" => Filter::Blur(Length::px(4.0)),
+                "blur()" => Filter::Blur(Length::px(0.0)),
+                "brightness(50%)" => Filter::Brightness(0.5),
+                "contrast(2)" => Filter::Contrast(2.0),
+                "grayscale()" => Filter::Grayscale(1.0),
+                "grayscale(150%)" => Filter::Grayscale(1.0),
+                "hue-rotate(90deg)" => Filter::HueRotate(Angle::Deg(90.0)),
+                "invert(0.25)" => Filter::Invert(0.25),
+                "saturate(300%)" => Filter::Saturate(3.0),
+                "sepia(1)" => Filter::Sepia(1.0),
+                "drop-shadow(2px 4px)" => Filter::DropShadow(DropShadow::new(Length::px(2.0), Length::px(4.0), None, None)),
+                "drop-shadow(2px 4px 6px red)" => Filter::DropShadow(DropShadow::new(Length::px(2.0), Length::px(4.0), Some(Length::px(6.0)), Some(Color::rgb(255, 0, 0)))),
+                "drop-shadow(red 2px 4px)" => Filter::DropShadow(DropShadow::new(Length::px(2.0), Length::px(4.0), None, Some(Color::rgb(255, 0, 0)))),
+            }
+
+            failure {
+                "brightness(-1)",
+                "drop-shadow(2px)",
+                "sharpen(2)",
+                "blur",
+            }
+        }
+    }
+
+    assert_parse! {
+        Vec<Filter>, assert_vec_filter,
+
+        custom {
+            success {
+                "none" => Vec::new(),
+                "grayscale(1) blur(2px)" => vec![Filter::Grayscale(1.0), Filter::Blur(Length::px(2.0))],
+            }
+
+            failure {
+                "grayscale(1), blur(2px)",
+                "grayscale(1) none",
+            }
+        }
+    }
+}
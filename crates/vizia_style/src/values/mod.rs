@@ -1,6 +1,9 @@
 pub mod alpha;
 pub mod angle;
-pub mod backdrop_filter;
+pub mod animation_direction;
+pub mod animation_fill_mode;
+pub mod animation_iteration_count;
+pub mod animation_play_state;
 pub mod background_size;
 pub mod basic;
 pub mod blend_mode;
@@ -15,6 +18,7 @@ pub mod direction;
 pub mod display;
 pub mod duration;
 pub mod easing;
+pub mod filter;
 pub mod font_family;
 pub mod font_size;
 pub mod font_size_keyword;
@@ -54,10 +58,14 @@ pub mod units;
 pub mod url;
 pub mod vertical_position_keyword;
 pub mod visibility;
+pub mod will_change;
 
 pub use alpha::*;
 pub use angle::*;
-pub use backdrop_filter::*;
+pub use animation_direction::*;
+pub use animation_fill_mode::*;
+pub use animation_iteration_count::*;
+pub use animation_play_state::*;
 pub use background_size::*;
 pub use basic::*;
 pub use blend_mode::*;
@@ -72,6 +80,7 @@ pub use direction::*;
 pub use display::*;
 pub use duration::*;
 pub use easing::*;
+pub use filter::*;
 pub use font_family::*;
 pub use font_size::*;
 pub use font_size_keyword::*;
@@ -111,3 +120,4 @@ pub use units::*;
 pub use url::*;
 pub use vertical_position_keyword::*;
 pub use visibility::*;
+pub use will_change::*;
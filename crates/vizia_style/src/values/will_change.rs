@@ -0,0 +1,86 @@
+use crate::{CustomParseError, Parse};
+use cssparser::*;
+
+/// Hints which properties of a view are expected to change.
+///
+/// Any value other than `auto` promotes the view to its own render layer. The view and its
+/// descendants are rendered into an offscreen surface which is reused until the subtree changes,
+/// so that changes to the transform or opacity of the view only need to composite the cached layer.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum WillChange {
+    /// The view is rendered directly without a render layer.
+    #[default]
+    Auto,
+    /// The view is rendered into a render layer. The names of the properties expected to change.
+    Properties(Vec<String>),
+}
+
+impl WillChange {
+    /// Returns true if the view should be rendered into its own render layer.
+    pub fn creates_layer(&self) -> bool {
+        !matches!(self, WillChange::Auto)
+    }
+}
+
+impl<'i> Parse<'i> for WillChange {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+            return Ok(WillChange::Auto);
+        }
+
+        let properties = input.parse_comma_separated(|input| {
+            let location = input.current_source_location();
+            let ident = input.expect_ident()?;
+            match_ignore_ascii_case! { &ident,
+                "auto" | "none" | "all" | "will-change" => {
+                    Err(location.new_custom_error(CustomParseError::InvalidValue))
+                },
+                _ => Ok(ident.to_ascii_lowercase()),
+            }
+        })?;
+
+        Ok(WillChange::Properties(properties))
+    }
+}
+
+impl From<bool> for WillChange {
+    fn from(boolean: bool) -> Self {
+        if boolean {
+            WillChange::Properties(vec![String::from("contents")])
+        } else {
+            WillChange::Auto
+        }
+    }
+}
+
+impl From<&str> for WillChange {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        WillChange::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        WillChange, assert_will_change,
+
+        custom {
+            success {
+                "auto" => WillChange::Auto,
+                "transform" => WillChange::Properties(vec![String::from("transform")]),
+                "Transform, opacity" => WillChange::Properties(vec![String::from("transform"), String::from("opacity")]),
+            }
+
+            failure {
+                "none",
+                "transform, auto",
+                "10px",
+            }
+        }
+    }
+}
@@ -561,7 +561,9 @@ impl ApplicationHandler<UserEvent> for Application {
                 .expect("Failed to send event");
         }
 
-        if self.cx.0.windows.iter().any(|(_, window_state)| !window_state.redraw_list.is_empty()) {
+        if self.cx.0.windows.iter().any(|(_, window_state)| {
+            !window_state.redraw_list.is_empty() || !window_state.recomposite_list.is_empty()
+        }) {
             for window in self.windows.values() {
                 window.window().request_redraw();
             }
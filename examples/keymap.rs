@@ -11,6 +11,9 @@
 //! `ALT+SHIFT+X`           => `Action::OnAltShiftX`
 //! `CTRL+ALT+SHIFT+Y`      => `Action::OnCtrlAltShiftY`
 //! `CTRL+ALT+SHIFT+LOGO+Z` => `Action::OnCtrlAltShiftLogoZ`
+//!
+//! The key chords can be changed with the keybinding editor, and are saved to `keybindings.txt`.
+//! The actions can also be found and executed from the command palette, opened with `CTRL+SHIFT+P`.
 
 use log::debug;
 use vizia::prelude::*;
@@ -18,7 +21,7 @@ use vizia::prelude::*;
 fn main() -> Result<(), ApplicationError> {
     Application::new(|cx| {
         // Build the keymap.
-        Keymap::from(vec![
+        let mut keymap = Keymap::from(vec![
             (
                 KeyChord::new(Modifiers::empty(), Code::KeyA),
                 KeymapEntry::new(Action::OnA, |_| debug!("Action A")),
@@ -65,14 +68,42 @@ fn main() -> Result<(), ApplicationError> {
                 }),
             ),
         ])
-        .build(cx);
+        .on_change(|_, keymap| {
+            if let Err(err) = std::fs::write(CONFIG_PATH, keymap.to_config()) {
+                debug!("Failed to save the keybindings: {}", err);
+            }
+        });
+
+        // Load the keybindings which were changed by the user.
+        if let Ok(config) = std::fs::read_to_string(CONFIG_PATH) {
+            if let Err(err) = keymap.load_config(&config) {
+                debug!("Failed to load the keybindings: {}", err);
+            }
+        }
+
+        // Every action of the keymap can also be executed from the command palette.
+        let commands =
+            keymap.export().into_iter().map(|(_, entry)| Command::from(*entry)).collect::<Vec<_>>();
+
+        keymap.build(cx);
+
+        CommandPalette::new(cx).keymap(Keymap::<Action>::bindings);
+        for command in commands {
+            command.register(cx, true);
+        }
+
+        ScrollView::new(cx, 0.0, 0.0, false, true, |cx| {
+            KeybindingEditor::new(cx, Keymap::<Action>::bindings).child_space(Pixels(16.0));
+        });
     })
     .title("Keymap")
     .run()
 }
 
+const CONFIG_PATH: &str = "keybindings.txt";
+
 // The actions that are associated with the key chords.
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Copy, Clone, Data)]
 enum Action {
     OnA,
     OnB,
@@ -85,3 +116,9 @@ enum Action {
     OnCtrlAltShiftY,
     OnCtrlAltShiftLogoZ,
 }
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
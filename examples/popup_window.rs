@@ -1,10 +1,5 @@
 pub use vizia::prelude::*;
 
-#[cfg(feature = "baseview")]
-fn main() {
-    panic!("This example is not supported on baseview");
-}
-
 #[derive(Lens)]
 struct AppData {
     color: Color,
@@ -37,7 +32,6 @@ pub enum AppEvent {
     SetBlue(f32),
 }
 
-#[cfg(not(feature = "baseview"))]
 fn main() -> Result<(), ApplicationError> {
     Application::new(|cx| {
         AppData { color: Color::white(), show_popup: false }.build(cx);
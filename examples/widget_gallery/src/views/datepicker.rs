@@ -5,10 +5,13 @@ use vizia::prelude::*;
 #[derive(Clone, Lens)]
 struct DatepickerState {
     date: NaiveDate,
+    check_in: NaiveDate,
+    check_out: NaiveDate,
 }
 
 pub enum DatepickerEvent {
     SetDate(NaiveDate),
+    SetStay(NaiveDate, NaiveDate),
 }
 
 impl Model for DatepickerState {
@@ -17,13 +20,19 @@ impl Model for DatepickerState {
             DatepickerEvent::SetDate(date) => {
                 self.date = *date;
             }
+
+            DatepickerEvent::SetStay(check_in, check_out) => {
+                self.check_in = *check_in;
+                self.check_out = *check_out;
+            }
         });
     }
 }
 
 pub fn datepicker(cx: &mut Context) {
     VStack::new(cx, |cx| {
-        DatepickerState { date: Utc::now().date_naive() }.build(cx);
+        let today = Utc::now().date_naive();
+        DatepickerState { date: today, check_in: today, check_out: today }.build(cx);
 
         Label::new(cx, "Datepicker").class("title");
         Label::new(cx, "").class("paragraph");
@@ -41,6 +50,24 @@ pub fn datepicker(cx: &mut Context) {
             r#"Datepicker::new(cx, DatepickerState::date)
     .on_select(|cx, date| cx.emit(DatepickerEvent::SetDate(date)));"#,
         );
+
+        Label::new(cx, "Range datepicker").class("header");
+
+        DemoRegion::new(
+            cx,
+            move |cx| {
+                Datepicker::range(cx, DatepickerState::check_in, DatepickerState::check_out)
+                    .min_date(today)
+                    .show_week_numbers(true)
+                    .on_select_range(|cx, start, end| {
+                        cx.emit(DatepickerEvent::SetStay(start, end))
+                    });
+            },
+            r#"Datepicker::range(cx, DatepickerState::check_in, DatepickerState::check_out)
+    .min_date(today)
+    .show_week_numbers(true)
+    .on_select_range(|cx, start, end| cx.emit(DatepickerEvent::SetStay(start, end)));"#,
+        );
     })
     .class("panel");
 }
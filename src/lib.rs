@@ -25,5 +25,7 @@ pub mod prelude {
     };
 
     #[cfg(all(not(feature = "winit"), feature = "baseview"))]
-    pub use vizia_baseview::{Application, ApplicationError, WindowHandle, WindowScalePolicy};
+    pub use vizia_baseview::{
+        Application, ApplicationError, Window, WindowHandle, WindowModifiers, WindowScalePolicy,
+    };
 }